@@ -68,6 +68,27 @@ impl AnimationState {
         self.active &= !FLAG_SPINNER;
     }
 
+    /// True while the scanning/connecting spinner is running
+    pub fn spinner_active(&self) -> bool {
+        self.active & FLAG_SPINNER != 0
+    }
+
+    /// True while the dialog slide-in is still moving
+    pub fn dialog_sliding(&self) -> bool {
+        self.active & FLAG_DIALOG_SLIDE != 0
+    }
+
+    /// True on ticks where a frame-based animation with the given period
+    /// (in ticks) advances to its next frame
+    pub fn frame_advanced(&self, period: u64) -> bool {
+        matches!(self.tick_count % period, 0)
+    }
+
+    /// True on ticks where the blinking cursor toggles
+    pub fn cursor_toggled(&self) -> bool {
+        matches!(self.tick_count % 20, 0 | 14)
+    }
+
     /// Check if cursor should be visible (blink effect)
     pub fn cursor_visible(&self) -> bool {
         // 70% duty cycle: visible for 14 out of 20 ticks
@@ -3,16 +3,24 @@ use crate::network::types::WiFiNetwork;
 
 /// Smooth signal strength display values toward their actual values.
 /// Call this every tick for each visible network.
-pub fn smooth_signals(networks: &mut [WiFiNetwork], factor: f32) {
+///
+/// Returns true if any rendered value changed (rounded signal or fade-in
+/// state), so the caller can skip redraws once everything has settled.
+pub fn smooth_signals(networks: &mut [WiFiNetwork], factor: f32) -> bool {
+    let mut changed = false;
     for net in networks.iter_mut() {
         let target = net.signal_strength as f32;
+        let before = net.display_signal.round();
         net.display_signal = ease_out(net.display_signal, target, factor);
+        changed |= net.display_signal.round() != before;
 
         // Increment seen ticks for fade-in (cap at 60 to avoid overflow)
         if net.seen_ticks < 60 {
+            changed |= fade_in_opacity(net.seen_ticks) < 1.0;
             net.seen_ticks = net.seen_ticks.saturating_add(1);
         }
     }
+    changed
 }
 
 /// Calculate opacity (0.0 - 1.0) for a newly discovered network based on seen_ticks.
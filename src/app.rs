@@ -1,12 +1,33 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, VecDeque};
+use std::time::{Duration, Instant};
+
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use tokio::sync::mpsc;
+use tracing::warn;
 
 use crate::animation::AnimationState;
 use crate::animation::transitions::smooth_signals;
-use crate::config::Config;
+use crate::bandwidth::BandwidthLog;
+use crate::config::{Config, InterfaceColumns, WifiColumns};
 use crate::event::{Event, NetworkCommand};
+use crate::favorites::Favorites;
+use crate::i18n::Language;
+use crate::ignore::IgnoreList;
+use crate::labels::Labels;
+use crate::network::diagnostics::{
+    DiagnosticReport, HeaderLine, MtuProbeLine, PUBLIC_IP, PingSample, PingStats,
+    default_header_url,
+};
+use crate::network::health::Health;
+use crate::network::routes::carries_default_route;
+use crate::network::stats::{InterfaceStats, StatsPoller, StatsSort, TimeRange, TrafficHistory};
+use crate::network::survey::Survey;
 use crate::network::types::*;
+use crate::palette::{Action, Hit};
+use crate::prometheus::TextfileExporter;
 use crate::ui::theme::Theme;
+use crate::view_state::ViewState;
 
 /// Application mode / state machine
 #[derive(Debug, Clone)]
@@ -15,8 +36,13 @@ pub enum AppMode {
     Normal,
     /// Scan in progress
     Scanning,
-    /// Password input dialog (for the given SSID)
-    PasswordInput { ssid: String },
+    /// Password input dialog for the given SSID, with its security and the
+    /// AP to activate on as they were when the dialog opened
+    PasswordInput {
+        ssid: String,
+        security: SecurityType,
+        ap_path: String,
+    },
     /// Connecting to a network
     Connecting,
     /// Disconnecting
@@ -27,10 +53,674 @@ pub enum AppMode {
     Help,
     /// Inline search / filter mode
     Search,
+    /// Single-line text input dialog
+    Input(InputKind),
+    /// Yes/no confirmation for a potentially disruptive action
+    Confirm(ConfirmAction),
+    /// Internet diagnostic report (running until `App::diagnostics` is set)
+    Diagnostics,
+    /// Response headers of a URL and its redirects, streamed in
+    HttpHeaders,
+    /// Path MTU search towards a host, streamed in
+    MtuProbe,
+    /// Several hosts pinged side by side
+    PingCompare,
+    /// Advanced settings of the active connection's profile
+    ProfileEdit,
+    /// nmcli command and keyfile path of the active profile
+    ShellEscape,
+    /// Continuous scanning with per-AP signal statistics
+    Survey,
+    /// Live log of NetworkManager device / connection state changes
+    Events,
+    /// Counters and traffic history of every interface
+    Statistics,
+    /// Jump to a view or run an action by typing part of its name
+    Palette,
+    /// Actions for the selected network
+    QuickActions,
+    /// Saved networks that connected most recently
+    Recent,
+    /// SSID patterns left out of the network list
+    IgnoreList,
+    /// Swatches of every color in the running theme
+    ThemePreview,
+    /// WiFi adapters to switch between
+    WifiDevices,
+    /// Fuzzy search over networks, interfaces and actions at once
+    GlobalSearch,
+    /// Cursor in the detail panel for copying single values
+    DetailFocus,
     /// Error dialog
     Error(String),
 }
 
+/// One of the diagnostics tools, each keeping the output of its last run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagTool {
+    Report,
+    HttpHeaders,
+    MtuProbe,
+    PingCompare,
+}
+
+impl DiagTool {
+    const ALL: [Self; 4] = [
+        Self::Report,
+        Self::HttpHeaders,
+        Self::MtuProbe,
+        Self::PingCompare,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Report => "Diagnostics",
+            Self::HttpHeaders => "HTTP headers",
+            Self::MtuProbe => "Path MTU",
+            Self::PingCompare => "Ping comparison",
+        }
+    }
+
+    fn mode(self) -> AppMode {
+        match self {
+            Self::Report => AppMode::Diagnostics,
+            Self::HttpHeaders => AppMode::HttpHeaders,
+            Self::MtuProbe => AppMode::MtuProbe,
+            Self::PingCompare => AppMode::PingCompare,
+        }
+    }
+
+    fn of(mode: &AppMode) -> Option<Self> {
+        match mode {
+            AppMode::Diagnostics => Some(Self::Report),
+            AppMode::HttpHeaders => Some(Self::HttpHeaders),
+            AppMode::MtuProbe => Some(Self::MtuProbe),
+            AppMode::PingCompare => Some(Self::PingCompare),
+            _ => None,
+        }
+    }
+}
+
+/// One row of the quick actions menu
+#[derive(Debug, Clone, Copy)]
+pub struct QuickAction {
+    pub action: Action,
+    pub label: &'static str,
+    /// Why the action can't run on the selection, if it can't
+    pub unavailable: Option<&'static str>,
+}
+
+/// A full-screen page, navigable by number and `[` / `]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    Networks,
+    Statistics,
+    Survey,
+}
+
+impl View {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Networks => "Networks",
+            Self::Statistics => "Statistics",
+            Self::Survey => "Survey",
+        }
+    }
+
+    /// Parse one `[layout] pages` entry
+    fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "networks" | "wifi" => Some(Self::Networks),
+            "statistics" | "stats" => Some(Self::Statistics),
+            "survey" => Some(Self::Survey),
+            _ => None,
+        }
+    }
+
+    /// Resolve `[layout] pages` into the page order, alongside the names that
+    /// weren't recognized. The network list is the home page every view
+    /// returns to, so it's kept (first) even when left out.
+    pub fn resolve(names: &[String]) -> (Vec<Self>, Vec<String>) {
+        let mut pages = Vec::new();
+        let mut unknown = Vec::new();
+        for name in names {
+            match Self::from_name(name) {
+                Some(view) if !pages.contains(&view) => pages.push(view),
+                Some(_) => {}
+                None => unknown.push(name.clone()),
+            }
+        }
+        if pages.is_empty() {
+            pages = vec![Self::Networks, Self::Statistics, Self::Survey];
+        } else if !pages.contains(&Self::Networks) {
+            pages.insert(0, Self::Networks);
+        }
+        (pages, unknown)
+    }
+}
+
+/// What a single-line input dialog is collecting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Mtu,
+    DhcpHostname,
+    DhcpClientId,
+    RouteMetric,
+    AutoconnectRetries,
+    PacUrl,
+    HeaderUrl,
+    MtuTarget,
+    PingTargets,
+    Nickname,
+    Note,
+    IgnorePattern,
+}
+
+impl InputKind {
+    /// Dialog title
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Mtu => "Set MTU",
+            Self::DhcpHostname => "DHCP Hostname",
+            Self::DhcpClientId => "DHCP Client ID",
+            Self::RouteMetric => "Route Metric",
+            Self::AutoconnectRetries => "Autoconnect Retries",
+            Self::PacUrl => "Proxy PAC URL",
+            Self::HeaderUrl => "Inspect HTTP Headers",
+            Self::MtuTarget => "Path MTU Probe",
+            Self::PingTargets => "Compare Ping",
+            Self::Nickname => "Nickname",
+            Self::Note => "Note",
+            Self::IgnorePattern => "Ignore Networks",
+        }
+    }
+
+    /// Field label shown before the input
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Mtu => "MTU",
+            Self::DhcpHostname => "Hostname",
+            Self::DhcpClientId => "Client ID",
+            Self::RouteMetric => "Metric",
+            Self::AutoconnectRetries => "Retries",
+            Self::PacUrl | Self::HeaderUrl => "URL",
+            Self::MtuTarget => "Host",
+            Self::PingTargets => "Hosts",
+            Self::Nickname => "Nickname",
+            Self::Note => "Note",
+            Self::IgnorePattern => "SSID",
+        }
+    }
+
+    /// Whether the input edits a row of the connection settings dialog
+    pub fn edits_profile(self) -> bool {
+        matches!(
+            self,
+            Self::DhcpHostname
+                | Self::DhcpClientId
+                | Self::RouteMetric
+                | Self::AutoconnectRetries
+                | Self::PacUrl
+        )
+    }
+
+    /// Whether the input edits a label from the detail panel
+    pub fn edits_label(self) -> bool {
+        matches!(self, Self::Nickname | Self::Note)
+    }
+
+    /// Dim helper line under the field
+    pub fn hint(self) -> &'static str {
+        match self {
+            Self::Mtu => "(68–9000 bytes, 0 = automatic)",
+            Self::DhcpHostname => "(empty = system hostname)",
+            Self::DhcpClientId => "(mac, duid, stable, hex bytes; empty = default)",
+            Self::RouteMetric => "(lower = preferred route; empty = default, 600 for WiFi)",
+            Self::AutoconnectRetries => "(0 = retry forever; empty or -1 = default, usually 4)",
+            Self::PacUrl => "(http://, https:// or file:// URL; empty = discover via WPAD)",
+            Self::HeaderUrl => "(http:// shows what a portal or proxy injects)",
+            Self::MtuTarget => "(IPv4 host or address to probe towards)",
+            Self::PingTargets => "(comma-separated; \"gateway\" = the default gateway)",
+            Self::Nickname => "(shown after the SSID, found by /; empty = remove)",
+            Self::Note => "(shown in the detail panel; empty = remove)",
+            Self::IgnorePattern => "(* = any text, ? = one character, e.g. DIRECT-*)",
+        }
+    }
+}
+
+/// An action waiting on the user's confirmation
+#[derive(Debug, Clone)]
+pub enum ConfirmAction {
+    SetMtu(u32),
+    /// Hand the (currently unmanaged) WiFi device over to NetworkManager
+    SetManaged,
+    /// Lift the soft block on the WiFi radio
+    EnableWireless,
+    /// Turn NM's connectivity check on or off; `uri` is what it fetches
+    SetConnectivityCheck {
+        enabled: bool,
+        uri: String,
+    },
+    /// Write the staged connection settings edits in one update
+    UpdateProfile(Vec<ProfileChange>),
+    /// Forget the profile that is currently active
+    ForgetActive {
+        ssid: String,
+    },
+    /// Forget a saved profile, then connect to the network afresh
+    Rejoin {
+        ssid: String,
+        active: bool,
+    },
+    /// Disconnect while this device carries the default route
+    DisconnectDefaultRoute {
+        over_ssh: bool,
+    },
+    /// Forget every marked profile; `includes_active` if one is in use
+    ForgetMarked {
+        ssids: Vec<String>,
+        includes_active: bool,
+    },
+}
+
+impl ConfirmAction {
+    /// Question shown in the confirm dialog
+    pub fn prompt(&self, lang: Language) -> String {
+        let reapplied = lang.tr("The connection may briefly drop while it is reapplied.");
+        match self {
+            Self::SetMtu(0) => format!("{}\n{reapplied}", lang.tr("Reset the MTU to automatic?")),
+            Self::SetMtu(mtu) => format!(
+                "{}\n{reapplied}",
+                lang.fill("Set the MTU to {mtu}?", &[("mtu", mtu)])
+            ),
+            Self::UpdateProfile(changes) => match changes.as_slice() {
+                [change] => format!("{}?\n{reapplied}", change.describe(lang)),
+                _ => format!(
+                    "{}\n{reapplied}",
+                    lang.fill(
+                        "Apply {count} changes to this connection?",
+                        &[("count", &changes.len())]
+                    )
+                ),
+            },
+            Self::EnableWireless => lang
+                .tr("WiFi is turned off (soft-blocked).\nTurn it on?")
+                .to_string(),
+            Self::SetConnectivityCheck { enabled: true, uri } => lang.fill(
+                "Turn on NetworkManager's connectivity check?\nNM will periodically fetch {uri} \
+                 to detect captive portals and dead uplinks.",
+                &[("uri", uri)],
+            ),
+            Self::SetConnectivityCheck {
+                enabled: false,
+                uri,
+            } => lang.fill(
+                "Turn off NetworkManager's connectivity check?\nNM stops fetching {uri}; \
+                 internet state will read \"Checking disabled\".",
+                &[("uri", uri)],
+            ),
+            Self::SetManaged => lang
+                .tr("Let NetworkManager manage this device?\n\
+                     Whatever tool manages it now (e.g. systemd-networkd) may lose control of it.")
+                .to_string(),
+            Self::ForgetActive { ssid } => lang.fill(
+                "Forget \"{ssid}\"?\nThis connection is ACTIVE — forgetting it will \
+                 disconnect you immediately.",
+                &[("ssid", ssid)],
+            ),
+            Self::Rejoin { ssid, active } => {
+                let mut prompt = lang.fill(
+                    "Forget \"{ssid}\" and join it again from scratch?\nThe saved profile is \
+                     deleted, including its password and custom settings.",
+                    &[("ssid", ssid)],
+                );
+                if *active {
+                    prompt.push(' ');
+                    prompt.push_str(lang.tr("You will be disconnected meanwhile."));
+                }
+                prompt
+            }
+            Self::DisconnectDefaultRoute { over_ssh: true } => lang
+                .tr(
+                    "Disconnect?\nThis connection carries your default route — disconnecting \
+                     will cut off this SSH session immediately.",
+                )
+                .to_string(),
+            Self::DisconnectDefaultRoute { over_ssh: false } => lang
+                .tr(
+                    "Disconnect?\nThis connection carries your default route — you will lose \
+                     network access immediately.",
+                )
+                .to_string(),
+            Self::ForgetMarked {
+                ssids,
+                includes_active,
+            } => {
+                // The dialog has room for a handful of names
+                const SHOWN: usize = 4;
+                let mut names = ssids[..ssids.len().min(SHOWN)].join(", ");
+                if ssids.len() > SHOWN {
+                    names.push(' ');
+                    names.push_str(
+                        &lang.fill("and {count} more", &[("count", &(ssids.len() - SHOWN))]),
+                    );
+                }
+                let mut prompt = lang.fill(
+                    "Forget {count} networks?\n{names}",
+                    &[("count", &ssids.len()), ("names", &names)],
+                );
+                if *includes_active {
+                    prompt.push('\n');
+                    prompt.push_str(lang.tr("One of them is ACTIVE — you will be disconnected."));
+                }
+                prompt
+            }
+        }
+    }
+
+    /// Whether the action cuts the network Nexus may itself depend on
+    pub fn is_dangerous(&self) -> bool {
+        matches!(
+            self,
+            Self::ForgetActive { .. }
+                | Self::Rejoin { active: true, .. }
+                | Self::DisconnectDefaultRoute { .. }
+                | Self::ForgetMarked {
+                    includes_active: true,
+                    ..
+                }
+        )
+    }
+}
+
+/// Rows of the connection settings dialog
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileField {
+    Hostname,
+    SendHostname,
+    ClientId,
+    RouteMetric,
+    Band,
+    FastBand,
+    Proxy,
+    PacUrl,
+    Zone,
+    AutoconnectRetries,
+}
+
+impl ProfileField {
+    pub const ALL: [Self; 10] = [
+        Self::Hostname,
+        Self::SendHostname,
+        Self::ClientId,
+        Self::RouteMetric,
+        Self::Band,
+        Self::FastBand,
+        Self::Proxy,
+        Self::PacUrl,
+        Self::Zone,
+        Self::AutoconnectRetries,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Hostname => "DHCP hostname",
+            Self::SendHostname => "Send hostname",
+            Self::ClientId => "DHCP client ID",
+            Self::RouteMetric => "Route metric",
+            Self::Band => "Band",
+            Self::FastBand => "Prefer faster band",
+            Self::Proxy => "Proxy",
+            Self::PacUrl => "Proxy PAC URL",
+            Self::Zone => "Firewall zone",
+            Self::AutoconnectRetries => "Autoconnect retries",
+        }
+    }
+
+    /// Current value as shown in the dialog
+    pub fn value(self, profile: &ProfileSettings, lang: Language) -> String {
+        let default = || lang.tr("(default)").to_string();
+        match self {
+            Self::Hostname => profile
+                .dhcp_hostname
+                .clone()
+                .unwrap_or_else(|| lang.tr("(system hostname)").to_string()),
+            Self::SendHostname => {
+                let send = if profile.dhcp_send_hostname {
+                    "yes"
+                } else {
+                    "no"
+                };
+                lang.tr(send).to_string()
+            }
+            Self::ClientId => profile.dhcp_client_id.clone().unwrap_or_else(default),
+            Self::RouteMetric => profile.route_metric.map_or_else(default, |m| m.to_string()),
+            Self::Band => profile.band.map_or_else(
+                || lang.tr("(any)").to_string(),
+                |b| lang.fill("{band} only", &[("band", &b)]),
+            ),
+            Self::FastBand => match (profile.fast_band_bssids.as_slice(), &profile.bssid) {
+                ([], None) => lang.tr("off").to_string(),
+                ([bssid], _) | ([], Some(bssid)) => lang.fill("on ({bssid})", &[("bssid", bssid)]),
+                (bssids, _) => lang.fill("on ({count} APs)", &[("count", &bssids.len())]),
+            },
+            Self::Proxy => lang
+                .tr(profile.proxy_summary().unwrap_or("none"))
+                .to_string(),
+            Self::PacUrl => profile
+                .proxy_pac_url
+                .clone()
+                .unwrap_or_else(|| lang.tr("(discover via WPAD)").to_string()),
+            Self::Zone => profile.zone.clone().unwrap_or_else(default),
+            Self::AutoconnectRetries => match profile.autoconnect_retries {
+                None => default(),
+                Some(0) => lang.tr("forever").to_string(),
+                Some(n) => n.to_string(),
+            },
+        }
+    }
+}
+
+/// Unapplied edits of the connection settings dialog, diffed against the
+/// settings NetworkManager had when editing began
+#[derive(Debug, Clone)]
+pub struct ConnectionEditState {
+    /// `connection.uuid` of the profile being edited
+    pub uuid: Option<String>,
+    pub original: ProfileSettings,
+    pub modified: ProfileSettings,
+    /// Pending "prefer faster band"; the access points are picked on apply
+    pub fast_band: bool,
+}
+
+impl ConnectionEditState {
+    pub fn new(profile: &ProfileSettings) -> Self {
+        Self {
+            uuid: profile.uuid.clone(),
+            original: profile.clone(),
+            modified: profile.clone(),
+            fast_band: profile.fast_band(),
+        }
+    }
+
+    /// Record one edit without writing it
+    pub fn stage(&mut self, change: ProfileChange) {
+        let m = &mut self.modified;
+        match change {
+            ProfileChange::Hostname(name) => m.dhcp_hostname = name,
+            ProfileChange::SendHostname(send) => m.dhcp_send_hostname = send,
+            ProfileChange::ClientId(id) => m.dhcp_client_id = id,
+            ProfileChange::RouteMetric(metric) => m.route_metric = metric,
+            // Choosing a band by hand lifts the access point pin
+            ProfileChange::Band(band) => {
+                m.band = band;
+                m.bssid = None;
+                m.fast_band_bssids.clear();
+                self.fast_band = false;
+            }
+            ProfileChange::FastBand(true) if self.original.fast_band() => {
+                m.band = self.original.band;
+                m.bssid = self.original.bssid.clone();
+                m.fast_band_bssids = self.original.fast_band_bssids.clone();
+                self.fast_band = true;
+            }
+            ProfileChange::FastBand(steer) => {
+                m.band = None;
+                m.bssid = None;
+                m.fast_band_bssids.clear();
+                self.fast_band = steer;
+            }
+            ProfileChange::ProxyAuto(auto) => m.proxy_auto = auto,
+            ProfileChange::PacUrl(url) => m.proxy_pac_url = url,
+            ProfileChange::Zone(zone) => m.zone = zone,
+            ProfileChange::AutoconnectRetries(retries) => m.autoconnect_retries = retries,
+        }
+    }
+
+    /// The edits that turn the original settings into the modified ones, in
+    /// the order they have to be written
+    pub fn changes(&self) -> Vec<ProfileChange> {
+        let (o, m) = (&self.original, &self.modified);
+        let mut changes = Vec::new();
+        if m.dhcp_hostname != o.dhcp_hostname {
+            changes.push(ProfileChange::Hostname(m.dhcp_hostname.clone()));
+        }
+        if m.dhcp_send_hostname != o.dhcp_send_hostname {
+            changes.push(ProfileChange::SendHostname(m.dhcp_send_hostname));
+        }
+        if m.dhcp_client_id != o.dhcp_client_id {
+            changes.push(ProfileChange::ClientId(m.dhcp_client_id.clone()));
+        }
+        if m.route_metric != o.route_metric {
+            changes.push(ProfileChange::RouteMetric(m.route_metric));
+        }
+        let pinned = o.fast_band();
+        if self.fast_band != pinned {
+            changes.push(ProfileChange::FastBand(self.fast_band));
+        }
+        // Unpinning clears the band, so a band chosen since goes after it
+        let band_changed = if pinned {
+            m.band.is_some()
+        } else {
+            m.band != o.band
+        };
+        if !self.fast_band && band_changed {
+            changes.push(ProfileChange::Band(m.band));
+        }
+        if m.proxy_auto != o.proxy_auto {
+            changes.push(ProfileChange::ProxyAuto(m.proxy_auto));
+        }
+        if m.proxy_pac_url != o.proxy_pac_url {
+            changes.push(ProfileChange::PacUrl(m.proxy_pac_url.clone()));
+        }
+        if m.zone != o.zone {
+            changes.push(ProfileChange::Zone(m.zone.clone()));
+        }
+        if m.autoconnect_retries != o.autoconnect_retries {
+            changes.push(ProfileChange::AutoconnectRetries(m.autoconnect_retries));
+        }
+        changes
+    }
+
+    pub fn is_modified(&self) -> bool {
+        !self.changes().is_empty()
+    }
+
+    pub fn revert(&mut self) {
+        *self = Self::new(&self.original);
+    }
+
+    /// Value of `field` with the pending edits applied
+    pub fn value(&self, field: ProfileField, lang: Language) -> String {
+        match field {
+            ProfileField::FastBand if self.fast_band && !self.modified.fast_band() => lang
+                .tr("on (fastest band in range, picked on apply)")
+                .to_string(),
+            _ => field.value(&self.modified, lang),
+        }
+    }
+
+    pub fn is_field_modified(&self, field: ProfileField) -> bool {
+        self.value(field, Language::En) != field.value(&self.original, Language::En)
+    }
+}
+
+/// Severity of a toast notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// Short-lived notification shown in the status bar
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub level: ToastLevel,
+    /// None keeps the toast up until another one replaces it
+    expires_at: Option<Instant>,
+}
+
+/// How long a toast stays on screen
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// A connection drop this soon after a user command is blamed on the command
+const USER_ACTION_GRACE: Duration = Duration::from_secs(15);
+
+/// Most hosts the ping comparison runs side by side
+const MAX_PING_TARGETS: usize = 8;
+
+/// Resume reports closer together than this are the same wake-up
+const RESUME_DEBOUNCE: Duration = Duration::from_secs(30);
+
+/// Tick interval once nothing has animated for `IDLE_AFTER`
+const IDLE_TICK: Duration = Duration::from_millis(500);
+
+/// Quiet time before ticks slow down, so brief pauses don't thrash the rate
+const IDLE_AFTER: Duration = Duration::from_secs(2);
+
+/// Tick interval while dimmed after `idle_dim_secs` without input
+const DIMMED_TICK: Duration = Duration::from_secs(1);
+
+/// Refreshes on regaining focus are never closer together than this
+const FOCUS_REFRESH_THROTTLE: Duration = Duration::from_secs(10);
+
+/// Errors longer than this open the error dialog instead of a toast
+const ERROR_TOAST_MAX_LEN: usize = 60;
+
+/// How often interface counters are sampled for the traffic graph
+const STATS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Watchdog probes are never sent closer together than this
+const MIN_WATCHDOG_INTERVAL: Duration = Duration::from_secs(5);
+
+/// NetworkManager refuses scan requests closer together than this
+const MIN_SCAN_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Auto-scan interval used when toggled on with `auto_scan_secs = 0`
+const DEFAULT_AUTO_SCAN: Duration = Duration::from_secs(30);
+
+/// How many connection events the log keeps
+const MAX_NM_EVENTS: usize = 200;
+
+/// Entries in the recent networks list
+const RECENT_LIMIT: usize = 5;
+
+/// Password prompts for one network before suggesting to forget it
+const MAX_PASSWORD_ATTEMPTS: u32 = 3;
+
+/// Percent the `<` / `>` keys move the weak signal threshold by
+const MIN_SIGNAL_STEP: i16 = 5;
+
+/// Valid MTU range accepted by the MTU dialog (0 means "automatic")
+const MTU_RANGE: std::ops::RangeInclusive<u32> = 68..=9000;
+
+/// firewalld zones offered by the connection settings dialog, from most to
+/// least trusting; a profile can name any other zone too
+const FIREWALL_ZONES: [&str; 5] = ["trusted", "home", "work", "internal", "public"];
+
 /// Sort ordering for the network list
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SortMode {
@@ -66,18 +756,178 @@ impl SortMode {
 pub struct App {
     pub mode: AppMode,
     pub networks: Vec<WiFiNetwork>,
+    /// Bumped whenever `networks` is replaced or cleared
+    pub networks_epoch: u64,
     /// Filtered view indices into `networks`
     pub filtered_indices: Vec<usize>,
     pub selected_index: usize,
     pub connection_status: ConnectionStatus,
+    /// Latest NM state of the WiFi device (drives the activation gauge)
+    pub device_state: DeviceState,
     pub password_input: String,
     pub password_visible: bool,
     pub hidden_ssid_input: String,
     pub hidden_password_input: String,
     pub hidden_field_focus: u8, // 0 = SSID, 1 = password
+    /// Buffer for the generic single-line input dialog
+    pub input_buffer: String,
+    /// Transient status-bar notification
+    pub toast: Option<Toast>,
+    /// Site survey accumulator (kept until reset, even when the view closes)
+    pub survey: Survey,
+    /// Highlighted row in the survey table
+    pub survey_selected: usize,
+    /// When the next survey scan is due
+    next_survey_scan: Instant,
+    /// Periodic rescan interval, None when auto-scan is off
+    pub auto_scan: Option<Duration>,
+    /// When the last scan was requested (manual or automatic)
+    last_scan: Instant,
+    /// SSIDs pinned to the top of the list regardless of sort
+    pub favorites: Favorites,
+    /// Nicknames and notes the user attached to SSIDs
+    pub labels: Labels,
+    /// SSID patterns hidden from the network list
+    pub ignore: IgnoreList,
+    /// View toggles kept across launches
+    pub view_state: ViewState,
+    /// List ignored networks anyway
+    pub show_ignored: bool,
+    /// Networks the ignore list currently hides
+    pub ignored_count: usize,
+    /// Unsaved networks below this signal (percent) are left out of the list
+    pub min_signal: u8,
+    /// List weak networks anyway
+    pub show_weak: bool,
+    /// Networks the signal threshold currently hides
+    pub weak_count: usize,
+    /// Highlighted row of the ignore list overlay
+    pub ignore_selected: usize,
+    /// Whether the pattern input was opened from the ignore list overlay
+    ignore_list_open: bool,
+    /// SSIDs marked for a bulk action; kept by name so they survive rescans
+    pub marked: BTreeSet<String>,
+    /// Consecutive watchdog probes that failed while NM claimed Full
+    watchdog_failures: u32,
+    /// Set once `watchdog.failures` probes in a row failed; cleared on recovery
+    pub watchdog_alert: bool,
+    next_watchdog_probe: Instant,
+    /// Connection that dropped without being asked to, and when
+    pub link_lost: Option<(String, Instant)>,
+    /// When the user last started a network command
+    last_user_action: Option<Instant>,
+    /// When the last resume from suspend was handled
+    last_resume: Option<Instant>,
+    /// Whether the terminal window has focus (assumed until told otherwise)
+    focused: bool,
+    /// The system bus is gone; NM actions are dropped until it's back
+    pub offline: bool,
+    /// When regaining focus last triggered a refresh
+    last_focus_refresh: Option<Instant>,
+    /// When scan results or the connection status were last applied
+    pub last_refresh: Option<Instant>,
+    /// Seconds since start at the last once-a-second redraw (clock, data age,
+    /// "Last Seen")
+    header_second: u64,
+    /// Local time zone's offset from UTC in seconds, for the header clock
+    pub utc_offset: i64,
+    /// When `utc_offset` was last read
+    utc_offset_read: Option<Instant>,
+    /// Last time anything animated or a key was pressed
+    last_busy: Instant,
+    /// Latest scan held back while a dialog is open, applied once it closes
+    pending_scan: Option<Vec<WiFiNetwork>>,
+    /// A fresh scan is among the held-back ones, so stale entries are
+    /// dropped when it is applied
+    pending_fresh: bool,
+    /// Last key press, for dimming after `idle_dim_secs`
+    last_input: Instant,
+    /// Dimmed for lack of input; the next key press only wakes the UI
+    pub dimmed: bool,
+    /// Most recently forgotten SSID and when, for undo
+    pub undo_forget: Option<(String, Instant)>,
+    /// SSID being forgotten to be joined again afresh
+    rejoin: Option<String>,
+    /// Passwords rejected in a row, and for which SSID
+    auth_failures: Option<(String, u32)>,
+    /// Highlighted row in the connection settings dialog
+    pub profile_selected: usize,
+    /// Unapplied edits in the connection settings dialog
+    pub profile_edit: Option<ConnectionEditState>,
+    /// Focused field of the detail panel (see `ui::details::fields`)
+    pub detail_cursor: usize,
+    /// Scroll offset of the error dialog body
+    pub error_scroll: u16,
+    /// Latest internet diagnostic report (None while a run is in progress)
+    pub diagnostics: Option<DiagnosticReport>,
+    /// A report is on its way; cleared with the output
+    diagnosing: bool,
+    /// Tags the current run so the report of an abandoned one is dropped
+    diagnostics_run: u64,
+    /// URL of the latest header inspection
+    pub http_headers_url: String,
+    /// Lines of the latest header inspection, ending in `Done` once finished
+    pub http_headers: Vec<HeaderLine>,
+    /// Tags the current inspection so lines of an abandoned one are dropped
+    http_headers_run: u64,
+    /// First visible line of the header inspection modal
+    pub http_headers_scroll: u16,
+    /// Target of the latest path MTU probe
+    pub mtu_probe_host: String,
+    /// Lines of the latest path MTU probe, ending in `Done` once finished
+    pub mtu_probe: Vec<MtuProbeLine>,
+    /// Tags the current probe so lines of an abandoned one are dropped
+    mtu_probe_run: u64,
+    /// Host list of the latest ping comparison, as typed
+    pub ping_targets: String,
+    /// Per-host statistics of the latest ping comparison
+    pub ping_compare: Vec<PingStats>,
+    /// Tags the current comparison so samples of an abandoned one are dropped
+    ping_compare_run: u64,
+    /// Recent NM state changes, oldest first, with when they arrived
+    pub nm_events: VecDeque<(Instant, String)>,
+    /// App start, the zero point of the event log timestamps
+    pub started: Instant,
+    /// Recent rx/tx rates of the active connection
+    pub traffic: TrafficHistory,
+    stats: Option<StatsPoller>,
+    next_stats_poll: Instant,
+    /// CSV sink for every traffic sample, when enabled
+    bandwidth_log: Option<BandwidthLog>,
+    /// Every interface's counters and history, sampled since launch
+    pub interface_stats: Vec<InterfaceStats>,
+    next_interface_poll: Instant,
+    /// Prometheus textfile rewritten after each interface poll, when enabled
+    prometheus: Option<TextfileExporter>,
+    /// Interface shown in the statistics graph (index into `interface_stats`)
+    pub stats_focus: usize,
+    pub stats_range: TimeRange,
+    pub stats_sort: StatsSort,
+    /// Text typed into the command palette or global search
+    pub palette_query: String,
+    /// Highlighted match in the command palette or global search
+    pub palette_selected: usize,
+    /// Highlighted row of the quick actions menu
+    pub quick_selected: usize,
+    /// Most recently connected saved networks; None while loading
+    pub recent: Option<Vec<SavedProfile>>,
+    /// Highlighted row of the recent networks list
+    pub recent_selected: usize,
+    /// WiFi adapters for the picker; None while loading
+    pub wifi_devices: Option<Vec<WifiDevice>>,
+    /// Highlighted row of the adapter picker
+    pub device_selected: usize,
+    /// Highlighted swatch of the theme preview
+    pub theme_selected: usize,
+    /// Pages in `[layout]` order
+    pub pages: Vec<View>,
     pub animation: AnimationState,
     pub should_quit: bool,
     pub detail_visible: bool,
+    /// Signal shown as estimated dBm rather than percent
+    pub signal_dbm: bool,
+    /// Tables show only their essential columns
+    pub compact_columns: bool,
     pub config: Config,
     pub theme: Theme,
     pub interface_name: String,
@@ -91,39 +941,145 @@ impl App {
         config: Config,
         theme: Theme,
         interface_name: String,
+        view_state: ViewState,
         event_tx: mpsc::UnboundedSender<Event>,
     ) -> Self {
-        let detail_visible = config.appearance.show_details;
-        Self {
+        let detail_visible = view_state
+            .details()
+            .unwrap_or(config.appearance.show_details);
+        let prometheus = (!config.stats.prometheus_textfile.is_empty())
+            .then(|| TextfileExporter::new(config.stats.prometheus_textfile.clone().into()));
+        let bandwidth_log = config
+            .general
+            .bandwidth_log
+            .then(|| {
+                BandwidthLog::open()
+                    .inspect_err(|e| warn!("Bandwidth log disabled: {e:#}"))
+                    .ok()
+            })
+            .flatten();
+        let (pages, unknown_pages) = View::resolve(&config.layout.pages);
+        let mut app = Self {
             mode: AppMode::Normal,
             networks: Vec::new(),
+            networks_epoch: 0,
             filtered_indices: Vec::new(),
             selected_index: 0,
             connection_status: ConnectionStatus::default(),
+            device_state: DeviceState::default(),
             password_input: String::new(),
             password_visible: false,
             hidden_ssid_input: String::new(),
             hidden_password_input: String::new(),
             hidden_field_focus: 0,
+            input_buffer: String::new(),
+            toast: None,
+            diagnostics: None,
+            diagnosing: false,
+            diagnostics_run: 0,
+            http_headers_url: default_header_url(),
+            http_headers: Vec::new(),
+            http_headers_run: 0,
+            http_headers_scroll: 0,
+            mtu_probe_host: PUBLIC_IP.to_string(),
+            mtu_probe: Vec::new(),
+            mtu_probe_run: 0,
+            ping_targets: format!("gateway, {PUBLIC_IP}"),
+            ping_compare: Vec::new(),
+            ping_compare_run: 0,
+            nm_events: VecDeque::with_capacity(MAX_NM_EVENTS),
+            started: Instant::now(),
+            traffic: TrafficHistory::default(),
+            stats: None,
+            next_stats_poll: Instant::now(),
+            bandwidth_log,
+            interface_stats: Vec::new(),
+            next_interface_poll: Instant::now(),
+            prometheus,
+            stats_focus: 0,
+            stats_range: TimeRange::default(),
+            stats_sort: StatsSort::default(),
+            palette_query: String::new(),
+            palette_selected: 0,
+            quick_selected: 0,
+            recent: None,
+            recent_selected: 0,
+            wifi_devices: None,
+            device_selected: 0,
+            theme_selected: 0,
+            pages,
+            error_scroll: 0,
+            profile_selected: 0,
+            profile_edit: None,
+            detail_cursor: 0,
+            auto_scan: (config.general.auto_scan_secs > 0)
+                .then(|| Duration::from_secs(config.general.auto_scan_secs).max(MIN_SCAN_INTERVAL)),
+            last_scan: Instant::now(),
+            survey: Survey::default(),
+            survey_selected: 0,
+            next_survey_scan: Instant::now(),
+            favorites: Favorites::load(),
+            labels: Labels::load(),
+            ignore: IgnoreList::load(),
+            view_state,
+            show_ignored: false,
+            ignored_count: 0,
+            ignore_selected: 0,
+            min_signal: config.general.min_signal.min(100),
+            show_weak: false,
+            weak_count: 0,
+            ignore_list_open: false,
+            marked: BTreeSet::new(),
+            undo_forget: None,
+            rejoin: None,
+            auth_failures: None,
+            link_lost: None,
+            watchdog_failures: 0,
+            watchdog_alert: false,
+            next_watchdog_probe: Instant::now(),
+            last_user_action: None,
+            last_resume: None,
+            focused: true,
+            offline: false,
+            last_focus_refresh: None,
+            last_refresh: None,
+            header_second: 0,
+            utc_offset: 0,
+            utc_offset_read: None,
+            last_busy: Instant::now(),
+            pending_scan: None,
+            pending_fresh: false,
+            last_input: Instant::now(),
+            dimmed: false,
             animation: AnimationState::default(),
             should_quit: false,
             detail_visible,
+            signal_dbm: config.appearance.signal_dbm,
+            compact_columns: false,
             config,
             theme,
             interface_name,
             sort_mode: SortMode::Signal,
             search_query: String::new(),
             event_tx,
+        };
+        if !unknown_pages.is_empty() {
+            let names = unknown_pages.join(", ");
+            warn!("Unknown pages in [layout] pages: {names}");
+            let msg = app
+                .lang()
+                .fill("Unknown layout pages: {names}", &[("names", &names)]);
+            app.show_toast(msg, ToastLevel::Warning);
         }
+        app
     }
 
-    /// Get the list of networks to display (filtered view).
-    /// Returns references via index.
-    pub fn visible_networks(&self) -> Vec<&WiFiNetwork> {
+    /// Iterate over the networks to display (filtered view).
+    /// Yields references via index — no per-frame allocation.
+    pub fn visible_networks(&self) -> impl Iterator<Item = &WiFiNetwork> + '_ {
         self.filtered_indices
             .iter()
             .filter_map(|&i| self.networks.get(i))
-            .collect()
     }
 
     /// Get the currently selected network (accounting for filter)
@@ -136,15 +1092,36 @@ impl App {
     /// Rebuild the filtered indices based on search query
     fn rebuild_filter(&mut self) {
         let query = self.search_query.to_lowercase();
+        let labels = &self.labels;
+        // The active network stays listed even if a pattern matches it
+        let ignore = &self.ignore;
+        let show_ignored = self.show_ignored;
+        let ignored =
+            |net: &WiFiNetwork| !show_ignored && !net.is_active && ignore.matches(&net.ssid);
+        // Saved networks stay listed however weak, so they remain selectable
+        let min_signal = if self.show_weak { 0 } else { self.min_signal };
+        let weak = |net: &WiFiNetwork| !net.is_saved && net.signal_strength < min_signal;
+        self.ignored_count = self.networks.iter().filter(|net| ignored(net)).count();
+        self.weak_count = self
+            .networks
+            .iter()
+            .filter(|net| !ignored(net) && weak(net))
+            .count();
         self.filtered_indices = self
             .networks
             .iter()
             .enumerate()
             .filter(|(_, net)| {
+                if ignored(net) || weak(net) {
+                    return false;
+                }
                 if query.is_empty() {
                     return true;
                 }
                 net.ssid.to_lowercase().contains(&query)
+                    || labels
+                        .nickname(&net.ssid)
+                        .is_some_and(|nick| nick.to_lowercase().contains(&query))
             })
             .map(|(i, _)| i)
             .collect();
@@ -175,6 +1152,7 @@ impl App {
             "end" => key.code == KeyCode::End,
             "backspace" => key.code == KeyCode::Backspace,
             "delete" => key.code == KeyCode::Delete,
+            "space" => key.code == KeyCode::Char(' '),
             s if s.len() == 1 => {
                 let ch = s.chars().next().unwrap();
                 key.code == KeyCode::Char(ch)
@@ -185,20 +1163,81 @@ impl App {
 
     /// Process a key event
     pub fn handle_key(&mut self, key: KeyEvent) {
+        self.last_busy = Instant::now();
+        self.last_input = Instant::now();
+        if self.dimmed {
+            self.dimmed = false;
+            return;
+        }
         match &self.mode {
             AppMode::Normal | AppMode::Scanning => self.handle_key_normal(key),
             AppMode::PasswordInput { .. } => self.handle_key_password(key),
             AppMode::Hidden => self.handle_key_hidden(key),
             AppMode::Help => self.handle_key_help(key),
             AppMode::Search => self.handle_key_search(key),
+            AppMode::Input(_) => self.handle_key_input(key),
+            AppMode::Confirm(_) => self.handle_key_confirm(key),
+            AppMode::Diagnostics => self.handle_key_diagnostics(key),
+            AppMode::HttpHeaders => self.handle_key_http_headers(key),
+            AppMode::MtuProbe => self.handle_key_mtu_probe(key),
+            AppMode::PingCompare => self.handle_key_ping_compare(key),
+            AppMode::ShellEscape => self.handle_key_shell_escape(key),
+            AppMode::Survey => self.handle_key_survey(key),
+            AppMode::Statistics => self.handle_key_statistics(key),
+            AppMode::Palette | AppMode::GlobalSearch => self.handle_key_palette(key),
+            AppMode::QuickActions => self.handle_key_quick_actions(key),
+            AppMode::Recent => self.handle_key_recent(key),
+            AppMode::IgnoreList => self.handle_key_ignore_list(key),
+            AppMode::ThemePreview => self.handle_key_theme_preview(key),
+            AppMode::WifiDevices => self.handle_key_wifi_devices(key),
+            AppMode::Events => self.handle_key_events(key),
+            AppMode::DetailFocus => self.handle_key_detail_focus(key),
+            AppMode::ProfileEdit => self.handle_key_profile(key),
             AppMode::Error(_) => self.handle_key_error(key),
-            AppMode::Connecting | AppMode::Disconnecting => {
+            AppMode::Connecting => {
+                // Quit, or cancel the attempt in progress
+                if key.code == KeyCode::Char('q') {
+                    self.should_quit = true;
+                } else if key.code == KeyCode::Esc
+                    || self.key_matches(&key, &self.config.keys.disconnect)
+                {
+                    self.cancel_connect();
+                }
+            }
+            AppMode::Disconnecting => {
                 // Only allow quit during busy states
                 if key.code == KeyCode::Char('q') {
                     self.should_quit = true;
                 }
             }
         }
+        self.apply_pending_scan();
+    }
+
+    /// Whether a dialog is open whose target was picked from the list, so
+    /// scan results must not re-sort the list under it
+    fn dialog_open(&self) -> bool {
+        matches!(
+            self.mode,
+            AppMode::PasswordInput { .. }
+                | AppMode::Hidden
+                | AppMode::Input(_)
+                | AppMode::Confirm(_)
+                | AppMode::QuickActions
+        )
+    }
+
+    /// Apply the scan held back while a dialog was open, once it has closed
+    fn apply_pending_scan(&mut self) {
+        if !self.dialog_open()
+            && let Some(networks) = self.pending_scan.take()
+        {
+            if std::mem::take(&mut self.pending_fresh) {
+                self.apply_fresh_scan(networks);
+            } else {
+                self.update_networks(networks);
+            }
+        }
     }
 
     /// Handle keys in normal/scanning mode — uses config keybindings
@@ -231,8 +1270,25 @@ impl App {
                 self.select_last();
                 return;
             }
+            KeyCode::Tab => {
+                self.detail_visible = true;
+                self.detail_cursor = 0;
+                self.mode = AppMode::DetailFocus;
+                return;
+            }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_recent();
+                return;
+            }
+            KeyCode::Char(c @ '1'..='9') if key.modifiers.contains(KeyModifiers::ALT) => {
+                self.connect_row(c as usize - '1' as usize);
+                return;
+            }
             _ => {}
         }
+        if self.handle_key_page(&key) {
+            return;
+        }
 
         // Config-driven action keys
         if self.key_matches(&key, &keys.connect) {
@@ -247,8 +1303,79 @@ impl App {
             self.action_hidden();
         } else if self.key_matches(&key, &keys.refresh) {
             self.action_refresh();
+        } else if self.key_matches(&key, &keys.mtu) {
+            self.action_mtu();
+        } else if self.key_matches(&key, &keys.manage) {
+            self.action_manage();
+        } else if self.key_matches(&key, &keys.favorite) {
+            self.action_toggle_favorite();
+        } else if self.key_matches(&key, &keys.mark) {
+            self.action_toggle_mark();
+        } else if self.key_matches(&key, &keys.mark_all) {
+            self.action_mark_all();
+        } else if self.key_matches(&key, &keys.undo) {
+            self.action_undo();
+        } else if self.key_matches(&key, &keys.rejoin) {
+            self.action_rejoin();
+        } else if self.key_matches(&key, &keys.compact) {
+            self.toggle_compact_columns();
+        } else if self.key_matches(&key, &keys.ignore) {
+            self.action_ignore();
+        } else if self.key_matches(&key, &keys.show_ignored) {
+            self.toggle_show_ignored();
+        } else if self.key_matches(&key, &keys.fresh_scan) {
+            self.action_fresh_scan();
+        } else if self.key_matches(&key, &keys.wifi_device) {
+            self.open_wifi_devices();
+        } else if self.key_matches(&key, &keys.weak_signals) {
+            self.toggle_show_weak();
+        } else if self.key_matches(&key, &keys.raise_min_signal) {
+            self.adjust_min_signal(MIN_SIGNAL_STEP);
+        } else if self.key_matches(&key, &keys.lower_min_signal) {
+            self.adjust_min_signal(-MIN_SIGNAL_STEP);
+        } else if self.key_matches(&key, &keys.theme_preview) {
+            self.mode = AppMode::ThemePreview;
+            self.animation.start_dialog_slide();
+        } else if self.key_matches(&key, &keys.edit) {
+            self.action_edit_profile();
+        } else if self.key_matches(&key, &keys.connectivity_check) {
+            self.action_connectivity_check();
+        } else if self.key_matches(&key, &keys.survey) {
+            self.action_survey();
+        } else if self.key_matches(&key, &keys.statistics) {
+            self.mode = AppMode::Statistics;
+        } else if self.key_matches(&key, &keys.palette) {
+            self.open_palette(AppMode::Palette);
+        } else if self.key_matches(&key, &keys.quick_actions) {
+            self.open_quick_actions();
+        } else if self.key_matches(&key, &keys.export) {
+            let format = self.config.general.export_format;
+            let _ = self
+                .event_tx
+                .send(Event::Command(NetworkCommand::Export { format }));
+        } else if self.key_matches(&key, &keys.auto_scan) {
+            self.action_toggle_auto_scan();
+        } else if self.key_matches(&key, &keys.shell) {
+            self.action_shell_escape();
+        } else if self.key_matches(&key, &keys.events) {
+            self.mode = AppMode::Events;
+            self.animation.start_dialog_slide();
+        } else if self.key_matches(&key, &keys.diagnose) {
+            self.action_diagnose();
         } else if self.key_matches(&key, &keys.details) {
-            self.detail_visible = !self.detail_visible;
+            self.toggle_details();
+        } else if self.key_matches(&key, &keys.signal_unit) {
+            self.signal_dbm = !self.signal_dbm;
+            let lang = self.lang();
+            let unit = lang.tr(if self.signal_dbm {
+                "estimated dBm"
+            } else {
+                "percent"
+            });
+            self.show_toast(
+                lang.fill("Signal shown in {unit}", &[("unit", &unit)]),
+                ToastLevel::Info,
+            );
         } else if self.key_matches(&key, &keys.help) {
             self.mode = AppMode::Help;
             self.animation.start_dialog_slide();
@@ -262,8 +1389,10 @@ impl App {
         } else if self.key_matches(&key, &keys.quit) {
             self.should_quit = true;
         } else if key.code == KeyCode::Esc {
-            // Clear filter if active, otherwise quit
-            if !self.search_query.is_empty() {
+            // Clear marks, then the filter, otherwise quit
+            if !self.marked.is_empty() {
+                self.marked.clear();
+            } else if !self.search_query.is_empty() {
                 self.search_query.clear();
                 self.rebuild_filter();
             } else {
@@ -301,8 +1430,18 @@ impl App {
         match key.code {
             KeyCode::Enter => {
                 let password = self.password_input.clone();
-                if let AppMode::PasswordInput { ssid } = &self.mode {
+                if let AppMode::PasswordInput {
+                    ssid,
+                    security,
+                    ap_path,
+                } = &self.mode
+                {
                     let ssid = ssid.clone();
+                    let ap_path = ap_path.clone();
+                    if let Err(msg) = validate_password(security, &password, self.lang()) {
+                        self.show_toast(msg, ToastLevel::Error);
+                        return;
+                    }
                     self.mode = AppMode::Connecting;
                     self.connection_status = ConnectionStatus::Connecting(ssid.clone());
                     self.animation.start_spinner();
@@ -312,12 +1451,13 @@ impl App {
                     } else {
                         Some(password)
                     };
-                    self.dispatch_connect(ssid, pwd);
+                    self.dispatch_connect(ssid, pwd, Some(ap_path));
                 }
             }
             KeyCode::Esc => {
                 self.password_input.clear();
                 self.password_visible = false;
+                self.auth_failures = None;
                 self.mode = AppMode::Normal;
             }
             KeyCode::Backspace => {
@@ -339,19 +1479,27 @@ impl App {
             KeyCode::Tab | KeyCode::BackTab => {
                 self.hidden_field_focus = if self.hidden_field_focus == 0 { 1 } else { 0 };
             }
-            KeyCode::Enter => {
-                if !self.hidden_ssid_input.is_empty() {
-                    let ssid = self.hidden_ssid_input.clone();
-                    let pwd = if self.hidden_password_input.is_empty() {
-                        None
-                    } else {
-                        Some(self.hidden_password_input.clone())
-                    };
-                    self.mode = AppMode::Connecting;
-                    self.connection_status = ConnectionStatus::Connecting(ssid.clone());
-                    self.animation.start_spinner();
-                    self.dispatch_connect_hidden(ssid, pwd);
+            KeyCode::Enter if !self.hidden_ssid_input.is_empty() => {
+                if !self.hidden_password_input.is_empty()
+                    && let Err(msg) = validate_password(
+                        &SecurityType::Unknown,
+                        &self.hidden_password_input,
+                        self.lang(),
+                    )
+                {
+                    self.show_toast(msg, ToastLevel::Error);
+                    return;
                 }
+                let ssid = self.hidden_ssid_input.clone();
+                let pwd = if self.hidden_password_input.is_empty() {
+                    None
+                } else {
+                    Some(self.hidden_password_input.clone())
+                };
+                self.mode = AppMode::Connecting;
+                self.connection_status = ConnectionStatus::Connecting(ssid.clone());
+                self.animation.start_spinner();
+                self.dispatch_connect_hidden(ssid, pwd);
             }
             KeyCode::Esc => {
                 self.hidden_ssid_input.clear();
@@ -381,159 +1529,2332 @@ impl App {
         }
     }
 
-    /// Handle keys in help overlay
-    fn handle_key_help(&mut self, key: KeyEvent) {
+    /// Handle keys in the generic single-line input dialog
+    fn handle_key_input(&mut self, key: KeyEvent) {
+        let AppMode::Input(kind) = self.mode else {
+            return;
+        };
         match key.code {
-            KeyCode::Char('?') | KeyCode::Char('/') | KeyCode::Esc | KeyCode::Char('q') => {
-                self.mode = AppMode::Normal;
+            KeyCode::Enter => self.submit_input(kind),
+            KeyCode::Esc => {
+                self.input_buffer.clear();
+                self.mode = if kind.edits_profile() {
+                    AppMode::ProfileEdit
+                } else if kind.edits_label() {
+                    AppMode::DetailFocus
+                } else if self.ignore_list_open {
+                    AppMode::IgnoreList
+                } else {
+                    AppMode::Normal
+                };
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
             }
             _ => {}
         }
     }
 
-    /// Handle keys in error dialog
-    fn handle_key_error(&mut self, key: KeyEvent) {
+    /// Validate and act on a submitted input dialog
+    fn submit_input(&mut self, kind: InputKind) {
+        let lang = self.lang();
+        let value = self.input_buffer.trim().to_string();
+        self.input_buffer.clear();
+        match kind {
+            InputKind::Nickname | InputKind::Note => self.save_label(kind, value),
+            InputKind::IgnorePattern => self.add_ignore_pattern(value),
+            InputKind::Mtu => match value.parse::<u32>() {
+                Ok(mtu) if mtu == 0 || MTU_RANGE.contains(&mtu) => {
+                    self.mode = AppMode::Confirm(ConfirmAction::SetMtu(mtu));
+                    self.animation.start_dialog_slide();
+                }
+                _ => self.show_error(lang.fill(
+                    "Invalid MTU \"{value}\": must be between {min} and {max} (or 0 for automatic)",
+                    &[
+                        ("value", &value),
+                        ("min", MTU_RANGE.start()),
+                        ("max", MTU_RANGE.end()),
+                    ],
+                )),
+            },
+            InputKind::DhcpHostname => {
+                if !value.is_empty()
+                    && let Err(e) = validate_hostname(&value, lang)
+                {
+                    self.show_error(lang.fill("Invalid hostname: {error}", &[("error", &e)]));
+                    return;
+                }
+                let name = (!value.is_empty()).then_some(value);
+                self.stage_profile_change(ProfileChange::Hostname(name));
+            }
+            InputKind::DhcpClientId => {
+                if value.contains(char::is_whitespace) {
+                    self.show_error(lang.tr("Invalid client ID: it may not contain spaces"));
+                    return;
+                }
+                let id = (!value.is_empty()).then_some(value);
+                self.stage_profile_change(ProfileChange::ClientId(id));
+            }
+            InputKind::PacUrl => {
+                if value.is_empty() {
+                    self.stage_profile_change(ProfileChange::PacUrl(None));
+                    return;
+                }
+                if !["http://", "https://", "file://"]
+                    .iter()
+                    .any(|scheme| value.starts_with(scheme))
+                    || value.contains(char::is_whitespace)
+                {
+                    self.show_error(lang.fill(
+                        "Invalid PAC URL \"{value}\": must be an http://, https:// or file:// URL",
+                        &[("value", &value)],
+                    ));
+                    return;
+                }
+                self.stage_profile_change(ProfileChange::PacUrl(Some(value)));
+            }
+            InputKind::HeaderUrl => {
+                if !["http://", "https://"]
+                    .iter()
+                    .any(|scheme| value.starts_with(scheme))
+                    || value.contains(char::is_whitespace)
+                {
+                    self.show_error(lang.fill(
+                        "Invalid URL \"{value}\": must be an http:// or https:// URL",
+                        &[("value", &value)],
+                    ));
+                    return;
+                }
+                self.inspect_headers(value);
+            }
+            InputKind::MtuTarget => {
+                // Never let the host be taken for a ping option
+                if value.is_empty() || value.starts_with('-') || value.contains(char::is_whitespace)
+                {
+                    self.show_error(lang.fill("Invalid host \"{host}\"", &[("host", &value)]));
+                    return;
+                }
+                self.probe_path_mtu(value);
+            }
+            InputKind::PingTargets => self.compare_ping(value),
+            InputKind::RouteMetric => {
+                if value.is_empty() {
+                    self.stage_profile_change(ProfileChange::RouteMetric(None));
+                    return;
+                }
+                match value.parse::<u32>() {
+                    Ok(metric) => {
+                        self.stage_profile_change(ProfileChange::RouteMetric(Some(metric)))
+                    }
+                    Err(_) => self.show_error(lang.fill(
+                        "Invalid route metric \"{value}\": must be a non-negative number",
+                        &[("value", &value)],
+                    )),
+                }
+            }
+            InputKind::AutoconnectRetries => {
+                // NM's own spelling of the default
+                if value.is_empty() || value == "-1" {
+                    self.stage_profile_change(ProfileChange::AutoconnectRetries(None));
+                    return;
+                }
+                // NM stores the count as an int32
+                match value.parse::<u32>() {
+                    Ok(retries) if i32::try_from(retries).is_ok() => {
+                        self.stage_profile_change(ProfileChange::AutoconnectRetries(Some(retries)))
+                    }
+                    _ => self.show_error(lang.fill(
+                        "Invalid autoconnect retries \"{value}\": must be -1 or 0 to {max}",
+                        &[("value", &value), ("max", &i32::MAX)],
+                    )),
+                }
+            }
+        }
+    }
+
+    /// Handle keys in the confirmation dialog
+    fn handle_key_confirm(&mut self, key: KeyEvent) {
         match key.code {
-            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                let mode = std::mem::replace(&mut self.mode, AppMode::Normal);
+                if let AppMode::Confirm(action) = mode {
+                    self.run_confirmed(action);
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc | KeyCode::Char('q') => {
                 self.mode = AppMode::Normal;
             }
             _ => {}
         }
     }
 
-    // ─── Navigation ─────────────────────────────────────────────────
-
-    fn select_prev(&mut self) {
-        if !self.filtered_indices.is_empty() {
-            self.selected_index = self.selected_index.saturating_sub(1);
+    /// Execute an action once the user has confirmed it
+    fn run_confirmed(&mut self, action: ConfirmAction) {
+        match action {
+            ConfirmAction::SetMtu(mtu) => {
+                let _ = self
+                    .event_tx
+                    .send(Event::Command(NetworkCommand::SetMtu { mtu }));
+            }
+            ConfirmAction::SetManaged => {
+                let _ = self
+                    .event_tx
+                    .send(Event::Command(NetworkCommand::SetManaged));
+            }
+            ConfirmAction::UpdateProfile(changes) => {
+                self.profile_edit = None;
+                let _ = self
+                    .event_tx
+                    .send(Event::Command(NetworkCommand::UpdateProfile(changes)));
+            }
+            ConfirmAction::ForgetActive { ssid } => {
+                let _ = self
+                    .event_tx
+                    .send(Event::Command(NetworkCommand::Forget { ssid }));
+            }
+            ConfirmAction::Rejoin { ssid, .. } => {
+                let msg = self.lang().fill("Forgetting '{ssid}'…", &[("ssid", &ssid)]);
+                self.show_toast(msg, ToastLevel::Info);
+                self.rejoin = Some(ssid.clone());
+                let _ = self
+                    .event_tx
+                    .send(Event::Command(NetworkCommand::Forget { ssid }));
+            }
+            ConfirmAction::DisconnectDefaultRoute { .. } => self.dispatch_disconnect(),
+            ConfirmAction::EnableWireless => {
+                self.show_toast(self.lang().tr("Turning WiFi on…"), ToastLevel::Info);
+                let _ = self
+                    .event_tx
+                    .send(Event::Command(NetworkCommand::EnableWireless));
+            }
+            ConfirmAction::ForgetMarked { ssids, .. } => {
+                let _ = self
+                    .event_tx
+                    .send(Event::Command(NetworkCommand::ForgetMany { ssids }));
+            }
+            ConfirmAction::SetConnectivityCheck { enabled, .. } => {
+                let _ = self
+                    .event_tx
+                    .send(Event::Command(NetworkCommand::SetConnectivityCheck {
+                        enabled,
+                    }));
+            }
         }
     }
 
-    fn select_next(&mut self) {
-        if !self.filtered_indices.is_empty() {
-            self.selected_index = (self.selected_index + 1).min(self.filtered_indices.len() - 1);
+    /// Stage an edit in the connection settings dialog; nothing is written
+    /// until the user applies the pending changes
+    fn stage_profile_change(&mut self, change: ProfileChange) {
+        if let Some(ref mut edit) = self.profile_edit {
+            edit.stage(change);
         }
+        self.mode = AppMode::ProfileEdit;
     }
 
-    fn select_first(&mut self) {
-        self.selected_index = 0;
-    }
-
-    fn select_last(&mut self) {
-        if !self.filtered_indices.is_empty() {
-            self.selected_index = self.filtered_indices.len() - 1;
+    /// Ask to write every pending edit in one update
+    fn apply_profile_edits(&mut self) {
+        let changes = self
+            .profile_edit
+            .as_ref()
+            .map(ConnectionEditState::changes)
+            .unwrap_or_default();
+        if changes.is_empty() {
+            self.show_toast(self.lang().tr("No unsaved changes"), ToastLevel::Info);
+            return;
         }
+        self.mode = AppMode::Confirm(ConfirmAction::UpdateProfile(changes));
+        self.animation.start_dialog_slide();
     }
 
-    // ─── Sorting ────────────────────────────────────────────────────
-
+    /// Handle keys in the connection settings dialog
+    fn handle_key_profile(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.profile_selected = self.profile_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.profile_selected =
+                    (self.profile_selected + 1).min(ProfileField::ALL.len() - 1);
+            }
+            KeyCode::Enter => self.edit_profile_field(ProfileField::ALL[self.profile_selected]),
+            KeyCode::Char('a') => self.apply_profile_edits(),
+            KeyCode::Char('r') => {
+                if let Some(ref mut edit) = self.profile_edit
+                    && edit.is_modified()
+                {
+                    edit.revert();
+                    self.show_toast(self.lang().tr("Reverted unsaved changes"), ToastLevel::Info);
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('q') => {
+                // Pending edits survive closing; reopening the dialog shows them
+                let pending = self
+                    .profile_edit
+                    .as_ref()
+                    .map_or(0, |edit| edit.changes().len());
+                if pending > 0 {
+                    let msg = self.lang().fill(
+                        "{pending} unsaved connection setting(s) — reopen the dialog to \
+                         apply or revert",
+                        &[("pending", &pending)],
+                    );
+                    self.show_toast(msg, ToastLevel::Warning);
+                }
+                self.mode = AppMode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    /// Open the editor for one row of the connection settings dialog
+    fn edit_profile_field(&mut self, field: ProfileField) {
+        if !self.connection_status.is_connected() {
+            self.mode = AppMode::Normal;
+            return;
+        }
+        let Some(ref edit) = self.profile_edit else {
+            self.mode = AppMode::Normal;
+            return;
+        };
+        let profile = &edit.modified;
+        match field {
+            ProfileField::Hostname => {
+                self.input_buffer = profile.dhcp_hostname.clone().unwrap_or_default();
+                self.mode = AppMode::Input(InputKind::DhcpHostname);
+            }
+            ProfileField::ClientId => {
+                self.input_buffer = profile.dhcp_client_id.clone().unwrap_or_default();
+                self.mode = AppMode::Input(InputKind::DhcpClientId);
+            }
+            ProfileField::RouteMetric => {
+                self.input_buffer = profile
+                    .route_metric
+                    .map(|m| m.to_string())
+                    .unwrap_or_default();
+                self.mode = AppMode::Input(InputKind::RouteMetric);
+            }
+            ProfileField::AutoconnectRetries => {
+                self.input_buffer = profile
+                    .autoconnect_retries
+                    .map(|n| n.to_string())
+                    .unwrap_or_default();
+                self.mode = AppMode::Input(InputKind::AutoconnectRetries);
+            }
+            ProfileField::Band => {
+                // Cycle any → 5 GHz → 2.4 GHz → any
+                let band = match profile.band {
+                    None => Some(FrequencyBand::FiveGhz),
+                    Some(FrequencyBand::FiveGhz) => Some(FrequencyBand::TwoGhz),
+                    Some(_) => None,
+                };
+                self.stage_profile_change(ProfileChange::Band(band));
+                return;
+            }
+            ProfileField::FastBand => {
+                let steer = !edit.fast_band;
+                self.stage_profile_change(ProfileChange::FastBand(steer));
+                return;
+            }
+            ProfileField::Proxy => {
+                let auto = !profile.proxy_auto;
+                self.stage_profile_change(ProfileChange::ProxyAuto(auto));
+                return;
+            }
+            ProfileField::PacUrl => {
+                if !profile.proxy_auto {
+                    self.show_error(
+                        self.lang()
+                            .tr("Set Proxy to auto first — a PAC URL is only used by auto"),
+                    );
+                    return;
+                }
+                self.input_buffer = profile.proxy_pac_url.clone().unwrap_or_default();
+                self.mode = AppMode::Input(InputKind::PacUrl);
+            }
+            ProfileField::SendHostname => {
+                let send = !profile.dhcp_send_hostname;
+                self.stage_profile_change(ProfileChange::SendHostname(send));
+                return;
+            }
+            ProfileField::Zone => {
+                // Cycle default → each common zone → default; a custom zone
+                // set elsewhere goes back to the default
+                let next = match profile.zone.as_deref() {
+                    None => FIREWALL_ZONES.first(),
+                    Some(zone) => FIREWALL_ZONES
+                        .iter()
+                        .position(|z| *z == zone)
+                        .and_then(|i| FIREWALL_ZONES.get(i + 1)),
+                };
+                let zone = next.map(|z| z.to_string());
+                self.stage_profile_change(ProfileChange::Zone(zone));
+                return;
+            }
+        }
+        self.animation.start_dialog_slide();
+    }
+
+    /// Handle keys in help overlay
+    fn handle_key_help(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('?') | KeyCode::Char('/') | KeyCode::Esc | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    /// Keys shared by the diagnostics tools: switch between the ones that
+    /// have output and clear it. Returns whether the key was handled.
+    fn handle_key_diag_tool(&mut self, key: &KeyEvent) -> bool {
+        let Some(current) = DiagTool::of(&self.mode) else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Tab => self.cycle_diag_tool(current, 1),
+            KeyCode::BackTab => self.cycle_diag_tool(current, DiagTool::ALL.len() - 1),
+            KeyCode::Char('c') => {
+                self.clear_diag_tool(current);
+                let lang = self.lang();
+                let msg = lang.fill("Cleared {tool}", &[("tool", &lang.tr(current.label()))]);
+                self.show_toast(msg, ToastLevel::Info);
+                self.mode = match self.diag_tools_with_output().first() {
+                    Some(tool) => tool.mode(),
+                    None => AppMode::Normal,
+                };
+            }
+            KeyCode::Char('C') => {
+                for tool in DiagTool::ALL {
+                    self.clear_diag_tool(tool);
+                }
+                self.show_toast(
+                    self.lang().tr("Cleared all diagnostics output"),
+                    ToastLevel::Info,
+                );
+                self.mode = AppMode::Normal;
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Show the next tool with output, `step` places on in `DiagTool::ALL`
+    fn cycle_diag_tool(&mut self, current: DiagTool, step: usize) {
+        let tools = DiagTool::ALL;
+        let at = tools.iter().position(|&t| t == current).unwrap_or(0);
+        let with_output = self.diag_tools_with_output();
+        let next = (1..tools.len())
+            .map(|i| tools[(at + i * step) % tools.len()])
+            .find(|tool| with_output.contains(tool));
+        if let Some(tool) = next {
+            self.mode = tool.mode();
+        }
+    }
+
+    /// The tools that ran (or are running) since last cleared
+    fn diag_tools_with_output(&self) -> Vec<DiagTool> {
+        DiagTool::ALL
+            .into_iter()
+            .filter(|tool| match tool {
+                DiagTool::Report => self.diagnosing || self.diagnostics.is_some(),
+                DiagTool::HttpHeaders => !self.http_headers.is_empty(),
+                DiagTool::MtuProbe => !self.mtu_probe.is_empty(),
+                DiagTool::PingCompare => !self.ping_compare.is_empty(),
+            })
+            .collect()
+    }
+
+    /// Drop a tool's output. Bumping the run tag drops whatever a run still
+    /// in progress streams in afterwards.
+    fn clear_diag_tool(&mut self, tool: DiagTool) {
+        match tool {
+            DiagTool::Report => {
+                self.diagnostics_run += 1;
+                self.diagnostics = None;
+                self.diagnosing = false;
+            }
+            DiagTool::HttpHeaders => {
+                self.http_headers_run += 1;
+                self.http_headers.clear();
+                self.http_headers_scroll = 0;
+            }
+            DiagTool::MtuProbe => {
+                self.mtu_probe_run += 1;
+                self.mtu_probe.clear();
+            }
+            DiagTool::PingCompare => {
+                self.ping_compare_run += 1;
+                self.ping_compare.clear();
+            }
+        }
+    }
+
+    /// Handle keys in the diagnostics report
+    fn handle_key_diagnostics(&mut self, key: KeyEvent) {
+        if self.handle_key_diag_tool(&key) {
+            return;
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Char('r') if self.diagnostics.is_some() => {
+                self.action_diagnose();
+            }
+            KeyCode::Char('h') => self.prompt_header_url(),
+            KeyCode::Char('m') => self.prompt_mtu_target(),
+            KeyCode::Char('p') => self.prompt_ping_targets(),
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the ping comparison
+    fn handle_key_ping_compare(&mut self, key: KeyEvent) {
+        if self.handle_key_diag_tool(&key) {
+            return;
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Char('r') if self.ping_compare_finished() => {
+                let targets = self.ping_targets.clone();
+                self.compare_ping(targets);
+            }
+            KeyCode::Char('p') => self.prompt_ping_targets(),
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the path MTU probe
+    fn handle_key_mtu_probe(&mut self, key: KeyEvent) {
+        if self.handle_key_diag_tool(&key) {
+            return;
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Char('r') if self.mtu_probe_finished() => {
+                let host = self.mtu_probe_host.clone();
+                self.probe_path_mtu(host);
+            }
+            KeyCode::Char('m') => self.prompt_mtu_target(),
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the HTTP header inspection
+    fn handle_key_http_headers(&mut self, key: KeyEvent) {
+        if self.handle_key_diag_tool(&key) {
+            return;
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.http_headers_scroll = self.http_headers_scroll.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                let last = self.http_headers.len().saturating_sub(1) as u16;
+                self.http_headers_scroll = (self.http_headers_scroll + 1).min(last);
+            }
+            KeyCode::Char('r') if self.http_headers_finished() => {
+                let url = self.http_headers_url.clone();
+                self.inspect_headers(url);
+            }
+            KeyCode::Char('h') => self.prompt_header_url(),
+            _ => {}
+        }
+    }
+
+    /// Handle keys in site survey mode
+    fn handle_key_survey(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Esc || self.key_matches(&key, &self.config.keys.survey) {
+            self.mode = AppMode::Normal;
+            self.show_toast(self.lang().tr("Site survey stopped"), ToastLevel::Info);
+            return;
+        }
+        if self.handle_key_page(&key) {
+            return;
+        }
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.survey_selected = self.survey_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.survey_selected + 1 < self.survey.len() => {
+                self.survey_selected += 1;
+            }
+            KeyCode::Char('c') => {
+                self.survey.reset();
+                self.survey_selected = 0;
+                self.show_toast(self.lang().tr("Survey statistics reset"), ToastLevel::Info);
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the interface statistics view
+    fn handle_key_statistics(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Esc || self.key_matches(&key, &self.config.keys.statistics) {
+            self.mode = AppMode::Normal;
+            return;
+        }
+        if self.handle_key_page(&key) {
+            return;
+        }
+        if self.key_matches(&key, &self.config.keys.palette) {
+            self.open_palette(AppMode::Palette);
+            return;
+        }
+        if self.key_matches(&key, &self.config.keys.compact) {
+            self.toggle_compact_columns();
+            return;
+        }
+        if self.key_matches(&key, &self.config.keys.sort) {
+            self.stats_sort = self.stats_sort.next();
+            self.sort_interfaces();
+            return;
+        }
+        match key.code {
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.stats_focus = self.stats_focus.saturating_sub(1);
+            }
+            KeyCode::Right | KeyCode::Char('l')
+                if self.stats_focus + 1 < self.interface_stats.len() =>
+            {
+                self.stats_focus += 1;
+            }
+            KeyCode::Char('r') => self.stats_range = self.stats_range.next(),
+            _ => {}
+        }
+    }
+
+    /// Page navigation, shared by every page: `1`–`9` jump to a page, the
+    /// next / previous page keys cycle through `[layout] pages`. Returns
+    /// whether the key was consumed.
+    fn handle_key_page(&mut self, key: &KeyEvent) -> bool {
+        if key.code == KeyCode::Char('f') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.open_palette(AppMode::GlobalSearch);
+            return true;
+        }
+        let target = if let KeyCode::Char(c @ '1'..='9') = key.code {
+            self.pages.get(c as usize - '1' as usize).copied()
+        } else if self.key_matches(key, &self.config.keys.next_page) {
+            self.adjacent_page(1)
+        } else if self.key_matches(key, &self.config.keys.prev_page) {
+            self.adjacent_page(self.pages.len() - 1)
+        } else {
+            return false;
+        };
+        if let Some(view) = target {
+            self.switch_view(view);
+        }
+        true
+    }
+
+    /// The page `step` places after the current one, wrapping around. A page
+    /// left out of the layout (opened by its own key) counts as before the first.
+    fn adjacent_page(&self, step: usize) -> Option<View> {
+        let len = self.pages.len();
+        let next = match self.pages.iter().position(|&p| p == self.current_view()) {
+            Some(i) => (i + step) % len,
+            None => 0,
+        };
+        self.pages.get(next).copied()
+    }
+
+    /// The page currently shown in the body
+    pub fn current_view(&self) -> View {
+        match self.mode {
+            AppMode::Survey => View::Survey,
+            AppMode::Statistics => View::Statistics,
+            _ => View::Networks,
+        }
+    }
+
+    fn switch_view(&mut self, view: View) {
+        if view == self.current_view() {
+            return;
+        }
+        if matches!(self.mode, AppMode::Survey) {
+            self.show_toast(self.lang().tr("Site survey stopped"), ToastLevel::Info);
+        }
+        match view {
+            View::Networks => self.mode = AppMode::Normal,
+            View::Statistics => self.mode = AppMode::Statistics,
+            View::Survey => {
+                self.mode = AppMode::Normal;
+                self.action_survey();
+            }
+        }
+    }
+
+    /// Results of the command palette (as search hits) or the global search
+    pub fn palette_hits(&self) -> Vec<Hit> {
+        if matches!(self.mode, AppMode::GlobalSearch) {
+            crate::palette::search(
+                &self.palette_query,
+                self.networks.iter().map(|n| n.ssid.as_str()),
+                self.interface_stats.iter().map(|s| s.name.as_str()),
+                &self.pages,
+            )
+        } else {
+            crate::palette::matches(&self.palette_query, &self.pages)
+                .into_iter()
+                .map(Hit::Action)
+                .collect()
+        }
+    }
+
+    /// Handle keys in the command palette and the global search
+    fn handle_key_palette(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                self.palette_query.clear();
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Enter => {
+                let picked = self.palette_hits().get(self.palette_selected).cloned();
+                self.palette_query.clear();
+                self.mode = AppMode::Normal;
+                if let Some(hit) = picked {
+                    self.run_hit(hit);
+                }
+            }
+            KeyCode::Up => self.palette_selected = self.palette_selected.saturating_sub(1),
+            KeyCode::Down => {
+                let count = self.palette_hits().len();
+                if self.palette_selected + 1 < count {
+                    self.palette_selected += 1;
+                }
+            }
+            KeyCode::Backspace => {
+                self.palette_query.pop();
+                self.palette_selected = 0;
+            }
+            KeyCode::Char(c) => {
+                self.palette_query.push(c);
+                self.palette_selected = 0;
+            }
+            _ => {}
+        }
+    }
+
+    /// Open the command palette or the global search with an empty query
+    fn open_palette(&mut self, mode: AppMode) {
+        self.palette_query.clear();
+        self.palette_selected = 0;
+        self.mode = mode;
+        self.animation.start_dialog_slide();
+    }
+
+    /// Jump to the page owning a search hit and select it there
+    fn run_hit(&mut self, hit: Hit) {
+        match hit {
+            Hit::Network(ssid) => {
+                self.switch_view(View::Networks);
+                // The list filter may be hiding it
+                if !self.select_ssid(&ssid) {
+                    self.search_query.clear();
+                    self.rebuild_filter();
+                    self.select_ssid(&ssid);
+                }
+            }
+            Hit::Interface(name) => {
+                self.switch_view(View::Statistics);
+                if let Some(pos) = self.interface_stats.iter().position(|s| s.name == name) {
+                    self.stats_focus = pos;
+                }
+            }
+            Hit::Action(action) => self.run_action(action),
+        }
+    }
+
+    fn open_quick_actions(&mut self) {
+        if self.selected_network().is_none() {
+            return;
+        }
+        self.quick_selected = 0;
+        self.mode = AppMode::QuickActions;
+        self.animation.start_dialog_slide();
+    }
+
+    /// Actions offered for the selected network, with the ones that don't
+    /// apply to it explained rather than hidden
+    pub fn quick_actions(&self) -> Vec<QuickAction> {
+        let Some(net) = self.selected_network() else {
+            return Vec::new();
+        };
+        let connected = self.connection_status.is_connected();
+        let item = |action: Action, unavailable: Option<&'static str>| QuickAction {
+            action,
+            label: action.label(),
+            unavailable,
+        };
+        let mut items = vec![
+            item(
+                Action::Connect,
+                net.is_active.then_some("already connected"),
+            ),
+            item(
+                Action::Disconnect,
+                (!net.is_active).then_some("not the active network"),
+            ),
+            QuickAction {
+                label: if self.favorites.contains(&net.ssid) {
+                    "Unpin"
+                } else {
+                    Action::TogglePin.label()
+                },
+                ..item(Action::TogglePin, None)
+            },
+            QuickAction {
+                label: if self.marked.contains(&net.ssid) {
+                    "Unmark"
+                } else {
+                    Action::ToggleMark.label()
+                },
+                ..item(Action::ToggleMark, (!net.is_saved).then_some("not saved"))
+            },
+            item(Action::Forget, (!net.is_saved).then_some("not saved")),
+            item(Action::ToggleDetails, None),
+        ];
+        let not_active = (!net.is_active || !connected).then_some("not the active network");
+        items.push(item(Action::ConnectionSettings, not_active));
+        items.push(item(Action::ShellEscape, not_active));
+        items
+    }
+
+    /// Handle keys in the quick actions menu
+    fn handle_key_quick_actions(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Esc || self.key_matches(&key, &self.config.keys.quick_actions) {
+            self.mode = AppMode::Normal;
+            return;
+        }
+        let items = self.quick_actions();
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.quick_selected = self.quick_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.quick_selected + 1 < items.len() => {
+                self.quick_selected += 1;
+            }
+            KeyCode::Enter => {
+                let Some(item) = items.get(self.quick_selected) else {
+                    return;
+                };
+                if let Some(reason) = item.unavailable {
+                    let lang = self.lang();
+                    let msg = format!("{}: {}", lang.tr(item.label), lang.tr(reason));
+                    self.show_toast(msg, ToastLevel::Info);
+                    return;
+                }
+                self.mode = AppMode::Normal;
+                self.run_action(item.action);
+            }
+            _ => {}
+        }
+    }
+
+    fn toggle_compact_columns(&mut self) {
+        self.compact_columns = !self.compact_columns;
+        let msg = self.lang().tr(if self.compact_columns {
+            "Compact columns"
+        } else {
+            "Configured columns"
+        });
+        self.show_toast(msg, ToastLevel::Info);
+    }
+
+    /// Network list columns in effect
+    pub fn wifi_columns(&self) -> WifiColumns {
+        if self.compact_columns {
+            WifiColumns::COMPACT
+        } else {
+            self.config.appearance.wifi_columns
+        }
+    }
+
+    /// Statistics table columns in effect
+    pub fn interface_columns(&self) -> InterfaceColumns {
+        if self.compact_columns {
+            InterfaceColumns::COMPACT
+        } else {
+            self.config.appearance.interface_columns
+        }
+    }
+
+    /// List the saved networks that connected most recently
+    fn open_recent(&mut self) {
+        self.recent = None;
+        self.recent_selected = 0;
+        self.mode = AppMode::Recent;
+        self.animation.start_dialog_slide();
+        let _ = self
+            .event_tx
+            .send(Event::Command(NetworkCommand::SavedProfiles));
+    }
+
+    /// Keep the profiles that ever connected, newest first
+    pub fn on_saved_profiles(&mut self, mut profiles: Vec<SavedProfile>) {
+        if !matches!(self.mode, AppMode::Recent) {
+            return;
+        }
+        profiles.retain(|p| p.last_connected.is_some());
+        profiles.sort_by_key(|p| Reverse(p.last_connected));
+        profiles.truncate(RECENT_LIMIT);
+        self.recent = Some(profiles);
+    }
+
+    /// List the WiFi adapters to pick from
+    fn open_wifi_devices(&mut self) {
+        self.wifi_devices = None;
+        self.device_selected = 0;
+        self.mode = AppMode::WifiDevices;
+        self.animation.start_dialog_slide();
+        let _ = self
+            .event_tx
+            .send(Event::Command(NetworkCommand::WifiDevices));
+    }
+
+    /// Show the adapters, starting on the one in use
+    pub fn on_wifi_devices(&mut self, devices: Vec<WifiDevice>) {
+        if !matches!(self.mode, AppMode::WifiDevices) {
+            return;
+        }
+        self.device_selected = devices
+            .iter()
+            .position(|d| d.interface == self.interface_name)
+            .unwrap_or(0);
+        self.wifi_devices = Some(devices);
+    }
+
+    /// Handle keys in the WiFi adapter picker
+    fn handle_key_wifi_devices(&mut self, key: KeyEvent) {
+        let count = self.wifi_devices.as_ref().map_or(0, Vec::len);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.mode = AppMode::Normal,
+            _ if self.key_matches(&key, &self.config.keys.wifi_device) => {
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.device_selected = self.device_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.device_selected + 1 < count => {
+                self.device_selected += 1;
+            }
+            KeyCode::Enter => {
+                let Some(interface) = self
+                    .wifi_devices
+                    .as_ref()
+                    .and_then(|d| d.get(self.device_selected))
+                    .map(|d| d.interface.clone())
+                else {
+                    return;
+                };
+                self.mode = AppMode::Normal;
+                if interface != self.interface_name {
+                    let _ = self
+                        .event_tx
+                        .send(Event::Command(NetworkCommand::SwitchDevice { interface }));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The backend moved to another adapter: nothing seen through the old
+    /// one applies, so start over with a refresh and a scan
+    pub fn on_device_switched(&mut self, interface: String) {
+        let msg = self
+            .lang()
+            .fill("Using {interface}", &[("interface", &interface)]);
+        self.show_toast(msg, ToastLevel::Success);
+        self.interface_name = interface;
+        self.networks.clear();
+        self.networks_epoch += 1;
+        self.rebuild_filter();
+        self.stats = None;
+        self.traffic.clear();
+        self.action_refresh();
+        self.action_scan();
+    }
+
+    /// Whether the last scan saw `ssid`
+    pub fn in_range(&self, ssid: &str) -> bool {
+        self.networks.iter().any(|n| n.ssid == ssid)
+    }
+
+    /// Handle keys in the recent networks list
+    fn handle_key_recent(&mut self, key: KeyEvent) {
+        let count = self.recent.as_ref().map_or(0, Vec::len);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.mode = AppMode::Normal,
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.recent_selected = self.recent_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.recent_selected + 1 < count => {
+                self.recent_selected += 1;
+            }
+            KeyCode::Enter => {
+                let Some(ssid) = self
+                    .recent
+                    .as_ref()
+                    .and_then(|r| r.get(self.recent_selected))
+                    .map(|p| p.ssid.clone())
+                else {
+                    return;
+                };
+                self.mode = AppMode::Normal;
+                self.connect_saved(ssid);
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the theme preview
+    fn handle_key_theme_preview(&mut self, key: KeyEvent) {
+        let swatches = crate::ui::theme_preview::swatches(&self.theme);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.mode = AppMode::Normal,
+            _ if self.key_matches(&key, &self.config.keys.theme_preview) => {
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.theme_selected = self.theme_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.theme_selected + 1 < swatches.len() => {
+                self.theme_selected += 1;
+            }
+            KeyCode::Char('y') => {
+                if let Some(&(name, color)) = swatches.get(self.theme_selected) {
+                    let value = crate::ui::theme_preview::color_value(color);
+                    self.copy_to_clipboard(&value, name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Activate a saved profile by name. Unlike `connect_row` the network
+    /// need not be in the scan results: NM probes for it, hidden or not.
+    fn connect_saved(&mut self, ssid: String) {
+        if self.reject_if_unmanaged() || self.reject_if_deactivating() || self.reject_if_radio_off()
+        {
+            return;
+        }
+        if matches!(&self.connection_status, ConnectionStatus::Connected(info) if info.ssid == ssid)
+        {
+            let msg = self
+                .lang()
+                .fill("Already connected to '{ssid}'", &[("ssid", &ssid)]);
+            self.show_toast(msg, ToastLevel::Info);
+            return;
+        }
+        self.mode = AppMode::Connecting;
+        self.connection_status = ConnectionStatus::Connecting(ssid.clone());
+        self.animation.start_spinner();
+        self.dispatch_connect(ssid, None, None);
+    }
+
+    /// Switch to the picked view or run the picked action, exactly as its
+    /// key would from the network list
+    fn run_action(&mut self, action: Action) {
+        match action {
+            Action::Page(view) => self.switch_view(view),
+            Action::Connect => self.action_connect(),
+            Action::TogglePin => self.action_toggle_favorite(),
+            Action::ToggleMark => self.action_toggle_mark(),
+            Action::Forget => self.action_forget(),
+            Action::ToggleDetails => self.toggle_details(),
+            Action::ShellEscape => self.action_shell_escape(),
+            Action::Events => {
+                self.mode = AppMode::Events;
+                self.animation.start_dialog_slide();
+            }
+            Action::Diagnostics => self.action_diagnose(),
+            Action::ConnectionSettings => self.action_edit_profile(),
+            Action::Help => {
+                self.mode = AppMode::Help;
+                self.animation.start_dialog_slide();
+            }
+            Action::Scan => self.action_scan(),
+            Action::Disconnect => self.action_disconnect(),
+            Action::Refresh => self.action_refresh(),
+            Action::IgnoreList => self.open_ignore_list(),
+            Action::WriteKeys => match crate::cheatsheet::write(self.config.keys()) {
+                Ok(path) => self.show_toast(
+                    self.lang()
+                        .fill("Keys written to {path}", &[("path", &path.display())]),
+                    ToastLevel::Success,
+                ),
+                Err(e) => self.report_error("Write keys", &format!("{e:#}")),
+            },
+        }
+    }
+
+    /// Handle keys while the detail panel has focus
+    fn handle_key_detail_focus(&mut self, key: KeyEvent) {
+        let fields = crate::ui::details::fields(self);
+        match key.code {
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Esc => {
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.detail_cursor = self.detail_cursor.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.detail_cursor + 1 < fields.len() => {
+                self.detail_cursor += 1;
+            }
+            KeyCode::Char('y') => {
+                let last = fields.len().saturating_sub(1);
+                if let Some(field) = fields.get(self.detail_cursor.min(last)) {
+                    self.copy_to_clipboard(&field.value, &field.label);
+                }
+            }
+            KeyCode::Char('c') => {
+                if let Some(cmd) = self.selected_network().map(WiFiNetwork::connect_command) {
+                    self.copy_to_clipboard(&cmd, self.lang().tr("Connect command"));
+                }
+            }
+            KeyCode::Char('n') => self.edit_label(InputKind::Nickname),
+            KeyCode::Char('N') => self.edit_label(InputKind::Note),
+            _ => {}
+        }
+    }
+
+    /// Ask for the selected network's nickname or note, starting from the
+    /// current one
+    fn edit_label(&mut self, kind: InputKind) {
+        let Some(net) = self.selected_network() else {
+            return;
+        };
+        let label = self.labels.get(&net.ssid).cloned().unwrap_or_default();
+        self.input_buffer = match kind {
+            InputKind::Nickname => label.nickname,
+            _ => label.note,
+        };
+        self.mode = AppMode::Input(kind);
+        self.animation.start_dialog_slide();
+    }
+
+    /// Store a submitted nickname or note for the selected network
+    fn save_label(&mut self, kind: InputKind, value: String) {
+        self.mode = AppMode::DetailFocus;
+        let Some(ssid) = self.selected_network().map(|n| n.ssid.clone()) else {
+            return;
+        };
+        let saved = if kind == InputKind::Nickname {
+            self.labels.set_nickname(&ssid, value)
+        } else {
+            self.labels.set_note(&ssid, value)
+        };
+        if let Err(e) = saved {
+            self.report_error("Save labels", &format!("{e:#}"));
+        }
+        // A nickname can change what the filter matches
+        self.rebuild_filter();
+        self.select_ssid(&ssid);
+    }
+
+    /// Handle keys in the connection events log
+    fn handle_key_events(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Char('c') => self.nm_events.clear(),
+            _ if self.key_matches(&key, &self.config.keys().events) => {
+                self.mode = AppMode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the shell escape dialog
+    fn handle_key_shell_escape(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Char('y') => {
+                if let Some(cmd) = self.nmcli_command() {
+                    self.copy_to_clipboard(&cmd, self.lang().tr("Command"));
+                }
+            }
+            KeyCode::Char('s') => {
+                if let ConnectionStatus::Connected(info) = &self.connection_status {
+                    let cmd = info.share_command();
+                    self.copy_to_clipboard(
+                        &cmd,
+                        self.lang().tr("Share command (password not included)"),
+                    );
+                }
+            }
+            KeyCode::Char('p') => {
+                let keyfile = match &self.connection_status {
+                    ConnectionStatus::Connected(info) => info.profile.keyfile.clone(),
+                    _ => None,
+                };
+                match keyfile {
+                    Some(path) => self.copy_to_clipboard(&path, self.lang().tr("Keyfile path")),
+                    None => {
+                        self.show_toast(self.lang().tr("Keyfile path unknown"), ToastLevel::Info)
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Copy `text` via OSC 52 and report it as `what`
+    fn copy_to_clipboard(&mut self, text: &str, what: &str) {
+        let lang = self.lang();
+        match crate::clipboard::copy(text) {
+            Ok(()) => self.show_toast(
+                lang.fill("{what} copied to clipboard", &[("what", &what)]),
+                ToastLevel::Success,
+            ),
+            Err(e) => self.show_toast(
+                lang.fill("Copy failed: {error}", &[("error", &e)]),
+                ToastLevel::Warning,
+            ),
+        }
+    }
+
+    /// `nmcli connection show <uuid>` for the active profile
+    pub fn nmcli_command(&self) -> Option<String> {
+        let ConnectionStatus::Connected(info) = &self.connection_status else {
+            return None;
+        };
+        let uuid = info.profile.uuid.as_deref()?;
+        Some(format!("nmcli connection show {uuid}"))
+    }
+
+    /// Handle keys in error dialog
+    fn handle_key_error(&mut self, key: KeyEvent) {
+        let AppMode::Error(msg) = &self.mode else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Char('y') => {
+                let msg = msg.clone();
+                self.copy_to_clipboard(&msg, self.lang().tr("Error"));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.error_scroll = self.error_scroll.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                // Rough upper bound on wrapped lines; the renderer clamps exactly
+                let max = (msg.lines().count() + msg.len() / 30) as u16;
+                self.error_scroll = (self.error_scroll + 1).min(max);
+            }
+            _ => {}
+        }
+    }
+
+    // ─── Navigation ─────────────────────────────────────────────────
+
+    fn select_prev(&mut self) {
+        if !self.filtered_indices.is_empty() {
+            self.selected_index = self.selected_index.saturating_sub(1);
+        }
+    }
+
+    fn select_next(&mut self) {
+        if !self.filtered_indices.is_empty() {
+            self.selected_index = (self.selected_index + 1).min(self.filtered_indices.len() - 1);
+        }
+    }
+
+    fn select_first(&mut self) {
+        self.selected_index = 0;
+    }
+
+    fn select_last(&mut self) {
+        if !self.filtered_indices.is_empty() {
+            self.selected_index = self.filtered_indices.len() - 1;
+        }
+    }
+
+    // ─── Sorting ────────────────────────────────────────────────────
+
     /// Apply the current sort mode to `self.networks`
     fn apply_sort(&mut self) {
-        match self.sort_mode {
-            SortMode::Signal => {
-                self.networks.sort_by(|a, b| {
-                    b.is_active
-                        .cmp(&a.is_active)
-                        .then(b.signal_strength.cmp(&a.signal_strength))
-                });
-            }
-            SortMode::Alphabetical => {
-                self.networks.sort_by(|a, b| {
-                    b.is_active
-                        .cmp(&a.is_active)
-                        .then(a.ssid.to_lowercase().cmp(&b.ssid.to_lowercase()))
-                });
-            }
-            SortMode::Security => {
-                self.networks.sort_by(|a, b| {
-                    b.is_active
-                        .cmp(&a.is_active)
-                        .then(security_rank(&b.security).cmp(&security_rank(&a.security)))
-                        .then(b.signal_strength.cmp(&a.signal_strength))
-                });
-            }
-            SortMode::Band => {
-                self.networks.sort_by(|a, b| {
-                    b.is_active
-                        .cmp(&a.is_active)
-                        .then(b.frequency.cmp(&a.frequency))
-                        .then(b.signal_strength.cmp(&a.signal_strength))
-                });
-            }
+        sort_networks(&mut self.networks, self.sort_mode, &self.favorites);
+    }
+
+    // ─── Actions ────────────────────────────────────────────────────
+
+    /// If the WiFi device is unmanaged, explain why an action can't run.
+    /// Returns true when the caller should bail out.
+    fn reject_if_unmanaged(&mut self) -> bool {
+        if self.device_state != DeviceState::Unmanaged {
+            return false;
+        }
+        let msg = self.lang().fill(
+            "{interface} is managed by another tool — press [{key}] to hand it to NetworkManager",
+            &[
+                ("interface", &self.interface_name),
+                ("key", &self.config.keys.manage),
+            ],
+        );
+        self.show_toast(msg, ToastLevel::Warning);
+        true
+    }
+
+    /// While the device is tearing its connection down, starting another
+    /// change would race it. Returns true when the caller should bail out.
+    fn reject_if_deactivating(&mut self) -> bool {
+        if self.device_state != DeviceState::Deactivating {
+            return false;
+        }
+        let msg = self.lang().fill(
+            "{interface} is disconnecting — wait for it to finish",
+            &[("interface", &self.interface_name)],
+        );
+        self.show_toast(msg, ToastLevel::Warning);
+        true
+    }
+
+    /// If the radio is off, offer to turn it on. Returns true when the
+    /// caller should bail out.
+    fn reject_if_radio_off(&mut self) -> bool {
+        if self.device_state != DeviceState::Unavailable {
+            return false;
+        }
+        self.mode = AppMode::Confirm(ConfirmAction::EnableWireless);
+        self.animation.start_dialog_slide();
+        true
+    }
+
+    fn action_connect(&mut self) {
+        self.connect_row(self.selected_index);
+    }
+
+    /// Connect to the network at `row` of the visible list, leaving the
+    /// selection where it is
+    fn connect_row(&mut self, row: usize) {
+        if self.reject_if_unmanaged() || self.reject_if_deactivating() || self.reject_if_radio_off()
+        {
+            return;
+        }
+        let Some(net) = self
+            .filtered_indices
+            .get(row)
+            .and_then(|&i| self.networks.get(i))
+        else {
+            return;
+        };
+
+        // Already connected
+        if net.is_active {
+            return;
+        }
+
+        // Only PSK profiles can be created here; 802.1X needs EAP settings
+        if net.security == SecurityType::WPA2Enterprise && !net.is_saved {
+            let msg = self.lang().fill(
+                "'{ssid}' uses 802.1X (enterprise) login — set it up once with nmcli or \
+                 nm-connection-editor, then connect here",
+                &[("ssid", &net.ssid)],
+            );
+            self.show_error(msg);
+            return;
+        }
+
+        if net.security.needs_password() && !net.is_saved {
+            let ssid = net.ssid.clone();
+            let security = net.security.clone();
+            let ap_path = net.ap_path.clone();
+            self.password_input.clear();
+            self.password_visible = false;
+            self.mode = AppMode::PasswordInput {
+                ssid,
+                security,
+                ap_path,
+            };
+            self.animation.start_dialog_slide();
+        } else {
+            let ssid = net.ssid.clone();
+            let ap_path = net.ap_path.clone();
+            self.mode = AppMode::Connecting;
+            self.connection_status = ConnectionStatus::Connecting(ssid.clone());
+            self.animation.start_spinner();
+            self.dispatch_connect(ssid, None, Some(ap_path));
+        }
+    }
+
+    fn action_disconnect(&mut self) {
+        if self.reject_if_unmanaged() || self.reject_if_deactivating() {
+            return;
+        }
+        if !self.connection_status.is_connected() || self.connection_status.is_busy() {
+            return;
+        }
+        if carries_default_route(&self.interface_name) {
+            let over_ssh = std::env::var_os("SSH_CONNECTION").is_some();
+            self.mode = AppMode::Confirm(ConfirmAction::DisconnectDefaultRoute { over_ssh });
+            self.animation.start_dialog_slide();
+            return;
+        }
+        self.dispatch_disconnect();
+    }
+
+    /// Abort an activation in progress by disconnecting the device
+    fn cancel_connect(&mut self) {
+        let ConnectionStatus::Connecting(ssid) = &self.connection_status else {
+            return;
+        };
+        let msg = self
+            .lang()
+            .fill("Cancelled connecting to {ssid}", &[("ssid", ssid)]);
+        self.show_toast(msg, ToastLevel::Info);
+        self.dispatch_disconnect();
+    }
+
+    fn dispatch_disconnect(&mut self) {
+        self.mode = AppMode::Disconnecting;
+        self.connection_status = ConnectionStatus::Disconnecting;
+        self.animation.start_spinner();
+        let _ = self
+            .event_tx
+            .send(Event::Command(NetworkCommand::Disconnect));
+    }
+
+    fn action_scan(&mut self) {
+        if matches!(self.mode, AppMode::Scanning) {
+            return;
+        }
+        self.mode = AppMode::Scanning;
+        self.animation.start_spinner();
+        self.last_scan = Instant::now();
+        let _ = self.event_tx.send(Event::Command(NetworkCommand::Scan));
+    }
+
+    /// Scan and replace the list with only what this scan sees, for when
+    /// NM's cache still lists networks from a previous location
+    fn action_fresh_scan(&mut self) {
+        if matches!(self.mode, AppMode::Scanning) {
+            return;
+        }
+        if self.reject_if_unmanaged() || self.reject_if_radio_off() {
+            return;
+        }
+        self.mode = AppMode::Scanning;
+        self.animation.start_spinner();
+        self.last_scan = Instant::now();
+        let _ = self
+            .event_tx
+            .send(Event::Command(NetworkCommand::FreshScan));
+    }
+
+    /// Replace the list with a fresh scan's networks, once no dialog is open
+    pub fn on_fresh_scan(&mut self, networks: Vec<WiFiNetwork>) {
+        if self.dialog_open() {
+            self.pending_scan = Some(networks);
+            self.pending_fresh = true;
+            return;
+        }
+        self.apply_fresh_scan(networks);
+    }
+
+    /// A fresh scan failed: leave the scanning state and report it
+    pub fn on_fresh_scan_failed(&mut self, error: &str) {
+        if matches!(self.mode, AppMode::Scanning) {
+            self.mode = AppMode::Normal;
+            self.animation.stop_spinner();
+        }
+        self.report_error("Fresh scan", error);
+    }
+
+    fn apply_fresh_scan(&mut self, networks: Vec<WiFiNetwork>) {
+        let before = self.networks.len();
+        // Without the old entries there is nothing to linger
+        self.networks
+            .retain(|old| networks.iter().any(|n| n.ssid == old.ssid));
+        let dropped = before - self.networks.len();
+        self.update_networks(networks);
+        let msg = self.lang().fill(
+            "Fresh scan: {count} networks, {dropped} stale dropped",
+            &[("count", &self.networks.len()), ("dropped", &dropped)],
+        );
+        self.show_toast(msg, ToastLevel::Success);
+    }
+
+    /// Enter site survey mode; the first scan starts right away
+    fn action_survey(&mut self) {
+        if self.reject_if_unmanaged() || self.reject_if_radio_off() {
+            return;
+        }
+        self.mode = AppMode::Survey;
+        self.next_survey_scan = Instant::now();
+    }
+
+    /// Issue the next survey scan when due
+    fn survey_scan_if_due(&mut self) {
+        if !matches!(self.mode, AppMode::Survey) || Instant::now() < self.next_survey_scan {
+            return;
         }
+        let every =
+            Duration::from_secs(self.config.general.survey_interval_secs).max(MIN_SCAN_INTERVAL);
+        self.next_survey_scan = Instant::now() + every;
+        self.last_scan = Instant::now();
+        let _ = self
+            .event_tx
+            .send(Event::Command(NetworkCommand::SurveyScan));
     }
 
-    // ─── Actions ────────────────────────────────────────────────────
+    fn action_toggle_auto_scan(&mut self) {
+        self.auto_scan = match self.auto_scan {
+            Some(_) => None,
+            None => {
+                let secs = self.config.general.auto_scan_secs;
+                let every = if secs > 0 {
+                    Duration::from_secs(secs)
+                } else {
+                    DEFAULT_AUTO_SCAN
+                };
+                Some(every.max(MIN_SCAN_INTERVAL))
+            }
+        };
+        let lang = self.lang();
+        let msg = match self.auto_scan {
+            Some(every) => lang.fill(
+                "Auto-scan on (every {secs}s)",
+                &[("secs", &every.as_secs())],
+            ),
+            None => lang.tr("Auto-scan off").to_string(),
+        };
+        self.show_toast(msg, ToastLevel::Info);
+    }
 
-    fn action_connect(&mut self) {
+    /// Fire a background rescan when auto-scan is due. Skipped while a
+    /// dialog is open, a connection is in flight, or the radio is off.
+    fn auto_scan_if_due(&mut self) {
+        let Some(every) = self.auto_scan else {
+            return;
+        };
+        if !matches!(self.mode, AppMode::Normal)
+            || matches!(
+                self.device_state,
+                DeviceState::Unmanaged | DeviceState::Unavailable
+            )
+            || self.last_scan.elapsed() < every
+        {
+            return;
+        }
+        self.last_scan = Instant::now();
+        let _ = self.event_tx.send(Event::Command(NetworkCommand::Scan));
+    }
+
+    fn action_forget(&mut self) {
+        if !self.marked.is_empty() {
+            let ssids: Vec<String> = self.marked.iter().cloned().collect();
+            let includes_active = self
+                .networks
+                .iter()
+                .any(|n| n.is_active && self.marked.contains(&n.ssid));
+            self.mode = AppMode::Confirm(ConfirmAction::ForgetMarked {
+                ssids,
+                includes_active,
+            });
+            self.animation.start_dialog_slide();
+            return;
+        }
         let net = match self.selected_network() {
             Some(n) => n,
             None => return,
         };
-
-        // Already connected
+        if !net.is_saved {
+            self.show_error(self.lang().tr("Network is not saved"));
+            return;
+        }
+        let ssid = net.ssid.clone();
         if net.is_active {
+            if self.reject_if_deactivating() {
+                return;
+            }
+            self.mode = AppMode::Confirm(ConfirmAction::ForgetActive { ssid });
+            self.animation.start_dialog_slide();
+            return;
+        }
+        let _ = self
+            .event_tx
+            .send(Event::Command(NetworkCommand::Forget { ssid }));
+    }
+
+    /// Forget the selected saved network and connect to it again, so NM
+    /// builds a fresh profile. Confirmed first, as the password goes too.
+    fn action_rejoin(&mut self) {
+        if self.reject_if_unmanaged() || self.reject_if_deactivating() || self.reject_if_radio_off()
+        {
+            return;
+        }
+        let Some(net) = self.selected_network() else {
+            return;
+        };
+        if !net.is_saved {
+            self.show_error(
+                self.lang()
+                    .tr("Network is not saved — connect to it instead"),
+            );
+            return;
+        }
+        // The EAP settings could not be entered again here
+        if net.security == SecurityType::WPA2Enterprise {
+            let msg = self.lang().fill(
+                "'{ssid}' uses 802.1X (enterprise) login — its profile can't be recreated here, \
+                 so it is not forgotten",
+                &[("ssid", &net.ssid)],
+            );
+            self.show_error(msg);
             return;
         }
+        self.mode = AppMode::Confirm(ConfirmAction::Rejoin {
+            ssid: net.ssid.clone(),
+            active: net.is_active,
+        });
+        self.animation.start_dialog_slide();
+    }
 
-        if net.security.needs_password() && !net.is_saved {
-            let ssid = net.ssid.clone();
+    /// Second half of a rejoin: the profile is gone, so connect as to a
+    /// new network, asking for the password if it needs one
+    fn rejoin_forgotten(&mut self, ssid: String) {
+        let Some((security, ap_path)) = self
+            .networks
+            .iter()
+            .find(|n| n.ssid == ssid)
+            .map(|n| (n.security.clone(), n.ap_path.clone()))
+        else {
+            let msg = self.lang().fill(
+                "Forgot '{ssid}', but it is no longer in range",
+                &[("ssid", &ssid)],
+            );
+            self.show_toast(msg, ToastLevel::Warning);
+            return;
+        };
+        if security.needs_password() {
+            let msg = self.lang().fill(
+                "Forgot '{ssid}' — enter the password to rejoin",
+                &[("ssid", &ssid)],
+            );
+            self.show_toast(msg, ToastLevel::Info);
             self.password_input.clear();
             self.password_visible = false;
-            self.mode = AppMode::PasswordInput { ssid };
+            self.mode = AppMode::PasswordInput {
+                ssid,
+                security,
+                ap_path,
+            };
             self.animation.start_dialog_slide();
         } else {
-            let ssid = net.ssid.clone();
+            let msg = self
+                .lang()
+                .fill("Forgot '{ssid}' — rejoining…", &[("ssid", &ssid)]);
+            self.show_toast(msg, ToastLevel::Info);
             self.mode = AppMode::Connecting;
             self.connection_status = ConnectionStatus::Connecting(ssid.clone());
             self.animation.start_spinner();
-            self.dispatch_connect(ssid, None);
+            self.dispatch_connect(ssid, None, Some(ap_path));
         }
     }
 
-    fn action_disconnect(&mut self) {
-        if !self.connection_status.is_connected() || self.connection_status.is_busy() {
+    fn action_hidden(&mut self) {
+        if self.reject_if_unmanaged() || self.reject_if_radio_off() {
             return;
         }
-        self.mode = AppMode::Disconnecting;
-        self.connection_status = ConnectionStatus::Disconnecting;
-        self.animation.start_spinner();
+        self.hidden_ssid_input.clear();
+        self.hidden_password_input.clear();
+        self.hidden_field_focus = 0;
+        self.password_visible = false;
+        self.mode = AppMode::Hidden;
+        self.animation.start_dialog_slide();
+    }
+
+    fn action_mtu(&mut self) {
+        if self.reject_if_unmanaged() || self.reject_if_deactivating() {
+            return;
+        }
+        let ConnectionStatus::Connected(info) = &self.connection_status else {
+            self.show_error(
+                self.lang()
+                    .tr("Not connected — MTU can only be changed on an active connection"),
+            );
+            return;
+        };
+        self.input_buffer = if info.mtu > 0 {
+            info.mtu.to_string()
+        } else {
+            String::new()
+        };
+        self.mode = AppMode::Input(InputKind::Mtu);
+        self.animation.start_dialog_slide();
+    }
+
+    fn action_edit_profile(&mut self) {
+        if self.reject_if_unmanaged() || self.reject_if_deactivating() {
+            return;
+        }
+        if !self.connection_status.is_connected() {
+            self.show_error(
+                self.lang()
+                    .tr("Not connected — connection settings can only be edited while connected"),
+            );
+            return;
+        }
+        // Keep pending edits of this profile; otherwise start from what NM has
+        if let ConnectionStatus::Connected(info) = &self.connection_status
+            && !self
+                .profile_edit
+                .as_ref()
+                .is_some_and(|edit| edit.uuid == info.profile.uuid && edit.is_modified())
+        {
+            self.profile_edit = Some(ConnectionEditState::new(&info.profile));
+        }
+        self.profile_selected = 0;
+        self.mode = AppMode::ProfileEdit;
+        self.animation.start_dialog_slide();
+    }
+
+    fn action_connectivity_check(&mut self) {
+        let ConnectionStatus::Connected(info) = &self.connection_status else {
+            self.show_error(
+                self.lang().tr(
+                    "Not connected — the connectivity check is read from the active connection",
+                ),
+            );
+            return;
+        };
+        let check = &info.connectivity_check;
+        let Some(uri) = check.uri.clone().filter(|_| check.available) else {
+            self.show_error(
+                self.lang()
+                    .tr("NetworkManager has no connectivity check URI — \
+                 set uri= under [connectivity] in NetworkManager.conf"),
+            );
+            return;
+        };
+        let enabled = !check.enabled;
+        self.mode = AppMode::Confirm(ConfirmAction::SetConnectivityCheck { enabled, uri });
+        self.animation.start_dialog_slide();
+    }
+
+    fn action_manage(&mut self) {
+        if self.device_state != DeviceState::Unmanaged {
+            let msg = self.lang().fill(
+                "{interface} is already managed by NetworkManager",
+                &[("interface", &self.interface_name)],
+            );
+            self.show_toast(msg, ToastLevel::Info);
+            return;
+        }
+        self.mode = AppMode::Confirm(ConfirmAction::SetManaged);
+        self.animation.start_dialog_slide();
+    }
+
+    /// Show how to reach the active profile from a shell
+    fn action_shell_escape(&mut self) {
+        if self.nmcli_command().is_none() {
+            self.show_error(
+                self.lang()
+                    .tr("Not connected — there is no active profile to inspect"),
+            );
+            return;
+        }
+        self.mode = AppMode::ShellEscape;
+        self.animation.start_dialog_slide();
+    }
+
+    /// Show or hide the detail panel, remembering the choice for the next
+    /// launch
+    fn toggle_details(&mut self) {
+        self.detail_visible = !self.detail_visible;
+        if let Err(e) = self.view_state.set_details(self.detail_visible) {
+            self.report_error("Save view state", &format!("{e:#}"));
+        }
+    }
+
+    /// Open the diagnostics report and start a fresh run
+    fn action_diagnose(&mut self) {
+        self.diagnostics_run += 1;
+        self.diagnostics = None;
+        self.diagnosing = true;
+        if !matches!(self.mode, AppMode::Diagnostics) {
+            self.mode = AppMode::Diagnostics;
+            self.animation.start_dialog_slide();
+        }
+        let _ = self.event_tx.send(Event::Command(NetworkCommand::Diagnose {
+            run: self.diagnostics_run,
+        }));
+    }
+
+    /// Ask for the URL to inspect, starting from the previous one
+    fn prompt_header_url(&mut self) {
+        self.input_buffer = self.http_headers_url.clone();
+        self.mode = AppMode::Input(InputKind::HeaderUrl);
+        self.animation.start_dialog_slide();
+    }
+
+    /// Open the header inspection modal and start fetching `url`
+    fn inspect_headers(&mut self, url: String) {
+        self.http_headers_run += 1;
+        self.http_headers.clear();
+        self.http_headers_scroll = 0;
+        self.http_headers_url = url.clone();
+        if !matches!(self.mode, AppMode::HttpHeaders) {
+            self.mode = AppMode::HttpHeaders;
+            self.animation.start_dialog_slide();
+        }
         let _ = self
             .event_tx
-            .send(Event::Command(NetworkCommand::Disconnect));
+            .send(Event::Command(NetworkCommand::HttpHeaders {
+                url,
+                run: self.http_headers_run,
+            }));
     }
 
-    fn action_scan(&mut self) {
-        if matches!(self.mode, AppMode::Scanning) {
+    /// Ask for the host to probe towards, starting from the previous one
+    fn prompt_mtu_target(&mut self) {
+        self.input_buffer = self.mtu_probe_host.clone();
+        self.mode = AppMode::Input(InputKind::MtuTarget);
+        self.animation.start_dialog_slide();
+    }
+
+    /// Open the path MTU probe modal and start searching towards `host`
+    fn probe_path_mtu(&mut self, host: String) {
+        let iface_mtu = match &self.connection_status {
+            ConnectionStatus::Connected(info) => info.mtu,
+            _ => 0,
+        };
+        self.mtu_probe_run += 1;
+        self.mtu_probe.clear();
+        self.mtu_probe_host = host.clone();
+        if !matches!(self.mode, AppMode::MtuProbe) {
+            self.mode = AppMode::MtuProbe;
+            self.animation.start_dialog_slide();
+        }
+        let _ = self.event_tx.send(Event::Command(NetworkCommand::MtuProbe {
+            host,
+            iface_mtu,
+            run: self.mtu_probe_run,
+        }));
+    }
+
+    /// Ask for the hosts to compare, starting from the previous list
+    fn prompt_ping_targets(&mut self) {
+        self.input_buffer = self.ping_targets.clone();
+        self.mode = AppMode::Input(InputKind::PingTargets);
+        self.animation.start_dialog_slide();
+    }
+
+    /// Open the ping comparison and start pinging every host in the
+    /// comma-separated `targets` at once
+    fn compare_ping(&mut self, targets: String) {
+        let gateway = match &self.connection_status {
+            ConnectionStatus::Connected(info) => info.gateway.clone(),
+            _ => None,
+        };
+        let mut hosts = Vec::new();
+        for target in targets.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            let host = if target.eq_ignore_ascii_case("gateway") {
+                match &gateway {
+                    Some(gw) => gw.clone(),
+                    None => {
+                        self.show_error(
+                            self.lang()
+                                .tr("No default gateway to ping — not connected?"),
+                        );
+                        return;
+                    }
+                }
+            } else {
+                target.to_string()
+            };
+            // Never let a host be taken for a ping option
+            if host.starts_with('-') || host.contains(char::is_whitespace) {
+                let msg = self
+                    .lang()
+                    .fill("Invalid host \"{host}\"", &[("host", &host)]);
+                self.show_error(msg);
+                return;
+            }
+            hosts.push(host);
+        }
+        if hosts.is_empty() || hosts.len() > MAX_PING_TARGETS {
+            self.show_error(self.lang().fill(
+                "Enter between 1 and {max} hosts, separated by commas",
+                &[("max", &MAX_PING_TARGETS)],
+            ));
             return;
         }
-        self.mode = AppMode::Scanning;
-        self.animation.start_spinner();
-        let _ = self.event_tx.send(Event::Command(NetworkCommand::Scan));
+
+        self.ping_compare_run += 1;
+        self.ping_compare = hosts.iter().cloned().map(PingStats::new).collect();
+        self.ping_targets = targets;
+        if !matches!(self.mode, AppMode::PingCompare) {
+            self.mode = AppMode::PingCompare;
+            self.animation.start_dialog_slide();
+        }
+        let _ = self
+            .event_tx
+            .send(Event::Command(NetworkCommand::PingCompare {
+                hosts,
+                run: self.ping_compare_run,
+            }));
+    }
+
+    /// Whether every host of the latest ping comparison is done
+    pub fn ping_compare_finished(&self) -> bool {
+        self.ping_compare.iter().all(|host| host.finished)
+    }
+
+    /// Whether the latest path MTU probe has finished
+    pub fn mtu_probe_finished(&self) -> bool {
+        matches!(self.mtu_probe.last(), Some(MtuProbeLine::Done { .. }))
+    }
+
+    /// Whether the latest header inspection has finished
+    pub fn http_headers_finished(&self) -> bool {
+        matches!(self.http_headers.last(), Some(HeaderLine::Done { .. }))
+    }
+
+    /// Restore the most recently forgotten profile, if still within the window
+    fn action_undo(&mut self) {
+        let Some((ssid, at)) = self.undo_forget.take() else {
+            self.show_toast(self.lang().tr("Nothing to undo"), ToastLevel::Info);
+            return;
+        };
+        if at.elapsed() >= UNDO_WINDOW {
+            self.show_toast(
+                self.lang()
+                    .tr("Nothing to undo — the undo window has expired"),
+                ToastLevel::Info,
+            );
+            return;
+        }
+        let msg = self.lang().fill("Restoring '{ssid}'…", &[("ssid", &ssid)]);
+        self.show_toast(msg, ToastLevel::Info);
+        let _ = self
+            .event_tx
+            .send(Event::Command(NetworkCommand::Restore { ssid }));
+    }
+
+    /// Pin or unpin the selected network to the top of the list
+    fn action_toggle_favorite(&mut self) {
+        let Some(net) = self.selected_network() else {
+            return;
+        };
+        let ssid = net.ssid.clone();
+        match self.favorites.toggle(&ssid) {
+            Ok(pinned) => {
+                let msg = self.lang().fill(
+                    if pinned {
+                        "Pinned '{ssid}' to the top"
+                    } else {
+                        "Unpinned '{ssid}'"
+                    },
+                    &[("ssid", &ssid)],
+                );
+                self.show_toast(msg, ToastLevel::Success);
+            }
+            Err(e) => self.report_error("Save favorites", &format!("{e:#}")),
+        }
+        self.apply_sort();
+        self.rebuild_filter();
+        // Keep the cursor on the network that just moved
+        self.select_ssid(&ssid);
+    }
+
+    /// Ask for an SSID pattern to ignore, starting from the selected SSID
+    fn action_ignore(&mut self) {
+        self.input_buffer = self
+            .selected_network()
+            .map(|net| net.ssid.clone())
+            .unwrap_or_default();
+        self.ignore_list_open = false;
+        self.mode = AppMode::Input(InputKind::IgnorePattern);
+        self.animation.start_dialog_slide();
+    }
+
+    /// Add a submitted pattern to the ignore list
+    fn add_ignore_pattern(&mut self, pattern: String) {
+        self.mode = if self.ignore_list_open {
+            AppMode::IgnoreList
+        } else {
+            AppMode::Normal
+        };
+        if pattern.is_empty() {
+            return;
+        }
+        match self.ignore.add(pattern.clone()) {
+            Ok(true) => {
+                self.rebuild_filter();
+                let msg = self.lang().fill(
+                    "Ignoring '{pattern}' ({count} hidden)",
+                    &[("pattern", &pattern), ("count", &self.ignored_count)],
+                );
+                self.show_toast(msg, ToastLevel::Success);
+            }
+            Ok(false) => {
+                let msg = self
+                    .lang()
+                    .fill("'{pattern}' is already ignored", &[("pattern", &pattern)]);
+                self.show_toast(msg, ToastLevel::Info);
+            }
+            Err(e) => self.report_error("Save ignore list", &format!("{e:#}")),
+        }
+    }
+
+    /// Show or hide the networks the ignore list matches
+    fn toggle_show_ignored(&mut self) {
+        let ssid = self.selected_network().map(|net| net.ssid.clone());
+        self.show_ignored = !self.show_ignored;
+        self.rebuild_filter();
+        if let Some(ssid) = ssid {
+            self.select_ssid(&ssid);
+        }
+        let msg = self.lang().tr(if self.show_ignored {
+            "Showing ignored networks"
+        } else {
+            "Hiding ignored networks"
+        });
+        self.show_toast(msg, ToastLevel::Info);
+    }
+
+    /// Show or hide the unsaved networks below the signal threshold
+    fn toggle_show_weak(&mut self) {
+        let ssid = self.selected_network().map(|net| net.ssid.clone());
+        self.show_weak = !self.show_weak;
+        self.rebuild_filter();
+        if let Some(ssid) = ssid {
+            self.select_ssid(&ssid);
+        }
+        let lang = self.lang();
+        let msg = if self.show_weak {
+            lang.tr("Showing weak networks").to_string()
+        } else {
+            lang.fill(
+                "Hiding unsaved networks below {percent}%",
+                &[("percent", &self.min_signal)],
+            )
+        };
+        self.show_toast(msg, ToastLevel::Info);
+    }
+
+    /// Move the signal threshold by `delta` percent, for this session
+    fn adjust_min_signal(&mut self, delta: i16) {
+        let ssid = self.selected_network().map(|net| net.ssid.clone());
+        self.min_signal = (i16::from(self.min_signal) + delta).clamp(0, 100) as u8;
+        // Adjusting implies wanting the threshold applied
+        self.show_weak = false;
+        self.rebuild_filter();
+        if let Some(ssid) = ssid {
+            self.select_ssid(&ssid);
+        }
+        let msg = self.lang().fill(
+            "Minimum signal {percent}% ({count} hidden)",
+            &[("percent", &self.min_signal), ("count", &self.weak_count)],
+        );
+        self.show_toast(msg, ToastLevel::Info);
+    }
+
+    fn open_ignore_list(&mut self) {
+        self.ignore_selected = 0;
+        self.mode = AppMode::IgnoreList;
+        self.animation.start_dialog_slide();
+    }
+
+    /// Handle keys in the ignore list overlay
+    fn handle_key_ignore_list(&mut self, key: KeyEvent) {
+        let count = self.ignore.patterns().len();
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.mode = AppMode::Normal,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.ignore_selected = self.ignore_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.ignore_selected + 1 < count => {
+                self.ignore_selected += 1;
+            }
+            KeyCode::Char('a') => {
+                self.input_buffer.clear();
+                self.ignore_list_open = true;
+                self.mode = AppMode::Input(InputKind::IgnorePattern);
+            }
+            KeyCode::Char('d') | KeyCode::Delete if self.ignore_selected < count => {
+                if let Err(e) = self.ignore.remove(self.ignore_selected) {
+                    self.report_error("Save ignore list", &format!("{e:#}"));
+                    return;
+                }
+                self.ignore_selected = self.ignore_selected.min(count.saturating_sub(2));
+                self.rebuild_filter();
+            }
+            _ => {}
+        }
+    }
+
+    /// Put the cursor on `ssid` if it's in the (filtered) list
+    fn select_ssid(&mut self, ssid: &str) -> bool {
+        let pos = self.visible_networks().position(|n| n.ssid == ssid);
+        if let Some(pos) = pos {
+            self.selected_index = pos;
+        }
+        pos.is_some()
+    }
+
+    /// Mark or unmark the selected saved network for a bulk action
+    fn action_toggle_mark(&mut self) {
+        let Some(net) = self.selected_network() else {
+            return;
+        };
+        if !net.is_saved {
+            self.show_toast(
+                self.lang().tr("Only saved networks can be marked"),
+                ToastLevel::Info,
+            );
+            return;
+        }
+        let ssid = net.ssid.clone();
+        if !self.marked.remove(&ssid) {
+            self.marked.insert(ssid);
+        }
+        self.select_next();
+    }
+
+    /// Mark every saved network matching the current filter, or clear the
+    /// marks if they are all marked already
+    fn action_mark_all(&mut self) {
+        let visible: Vec<String> = self
+            .visible_networks()
+            .filter(|n| n.is_saved)
+            .map(|n| n.ssid.clone())
+            .collect();
+        if visible.iter().all(|s| self.marked.contains(s)) {
+            self.marked.clear();
+        } else {
+            self.marked.extend(visible);
+        }
+    }
+
+    /// Progress of a bulk forget
+    pub fn on_bulk_progress(&mut self, done: usize, total: usize) {
+        let msg = self.lang().fill(
+            "Forgetting {done}/{total}…",
+            &[("done", &done), ("total", &total)],
+        );
+        self.show_toast(msg, ToastLevel::Info);
+    }
+
+    /// A bulk forget finished; `failed` pairs each SSID with its error
+    pub fn on_bulk_finished(&mut self, forgotten: Vec<String>, failed: Vec<(String, String)>) {
+        for ssid in &forgotten {
+            self.marked.remove(ssid);
+        }
+        if failed.is_empty() {
+            let msg = self
+                .lang()
+                .fill("Forgot {count} networks", &[("count", &forgotten.len())]);
+            self.show_toast(msg, ToastLevel::Success);
+            return;
+        }
+        for (ssid, error) in &failed {
+            warn!("Forget '{}' failed: {}", ssid, error);
+        }
+        let names: Vec<&str> = failed.iter().map(|(s, _)| s.as_str()).collect();
+        let msg = self.lang().fill(
+            "Forgot {done}/{total} — failed: {names}",
+            &[
+                ("done", &forgotten.len()),
+                ("total", &(forgotten.len() + failed.len())),
+                ("names", &names.join(", ")),
+            ],
+        );
+        self.show_toast(msg, ToastLevel::Warning);
+    }
+
+    /// A forget went through: offer undo for the rest of the window
+    pub fn on_network_forgotten(&mut self, outcome: ForgetOutcome) {
+        // No undo for a rejoin: the point was to start over
+        if self.rejoin.as_ref() == Some(&outcome.ssid) {
+            self.rejoin = None;
+            self.rejoin_forgotten(outcome.ssid);
+            return;
+        }
+        let lang = self.lang();
+        let mut msg = lang.fill(
+            "Forgot '{ssid}' — press {key} to undo ({secs}s)",
+            &[
+                ("ssid", &outcome.ssid),
+                ("key", &self.config.keys.undo),
+                ("secs", &UNDO_WINDOW.as_secs()),
+            ],
+        );
+        if !outcome.secrets_preserved {
+            msg.push_str(" · ");
+            msg.push_str(lang.tr("password not kept, you'll be asked again"));
+        }
+        self.show_toast_for(msg, ToastLevel::Info, UNDO_WINDOW);
+        self.undo_forget = Some((outcome.ssid, Instant::now()));
+    }
+
+    /// A forget failed: a pending rejoin can't go ahead either
+    pub fn on_forget_failed(&mut self, error: &str) {
+        self.rejoin = None;
+        self.report_error("Forget", error);
+    }
+
+    /// Health of the active connection; None while not connected
+    pub fn health(&self) -> Option<Health> {
+        let ConnectionStatus::Connected(info) = &self.connection_status else {
+            return None;
+        };
+        let faults = self
+            .interface_stats
+            .iter()
+            .find(|s| s.name == info.interface)
+            .and_then(InterfaceStats::recent);
+        Some(Health::assess(info, faults.as_ref(), self.watchdog_alert))
+    }
+
+    /// Language the interface text is shown in
+    pub fn lang(&self) -> Language {
+        self.config.general.language
+    }
+
+    /// Show a transient notification in the status bar
+    pub fn show_toast(&mut self, message: impl Into<String>, level: ToastLevel) {
+        self.show_toast_for(message, level, TOAST_DURATION);
+    }
+
+    /// Show a notification that stays up for `duration`
+    fn show_toast_for(
+        &mut self,
+        message: impl Into<String>,
+        level: ToastLevel,
+        duration: Duration,
+    ) {
+        self.toast = Some(Toast {
+            message: message.into(),
+            level,
+            expires_at: Some(Instant::now() + duration),
+        });
+    }
+
+    /// Show a notification that stays until another one replaces it
+    fn show_sticky_toast(&mut self, message: impl Into<String>, level: ToastLevel) {
+        self.toast = Some(Toast {
+            message: message.into(),
+            level,
+            expires_at: None,
+        });
+    }
+
+    /// Open the error dialog with the given message. The first line is
+    /// shown as the summary, anything after it as scrollable details.
+    fn show_error(&mut self, msg: impl Into<String>) {
+        self.mode = AppMode::Error(msg.into());
+        self.error_scroll = 0;
+        self.animation.start_dialog_slide();
     }
 
-    fn action_forget(&mut self) {
-        let net = match self.selected_network() {
-            Some(n) => n,
-            None => return,
+    /// Surface a failed background action. Well-known NM/D-Bus failures are
+    /// translated into a friendly message; short errors become a toast, long
+    /// ones open the error dialog with the raw text as details.
+    pub fn report_error(&mut self, action: &'static str, error: &str) {
+        warn!("{} failed: {}", action, error);
+        let lang = self.lang();
+        let friendly = crate::error::friendly(error);
+        let summary = match friendly {
+            Some(friendly) => lang.fill(
+                "{action} failed: {reason}",
+                &[("action", &lang.tr(action)), ("reason", &lang.tr(friendly))],
+            ),
+            None => lang.fill("{action} failed", &[("action", &lang.tr(action))]),
         };
-        if !net.is_saved {
-            self.mode = AppMode::Error("Network is not saved".to_string());
-            self.animation.start_dialog_slide();
-            return;
+        if error.len() <= ERROR_TOAST_MAX_LEN && !error.contains('\n') {
+            let message = if friendly.is_some() {
+                summary
+            } else {
+                format!("{summary}: {error}")
+            };
+            self.show_toast(message, ToastLevel::Error);
+        } else {
+            self.show_error(format!("{summary}\n{error}"));
         }
-        let ssid = net.ssid.clone();
-        let _ = self
-            .event_tx
-            .send(Event::Command(NetworkCommand::Forget { ssid }));
     }
 
-    fn action_hidden(&mut self) {
-        self.hidden_ssid_input.clear();
-        self.hidden_password_input.clear();
-        self.hidden_field_focus = 0;
+    /// Activation failed for lack of a stored password: ask for it, unless
+    /// the user has moved on to another dialog meanwhile
+    pub fn on_secrets_required(&mut self, ssid: String, rejected: bool) {
+        if !matches!(self.mode, AppMode::Normal | AppMode::Connecting) {
+            self.auth_failures = None;
+            self.report_error("Connect", "Secrets were required, but not provided");
+            return;
+        }
+        self.animation.stop_spinner();
+        if rejected {
+            let failures = match &self.auth_failures {
+                Some((last, count)) if *last == ssid => count + 1,
+                _ => 1,
+            };
+            if failures >= MAX_PASSWORD_ATTEMPTS {
+                self.auth_failures = None;
+                let keys = &self.config.keys;
+                let msg = self.lang().fill(
+                    "The password for \"{ssid}\" was rejected {failures} times.\n\
+                     If it changed, forget the network ({forget}) or rejoin it from scratch ({rejoin}).",
+                    &[
+                        ("ssid", &ssid),
+                        ("failures", &failures),
+                        ("forget", &keys.forget),
+                        ("rejoin", &keys.rejoin),
+                    ],
+                );
+                self.show_error(msg);
+                return;
+            }
+            self.auth_failures = Some((ssid.clone(), failures));
+            let msg = self.lang().fill(
+                "Wrong password for \"{ssid}\" — try again ({attempt} of {max})",
+                &[
+                    ("ssid", &ssid),
+                    ("attempt", &(failures + 1)),
+                    ("max", &MAX_PASSWORD_ATTEMPTS),
+                ],
+            );
+            self.show_toast(msg, ToastLevel::Warning);
+        } else {
+            let msg = self.lang().fill(
+                "No password is stored for \"{ssid}\" — enter it to save it in the profile",
+                &[("ssid", &ssid)],
+            );
+            self.show_toast(msg, ToastLevel::Warning);
+        }
+        let (security, ap_path) = self
+            .networks
+            .iter()
+            .find(|n| n.ssid == ssid)
+            .map_or((SecurityType::Unknown, String::new()), |n| {
+                (n.security.clone(), n.ap_path.clone())
+            });
+        self.password_input.clear();
         self.password_visible = false;
-        self.mode = AppMode::Hidden;
+        self.mode = AppMode::PasswordInput {
+            ssid,
+            security,
+            ap_path,
+        };
         self.animation.start_dialog_slide();
     }
 
@@ -543,10 +3864,17 @@ impl App {
             .send(Event::Command(NetworkCommand::RefreshConnection));
     }
 
-    fn dispatch_connect(&mut self, ssid: String, password: Option<String>) {
-        let _ = self
-            .event_tx
-            .send(Event::Command(NetworkCommand::Connect { ssid, password }));
+    fn dispatch_connect(
+        &mut self,
+        ssid: String,
+        password: Option<String>,
+        ap_path: Option<String>,
+    ) {
+        let _ = self.event_tx.send(Event::Command(NetworkCommand::Connect {
+            ssid,
+            password,
+            ap_path,
+        }));
     }
 
     fn dispatch_connect_hidden(&mut self, ssid: String, password: Option<String>) {
@@ -560,28 +3888,322 @@ impl App {
 
     // ─── Tick / Animation Updates ───────────────────────────────────
 
-    /// Called every tick to advance animations and smooth values
-    pub fn tick(&mut self) {
-        // Only advance animations if enabled in config
-        if self.config.animations() {
+    /// Called every tick to advance animations and smooth values.
+    /// Returns true if anything visible changed and a redraw is needed.
+    pub fn tick(&mut self) -> bool {
+        let mut changed = false;
+
+        // Only advance animations if enabled in config, and while anyone
+        // can see them
+        let dim_after = self.config.appearance.idle_dim_secs;
+        let dimmed = dim_after > 0 && self.last_input.elapsed() >= Duration::from_secs(dim_after);
+        changed |= dimmed != self.dimmed;
+        self.dimmed = dimmed;
+
+        let paused = self.dimmed || (!self.focused && self.config.appearance.pause_unfocused);
+        if self.config.animations() && !paused {
             self.animation.tick();
+            changed |= self.animation_frame_changed();
+        }
+
+        // The header clock, data age and the detail panel's "Last Seen"
+        // move once a second
+        let appearance = &self.config.appearance;
+        if appearance.show_clock || appearance.show_data_age || self.detail_visible {
+            let second = self.started.elapsed().as_secs();
+            changed |= second != self.header_second;
+            self.header_second = second;
+        }
+        if appearance.show_clock
+            && self
+                .utc_offset_read
+                .is_none_or(|at| at.elapsed() >= Duration::from_secs(60))
+        {
+            self.utc_offset = crate::ui::header::read_utc_offset();
+            self.utc_offset_read = Some(Instant::now());
         }
 
         // Smooth signal strength display values
-        smooth_signals(&mut self.networks, 0.2);
+        changed |= smooth_signals(&mut self.networks, 0.2);
+
+        changed |= self.sample_traffic();
+        changed |= self.sample_interfaces();
+        self.auto_scan_if_due();
+        self.survey_scan_if_due();
+        self.watchdog_if_due();
+
+        // Expire the toast
+        if self
+            .toast
+            .as_ref()
+            .and_then(|toast| toast.expires_at)
+            .is_some_and(|at| Instant::now() >= at)
+        {
+            self.toast = None;
+            changed = true;
+        }
+
+        if self.pending_scan.is_some() && !self.dialog_open() {
+            self.apply_pending_scan();
+            changed = true;
+        }
+
+        if self.is_animating() {
+            self.last_busy = Instant::now();
+        }
+        changed
+    }
+
+    /// How often the event loop should tick: the configured FPS while
+    /// anything moves, `IDLE_TICK` once it has been still for `IDLE_AFTER`,
+    /// and `DIMMED_TICK` while dimmed
+    pub fn tick_interval(&self) -> Duration {
+        let fast = Duration::from_millis(self.config.tick_rate_ms());
+        if self.dimmed {
+            fast.max(DIMMED_TICK)
+        } else if self.last_busy.elapsed() < IDLE_AFTER || self.is_animating() {
+            fast
+        } else {
+            fast.max(IDLE_TICK)
+        }
+    }
+
+    /// Whether anything on screen needs frame-rate ticks: spinners, dialog
+    /// slides, a toast counting down, a running diagnostic, a blinking
+    /// cursor, or signal bars still easing towards a new value
+    fn is_animating(&self) -> bool {
+        let anim = &self.animation;
+        let expiring_toast = self.toast.as_ref().is_some_and(|t| t.expires_at.is_some());
+        if anim.spinner_active() || anim.dialog_sliding() || expiring_toast {
+            return true;
+        }
+        let running = match self.mode {
+            AppMode::Survey
+            | AppMode::PasswordInput { .. }
+            | AppMode::Hidden
+            | AppMode::Search
+            | AppMode::Input(_)
+            | AppMode::Palette
+            | AppMode::GlobalSearch => true,
+            AppMode::Diagnostics => self.diagnostics.is_none(),
+            AppMode::HttpHeaders => !self.http_headers_finished(),
+            AppMode::PingCompare => !self.ping_compare_finished(),
+            AppMode::MtuProbe => !self.mtu_probe_finished(),
+            _ => false,
+        };
+        running
+            || self.device_state == DeviceState::Deactivating
+            || self.networks.iter().any(|net| {
+                net.seen_ticks < 10
+                    || (net.display_signal - f32::from(net.signal_strength)).abs() >= 0.5
+            })
+    }
+
+    /// Sample the active interface's counters once per `STATS_INTERVAL`.
+    /// Returns true when a new point was added to the graph.
+    fn sample_traffic(&mut self) -> bool {
+        let ConnectionStatus::Connected(ref info) = self.connection_status else {
+            if self.stats.take().is_some() {
+                self.traffic.clear();
+                return true;
+            }
+            return false;
+        };
+        let now = Instant::now();
+        if now < self.next_stats_poll {
+            return false;
+        }
+        self.next_stats_poll = next_poll(self.next_stats_poll, now);
+
+        let stats = self
+            .stats
+            .get_or_insert_with(|| StatsPoller::new(&info.interface));
+        let Some(rates) = stats.poll() else {
+            return false;
+        };
+        self.traffic.push(rates);
+
+        if let (Some(log), Some(counters)) = (&mut self.bandwidth_log, stats.counters())
+            && let Err(e) = log.record(&info.interface, counters, rates)
+        {
+            warn!("Bandwidth log disabled: {e:#}");
+            self.bandwidth_log = None;
+            self.show_toast(
+                self.lang().tr("Bandwidth log stopped — write failed"),
+                ToastLevel::Warning,
+            );
+        }
+        true
+    }
+
+    /// Sample every interface once per `STATS_INTERVAL`, picking up ones
+    /// that appear and dropping ones that go away. Returns true when the
+    /// statistics view needs a redraw.
+    fn sample_interfaces(&mut self) -> bool {
+        let now = Instant::now();
+        if now < self.next_interface_poll {
+            return false;
+        }
+        self.next_interface_poll = next_poll(self.next_interface_poll, now);
+
+        let names = crate::network::stats::interfaces();
+        let focused = self.focused_interface();
+        self.interface_stats.retain(|s| names.contains(&s.name));
+        for name in names {
+            if !self.interface_stats.iter().any(|s| s.name == name) {
+                self.interface_stats.push(InterfaceStats::new(name));
+            }
+        }
+        for stats in &mut self.interface_stats {
+            stats.poll();
+        }
+        self.stats_sort.sort(&mut self.interface_stats);
+        self.focus_interface(focused);
+        if let Some(exporter) = &self.prometheus {
+            let wifi = match &self.connection_status {
+                ConnectionStatus::Connected(info) => Some(info.as_ref()),
+                _ => None,
+            };
+            if let Err(e) = exporter.write(&self.interface_stats, wifi) {
+                warn!("Prometheus textfile disabled: {e:#}");
+                self.prometheus = None;
+                self.show_toast(
+                    self.lang().tr("Prometheus textfile stopped — write failed"),
+                    ToastLevel::Warning,
+                );
+            }
+        }
+
+        matches!(self.mode, AppMode::Statistics)
+    }
+
+    /// Re-sort the statistics table by the current key
+    fn sort_interfaces(&mut self) {
+        let focused = self.focused_interface();
+        self.stats_sort.sort(&mut self.interface_stats);
+        self.focus_interface(focused);
+    }
+
+    fn focused_interface(&self) -> Option<String> {
+        self.interface_stats
+            .get(self.stats_focus)
+            .map(|s| s.name.clone())
+    }
+
+    /// Keep the focus on the same interface while others come and go or
+    /// the table is re-sorted
+    fn focus_interface(&mut self, name: Option<String>) {
+        self.stats_focus = name
+            .and_then(|name| self.interface_stats.iter().position(|s| s.name == name))
+            .unwrap_or(0);
+    }
+
+    /// Whether the tick that just happened advanced any on-screen animation
+    fn animation_frame_changed(&self) -> bool {
+        let anim = &self.animation;
+        if anim.dialog_sliding() {
+            return true;
+        }
+        // Braille spinner (3 ticks) and bar spinner (4 ticks)
+        if anim.spinner_active() && (anim.frame_advanced(3) || anim.frame_advanced(4)) {
+            return true;
+        }
+        // Bar spinner on the connection the device is tearing down
+        if self.device_state == DeviceState::Deactivating && anim.frame_advanced(4) {
+            return true;
+        }
+        // Spinner and last-seen ages in the survey table
+        if matches!(self.mode, AppMode::Survey) && anim.frame_advanced(3) {
+            return true;
+        }
+        // Spinner in the diagnostics report while a run is in progress
+        if matches!(self.mode, AppMode::Diagnostics)
+            && self.diagnostics.is_none()
+            && anim.frame_advanced(3)
+        {
+            return true;
+        }
+        // Spinner in the header inspection while curl is still running
+        if matches!(self.mode, AppMode::HttpHeaders)
+            && !self.http_headers_finished()
+            && anim.frame_advanced(3)
+        {
+            return true;
+        }
+        // Spinner in the ping comparison while hosts are still pinged
+        if matches!(self.mode, AppMode::PingCompare)
+            && !self.ping_compare_finished()
+            && anim.frame_advanced(3)
+        {
+            return true;
+        }
+        // Spinner in the path MTU probe while the search is running
+        if matches!(self.mode, AppMode::MtuProbe)
+            && !self.mtu_probe_finished()
+            && anim.frame_advanced(3)
+        {
+            return true;
+        }
+        // Connected pulse in the header (5 ticks)
+        if self.connection_status.is_connected() && anim.frame_advanced(5) {
+            return true;
+        }
+        // Blinking cursor in text inputs
+        let has_cursor = matches!(
+            self.mode,
+            AppMode::PasswordInput { .. }
+                | AppMode::Hidden
+                | AppMode::Search
+                | AppMode::Input(_)
+                | AppMode::Palette
+                | AppMode::GlobalSearch
+        );
+        has_cursor && anim.cursor_toggled()
     }
 
     /// Update network list from scan results
     pub fn update_networks(&mut self, mut networks: Vec<WiFiNetwork>) {
-        // Preserve seen_ticks and display_signal for networks that were already visible
+        // Re-sorting under an open dialog would move the list out from
+        // under it; keep only the newest scan until it closes
+        if self.dialog_open() {
+            self.pending_scan = Some(networks);
+            return;
+        }
+        self.last_refresh = Some(Instant::now());
         for new_net in networks.iter_mut() {
+            // Preserve seen_ticks and display_signal for networks that were
+            // already visible, even when a different BSSID now stands for them
             if let Some(existing) = self.networks.iter().find(|n| n.ssid == new_net.ssid) {
                 new_net.seen_ticks = existing.seen_ticks;
                 new_net.display_signal = existing.display_signal;
             }
+            // NM keeps listing an AP for a while after its last sighting; one
+            // it hasn't heard since the previous scan missed this one too
+            if let Some(existing) = self.networks.iter().find(|n| n.bssid == new_net.bssid)
+                && new_net.last_seen <= existing.last_seen
+            {
+                new_net.missed_scans = existing.missed_scans + 1;
+            }
         }
 
+        // Networks missing from this scan linger (greyed out) until they
+        // have missed `stale_scans` scans in a row
+        let lingering: Vec<WiFiNetwork> = self
+            .networks
+            .drain(..)
+            .filter(|old| !networks.iter().any(|n| n.ssid == old.ssid))
+            .map(|mut old| {
+                old.is_active = false;
+                old.missed_scans += 1;
+                old
+            })
+            .collect();
+        networks.extend(lingering);
+        let stale_scans = self.config.general.stale_scans.max(1);
+        networks.retain(|n| n.is_active || n.missed_scans < stale_scans);
+
         self.networks = networks;
+        self.networks_epoch += 1;
 
         // Apply current sort
         self.apply_sort();
@@ -595,14 +4217,344 @@ impl App {
         }
     }
 
+    /// Store a finished diagnostic run, unless it has since been replaced
+    /// or cleared
+    pub fn update_diagnostics(&mut self, run: u64, report: DiagnosticReport) {
+        if run != self.diagnostics_run {
+            return;
+        }
+        self.diagnosing = false;
+        self.diagnostics = Some(report);
+    }
+
+    /// The machine woke from suspend: rescan, re-read the connection and
+    /// restart the traffic rates.
+    /// The link usually drops and comes back on its own meanwhile, which is
+    /// no reason for a lost-link warning or a watchdog alert.
+    pub fn on_resume(&mut self) {
+        let now = Instant::now();
+        if self
+            .last_resume
+            .is_some_and(|at| now.duration_since(at) < RESUME_DEBOUNCE)
+        {
+            return;
+        }
+        self.last_resume = Some(now);
+        self.note_user_action();
+        self.watchdog_failures = 0;
+        self.next_watchdog_probe = now + USER_ACTION_GRACE;
+        self.show_toast(self.lang().tr("Resumed — refreshing"), ToastLevel::Info);
+        if let Some(stats) = &mut self.stats {
+            stats.restart();
+        }
+        for stats in &mut self.interface_stats {
+            stats.restart();
+        }
+        self.last_scan = now;
+        let _ = self.event_tx.send(Event::Command(NetworkCommand::Scan));
+        let _ = self
+            .event_tx
+            .send(Event::Command(NetworkCommand::RefreshConnection));
+    }
+
+    /// The system bus connection dropped. The header shows a banner until
+    /// it's back.
+    pub fn on_bus_lost(&mut self) {
+        self.offline = true;
+        self.settle_offline();
+    }
+
+    /// Leave the modes that wait on NM, which won't answer while offline
+    pub fn settle_offline(&mut self) {
+        if matches!(
+            self.mode,
+            AppMode::Scanning | AppMode::Connecting | AppMode::Disconnecting
+        ) {
+            self.mode = AppMode::Normal;
+        }
+    }
+
+    /// The system bus is back: everything shown may be stale
+    pub fn on_bus_restored(&mut self) {
+        self.offline = false;
+        self.show_toast(
+            self.lang().tr("System bus reconnected"),
+            ToastLevel::Success,
+        );
+        self.last_scan = Instant::now();
+        let _ = self.event_tx.send(Event::Command(NetworkCommand::Scan));
+        let _ = self
+            .event_tx
+            .send(Event::Command(NetworkCommand::RefreshConnection));
+    }
+
+    /// The terminal regained focus: refresh what may have changed meanwhile
+    pub fn on_focus_gained(&mut self) {
+        self.focused = true;
+        if !self.config.general.refresh_on_focus {
+            return;
+        }
+        let now = Instant::now();
+        if self
+            .last_focus_refresh
+            .is_some_and(|at| now.duration_since(at) < FOCUS_REFRESH_THROTTLE)
+        {
+            return;
+        }
+        self.last_focus_refresh = Some(now);
+        let _ = self
+            .event_tx
+            .send(Event::Command(NetworkCommand::RefreshConnection));
+        // Leave scanning alone while something else owns it or can't scan
+        if matches!(self.mode, AppMode::Normal)
+            && !matches!(
+                self.device_state,
+                DeviceState::Unmanaged | DeviceState::Unavailable
+            )
+            && self.last_scan.elapsed() >= MIN_SCAN_INTERVAL
+        {
+            self.last_scan = now;
+            let _ = self.event_tx.send(Event::Command(NetworkCommand::Scan));
+        }
+    }
+
+    pub fn on_focus_lost(&mut self) {
+        self.focused = false;
+    }
+
+    /// Fold a ping sample into its host's statistics, unless it belongs to
+    /// a comparison that has since been replaced
+    pub fn on_ping_sample(&mut self, run: u64, host: usize, sample: PingSample) {
+        if run == self.ping_compare_run
+            && let Some(stats) = self.ping_compare.get_mut(host)
+        {
+            stats.record(sample);
+        }
+    }
+
+    /// Append a streamed path MTU probe line, unless it belongs to a probe
+    /// that has since been replaced
+    pub fn on_mtu_probe_line(&mut self, run: u64, line: MtuProbeLine) {
+        if run == self.mtu_probe_run {
+            self.mtu_probe.push(line);
+        }
+    }
+
+    /// Append a streamed header inspection line, unless it belongs to an
+    /// inspection that has since been replaced
+    pub fn on_http_header_line(&mut self, run: u64, line: HeaderLine) {
+        if run == self.http_headers_run {
+            self.http_headers.push(line);
+        }
+    }
+
+    /// Send a watchdog probe when one is due. Probes only run while the
+    /// device is activated, so WiFi being off, airplane mode or a deliberate
+    /// disconnect never raise an alert.
+    fn watchdog_if_due(&mut self) {
+        let watchdog = &self.config.watchdog;
+        let now = Instant::now();
+        if !watchdog.enabled || now < self.next_watchdog_probe {
+            return;
+        }
+        self.next_watchdog_probe =
+            now + Duration::from_secs(watchdog.interval_secs).max(MIN_WATCHDOG_INTERVAL);
+
+        let gateway = match &self.connection_status {
+            ConnectionStatus::Connected(info) if self.device_state == DeviceState::Activated => {
+                info.gateway.clone()
+            }
+            _ => {
+                self.watchdog_failures = 0;
+                self.watchdog_alert = false;
+                return;
+            }
+        };
+        let target = match watchdog.target.trim() {
+            "" => gateway,
+            host => Some(host.to_string()),
+        };
+        if let Some(target) = target {
+            let _ = self
+                .event_tx
+                .send(Event::Command(NetworkCommand::WatchdogProbe { target }));
+        }
+    }
+
+    /// Count a watchdog probe. Only failures while NM still claims Full
+    /// connectivity count — otherwise NM already shows the problem.
+    pub fn on_watchdog_result(&mut self, target: &str, reachable: bool, nm_full: bool) {
+        if reachable || !nm_full {
+            self.watchdog_failures = 0;
+            if reachable && std::mem::take(&mut self.watchdog_alert) {
+                let msg = self
+                    .lang()
+                    .fill("{target} is answering again", &[("target", &target)]);
+                self.show_toast(msg, ToastLevel::Success);
+            }
+            return;
+        }
+        self.watchdog_failures += 1;
+        if !self.watchdog_alert && self.watchdog_failures >= self.config.watchdog.failures.max(1) {
+            self.watchdog_alert = true;
+            let msg = self.lang().fill(
+                "{target} unreachable for {count} probes, but NetworkManager reports full connectivity",
+                &[("target", &target), ("count", &self.watchdog_failures)],
+            );
+            self.show_toast(msg, ToastLevel::Warning);
+        }
+    }
+
+    /// Remember that the user just started a network command, so the
+    /// connection drop it may cause isn't reported as a lost link
+    pub fn note_user_action(&mut self) {
+        self.last_user_action = Some(Instant::now());
+    }
+
+    /// Warn when the active connection drops on its own, and report the
+    /// downtime once NM brings a connection back
+    fn track_link_loss(&mut self, status: &ConnectionStatus) {
+        match (&self.connection_status, status) {
+            // Roaming or a refresh, not a drop
+            (ConnectionStatus::Connected(_), ConnectionStatus::Connected(_)) => {}
+            (ConnectionStatus::Connected(old), _) => {
+                let expected = matches!(self.mode, AppMode::Connecting | AppMode::Disconnecting)
+                    || self
+                        .last_user_action
+                        .is_some_and(|at| at.elapsed() < USER_ACTION_GRACE);
+                if expected {
+                    return;
+                }
+                let ssid = old.ssid.clone();
+                let msg = self.lang().fill(
+                    "Lost connection to {ssid} — NM will retry automatically",
+                    &[("ssid", &ssid)],
+                );
+                self.show_sticky_toast(msg, ToastLevel::Warning);
+                self.link_lost = Some((ssid, Instant::now()));
+            }
+            (_, ConnectionStatus::Connected(new)) => {
+                let Some((ssid, since)) = self.link_lost.take() else {
+                    return;
+                };
+                let down = format_downtime(since.elapsed());
+                let lang = self.lang();
+                let msg = if new.ssid == ssid {
+                    lang.fill(
+                        "Reconnected to {ssid} after {down}",
+                        &[("ssid", &ssid), ("down", &down)],
+                    )
+                } else {
+                    lang.fill(
+                        "Connected to {new} — {ssid} was down for {down}",
+                        &[("new", &new.ssid), ("ssid", &ssid), ("down", &down)],
+                    )
+                };
+                self.show_toast(msg, ToastLevel::Success);
+            }
+            _ => {}
+        }
+    }
+
+    /// Append a line to the connection events log, dropping the oldest
+    pub fn on_nm_event(&mut self, line: String) {
+        if self.nm_events.len() == MAX_NM_EVENTS {
+            self.nm_events.pop_front();
+        }
+        self.nm_events.push_back((Instant::now(), line));
+    }
+
+    /// Record a WiFi device state transition. Returns true if it changed.
+    pub fn update_device_state(&mut self, state: DeviceState) -> bool {
+        if self.device_state == state {
+            return false;
+        }
+        if self.device_state == DeviceState::Unmanaged {
+            let msg = self.lang().fill(
+                "{interface} is now managed by NetworkManager",
+                &[("interface", &self.interface_name)],
+            );
+            self.show_toast(msg, ToastLevel::Success);
+        }
+        self.device_state = state;
+        true
+    }
+
     /// Update connection status
-    pub fn update_connection_status(&mut self, status: ConnectionStatus) {
+    ///
+    /// Returns false when the status is identical to the current one and no
+    /// mode transition happened, so periodic refreshes don't force a redraw.
+    pub fn update_connection_status(&mut self, status: ConnectionStatus) -> bool {
+        self.last_refresh = Some(Instant::now());
+        let mut changed = self.connection_status != status;
+        self.track_link_loss(&status);
+        if status.is_connected() {
+            self.auth_failures = None;
+        }
         self.connection_status = status;
 
         // If we were connecting/disconnecting, return to normal
         if matches!(self.mode, AppMode::Connecting | AppMode::Disconnecting) {
             self.mode = AppMode::Normal;
             self.animation.stop_spinner();
+            changed = true;
+        }
+        changed
+    }
+}
+
+/// When a once-per-`STATS_INTERVAL` sample falls due next. Kept on its
+/// schedule rather than counted from `now`, so slow idle ticks don't
+/// stretch the interval; after a long gap it restarts from `now`.
+fn next_poll(due: Instant, now: Instant) -> Instant {
+    let next = due + STATS_INTERVAL;
+    if next > now {
+        next
+    } else {
+        now + STATS_INTERVAL
+    }
+}
+
+/// "42s" or "3m 05s"
+fn format_downtime(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{secs}s")
+    } else {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// Sort favorites first, then the active network, then by `mode`
+fn sort_networks(networks: &mut [WiFiNetwork], mode: SortMode, favorites: &Favorites) {
+    let pinned = |a: &WiFiNetwork, b: &WiFiNetwork| {
+        favorites
+            .contains(&b.ssid)
+            .cmp(&favorites.contains(&a.ssid))
+            .then(b.is_active.cmp(&a.is_active))
+    };
+    match mode {
+        SortMode::Signal => {
+            networks.sort_by(|a, b| pinned(a, b).then(b.signal_strength.cmp(&a.signal_strength)));
+        }
+        SortMode::Alphabetical => {
+            networks.sort_by(|a, b| {
+                pinned(a, b).then(a.ssid.to_lowercase().cmp(&b.ssid.to_lowercase()))
+            });
+        }
+        SortMode::Security => {
+            networks.sort_by(|a, b| {
+                pinned(a, b)
+                    .then(security_rank(&b.security).cmp(&security_rank(&a.security)))
+                    .then(b.signal_strength.cmp(&a.signal_strength))
+            });
+        }
+        SortMode::Band => {
+            networks.sort_by(|a, b| {
+                pinned(a, b)
+                    .then(b.frequency.cmp(&a.frequency))
+                    .then(b.signal_strength.cmp(&a.signal_strength))
+            });
         }
     }
 }
@@ -619,3 +4571,274 @@ fn security_rank(sec: &SecurityType) -> u8 {
         SecurityType::Unknown => 0,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn net(ssid: &str, signal: u8, frequency: u32, security: SecurityType) -> WiFiNetwork {
+        WiFiNetwork {
+            frequency,
+            security,
+            ..WiFiNetwork::test(ssid, signal)
+        }
+    }
+
+    fn mixed() -> Vec<WiFiNetwork> {
+        let mut active = net("Active", 60, 5180, SecurityType::WPA2);
+        active.is_active = true;
+        vec![
+            net("strong", 90, 2437, SecurityType::Open),
+            net("Fav weak", 10, 2412, SecurityType::Wep),
+            active,
+            net("alpha", 40, 5500, SecurityType::WPA3),
+            net("Fav strong", 70, 5745, SecurityType::WPA2),
+        ]
+    }
+
+    fn favorites(ssids: &[&str]) -> Favorites {
+        ssids.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn sorted(mode: SortMode, favorites: &Favorites) -> Vec<String> {
+        let mut networks = mixed();
+        sort_networks(&mut networks, mode, favorites);
+        networks.into_iter().map(|n| n.ssid).collect()
+    }
+
+    #[test]
+    fn favorites_sort_first_in_every_mode() {
+        let favs = favorites(&["Fav weak", "Fav strong"]);
+        assert_eq!(
+            sorted(SortMode::Signal, &favs),
+            ["Fav strong", "Fav weak", "Active", "strong", "alpha"]
+        );
+        assert_eq!(
+            sorted(SortMode::Alphabetical, &favs),
+            ["Fav strong", "Fav weak", "Active", "alpha", "strong"]
+        );
+        assert_eq!(
+            sorted(SortMode::Security, &favs),
+            ["Fav strong", "Fav weak", "Active", "alpha", "strong"]
+        );
+        assert_eq!(
+            sorted(SortMode::Band, &favs),
+            ["Fav strong", "Fav weak", "Active", "alpha", "strong"]
+        );
+    }
+
+    #[test]
+    fn favorite_outranks_active_network() {
+        let favs = favorites(&["alpha"]);
+        assert_eq!(
+            sorted(SortMode::Signal, &favs),
+            ["alpha", "Active", "strong", "Fav strong", "Fav weak"]
+        );
+    }
+
+    #[test]
+    fn no_favorites_keeps_active_first() {
+        let favs = Favorites::default();
+        assert_eq!(
+            sorted(SortMode::Signal, &favs),
+            ["Active", "strong", "Fav strong", "alpha", "Fav weak"]
+        );
+        // A favorite that is not in range changes nothing
+        assert_eq!(
+            sorted(SortMode::Signal, &favorites(&["Elsewhere"])),
+            sorted(SortMode::Signal, &favs)
+        );
+    }
+
+    /// An app with no state from disk, and the channel its commands go to
+    fn app_with_commands() -> (App, mpsc::UnboundedReceiver<Event>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let config = Config::default();
+        let theme = Theme::from_config(&config);
+        let mut app = App::new(config, theme, "wlan0".to_string(), ViewState::default(), tx);
+        app.favorites = Favorites::default();
+        app.labels = Labels::default();
+        app.ignore = IgnoreList::default();
+        (app, rx)
+    }
+
+    fn app() -> App {
+        app_with_commands().0
+    }
+
+    fn heard(ssid: &str, bssid: &str, last_seen: Instant) -> WiFiNetwork {
+        let mut net = net(ssid, 50, 2437, SecurityType::WPA2);
+        net.bssid = bssid.to_string();
+        net.last_seen = last_seen;
+        net
+    }
+
+    fn stale(app: &App) -> Vec<(String, u32)> {
+        let mut stale: Vec<(String, u32)> = app
+            .networks
+            .iter()
+            .filter(|n| n.is_stale())
+            .map(|n| (n.ssid.clone(), n.missed_scans))
+            .collect();
+        stale.sort();
+        stale
+    }
+
+    #[test]
+    fn networks_go_stale_by_missed_scans() {
+        let mut app = app();
+        let first = Instant::now();
+        app.update_networks(vec![heard("A", "aa", first), heard("B", "bb", first)]);
+        assert!(stale(&app).is_empty());
+
+        // B is missing; A is listed again but NM hasn't heard it since
+        app.update_networks(vec![heard("A", "aa", first)]);
+        assert_eq!(stale(&app), [("A".into(), 1), ("B".into(), 1)]);
+
+        // A is heard again, through another BSSID
+        let later = first + Duration::from_secs(30);
+        app.update_networks(vec![heard("A", "a2", later)]);
+        assert_eq!(stale(&app), [("B".into(), 2)]);
+
+        // The third missed scan (stale_scans = 3) drops B
+        app.update_networks(vec![heard("A", "a2", later + Duration::from_secs(30))]);
+        assert!(stale(&app).is_empty());
+        assert_eq!(app.networks.len(), 1);
+    }
+
+    #[test]
+    fn password_prompt_connects_on_the_ap_it_opened_for() {
+        let (mut app, mut rx) = app_with_commands();
+        let mut home = heard("Home", "aa", Instant::now());
+        home.ap_path = "/org/freedesktop/NetworkManager/AccessPoint/7".to_string();
+        app.update_networks(vec![home]);
+
+        app.connect_row(0);
+        assert!(matches!(app.mode, AppMode::PasswordInput { .. }));
+        // A scan landing mid-typing sees the AP under another path
+        let mut moved = heard("Home", "aa", Instant::now());
+        moved.ap_path = "/org/freedesktop/NetworkManager/AccessPoint/9".to_string();
+        app.update_networks(vec![moved]);
+
+        for c in "hunter22".chars() {
+            app.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+
+        let command = std::iter::from_fn(|| rx.try_recv().ok()).find_map(|event| match event {
+            Event::Command(NetworkCommand::Connect {
+                ssid,
+                password,
+                ap_path,
+            }) => Some((ssid, password, ap_path)),
+            _ => None,
+        });
+        assert_eq!(
+            command,
+            Some((
+                "Home".to_string(),
+                Some("hunter22".to_string()),
+                Some("/org/freedesktop/NetworkManager/AccessPoint/7".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn only_an_expiring_toast_keeps_ticks_fast() {
+        let mut app = app();
+        app.animation = AnimationState::default();
+        assert!(!app.is_animating());
+
+        app.show_sticky_toast("Scanning…", ToastLevel::Info);
+        assert!(!app.is_animating());
+
+        app.show_toast("Connected", ToastLevel::Success);
+        assert!(app.is_animating());
+    }
+
+    #[test]
+    fn only_the_latest_diagnostics_run_lands() {
+        let (mut app, mut rx) = app_with_commands();
+        let mut runs = Vec::new();
+        for _ in 0..2 {
+            app.action_diagnose();
+            let Ok(Event::Command(NetworkCommand::Diagnose { run })) = rx.try_recv() else {
+                panic!("no diagnose command sent");
+            };
+            runs.push(run);
+        }
+
+        app.update_diagnostics(runs[0], DiagnosticReport::default());
+        assert!(app.diagnostics.is_none());
+        app.update_diagnostics(runs[1], DiagnosticReport::default());
+        assert!(app.diagnostics.is_some());
+
+        // Cleared mid-run: the report arriving afterwards is dropped too
+        app.action_diagnose();
+        app.clear_diag_tool(DiagTool::Report);
+        app.update_diagnostics(app.diagnostics_run - 1, DiagnosticReport::default());
+        assert!(app.diagnostics.is_none());
+    }
+
+    #[test]
+    fn fresh_scan_waits_for_an_open_dialog() {
+        let mut app = app();
+        let now = Instant::now();
+        app.update_networks(vec![heard("A", "aa", now), heard("B", "bb", now)]);
+
+        app.mode = AppMode::QuickActions;
+        app.on_fresh_scan(vec![heard("A", "aa", now)]);
+        assert_eq!(app.networks.len(), 2);
+        assert!(app.toast.is_none());
+
+        app.mode = AppMode::Normal;
+        app.apply_pending_scan();
+        assert_eq!(app.networks.len(), 1);
+        let toast = app.toast.as_ref().expect("no toast");
+        assert_eq!(toast.message, "Fresh scan: 1 networks, 1 stale dropped");
+    }
+
+    #[test]
+    fn failed_forget_and_fresh_scan_reset_their_state() {
+        let mut app = app();
+        app.rejoin = Some("A".into());
+        app.on_forget_failed("boom");
+        assert!(app.rejoin.is_none());
+
+        app.mode = AppMode::Scanning;
+        app.on_fresh_scan_failed("boom");
+        assert!(matches!(app.mode, AppMode::Normal));
+        let toast = app.toast.as_ref().expect("no toast");
+        assert_eq!(toast.message, "Fresh scan failed: boom");
+    }
+
+    #[test]
+    fn statistics_sort_keeps_the_focus() {
+        use crate::network::stats::Rates;
+
+        let mut app = app();
+        app.interface_stats = [("eth0", 10.0), ("lo", 1.0), ("wlan0", 500.0)]
+            .into_iter()
+            .map(|(name, rx)| {
+                let mut stats = InterfaceStats::new(name.to_string());
+                stats.history.push(Rates { rx, tx: 0.0 });
+                stats
+            })
+            .collect();
+        app.stats_focus = 1;
+        let names = |app: &App| -> Vec<String> {
+            app.interface_stats.iter().map(|s| s.name.clone()).collect()
+        };
+
+        app.stats_sort = app.stats_sort.next();
+        app.sort_interfaces();
+        assert_eq!(app.stats_sort, StatsSort::RxRate);
+        assert_eq!(names(&app), ["wlan0", "eth0", "lo"]);
+        assert_eq!(app.interface_stats[app.stats_focus].name, "lo");
+
+        // No TX anywhere: ties fall back to the name
+        app.stats_sort = app.stats_sort.next();
+        app.sort_interfaces();
+        assert_eq!(names(&app), ["eth0", "lo", "wlan0"]);
+    }
+}
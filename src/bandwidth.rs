@@ -0,0 +1,80 @@
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use eyre::{Result, WrapErr};
+
+use crate::config::Config;
+use crate::export::rfc3339;
+use crate::network::stats::Rates;
+
+const CSV_HEADER: &str = "timestamp,iface,rx_bytes,tx_bytes,rx_rate,tx_rate";
+
+/// Rotate to `bandwidth.csv.1` once the log grows past this (~10 days at 1 Hz)
+const MAX_LOG_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Per-second interface counters and rates appended to
+/// ~/.local/state/nexus/bandwidth.csv for later analysis
+pub struct BandwidthLog {
+    file: File,
+    /// Current size of the file, to know when to rotate
+    len: u64,
+}
+
+impl BandwidthLog {
+    fn path() -> PathBuf {
+        Config::log_dir().join("bandwidth.csv")
+    }
+
+    /// Open the log for appending, writing the header to a new file
+    pub fn open() -> Result<Self> {
+        let path = Self::path();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .wrap_err_with(|| format!("Failed to open {}", path.display()))?;
+        let len = file.metadata().map_or(0, |m| m.len());
+        let mut log = Self { file, len };
+        if len == 0 {
+            log.write_line(CSV_HEADER)?;
+        }
+        Ok(log)
+    }
+
+    /// Append one sample. Rates are in bytes per second.
+    pub fn record(
+        &mut self,
+        iface: &str,
+        (rx_bytes, tx_bytes): (u64, u64),
+        rates: Rates,
+    ) -> Result<()> {
+        if self.len >= MAX_LOG_BYTES {
+            self.rotate()?;
+        }
+        let line = format!(
+            "{},{iface},{rx_bytes},{tx_bytes},{:.0},{:.0}",
+            rfc3339(SystemTime::now()),
+            rates.rx,
+            rates.tx
+        );
+        self.write_line(&line)
+    }
+
+    /// Keep one previous file and start a fresh one
+    fn rotate(&mut self) -> Result<()> {
+        let path = Self::path();
+        let old = path.with_extension("csv.1");
+        std::fs::rename(&path, &old)
+            .wrap_err_with(|| format!("Failed to rotate {}", path.display()))?;
+        *self = Self::open()?;
+        Ok(())
+    }
+
+    fn write_line(&mut self, line: &str) -> Result<()> {
+        writeln!(self.file, "{line}").wrap_err("Failed to write the bandwidth log")?;
+        self.len += line.len() as u64 + 1;
+        Ok(())
+    }
+}
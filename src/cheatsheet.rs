@@ -0,0 +1,102 @@
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use eyre::{Result, WrapErr};
+
+use crate::config::{KeysConfig, KeysFormat};
+
+/// Keys of the network list that can't be remapped
+const LIST_FIXED: &[(&str, &str)] = &[
+    ("↑ / k", "Move up"),
+    ("↓ / j", "Move down"),
+    ("g", "Go to top"),
+    ("G", "Go to bottom"),
+    (
+        "Tab",
+        "Focus details (y copy, c connect command, n/N nickname/note)",
+    ),
+    ("1-9", "Jump to page"),
+    ("Alt+1-9", "Connect to numbered row"),
+    ("Ctrl+F", "Search networks, interfaces, actions"),
+    ("Ctrl+R", "Recently connected networks"),
+];
+
+/// Keys shared by the dialogs
+const DIALOGS: &[(&str, &str)] = &[
+    ("Ctrl+H", "Show/hide password"),
+    ("Tab", "Switch fields"),
+    ("Esc", "Close dialog / cancel"),
+];
+
+/// The keymap in effect, as (heading, [(key, description)]) groups
+pub fn groups(keys: &KeysConfig) -> Vec<(&'static str, Vec<(String, &'static str)>)> {
+    let fixed = |entries: &[(&str, &'static str)]| -> Vec<(String, &'static str)> {
+        entries
+            .iter()
+            .map(|&(key, desc)| (key.to_string(), desc))
+            .collect()
+    };
+    let mut list = fixed(LIST_FIXED);
+    list.extend(
+        keys.bindings()
+            .into_iter()
+            .map(|(binding, desc)| (display_key(binding), desc)),
+    );
+    vec![("Network list", list), ("Dialogs", fixed(DIALOGS))]
+}
+
+/// A `[keys]` value the way it is shown: named keys capitalized
+pub fn display_key(binding: &str) -> String {
+    match binding {
+        "backtab" => "Shift+Tab".to_string(),
+        named if named.chars().count() > 1 => {
+            let mut chars = named.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+        key => key.to_string(),
+    }
+}
+
+/// The cheatsheet as aligned text or Markdown tables
+pub fn render(keys: &KeysConfig, format: KeysFormat) -> String {
+    let mut out = String::new();
+    for (i, (heading, entries)) in groups(keys).into_iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        match format {
+            KeysFormat::Text => {
+                let _ = writeln!(out, "{heading}");
+                for (key, desc) in entries {
+                    let _ = writeln!(out, "  {key:<12}{desc}");
+                }
+            }
+            KeysFormat::Markdown => {
+                let _ = writeln!(out, "## {heading}\n\n| Key | Action |\n|---|---|");
+                for (key, desc) in entries {
+                    let _ = writeln!(out, "| `{}` | {desc} |", key.replace('|', "\\|"));
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Write the Markdown cheatsheet to ~/.local/share/nexus/keys.md
+pub fn write(keys: &KeysConfig) -> Result<PathBuf> {
+    let dir = dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("nexus");
+    std::fs::create_dir_all(&dir)
+        .wrap_err_with(|| format!("Failed to create {}", dir.display()))?;
+    let path = dir.join("keys.md");
+    let text = format!(
+        "# Nexus keybindings\n\n{}",
+        render(keys, KeysFormat::Markdown)
+    );
+    std::fs::write(&path, text).wrap_err_with(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
@@ -0,0 +1,15 @@
+use std::io::{self, Write};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+/// Copy text to the system clipboard with an OSC 52 escape sequence.
+///
+/// The terminal emulator does the actual copy, so this works over SSH and
+/// without a display server, and needs no clipboard daemon. Terminals that
+/// don't support OSC 52 silently ignore it.
+pub fn copy(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+    stdout.flush()
+}
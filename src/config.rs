@@ -1,11 +1,13 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
 use eyre::{Result, WrapErr};
 use ratatui::style::Color;
 use serde::{Deserialize, Deserializer};
 use tracing::info;
 
+use crate::i18n::Language;
+
 // ─── Embedded Default ────────────────────────────────────────────────────
 /// Baked into the binary at compile time. The app can never crash due to a
 /// missing config file — this is always available as the base layer.
@@ -39,6 +41,84 @@ pub struct CliArgs {
     /// Target FPS for the render loop (overrides config file)
     #[arg(long)]
     pub fps: Option<u16>,
+
+    /// Listen for JSON control commands on this unix socket path
+    #[arg(long, value_name = "PATH")]
+    pub control_socket: Option<PathBuf>,
+
+    /// Run against a mock backend with fake networks (no NetworkManager needed)
+    #[arg(long)]
+    pub demo: bool,
+
+    /// Print a one-line connection status (for prompts / status bars) and exit
+    #[arg(long)]
+    pub oneline: bool,
+
+    /// Append per-second interface rates to bandwidth.csv (overrides config file)
+    #[arg(long)]
+    pub log_bandwidth: bool,
+
+    /// Write the log to this file instead of the daily logs in the state dir
+    #[arg(long, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// Template for --oneline: {ssid} {signal} {ip} {iface} {rx} {tx}
+    #[arg(long, value_name = "TEMPLATE", requires = "oneline")]
+    pub format: Option<String>,
+
+    /// Print the keybindings in effect (after config overrides) and exit
+    #[arg(long, value_name = "FORMAT", num_args = 0..=1, default_missing_value = "text")]
+    pub print_keys: Option<KeysFormat>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Output format of `--print-keys`
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeysFormat {
+    /// Aligned plain text, one binding per line
+    Text,
+    /// Markdown tables
+    Markdown,
+}
+
+/// Non-interactive subcommands
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Scan and print every access point (one row per BSSID) as CSV
+    Scan {
+        /// Print JSON instead of CSV
+        #[arg(long)]
+        json: bool,
+    },
+    /// Run the network health check and print a PASS/FAIL checklist; exits
+    /// non-zero if any step fails
+    Doctor,
+    /// Connect to the network whose SSID matches; part of the name is
+    /// enough when exactly one network in range contains it
+    Connect {
+        /// SSID, or a case-insensitive part of it
+        ssid: String,
+        /// Password, for a network without a saved profile. Visible to
+        /// other users in the process list; prefer --password-stdin
+        #[arg(short, long)]
+        password: Option<String>,
+        /// Read the password from stdin, prompting without echo when it is
+        /// a terminal
+        #[arg(long, conflicts_with = "password")]
+        password_stdin: bool,
+    },
+    /// Print network events (connected, disconnected, connectivity, APs
+    /// appearing, devices added) as JSON lines until interrupted
+    Watch,
+}
+
+impl CliArgs {
+    /// Whether to use the mock backend (`--demo` or `NEXUS_BACKEND=mock`)
+    pub fn use_mock_backend(&self) -> bool {
+        self.demo || std::env::var("NEXUS_BACKEND").is_ok_and(|v| v.eq_ignore_ascii_case("mock"))
+    }
 }
 
 // ─── TOML Structs ───────────────────────────────────────────────────────
@@ -51,6 +131,12 @@ pub struct Config {
     #[serde(default)]
     pub appearance: AppearanceConfig,
     #[serde(default)]
+    pub layout: LayoutConfig,
+    #[serde(default)]
+    pub watchdog: WatchdogConfig,
+    #[serde(default)]
+    pub stats: StatsConfig,
+    #[serde(default)]
     pub theme: ThemeConfig,
     #[serde(default)]
     pub keys: KeysConfig,
@@ -67,9 +153,74 @@ pub struct GeneralConfig {
     #[serde(default = "default_log_level")]
     pub log_level: String,
 
+    /// Delete daily log files older than this many days (0 = keep all)
+    #[serde(default = "default_log_retention_days")]
+    pub log_retention_days: u32,
+
+    /// Cap on the daily log files' total size in MiB, oldest removed
+    /// first (0 = no cap)
+    #[serde(default = "default_log_max_mb")]
+    pub log_max_mb: u64,
+
+    /// Shorten SSIDs in crash reports to their first character
+    #[serde(default = "default_true")]
+    pub redact_crash_reports: bool,
+
     /// Polling interval for NM signal listener (seconds)
     #[serde(default = "default_scan_interval")]
     pub scan_interval_secs: u64,
+
+    /// Periodic WiFi rescan interval (seconds, 0 = off)
+    #[serde(default)]
+    pub auto_scan_secs: u64,
+
+    /// File format for scan exports: "csv" or "json"
+    pub export_format: ExportFormat,
+
+    /// Drop networks missing from this many consecutive scans
+    #[serde(default = "default_stale_scans")]
+    pub stale_scans: u32,
+
+    /// Seconds between scans in site survey mode
+    #[serde(default = "default_survey_interval")]
+    pub survey_interval_secs: u64,
+
+    /// Extra attempts after a transient activation failure (0 = off)
+    #[serde(default = "default_connect_retries")]
+    pub connect_retries: u32,
+
+    /// Append per-second interface rates to bandwidth.csv in the state dir
+    #[serde(default)]
+    pub bandwidth_log: bool,
+
+    /// Refresh the connection and rescan when the terminal regains focus
+    #[serde(default = "default_true")]
+    pub refresh_on_focus: bool,
+
+    /// Language of the interface text
+    pub language: Language,
+
+    /// Hide unsaved networks weaker than this (percent, 0 = show all)
+    #[serde(default = "default_min_signal")]
+    pub min_signal: u8,
+}
+
+/// File format of scan exports
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -94,6 +245,171 @@ pub struct AppearanceConfig {
     /// Border style: "rounded", "plain", "thick", "double"
     #[serde(default = "default_border_style")]
     pub border_style: String,
+
+    /// Traffic graph style: "bars" or "line"
+    pub graph_style: GraphStyle,
+
+    /// Stop animating while the terminal doesn't have focus
+    #[serde(default = "default_true")]
+    pub pause_unfocused: bool,
+
+    /// Show the local time (HH:MM) in the header
+    #[serde(default)]
+    pub show_clock: bool,
+
+    /// Show how long ago network data was last refreshed in the header
+    #[serde(default = "default_true")]
+    pub show_data_age: bool,
+
+    /// Show signal strength as estimated dBm instead of percent
+    #[serde(default)]
+    pub signal_dbm: bool,
+
+    /// Number the first nine rows of the network list for Alt+1–9
+    #[serde(default = "default_true")]
+    pub row_numbers: bool,
+
+    /// Dim the UI after this many seconds without a key press (0 = never)
+    #[serde(default)]
+    pub idle_dim_secs: u64,
+
+    /// While dimmed, show only a one-line status instead of the full UI
+    #[serde(default)]
+    pub idle_minimal: bool,
+
+    /// "full" (the `[theme]` colors), "high-contrast" or "mono"
+    pub color_mode: ColorMode,
+
+    /// Optional columns of the network list
+    pub wifi_columns: WifiColumns,
+
+    /// Optional columns of the interface statistics table
+    pub interface_columns: InterfaceColumns,
+}
+
+/// Columns of the network list besides the SSID and signal icon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct WifiColumns {
+    /// Signal as a number (percent or dBm)
+    pub signal: bool,
+    /// Lock icon and security type
+    pub security: bool,
+    /// Saved and pinned markers
+    pub saved: bool,
+    /// 5G / 6G
+    pub band: bool,
+}
+
+impl WifiColumns {
+    /// SSID and signal only, for narrow terminals
+    pub const COMPACT: Self = Self {
+        signal: true,
+        security: false,
+        saved: false,
+        band: false,
+    };
+}
+
+impl Default for WifiColumns {
+    fn default() -> Self {
+        Self {
+            signal: true,
+            security: true,
+            saved: true,
+            band: true,
+        }
+    }
+}
+
+/// Columns of the statistics table besides the interface name and totals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct InterfaceColumns {
+    /// RX / TX error counters
+    pub errors: bool,
+    /// RX / TX drop counters
+    pub drops: bool,
+}
+
+impl InterfaceColumns {
+    /// Interface and totals only, for narrow terminals
+    pub const COMPACT: Self = Self {
+        errors: false,
+        drops: false,
+    };
+}
+
+impl Default for InterfaceColumns {
+    fn default() -> Self {
+        Self {
+            errors: true,
+            drops: true,
+        }
+    }
+}
+
+/// How much the UI relies on color
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ColorMode {
+    /// The `[theme]` palette
+    #[default]
+    Full,
+    /// A fixed bright-on-black palette; `[theme]` colors are ignored
+    HighContrast,
+    /// No color at all: states are told apart by text, symbols, bold and
+    /// reverse video, for monochrome terminals and screen readers
+    Mono,
+}
+
+/// How the traffic graph is drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphStyle {
+    /// Block-bar sparklines, RX above TX
+    #[default]
+    Bars,
+    /// Braille line chart with RX and TX overlaid
+    Line,
+}
+
+/// Which full-screen pages exist and in what order
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LayoutConfig {
+    /// Page names: "networks" (or "wifi"), "statistics", "survey".
+    /// Resolved by `app::View::resolve`.
+    pub pages: Vec<String>,
+}
+
+/// Background probe that notices a dead uplink NM hasn't noticed yet
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WatchdogConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Seconds between probes
+    #[serde(default = "default_watchdog_interval")]
+    pub interval_secs: u64,
+
+    /// Host to ping (empty = the default gateway)
+    #[serde(default)]
+    pub target: String,
+
+    /// Consecutive failed probes before alerting
+    #[serde(default = "default_watchdog_failures")]
+    pub failures: u32,
+}
+
+/// Exporting interface statistics to other tools
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct StatsConfig {
+    /// File for node_exporter's textfile collector, rewritten every second
+    /// (empty = off)
+    pub prometheus_textfile: String,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -140,6 +456,27 @@ pub struct ThemeConfig {
 
     #[serde(default)]
     pub signal: SignalColors,
+
+    #[serde(default)]
+    pub pages: PageColors,
+}
+
+/// Per-page accent overrides; unset pages use the global accent
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PageColors {
+    #[serde(deserialize_with = "deserialize_optional_color")]
+    pub networks: Option<Color>,
+    #[serde(deserialize_with = "deserialize_optional_color")]
+    pub details: Option<Color>,
+    #[serde(deserialize_with = "deserialize_optional_color")]
+    pub diagnostics: Option<Color>,
+    #[serde(deserialize_with = "deserialize_optional_color")]
+    pub survey: Option<Color>,
+    #[serde(deserialize_with = "deserialize_optional_color")]
+    pub events: Option<Color>,
+    #[serde(deserialize_with = "deserialize_optional_color")]
+    pub statistics: Option<Color>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -208,6 +545,36 @@ pub struct KeysConfig {
     pub hidden: String,
     pub details: String,
     pub refresh: String,
+    pub mtu: String,
+    pub manage: String,
+    pub edit: String,
+    pub undo: String,
+    pub rejoin: String,
+    pub compact: String,
+    pub theme_preview: String,
+    pub ignore: String,
+    pub show_ignored: String,
+    pub weak_signals: String,
+    pub fresh_scan: String,
+    pub wifi_device: String,
+    pub raise_min_signal: String,
+    pub lower_min_signal: String,
+    pub favorite: String,
+    pub shell: String,
+    pub events: String,
+    pub connectivity_check: String,
+    pub auto_scan: String,
+    pub export: String,
+    pub survey: String,
+    pub statistics: String,
+    pub palette: String,
+    pub quick_actions: String,
+    pub next_page: String,
+    pub prev_page: String,
+    pub signal_unit: String,
+    pub mark: String,
+    pub mark_all: String,
+    pub diagnose: String,
     pub help: String,
     pub quit: String,
     pub sort: String,
@@ -229,7 +596,30 @@ impl Default for GeneralConfig {
         Self {
             interface: String::new(),
             log_level: "info".into(),
+            log_retention_days: 14,
+            log_max_mb: 100,
+            redact_crash_reports: true,
             scan_interval_secs: 5,
+            auto_scan_secs: 0,
+            export_format: ExportFormat::default(),
+            survey_interval_secs: 10,
+            stale_scans: 3,
+            connect_retries: 2,
+            bandwidth_log: false,
+            refresh_on_focus: true,
+            language: Language::default(),
+            min_signal: 15,
+        }
+    }
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: 30,
+            target: String::new(),
+            failures: 3,
         }
     }
 }
@@ -242,6 +632,25 @@ impl Default for AppearanceConfig {
             fps: 60,
             show_details: true,
             border_style: "rounded".into(),
+            graph_style: GraphStyle::default(),
+            pause_unfocused: true,
+            show_clock: false,
+            show_data_age: true,
+            signal_dbm: false,
+            row_numbers: true,
+            idle_dim_secs: 0,
+            idle_minimal: false,
+            color_mode: ColorMode::default(),
+            wifi_columns: WifiColumns::default(),
+            interface_columns: InterfaceColumns::default(),
+        }
+    }
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            pages: vec!["networks".into(), "statistics".into(), "survey".into()],
         }
     }
 }
@@ -258,6 +667,7 @@ impl Default for ThemeConfig {
             border_focused: Color::Cyan,
             semantic: SemanticColors::default(),
             signal: SignalColors::default(),
+            pages: PageColors::default(),
         }
     }
 }
@@ -295,6 +705,36 @@ impl Default for KeysConfig {
             hidden: "h".into(),
             details: "i".into(),
             refresh: "r".into(),
+            mtu: "m".into(),
+            manage: "M".into(),
+            edit: "e".into(),
+            undo: "u".into(),
+            rejoin: "R".into(),
+            compact: "C".into(),
+            theme_preview: "T".into(),
+            ignore: "I".into(),
+            show_ignored: "v".into(),
+            weak_signals: "w".into(),
+            fresh_scan: "F".into(),
+            wifi_device: "W".into(),
+            raise_min_signal: ">".into(),
+            lower_min_signal: "<".into(),
+            favorite: "*".into(),
+            shell: "n".into(),
+            events: "l".into(),
+            connectivity_check: "c".into(),
+            auto_scan: "A".into(),
+            export: "x".into(),
+            survey: "Y".into(),
+            statistics: "t".into(),
+            palette: ":".into(),
+            quick_actions: "o".into(),
+            next_page: "]".into(),
+            prev_page: "[".into(),
+            signal_unit: "%".into(),
+            mark: "space".into(),
+            mark_all: "a".into(),
+            diagnose: "D".into(),
             help: "?".into(),
             quit: "q".into(),
             sort: "S".into(),
@@ -303,6 +743,56 @@ impl Default for KeysConfig {
     }
 }
 
+impl KeysConfig {
+    /// Every configurable binding with what it does, in the order the help
+    /// lists them
+    pub fn bindings(&self) -> Vec<(&str, &'static str)> {
+        vec![
+            (&self.connect, "Connect to selected network"),
+            (&self.disconnect, "Disconnect from current network"),
+            (&self.scan, "Scan for networks"),
+            (&self.fresh_scan, "Fresh scan (drop cached networks)"),
+            (&self.wifi_device, "Pick WiFi adapter"),
+            (&self.forget, "Forget selected network"),
+            (&self.undo, "Undo last forget"),
+            (&self.rejoin, "Forget and rejoin network"),
+            (&self.compact, "Compact / configured columns"),
+            (&self.auto_scan, "Toggle auto-scan"),
+            (&self.export, "Export scan results"),
+            (&self.survey, "Site survey mode"),
+            (&self.statistics, "Interface statistics"),
+            (&self.favorite, "Pin / unpin favorite"),
+            (&self.mark, "Mark for bulk forget"),
+            (&self.mark_all, "Mark all visible saved"),
+            (&self.hidden, "Connect to hidden network"),
+            (&self.details, "Toggle detail panel"),
+            (&self.signal_unit, "Signal as percent / est. dBm"),
+            (&self.refresh, "Refresh connection info"),
+            (&self.mtu, "Set MTU of active connection"),
+            (&self.manage, "Let NetworkManager manage device"),
+            (&self.edit, "Edit active connection settings"),
+            (&self.shell, "nmcli command / keyfile / share"),
+            (&self.events, "Connection events log"),
+            (&self.connectivity_check, "Toggle NM connectivity check"),
+            (&self.diagnose, "Diagnose internet connectivity"),
+            (&self.search, "Search / filter networks"),
+            (&self.next_page, "Next page"),
+            (&self.prev_page, "Previous page"),
+            (&self.quick_actions, "Quick actions for selection"),
+            (&self.palette, "Go to view / run action by name"),
+            (&self.sort, "Cycle sort mode"),
+            (&self.theme_preview, "Preview theme colors"),
+            (&self.ignore, "Ignore SSID / pattern"),
+            (&self.show_ignored, "Show/hide ignored networks"),
+            (&self.weak_signals, "Show/hide weak networks"),
+            (&self.lower_min_signal, "Lower weak threshold"),
+            (&self.raise_min_signal, "Raise weak threshold"),
+            (&self.help, "Toggle this help"),
+            (&self.quit, "Quit Nexus"),
+        ]
+    }
+}
+
 // ─── Color Deserializer ─────────────────────────────────────────────────
 
 fn deserialize_color<'de, D>(deserializer: D) -> std::result::Result<Color, D::Error>
@@ -313,6 +803,16 @@ where
     parse_color(&s).ok_or_else(|| serde::de::Error::custom(format!("invalid color: \"{s}\"")))
 }
 
+/// Like `deserialize_color`, for colors that may be left out
+fn deserialize_optional_color<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Color>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_color(deserializer).map(Some)
+}
+
 /// Parse a color string into a ratatui Color.
 /// Supports: named colors, "reset", "#RRGGBB" hex.
 pub fn parse_color(s: &str) -> Option<Color> {
@@ -353,9 +853,33 @@ fn default_true() -> bool {
 fn default_log_level() -> String {
     "info".into()
 }
+fn default_log_retention_days() -> u32 {
+    14
+}
+fn default_log_max_mb() -> u64 {
+    100
+}
 fn default_scan_interval() -> u64 {
     5
 }
+fn default_stale_scans() -> u32 {
+    3
+}
+fn default_survey_interval() -> u64 {
+    10
+}
+fn default_connect_retries() -> u32 {
+    2
+}
+fn default_min_signal() -> u8 {
+    15
+}
+fn default_watchdog_interval() -> u64 {
+    30
+}
+fn default_watchdog_failures() -> u32 {
+    3
+}
 fn default_fps() -> u16 {
     60
 }
@@ -398,7 +922,7 @@ impl Config {
             .join("config.toml")
     }
 
-    /// Log directory: ~/.local/state/nexus/
+    /// State directory (logs, labels, ignore list): ~/.local/state/nexus/
     pub fn log_dir() -> PathBuf {
         let data_dir = dirs::state_dir()
             .unwrap_or_else(|| PathBuf::from("."))
@@ -497,6 +1021,9 @@ pub fn load(cli: &CliArgs) -> Result<Config> {
     if let Some(fps) = cli.fps {
         config.appearance.fps = fps;
     }
+    if cli.log_bandwidth {
+        config.general.bandwidth_log = true;
+    }
 
     Ok(config)
 }
@@ -0,0 +1,231 @@
+use std::io::{BufRead, IsTerminal, Write};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+use eyre::{Result, WrapErr};
+use tokio::sync::mpsc;
+
+use crate::config::{CliArgs, Config};
+use crate::network::NetworkBackend;
+use crate::network::manager::NmBackend;
+use crate::network::mock::MockBackend;
+use crate::network::types::WiFiNetwork;
+
+/// How a partial SSID resolved against the scan results
+#[derive(Debug, PartialEq, Eq)]
+enum Pick {
+    One(String),
+    Ambiguous(Vec<String>),
+    NotFound,
+}
+
+/// Where `nexus connect` gets the password from
+pub enum Password {
+    None,
+    /// `-p`, in argv
+    Given(String),
+    /// `--password-stdin`: read once the SSID is resolved
+    Stdin,
+}
+
+/// `nexus connect <SSID>`: scan, resolve a partial SSID and connect to it.
+/// Returns false (exit 1) when the name matches no network or several, or
+/// the password prompt is aborted.
+pub async fn run(cli: &CliArgs, config: &Config, query: &str, password: Password) -> Result<bool> {
+    if cli.use_mock_backend() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        connect(&MockBackend::new(tx), query, password).await
+    } else {
+        connect(&NmBackend::new(config.interface()).await?, query, password).await
+    }
+}
+
+async fn connect<B: NetworkBackend>(backend: &B, query: &str, password: Password) -> Result<bool> {
+    let networks = backend.scan().await?;
+    match pick(&networks, query) {
+        Pick::One(ssid) => {
+            let password = match password {
+                Password::None => None,
+                Password::Given(password) => Some(password),
+                Password::Stdin => {
+                    let prompt = format!("Password for '{ssid}': ");
+                    let read = tokio::task::spawn_blocking(move || read_password(&prompt));
+                    match read.await?? {
+                        Some(password) => Some(password),
+                        None => return Ok(false),
+                    }
+                }
+            };
+            backend.connect(&ssid, password.as_deref(), None).await?;
+            println!("Connected to '{ssid}'");
+            Ok(true)
+        }
+        Pick::Ambiguous(ssids) => {
+            eprintln!("'{query}' matches {} networks:", ssids.len());
+            for ssid in &ssids {
+                eprintln!("  {ssid}");
+            }
+            eprintln!("Give a more specific name.");
+            Ok(false)
+        }
+        Pick::NotFound => {
+            eprintln!("No network in range matches '{query}'");
+            Ok(false)
+        }
+    }
+}
+
+/// An exact SSID wins, then a case-insensitive exact one, then the only
+/// SSID containing `query` case-insensitively, like the `/` filter does
+fn pick(networks: &[WiFiNetwork], query: &str) -> Pick {
+    let mut ssids: Vec<&str> = Vec::new();
+    for net in networks {
+        if !net.ssid.is_empty() && !ssids.contains(&net.ssid.as_str()) {
+            ssids.push(&net.ssid);
+        }
+    }
+    if ssids.contains(&query) {
+        return Pick::One(query.to_string());
+    }
+
+    let query = query.to_lowercase();
+    let exact: Vec<&str> = ssids
+        .iter()
+        .copied()
+        .filter(|ssid| ssid.to_lowercase() == query)
+        .collect();
+    let matching = if exact.is_empty() {
+        ssids
+            .into_iter()
+            .filter(|ssid| ssid.to_lowercase().contains(&query))
+            .collect()
+    } else {
+        exact
+    };
+    match matching.as_slice() {
+        [] => Pick::NotFound,
+        [ssid] => Pick::One(ssid.to_string()),
+        _ => Pick::Ambiguous(matching.into_iter().map(str::to_string).collect()),
+    }
+}
+
+/// Read a password from stdin: the first line when it is piped, or typed
+/// at a prompt without echo when it is a terminal. None when the prompt is
+/// aborted with Esc or Ctrl+C.
+fn read_password(prompt: &str) -> Result<Option<String>> {
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() {
+        let mut line = String::new();
+        stdin
+            .lock()
+            .read_line(&mut line)
+            .wrap_err("Failed to read the password from stdin")?;
+        return Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()));
+    }
+
+    eprint!("{prompt}");
+    std::io::stderr().flush()?;
+    terminal::enable_raw_mode()?;
+    let typed = read_hidden();
+    terminal::disable_raw_mode()?;
+    eprintln!();
+    typed
+}
+
+fn read_hidden() -> Result<Option<String>> {
+    let mut password = String::new();
+    loop {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind == KeyEventKind::Release {
+            continue;
+        }
+        match key.code {
+            KeyCode::Enter => return Ok(Some(password)),
+            KeyCode::Esc => return Ok(None),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Ok(None);
+            }
+            KeyCode::Backspace => {
+                password.pop();
+            }
+            KeyCode::Char(c) => password.push(c),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ap(ssid: &str) -> WiFiNetwork {
+        WiFiNetwork::test(ssid, 50)
+    }
+
+    fn networks() -> Vec<WiFiNetwork> {
+        [
+            "Home",
+            "home",
+            "HomeWiFi-5G",
+            "Cafe",
+            "Cafe",
+            "Corp-Guest",
+            "",
+        ]
+        .into_iter()
+        .map(ap)
+        .collect()
+    }
+
+    fn one(ssid: &str) -> Pick {
+        Pick::One(ssid.to_string())
+    }
+
+    #[test]
+    fn exact_ssid_wins() {
+        assert_eq!(pick(&networks(), "home"), one("home"));
+        assert_eq!(pick(&networks(), "Home"), one("Home"));
+    }
+
+    #[test]
+    fn case_insensitive_exact_beats_substring() {
+        assert_eq!(pick(&networks(), "CAFE"), one("Cafe"));
+        // Both "Home" and "home" match case-insensitively
+        assert_eq!(
+            pick(&networks(), "HOME"),
+            Pick::Ambiguous(vec!["Home".to_string(), "home".to_string()])
+        );
+    }
+
+    #[test]
+    fn unique_substring_matches() {
+        assert_eq!(pick(&networks(), "guest"), one("Corp-Guest"));
+        assert_eq!(pick(&networks(), "5g"), one("HomeWiFi-5G"));
+    }
+
+    #[test]
+    fn ambiguous_and_missing() {
+        assert_eq!(
+            pick(&networks(), "o"),
+            Pick::Ambiguous(vec![
+                "Home".to_string(),
+                "home".to_string(),
+                "HomeWiFi-5G".to_string(),
+                "Corp-Guest".to_string(),
+            ])
+        );
+        assert_eq!(pick(&networks(), "Office"), Pick::NotFound);
+        assert_eq!(
+            pick(&networks(), ""),
+            Pick::Ambiguous(vec![
+                "Home".to_string(),
+                "home".to_string(),
+                "HomeWiFi-5G".to_string(),
+                "Cafe".to_string(),
+                "Corp-Guest".to_string(),
+            ])
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use eyre::{Result, WrapErr};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
+
+use crate::event::{Event, NetworkCommand};
+use crate::network::NetworkBackend;
+use crate::network::types::{ConnectionInfo, WiFiNetwork};
+
+// ─── Protocol ───────────────────────────────────────────────────────────
+// Newline-delimited JSON over a unix stream socket. Each request line gets
+// exactly one response line:
+//
+//   → {"cmd":"status"}
+//   ← {"ok":true,"connected":true,"ssid":"HomeWiFi","signal":72,...}
+//   → {"cmd":"connect","ssid":"Cafe","password":"hunter22"}
+//   ← {"ok":true}
+//   → {"cmd":"bogus"}
+//   ← {"ok":false,"error":"..."}
+
+/// A single control request
+#[derive(Debug, Deserialize)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+enum Request {
+    /// Current connection info
+    Status,
+    /// Trigger a scan and return the results
+    Scan,
+    /// Connect to a network by SSID
+    Connect {
+        ssid: String,
+        #[serde(default)]
+        password: Option<String>,
+    },
+    /// Disconnect the active WiFi connection
+    Disconnect,
+}
+
+/// Bind the control socket and serve clients until the process exits.
+/// A stale socket left by a crashed run is replaced; anything else at the
+/// path (a regular file, or a socket another instance is serving) is an error.
+pub async fn serve<B: NetworkBackend + 'static>(
+    path: PathBuf,
+    backend: Arc<B>,
+    event_tx: mpsc::UnboundedSender<Event>,
+) -> Result<()> {
+    remove_stale_socket(&path)?;
+    let listener = bind_private(&path)?;
+
+    info!("Control socket listening on {}", path.display());
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let backend = Arc::clone(&backend);
+                    let tx = event_tx.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = handle_client(stream, backend, tx).await {
+                            debug!("Control client error: {}", e);
+                        }
+                    });
+                }
+                Err(e) => {
+                    warn!("Control socket accept failed: {}", e);
+                    return;
+                }
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Remove the socket file on shutdown (best effort)
+pub fn cleanup(path: &Path) {
+    let _ = std::fs::remove_file(path);
+}
+
+/// Unlink `path` only if it is a socket nobody is listening on
+fn remove_stale_socket(path: &Path) -> Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+
+    let meta = match std::fs::symlink_metadata(path) {
+        Ok(meta) => meta,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => {
+            return Err(e).wrap_err_with(|| format!("Failed to inspect {}", path.display()));
+        }
+    };
+    if !meta.file_type().is_socket() {
+        eyre::bail!(
+            "{} exists and is not a socket; refusing to replace it",
+            path.display()
+        );
+    }
+    match std::os::unix::net::UnixStream::connect(path) {
+        Ok(_) => eyre::bail!(
+            "Another Nexus instance is already listening on {}",
+            path.display()
+        ),
+        Err(e) if e.kind() == std::io::ErrorKind::ConnectionRefused => std::fs::remove_file(path)
+            .wrap_err_with(|| format!("Failed to remove stale socket {}", path.display())),
+        Err(e) => Err(e).wrap_err_with(|| format!("Failed to probe {}", path.display())),
+    }
+}
+
+/// Bind the socket inside a private 0700 directory, restrict it to 0600 and
+/// only then move it to `path`, so it is never reachable by other users.
+/// Only the owning user may drive Nexus through the socket.
+fn bind_private(path: &Path) -> Result<UnixListener> {
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+
+    let parent = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let staging = parent.join(format!(".nexus-control.{}", std::process::id()));
+    std::fs::DirBuilder::new()
+        .mode(0o700)
+        .create(&staging)
+        .wrap_err_with(|| format!("Failed to create {}", staging.display()))?;
+
+    let result = (|| {
+        let tmp = staging.join("sock");
+        let listener = UnixListener::bind(&tmp)
+            .wrap_err_with(|| format!("Failed to bind control socket {}", path.display()))?;
+        std::fs::set_permissions(&tmp, std::fs::Permissions::from_mode(0o600))
+            .wrap_err("Failed to restrict control socket permissions")?;
+        std::fs::rename(&tmp, path)
+            .wrap_err_with(|| format!("Failed to move control socket to {}", path.display()))?;
+        Ok(listener)
+    })();
+    let _ = std::fs::remove_dir_all(&staging);
+    result
+}
+
+/// Serve one client connection: read request lines, write response lines
+async fn handle_client<B: NetworkBackend>(
+    stream: UnixStream,
+    backend: Arc<B>,
+    event_tx: mpsc::UnboundedSender<Event>,
+) -> Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<Request>(&line) {
+            Ok(request) => {
+                debug!("Control request: {:?}", request);
+                dispatch(request, backend.as_ref(), &event_tx).await
+            }
+            Err(e) => error_response(format!("Invalid request: {e}")),
+        };
+        let mut out = response.to_string();
+        out.push('\n');
+        writer.write_all(out.as_bytes()).await?;
+    }
+
+    Ok(())
+}
+
+/// Run a request against the backend and build its JSON response.
+/// State-changing requests also nudge the TUI so it reflects the change.
+async fn dispatch<B: NetworkBackend>(
+    request: Request,
+    backend: &B,
+    event_tx: &mpsc::UnboundedSender<Event>,
+) -> Value {
+    match request {
+        Request::Status => match backend.current_connection().await {
+            Ok(Some(info)) => status_json(&info),
+            Ok(None) => json!({ "ok": true, "connected": false }),
+            Err(e) => error_response(e.to_string()),
+        },
+        Request::Scan => match backend.scan().await {
+            Ok(networks) => {
+                let list: Vec<Value> = networks.iter().map(network_json).collect();
+                let _ = event_tx.send(Event::NetworkScan(networks));
+                json!({ "ok": true, "networks": list })
+            }
+            Err(e) => error_response(e.to_string()),
+        },
+        Request::Connect { ssid, password } => {
+            let result = backend.connect(&ssid, password.as_deref(), None).await;
+            refresh_ui(event_tx);
+            match result {
+                Ok(()) => json!({ "ok": true }),
+                Err(e) => error_response(e.to_string()),
+            }
+        }
+        Request::Disconnect => {
+            let result = backend.disconnect().await;
+            refresh_ui(event_tx);
+            match result {
+                Ok(()) => json!({ "ok": true }),
+                Err(e) => error_response(e.to_string()),
+            }
+        }
+    }
+}
+
+fn refresh_ui(event_tx: &mpsc::UnboundedSender<Event>) {
+    let _ = event_tx.send(Event::Command(NetworkCommand::RefreshConnection));
+    let _ = event_tx.send(Event::Command(NetworkCommand::Scan));
+}
+
+fn status_json(info: &ConnectionInfo) -> Value {
+    json!({
+        "ok": true,
+        "connected": true,
+        "ssid": info.ssid,
+        "bssid": info.bssid,
+        "signal": info.signal,
+        "frequency": info.frequency,
+        "ip4": info.ip4,
+        "ip6": info.ip6,
+        "gateway": info.gateway,
+        "speed": info.speed,
+        "interface": info.interface,
+    })
+}
+
+fn network_json(net: &WiFiNetwork) -> Value {
+    json!({
+        "ssid": net.ssid,
+        "bssid": net.bssid,
+        "signal": net.signal_strength,
+        "frequency": net.frequency,
+        "channel": net.channel(),
+        "security": net.security.to_string(),
+        "saved": net.is_saved,
+        "active": net.is_active,
+    })
+}
+
+fn error_response(msg: String) -> Value {
+    json!({ "ok": false, "error": msg })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("nexus-control-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn regular_file_is_not_removed() {
+        let dir = scratch("file");
+        let path = dir.join("notes.txt");
+        std::fs::write(&path, "keep me").unwrap();
+        assert!(remove_stale_socket(&path).is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "keep me");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn live_socket_is_not_taken_over() {
+        let dir = scratch("live");
+        let path = dir.join("nexus.sock");
+        let _listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+        assert!(remove_stale_socket(&path).is_err());
+        assert!(path.exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn stale_socket_is_replaced() {
+        let dir = scratch("stale");
+        let path = dir.join("nexus.sock");
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+        remove_stale_socket(&path).unwrap();
+        assert!(!path.exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn bound_socket_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = scratch("bind");
+        let path = dir.join("nexus.sock");
+        let _listener = bind_private(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,231 @@
+use std::backtrace::Backtrace;
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::panic::PanicHookInfo;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
+
+use crate::app::App;
+use crate::config::Config;
+use crate::export::rfc3339;
+use crate::network::types::ConnectionStatus;
+
+/// What the UI showed when it was last drawn, for the crash report
+struct Snapshot {
+    view: &'static str,
+    /// AppMode variant, without the data it carries
+    mode: String,
+    networks: usize,
+    saved: usize,
+    device_state: String,
+    /// SSID of the active (or activating) network
+    ssid: Option<String>,
+    connection: &'static str,
+    /// Every SSID in the list, so redaction can find them anywhere in the
+    /// panic message
+    ssids: BTreeSet<String>,
+    /// `App::networks_epoch` that `ssids` was collected at
+    networks_epoch: u64,
+}
+
+static SNAPSHOT: Mutex<Option<Snapshot>> = Mutex::new(None);
+static NM_VERSION: OnceLock<String> = OnceLock::new();
+
+/// Remember the state about to be drawn; a panic report includes the last one
+pub fn record(app: &App) {
+    let mode = format!("{:?}", app.mode);
+    let mode = mode
+        .split(['(', '{', ' '])
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let (connection, ssid) = match &app.connection_status {
+        ConnectionStatus::Connected(info) => ("connected", Some(info.ssid.clone())),
+        ConnectionStatus::Connecting(ssid) => ("connecting", Some(ssid.clone())),
+        ConnectionStatus::Disconnecting => ("disconnecting", None),
+        ConnectionStatus::Disconnected => ("disconnected", None),
+        ConnectionStatus::Failed(_) => ("failed", None),
+    };
+    let Ok(mut last) = SNAPSHOT.lock() else {
+        return;
+    };
+    // This runs before every draw; collect the SSIDs only for a new list
+    let ssids = match last.take() {
+        Some(s) if s.networks_epoch == app.networks_epoch => s.ssids,
+        _ => app
+            .networks
+            .iter()
+            .filter(|n| !n.ssid.is_empty())
+            .map(|n| n.ssid.clone())
+            .collect(),
+    };
+    *last = Some(Snapshot {
+        view: app.current_view().label(),
+        mode,
+        networks: app.networks.len(),
+        saved: app.networks.iter().filter(|n| n.is_saved).count(),
+        device_state: format!("{:?}", app.device_state),
+        ssid,
+        connection,
+        ssids,
+        networks_epoch: app.networks_epoch,
+    });
+}
+
+/// NetworkManager's version, for the crash report
+pub fn set_nm_version(version: String) {
+    let _ = NM_VERSION.set(version);
+}
+
+/// Write ~/.local/state/nexus/crash-<timestamp>.txt for a panic. With
+/// `redact`, SSIDs keep only their first character and length. Returns the
+/// path, or None if the file couldn't be written.
+pub fn write_report(info: &PanicHookInfo, redact: bool) -> Option<PathBuf> {
+    let now = SystemTime::now();
+    let message = info
+        .payload()
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "(no message)".to_string());
+    let location = info
+        .location()
+        .map(|l| format!("{}:{}", l.file(), l.line()))
+        .unwrap_or_default();
+    // A panic inside `record` would leave the lock poisoned; report without it
+    let snapshot = SNAPSHOT.try_lock().ok();
+    let snapshot = snapshot.as_ref().and_then(|s| s.as_ref());
+    let report = render(&message, &location, snapshot, redact, now);
+    let stamp: String = rfc3339(now).chars().filter(char::is_ascii_digit).collect();
+    let path = Config::log_dir().join(format!("crash-{stamp}.txt"));
+    std::fs::write(&path, report).ok()?;
+    Some(path)
+}
+
+/// The text of the crash report for a panic with `message` at `location`
+fn render(
+    message: &str,
+    location: &str,
+    snapshot: Option<&Snapshot>,
+    redact: bool,
+    now: SystemTime,
+) -> String {
+    let mut report = String::new();
+    let _ = writeln!(
+        report,
+        "Nexus {} crashed at {}",
+        env!("CARGO_PKG_VERSION"),
+        rfc3339(now)
+    );
+    let _ = writeln!(
+        report,
+        "NetworkManager: {}",
+        NM_VERSION.get().map_or("unknown", String::as_str)
+    );
+
+    let ssid = snapshot.and_then(|s| s.ssid.as_deref());
+    // Any listed SSID may turn up in the message, e.g. from a failed
+    // truncation of a list entry. Longest first, so one SSID that contains
+    // another is still replaced whole.
+    let mut ssids: Vec<&str> = match snapshot {
+        Some(s) if redact => s
+            .ssids
+            .iter()
+            .map(String::as_str)
+            .chain(ssid)
+            .filter(|s| !s.is_empty())
+            .collect(),
+        _ => Vec::new(),
+    };
+    ssids.sort_by_key(|s| std::cmp::Reverse(s.len()));
+    let shown = |text: &str| {
+        ssids.iter().fold(text.to_string(), |text, ssid| {
+            text.replace(ssid, &redacted(ssid))
+        })
+    };
+
+    let _ = writeln!(report, "\nPanic at {location}:\n{}", shown(message));
+    let _ = writeln!(report, "\nState when last drawn:");
+    match snapshot {
+        Some(s) => {
+            let _ = writeln!(report, "  view:         {}", s.view);
+            let _ = writeln!(report, "  mode:         {}", s.mode);
+            let _ = writeln!(report, "  networks:     {} ({} saved)", s.networks, s.saved);
+            let _ = writeln!(report, "  device state: {}", s.device_state);
+            let _ = writeln!(report, "  connection:   {}", s.connection);
+            if let Some(ssid) = ssid {
+                let _ = writeln!(report, "  ssid:         {}", shown(ssid));
+            }
+        }
+        None => {
+            let _ = writeln!(report, "  (none recorded)");
+        }
+    }
+    let _ = writeln!(report, "\nBacktrace:\n{}", Backtrace::force_capture());
+    report
+}
+
+/// First character and a star per further one: "HomeWiFi" → "H*******"
+fn redacted(ssid: &str) -> String {
+    let mut chars = ssid.chars();
+    let first = chars.next().map(String::from).unwrap_or_default();
+    first + &"*".repeat(chars.count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A panic whose message quotes a list entry's SSID
+    fn report_for(redact: bool) -> String {
+        let snapshot = Snapshot {
+            view: "Networks",
+            mode: "Normal".to_string(),
+            networks: 2,
+            saved: 1,
+            device_state: "Activated".to_string(),
+            ssid: Some("HomeWiFi".to_string()),
+            connection: "connected",
+            ssids: ["Café Ünïcødé", "HomeWiFi", "HomeWiFi-5G"]
+                .map(String::from)
+                .into(),
+            networks_epoch: 1,
+        };
+        let message = "byte index 4 is not a char boundary; it is inside 'é' \
+                       of `Café Ünïcødé`, next to HomeWiFi-5G";
+        render(
+            message,
+            "src/ui/network_list.rs:42",
+            Some(&snapshot),
+            redact,
+            SystemTime::now(),
+        )
+    }
+
+    #[test]
+    fn panic_report() {
+        let report = report_for(true);
+        assert!(report.contains("crashed at"), "{report}");
+        assert!(report.contains("not a char boundary"), "{report}");
+        assert!(report.contains("connection:   connected"), "{report}");
+        // Both the active SSID and the list entries in the message are
+        // redacted, the longer one whole
+        assert!(report.contains("ssid:         H*******"), "{report}");
+        assert!(report.contains("C***********"), "{report}");
+        assert!(report.contains("H**********"), "{report}");
+        assert!(!report.contains("HomeWiFi"), "{report}");
+        assert!(!report.contains("Café"), "{report}");
+
+        let report = report_for(false);
+        assert!(report.contains("Café Ünïcødé"), "{report}");
+        assert!(report.contains("ssid:         HomeWiFi"), "{report}");
+    }
+
+    #[test]
+    fn redacted_keeps_first_char_and_length() {
+        assert_eq!(redacted("HomeWiFi"), "H*******");
+        assert_eq!(redacted("Ünï"), "Ü**");
+        assert_eq!(redacted(""), "");
+    }
+}
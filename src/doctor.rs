@@ -0,0 +1,45 @@
+use eyre::Result;
+use tokio::sync::mpsc;
+
+use crate::config::{CliArgs, Config};
+use crate::network::diagnostics::{self, DiagnosticReport};
+use crate::network::manager::NmBackend;
+use crate::network::mock::MockBackend;
+
+/// `nexus doctor`: run the health check once and print a PASS/FAIL
+/// checklist with timings. Returns whether every step passed.
+pub async fn run(cli: &CliArgs, config: &Config) -> Result<bool> {
+    let report = if cli.use_mock_backend() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        diagnostics::diagnose(&MockBackend::new(tx)).await
+    } else {
+        diagnostics::diagnose(&NmBackend::new(config.interface()).await?).await
+    };
+
+    print!("{}", render(&report));
+    Ok(report.first_failure().is_none())
+}
+
+fn render(report: &DiagnosticReport) -> String {
+    let mut out = String::new();
+    for step in &report.steps {
+        let mark = if step.passed { "PASS" } else { "FAIL" };
+        out.push_str(&format!(
+            "{mark}  {:<16} {:>7}  {}\n",
+            step.layer.label(),
+            step.timing(),
+            step.detail
+        ));
+        if let Some(ref ping) = step.ping {
+            out.push_str(&format!("{:<33}{}\n", "", ping.describe()));
+        }
+    }
+    out.push('\n');
+    out.push_str(&report.summary());
+    out.push('\n');
+    if let Some(step) = report.first_failure() {
+        out.push_str(step.layer.advice());
+        out.push('\n');
+    }
+    out
+}
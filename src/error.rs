@@ -0,0 +1,191 @@
+// ─── Friendly Error Messages ────────────────────────────────────────────
+// Raw zbus/NetworkManager errors ("org.freedesktop.NetworkManager.Settings
+// .Connection.InvalidProperty: 802-11-wireless-security.psk: ...") are
+// precise but user-hostile. These patterns map the common ones onto short,
+// actionable messages. The raw text is still logged and shown as details in
+// the error dialog.
+
+/// Substring → message, checked in order (most specific first)
+const KNOWN_ERRORS: &[(&str, &str)] = &[
+    (
+        "802-11-wireless-security.psk",
+        "Invalid password — WPA passwords are 8–63 characters",
+    ),
+    ("Secrets were required", "Wrong or missing password"),
+    (
+        "supplicant disconnected",
+        "Authentication failed — check the password",
+    ),
+    (
+        "supplicant timed out",
+        "Authentication timed out — the access point did not answer",
+    ),
+    ("NoSecrets", "Wrong or missing password"),
+    ("AlreadyActive", "That connection is already active"),
+    ("NotActive", "That connection is not active"),
+    (
+        "SettingNotFound",
+        "The saved profile is missing a required setting",
+    ),
+    ("UnknownConnection", "That saved profile no longer exists"),
+    ("InvalidProperty", "The profile contains an invalid setting"),
+    (
+        "AccessDenied",
+        "Permission denied — a polkit agent may be required",
+    ),
+    (
+        "NotAuthorized",
+        "Permission denied — a polkit agent may be required",
+    ),
+    (
+        "PermissionDenied",
+        "Permission denied — a polkit agent may be required",
+    ),
+    (
+        "IP configuration could not be reserved",
+        "Couldn't get an IP address — DHCP timed out",
+    ),
+    (
+        "DHCP lease expired",
+        "The DHCP lease expired — the network may be flaky",
+    ),
+    ("ServiceUnknown", "NetworkManager is not running"),
+    ("NoReply", "NetworkManager did not respond in time"),
+    ("Timeout", "NetworkManager did not respond in time"),
+    ("timed out", "NetworkManager did not respond in time"),
+];
+
+/// A short, actionable message for a well-known failure, if recognised
+pub fn friendly(raw: &str) -> Option<&'static str> {
+    KNOWN_ERRORS
+        .iter()
+        .find(|(pattern, _)| raw.contains(pattern))
+        .map(|(_, message)| *message)
+}
+
+/// The friendly message when there is one, otherwise the raw text
+pub fn summarize(raw: &str) -> String {
+    friendly(raw).map_or_else(|| raw.to_string(), str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::types::ActivationFailed;
+
+    /// Error text as zbus and `ActivationFailed` render it
+    const CAPTURED: &[(&str, &str)] = &[
+        (
+            "org.freedesktop.NetworkManager.Settings.Connection.InvalidProperty: \
+             802-11-wireless-security.psk: property is invalid",
+            "Invalid password — WPA passwords are 8–63 characters",
+        ),
+        (
+            "org.freedesktop.NetworkManager.ConnectionAlreadyActive: \
+             This connection is already active.",
+            "That connection is already active",
+        ),
+        (
+            "org.freedesktop.NetworkManager.Device.NotActive: This device is not active",
+            "That connection is not active",
+        ),
+        (
+            "org.freedesktop.NetworkManager.Settings.Connection.SettingNotFound: \
+             setting '802-11-wireless-security' not found",
+            "The saved profile is missing a required setting",
+        ),
+        (
+            "org.freedesktop.NetworkManager.Settings.InvalidConnection: \
+             org.freedesktop.NetworkManager.Settings.UnknownConnection: \
+             No connection with the UUID was found",
+            "That saved profile no longer exists",
+        ),
+        (
+            "org.freedesktop.DBus.Error.AccessDenied: Rejected send message, \
+             2 matched rules; type=\"method_call\"",
+            "Permission denied — a polkit agent may be required",
+        ),
+        (
+            "org.freedesktop.NetworkManager.PermissionDenied: \
+             Not authorized to control networking.",
+            "Permission denied — a polkit agent may be required",
+        ),
+        (
+            "org.freedesktop.DBus.Error.ServiceUnknown: The name \
+             org.freedesktop.NetworkManager was not provided by any .service files",
+            "NetworkManager is not running",
+        ),
+        (
+            "org.freedesktop.DBus.Error.NoReply: Did not receive a reply. \
+             Possible causes include: the remote application did not send a reply",
+            "NetworkManager did not respond in time",
+        ),
+        (
+            "Activation timed out",
+            "NetworkManager did not respond in time",
+        ),
+    ];
+
+    #[test]
+    fn captured_errors_map_to_messages() {
+        for (raw, message) in CAPTURED {
+            assert_eq!(friendly(raw), Some(*message), "{raw}");
+        }
+    }
+
+    #[test]
+    fn activation_failures_map_to_messages() {
+        let cases = [
+            (
+                ActivationFailed::IP_CONFIG_UNAVAILABLE,
+                "Couldn't get an IP address — DHCP timed out",
+            ),
+            (
+                ActivationFailed::IP_CONFIG_EXPIRED,
+                "The DHCP lease expired — the network may be flaky",
+            ),
+            (ActivationFailed::NO_SECRETS, "Wrong or missing password"),
+            (
+                ActivationFailed::SUPPLICANT_DISCONNECT,
+                "Authentication failed — check the password",
+            ),
+            (
+                ActivationFailed::SUPPLICANT_TIMEOUT,
+                "Authentication timed out — the access point did not answer",
+            ),
+        ];
+        for (reason, message) in cases {
+            let raw = format!("Activation failed: {}", ActivationFailed { reason });
+            assert_eq!(friendly(&raw), Some(message), "{raw}");
+        }
+    }
+
+    #[test]
+    fn more_specific_patterns_win() {
+        // "timed out" also matches the generic D-Bus timeout
+        let raw = ActivationFailed {
+            reason: ActivationFailed::SUPPLICANT_TIMEOUT,
+        }
+        .to_string();
+        assert_ne!(
+            friendly(&raw),
+            Some("NetworkManager did not respond in time")
+        );
+        // "AlreadyActive" must not be read as "NotActive" or vice versa
+        assert_eq!(
+            friendly("ConnectionAlreadyActive"),
+            Some("That connection is already active")
+        );
+    }
+
+    #[test]
+    fn unknown_errors_pass_through() {
+        let raw = "org.freedesktop.NetworkManager.Device.Busy: Device is busy";
+        assert_eq!(friendly(raw), None);
+        assert_eq!(summarize(raw), raw);
+        assert_eq!(
+            summarize("org.freedesktop.DBus.Error.NoReply: no reply"),
+            "NetworkManager did not respond in time"
+        );
+    }
+}
@@ -1,20 +1,28 @@
 use crossterm::event::{Event as CrosstermEvent, EventStream, KeyEvent, KeyEventKind};
 use futures::StreamExt;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 
-use crate::network::types::{ConnectionStatus, WiFiNetwork};
+use crate::config::ExportFormat;
+use crate::network::diagnostics::{DiagnosticReport, HeaderLine, MtuProbeLine, PingSample};
+use crate::network::types::{
+    ConnectionStatus, DeviceState, ForgetOutcome, ProfileChange, SavedProfile, WiFiNetwork,
+    WifiDevice,
+};
 
 /// Commands dispatched from the UI to the network backend.
 /// Replaces the old stringly-typed `Event::Error("CONNECT:...")` hack.
 #[derive(Debug, Clone)]
 pub enum NetworkCommand {
-    /// Connect to a known/open network
+    /// Connect to a known/open network, on the access point at `ap_path`
+    /// if given
     Connect {
         ssid: String,
         password: Option<String>,
+        ap_path: Option<String>,
     },
     /// Connect to a hidden network
     ConnectHidden {
@@ -25,10 +33,75 @@ pub enum NetworkCommand {
     Disconnect,
     /// Forget a saved network profile
     Forget { ssid: String },
+    /// Forget several profiles one after another, reporting progress
+    ForgetMany { ssids: Vec<String> },
+    /// Undo a recent forget
+    Restore { ssid: String },
+    /// List saved profiles with when they last connected
+    SavedProfiles,
+    /// List the WiFi adapters NetworkManager knows
+    WifiDevices,
+    /// Make `interface` the adapter every other command goes to
+    SwitchDevice { interface: String },
     /// Trigger a WiFi scan
     Scan,
+    /// Scan, wait for it to finish and replace the list, dropping NM's
+    /// cached access points
+    FreshScan,
     /// Refresh connection info
     RefreshConnection,
+    /// Change the MTU of the active connection (0 = automatic)
+    SetMtu { mtu: u32 },
+    /// Let NetworkManager take over an unmanaged WiFi device
+    SetManaged,
+    /// Turn the WiFi radio back on
+    EnableWireless,
+    /// Turn NetworkManager's connectivity check on or off
+    SetConnectivityCheck { enabled: bool },
+    /// Scan and report every access point, for the site survey
+    SurveyScan,
+    /// Write every access point of the last scan to a file
+    Export { format: ExportFormat },
+    /// Edit settings of the active connection's saved profile in one update
+    UpdateProfile(Vec<ProfileChange>),
+    /// Run the step-by-step internet connectivity check; `run` tags the
+    /// report so a stale run can be told apart
+    Diagnose { run: u64 },
+    /// Ping `target` once for the connectivity watchdog
+    WatchdogProbe { target: String },
+    /// Print the response headers of `url` and its redirects; `run` tags
+    /// the streamed lines so a stale run can be told apart
+    HttpHeaders { url: String, run: u64 },
+    /// Ping every host concurrently for the ping comparison; samples come
+    /// back tagged with the host's index and `run`
+    PingCompare { hosts: Vec<String>, run: u64 },
+    /// Search the path MTU towards `host`; `iface_mtu` is the interface's
+    /// own (0 = unknown) and `run` tags the streamed lines
+    MtuProbe {
+        host: String,
+        iface_mtu: u32,
+        run: u64,
+    },
+}
+
+impl NetworkCommand {
+    /// Commands the user started, as opposed to periodic refreshes. Any of
+    /// them cancels a pending connect retry.
+    pub fn is_user_action(&self) -> bool {
+        !matches!(
+            self,
+            Self::Scan | Self::RefreshConnection | Self::SurveyScan | Self::WatchdogProbe { .. }
+        )
+    }
+
+    /// Commands that talk to NetworkManager, as opposed to the diagnostics
+    /// tools that only ping and fetch
+    pub fn needs_bus(&self) -> bool {
+        !matches!(
+            self,
+            Self::HttpHeaders { .. } | Self::PingCompare { .. } | Self::MtuProbe { .. }
+        )
+    }
 }
 
 /// Application-level events
@@ -40,14 +113,82 @@ pub enum Event {
     Tick,
     /// Terminal resize
     Resize(u16, u16),
+    /// The terminal window gained focus
+    FocusGained,
+    /// The terminal window lost focus
+    FocusLost,
     /// WiFi scan results arrived
     NetworkScan(Vec<WiFiNetwork>),
+    /// Results of a fresh scan, to replace the list rather than merge
+    FreshScan(Vec<WiFiNetwork>),
+    /// A fresh scan failed with this error text
+    FreshScanFailed(String),
     /// Connection status change
     ConnectionChanged(ConnectionStatus),
+    /// WiFi device moved to a new NM device state (activation progress)
+    DeviceStateChanged(DeviceState),
+    /// A connect failed transiently; attempt `attempt` of `total` follows
+    ConnectRetry {
+        attempt: u32,
+        total: u32,
+        reason: String,
+    },
+    /// Activation needs a password: none is stored, or (`rejected`) the
+    /// one given or stored was refused; ask for it
+    SecretsRequired { ssid: String, rejected: bool },
+    /// One line for the connection events log ("wlan0: Config→NeedAuth")
+    NmLog(String),
+    /// A saved profile was forgotten (and can be restored for a while)
+    NetworkForgotten(ForgetOutcome),
+    /// Forgetting a profile failed with this error text
+    ForgetFailed(String),
+    /// Saved profiles and their last connection times
+    SavedProfiles(Vec<SavedProfile>),
+    /// Every WiFi adapter, for the adapter picker
+    WifiDevices(Vec<WifiDevice>),
+    /// The backend now uses the adapter with this interface name
+    DeviceSwitched(String),
+    /// A bulk forget finished `done` of `total` profiles
+    BulkProgress { done: usize, total: usize },
+    /// A bulk forget finished; failures carry the error text
+    BulkFinished {
+        forgotten: Vec<String>,
+        failed: Vec<(String, String)>,
+    },
+    /// One site survey scan, one entry per BSSID
+    SurveySample(Vec<WiFiNetwork>),
+    /// A scan export was written
+    Exported { count: usize, path: PathBuf },
+    /// A watchdog probe finished; `nm_full` is whether NM claimed Full
+    /// connectivity at the time
+    WatchdogResult {
+        target: String,
+        reachable: bool,
+        nm_full: bool,
+    },
+    /// The machine woke from suspend; the signal listeners were restarted
+    /// and everything shown may be stale
+    Resumed,
+    /// The system bus connection dropped; reconnecting in the background
+    BusLost,
+    /// The system bus is back and the signal listeners were restarted
+    BusRestored,
+    /// Internet diagnostic run `run` completed
+    Diagnostics { run: u64, report: DiagnosticReport },
+    /// One line of header inspection `run`
+    HttpHeaderLine { run: u64, line: HeaderLine },
+    /// One observation for host `host` (an index) of ping comparison `run`
+    PingSample {
+        run: u64,
+        host: usize,
+        sample: PingSample,
+    },
+    /// One line of path MTU probe `run`
+    MtuProbeLine { run: u64, line: MtuProbeLine },
     /// A network command dispatched by the UI (processed by main loop)
     Command(NetworkCommand),
-    /// An error from an async operation
-    Error(String),
+    /// A background action failed; `action` names what was attempted
+    ActionError { action: &'static str, error: String },
 }
 
 /// Handles event collection from multiple sources.
@@ -58,6 +199,8 @@ pub enum Event {
 pub struct EventHandler {
     rx: mpsc::UnboundedReceiver<Event>,
     _tx: mpsc::UnboundedSender<Event>,
+    /// Current tick interval; the tick task restarts its timer on change
+    tick_rate: watch::Sender<Duration>,
     stop: Arc<AtomicBool>,
 }
 
@@ -82,17 +225,26 @@ impl EventHandler {
                     return;
                 }
                 match maybe_event {
-                    Some(Ok(CrosstermEvent::Key(key))) => {
+                    Some(Ok(CrosstermEvent::Key(key)))
                         if key.kind == KeyEventKind::Press
-                            && input_tx.send(Event::Key(key)).is_err()
-                        {
-                            return;
-                        }
+                            && input_tx.send(Event::Key(key)).is_err() =>
+                    {
+                        return;
                     }
-                    Some(Ok(CrosstermEvent::Resize(w, h))) => {
-                        if input_tx.send(Event::Resize(w, h)).is_err() {
-                            return;
-                        }
+                    Some(Ok(CrosstermEvent::Resize(w, h)))
+                        if input_tx.send(Event::Resize(w, h)).is_err() =>
+                    {
+                        return;
+                    }
+                    Some(Ok(CrosstermEvent::FocusGained))
+                        if input_tx.send(Event::FocusGained).is_err() =>
+                    {
+                        return;
+                    }
+                    Some(Ok(CrosstermEvent::FocusLost))
+                        if input_tx.send(Event::FocusLost).is_err() =>
+                    {
+                        return;
                     }
                     Some(Err(_)) | None => {
                         // Stream ended or errored — exit gracefully
@@ -104,12 +256,19 @@ impl EventHandler {
         });
 
         // Tick task
+        let (tick_rate, mut rate_rx) = watch::channel(Duration::from_millis(tick_rate_ms));
         let tick_tx = tx.clone();
         let tick_stop = stop.clone();
         tokio::spawn(async move {
-            let mut interval = tokio::time::interval(Duration::from_millis(tick_rate_ms));
+            let mut interval = tokio::time::interval(*rate_rx.borrow_and_update());
             loop {
-                interval.tick().await;
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    Ok(()) = rate_rx.changed() => {
+                        interval = tokio::time::interval(*rate_rx.borrow_and_update());
+                        continue;
+                    }
+                }
                 if tick_stop.load(Ordering::Relaxed) {
                     return;
                 }
@@ -119,7 +278,21 @@ impl EventHandler {
             }
         });
 
-        Self { rx, _tx: tx, stop }
+        Self {
+            rx,
+            _tx: tx,
+            tick_rate,
+            stop,
+        }
+    }
+
+    /// Change the tick interval; a no-op when it's already `every`
+    pub fn set_tick_rate(&self, every: Duration) {
+        self.tick_rate.send_if_modified(|current| {
+            let changed = *current != every;
+            *current = every;
+            changed
+        });
     }
 
     /// Get a clone of the sender for forwarding network events
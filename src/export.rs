@@ -0,0 +1,226 @@
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use eyre::{Result, WrapErr};
+use serde::Serialize;
+use tokio::sync::mpsc;
+
+use crate::config::{CliArgs, Config, ExportFormat};
+use crate::network::NetworkBackend;
+use crate::network::manager::NmBackend;
+use crate::network::mock::MockBackend;
+use crate::network::types::WiFiNetwork;
+
+/// One exported access point (a row of the CSV, an object in the JSON)
+#[derive(Debug, Serialize)]
+struct ScanRecord<'a> {
+    ssid: &'a str,
+    bssid: &'a str,
+    frequency: u32,
+    channel: u32,
+    band: String,
+    strength: u8,
+    security: String,
+    saved: bool,
+    active: bool,
+    timestamp: &'a str,
+}
+
+const CSV_HEADER: &str =
+    "ssid,bssid,frequency,channel,band,strength,security,saved,active,timestamp";
+
+/// `nexus scan`: trigger a scan and print every access point to stdout
+pub async fn run(cli: &CliArgs, config: &Config, json: bool) -> Result<()> {
+    let aps = if cli.use_mock_backend() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        fresh_access_points(&MockBackend::new(tx)).await?
+    } else {
+        fresh_access_points(&NmBackend::new(config.interface()).await?).await?
+    };
+    let format = if json {
+        ExportFormat::Json
+    } else {
+        ExportFormat::Csv
+    };
+    print!("{}", render(&aps, format, &rfc3339(SystemTime::now()))?);
+    Ok(())
+}
+
+async fn fresh_access_points<B: NetworkBackend>(backend: &B) -> Result<Vec<WiFiNetwork>> {
+    backend.scan().await?;
+    backend.access_points().await
+}
+
+/// Write access points to ~/.local/share/nexus/scans/scan-<timestamp>.<ext>
+pub fn write(aps: &[WiFiNetwork], format: ExportFormat) -> Result<PathBuf> {
+    let now = SystemTime::now();
+    let dir = dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("nexus")
+        .join("scans");
+    std::fs::create_dir_all(&dir)
+        .wrap_err_with(|| format!("Failed to create {}", dir.display()))?;
+
+    let stamp: String = rfc3339(now)
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .collect();
+    let path = dir.join(format!("scan-{stamp}.{}", format.extension()));
+    std::fs::write(&path, render(aps, format, &rfc3339(now))?)
+        .wrap_err_with(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+fn render(aps: &[WiFiNetwork], format: ExportFormat, timestamp: &str) -> Result<String> {
+    let records: Vec<ScanRecord> = aps
+        .iter()
+        .map(|ap| ScanRecord {
+            ssid: &ap.ssid,
+            bssid: &ap.bssid,
+            frequency: ap.frequency,
+            channel: ap.channel(),
+            band: ap.band().to_string(),
+            strength: ap.signal_strength,
+            security: ap.security.to_string(),
+            saved: ap.is_saved,
+            active: ap.is_active,
+            timestamp,
+        })
+        .collect();
+
+    match format {
+        ExportFormat::Json => {
+            let mut out = serde_json::to_string_pretty(&records)?;
+            out.push('\n');
+            Ok(out)
+        }
+        ExportFormat::Csv => {
+            let mut out = String::from(CSV_HEADER);
+            out.push('\n');
+            for r in &records {
+                let _ = writeln!(
+                    out,
+                    "{},{},{},{},{},{},{},{},{},{}",
+                    csv_field(r.ssid),
+                    csv_field(r.bssid),
+                    r.frequency,
+                    r.channel,
+                    csv_field(&r.band),
+                    r.strength,
+                    csv_field(&r.security),
+                    r.saved,
+                    r.active,
+                    r.timestamp
+                );
+            }
+            Ok(out)
+        }
+    }
+}
+
+/// RFC 4180 quoting: wrap in quotes when the field contains a comma, quote
+/// or line break, doubling embedded quotes. SSIDs may contain any of these.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) || value.starts_with(' ') || value.ends_with(' ') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// UTC timestamp like `2024-05-01T13:37:00Z`, without pulling in a date crate
+pub fn rfc3339(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+    let (year, month, day) = civil_from_days(days as i64);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+/// Days since 1970-01-01 to (year, month, day), per Howard Hinnant's
+/// `civil_from_days`
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn ap(ssid: &str) -> WiFiNetwork {
+        WiFiNetwork {
+            bssid: "AA:BB:CC:DD:EE:FF".to_string(),
+            max_bitrate: 144,
+            is_saved: true,
+            ..WiFiNetwork::test(ssid, 70)
+        }
+    }
+
+    #[test]
+    fn plain_fields_are_not_quoted() {
+        assert_eq!(csv_field("HomeWiFi"), "HomeWiFi");
+        assert_eq!(csv_field("Café ☕"), "Café ☕");
+        assert_eq!(csv_field(""), "");
+    }
+
+    #[test]
+    fn special_fields_are_quoted() {
+        assert_eq!(csv_field("Bob, Alice"), "\"Bob, Alice\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+        assert_eq!(csv_field("cr\r"), "\"cr\r\"");
+        // Leading and trailing spaces survive spreadsheet imports
+        assert_eq!(csv_field(" padded "), "\" padded \"");
+    }
+
+    #[test]
+    fn csv_rows_keep_their_column_count() {
+        let aps = [ap("Plain"), ap("a,b \"c\"\nd")];
+        let out = render(&aps, ExportFormat::Csv, "2024-05-01T13:37:00Z").unwrap();
+        let mut lines = out.lines();
+        assert_eq!(lines.next(), Some(CSV_HEADER));
+        assert_eq!(
+            lines.next(),
+            Some("Plain,AA:BB:CC:DD:EE:FF,2437,6,2.4 GHz,70,WPA2,true,false,2024-05-01T13:37:00Z")
+        );
+        // The quoted SSID spans two physical lines
+        assert_eq!(lines.next(), Some("\"a,b \"\"c\"\""));
+        assert_eq!(
+            lines.next(),
+            Some("d\",AA:BB:CC:DD:EE:FF,2437,6,2.4 GHz,70,WPA2,true,false,2024-05-01T13:37:00Z")
+        );
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn json_keeps_ssid_verbatim() {
+        let out = render(&[ap("a,b \"c\"")], ExportFormat::Json, "t").unwrap();
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value[0]["ssid"], "a,b \"c\"");
+        assert_eq!(value[0]["channel"], 6);
+    }
+
+    #[test]
+    fn rfc3339_dates() {
+        let at = |secs| rfc3339(UNIX_EPOCH + Duration::from_secs(secs));
+        assert_eq!(at(0), "1970-01-01T00:00:00Z");
+        assert_eq!(at(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(at(1_714_570_620), "2024-05-01T13:37:00Z");
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::config::Config;
+
+/// SSIDs pinned to the top of the network list, persisted as TOML in
+/// ~/.config/nexus/favorites.toml
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Favorites {
+    ssids: BTreeSet<String>,
+}
+
+impl Favorites {
+    fn path() -> PathBuf {
+        Config::config_path().with_file_name("favorites.toml")
+    }
+
+    /// Load the saved favorites. A missing file means none, and a corrupt
+    /// one is logged and ignored.
+    pub fn load() -> Self {
+        let path = Self::path();
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        toml::from_str(&text).unwrap_or_else(|e| {
+            warn!("Ignoring unreadable {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    pub fn contains(&self, ssid: &str) -> bool {
+        self.ssids.contains(ssid)
+    }
+
+    /// Pin or unpin `ssid` and write the file. Returns whether it is now
+    /// pinned. If the write fails the in-memory set is left unchanged, so
+    /// the list never shows a pin that wasn't saved.
+    pub fn toggle(&mut self, ssid: &str) -> Result<bool> {
+        let mut next = self.clone();
+        let pinned = if next.ssids.remove(ssid) {
+            false
+        } else {
+            next.ssids.insert(ssid.to_string())
+        };
+        next.save()?;
+        *self = next;
+        Ok(pinned)
+    }
+
+    fn save(&self) -> Result<()> {
+        let text = toml::to_string(self).wrap_err("Failed to encode favorites")?;
+        let path = Self::path();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .wrap_err_with(|| format!("Failed to create {}", dir.display()))?;
+        }
+        // Write beside the file and rename over it, so a crash mid-write
+        // can't leave a truncated list behind
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, text)
+            .and_then(|()| std::fs::rename(&tmp, &path))
+            .wrap_err_with(|| format!("Failed to write {}", path.display()))?;
+        Ok(())
+    }
+}
+
+impl FromIterator<String> for Favorites {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        Self {
+            ssids: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toml_round_trip() {
+        let favorites: Favorites = ["Home".to_string(), "Café, \"2G\"".to_string()]
+            .into_iter()
+            .collect();
+        let text = toml::to_string(&favorites).unwrap();
+        let parsed: Favorites = toml::from_str(&text).unwrap();
+        assert!(parsed.contains("Home"));
+        assert!(parsed.contains("Café, \"2G\""));
+        assert!(!parsed.contains("Other"));
+    }
+
+    #[test]
+    fn missing_key_means_no_favorites() {
+        let parsed: Favorites = toml::from_str("").unwrap();
+        assert!(!parsed.contains(""));
+        assert!(toml::from_str::<Favorites>("ssids = 3").is_err());
+    }
+}
@@ -0,0 +1,1188 @@
+// ─── Message Catalog ────────────────────────────────────────────────────
+// User-visible text is written in English at the call site and looked up
+// here by that English text, so an untranslated string simply shows in
+// English. Placeholders are `{name}`, filled by `Language::fill`.
+
+use std::fmt::Display;
+
+use serde::Deserialize;
+
+/// UI language (`[general] language`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    En,
+    De,
+}
+
+/// German: English source text → translation
+const DE: &[(&str, &str)] = &[
+    // Status bar hints
+    ("Add", "Hinzufügen"),
+    ("Apply", "Anwenden"),
+    ("Back to list", "Zur Liste"),
+    ("Cancel", "Abbrechen"),
+    ("Clear", "Leeren"),
+    ("Clear / all", "Leeren / alle"),
+    ("Clear/Cancel", "Leeren/Abbrechen"),
+    ("Close", "Schließen"),
+    ("Confirm", "Bestätigen"),
+    ("Connect", "Verbinden"),
+    ("Copy", "Kopieren"),
+    ("Copy command", "Befehl kopieren"),
+    ("Copy connect command", "Verbindungsbefehl kopieren"),
+    ("Copy keyfile path", "Keyfile-Pfad kopieren"),
+    ("Copy share command", "Freigabebefehl kopieren"),
+    ("Copy value", "Wert kopieren"),
+    ("Delete", "Löschen"),
+    ("Disconnect", "Trennen"),
+    ("Edit", "Bearbeiten"),
+    ("Filter", "Filtern"),
+    ("Go", "Los"),
+    ("HTTP headers", "HTTP-Header"),
+    ("Help", "Hilfe"),
+    ("Interface", "Schnittstelle"),
+    ("Navigate", "Navigieren"),
+    ("New URL", "Neue URL"),
+    ("New host", "Neuer Host"),
+    ("New hosts", "Neue Hosts"),
+    ("Nickname / note", "Spitzname / Notiz"),
+    ("Other tools", "Andere Werkzeuge"),
+    ("Path MTU", "Pfad-MTU"),
+    ("Ping compare", "Ping-Vergleich"),
+    ("Quit", "Beenden"),
+    ("Reset stats", "Statistik zurücksetzen"),
+    ("Revert", "Verwerfen"),
+    ("Run", "Ausführen"),
+    ("Run again", "Erneut ausführen"),
+    ("Scan", "Suchen"),
+    ("Scroll", "Blättern"),
+    ("Search", "Suche"),
+    ("Select", "Auswählen"),
+    ("Sort", "Sortieren"),
+    ("Stop survey", "Messung beenden"),
+    ("Submit", "Senden"),
+    ("Switch field", "Feld wechseln"),
+    ("Time range", "Zeitraum"),
+    ("Toggle visibility", "Sichtbarkeit umschalten"),
+    ("Uplink down", "Uplink ausgefallen"),
+    ("Use adapter", "Adapter verwenden"),
+    ("Connecting…", "Verbinde…"),
+    ("Please wait…", "Bitte warten…"),
+    // Network list
+    ("WiFi Networks", "WLAN-Netze"),
+    ("Scanning…", "Suche läuft…"),
+    ("{count} marked", "{count} markiert"),
+    ("{count} hidden", "{count} ausgeblendet"),
+    ("{count} weak", "{count} schwach"),
+    ("Scanning for networks…", "Suche nach Netzen…"),
+    ("No matching networks", "Keine passenden Netze"),
+    (
+        "No networks found. Press [s] to scan.",
+        "Keine Netze gefunden. [s] startet die Suche.",
+    ),
+    ("auto-scan {seconds}s", "Auto-Suche {seconds}s"),
+    ("connecting…", "verbinde…"),
+    ("ACTIVE", "AKTIV"),
+    ("stale", "veraltet"),
+    ("↓Signal", "↓Signal"),
+    ("↓A-Z", "↓A-Z"),
+    ("↓Security", "↓Sicherheit"),
+    ("↓Band", "↓Band"),
+    // Detail panel
+    ("Details", "Details"),
+    ("No network selected", "Kein Netz ausgewählt"),
+    ("AP Path", "AP-Pfad"),
+    ("Last Seen", "Zuletzt"),
+    ("just now", "gerade eben"),
+    ("{seconds}s ago", "vor {seconds}s"),
+    ("Signal", "Signal"),
+    ("Frequency", "Frequenz"),
+    ("Channel", "Kanal"),
+    ("Standard", "Standard"),
+    ("unknown", "unbekannt"),
+    ("{generation} (inferred)", "{generation} (geschätzt)"),
+    ("Max Rate", "Max. Rate"),
+    ("Security", "Sicherheit"),
+    ("Suite", "Suite"),
+    ("Saved", "Gespeichert"),
+    ("Status", "Status"),
+    ("Connected", "Verbunden"),
+    ("Not connected", "Nicht verbunden"),
+    ("Connection Info", "Verbindung"),
+    ("Health", "Zustand"),
+    ("Stack", "Stack"),
+    ("IPv6 via {source}", "IPv6 über {source}"),
+    ("Gateway", "Gateway"),
+    ("Speed", "Tempo"),
+    ("DHCP Host", "DHCP-Host"),
+    ("Send Host", "Host senden"),
+    (
+        "{metric} (profile: {profile})",
+        "{metric} (Profil: {profile})",
+    ),
+    ("Band Pref", "Bandwahl"),
+    ("Locked AP", "Fester AP"),
+    ("Fast Band", "Schnelles Band"),
+    ("PAC URL", "PAC-URL"),
+    ("Zone", "Zone"),
+    ("Internet", "Internet"),
+    ("NM Check", "NM-Prüfung"),
+    ("(no URI configured)", "(keine URI konfiguriert)"),
+    ("Server", "Server"),
+    ("Domain", "Domäne"),
+    ("{left} left of {total}", "noch {left} von {total}"),
+    ("{left} left", "noch {left}"),
+    ("lease held", "Lease gehalten"),
+    ("Static", "Statisch"),
+    ("Link-local", "Link-lokal"),
+    ("Shared", "Geteilt"),
+    ("Disabled", "Deaktiviert"),
+    ("Auto", "Auto"),
+    ("SLAAC (auto)", "SLAAC (auto)"),
+    ("static", "statisch"),
+    ("link-local only", "nur link-lokal"),
+    ("disabled", "deaktiviert"),
+    ("Checking disabled", "Prüfung aus"),
+    ("Unknown", "Unbekannt"),
+    ("None", "Keine"),
+    ("Captive portal", "Captive Portal"),
+    ("Limited", "Eingeschränkt"),
+    ("Full", "Voll"),
+    ("good", "gut"),
+    ("no internet", "kein Internet"),
+    ("limited connectivity", "eingeschränkte Verbindung"),
+    ("captive portal", "Captive Portal"),
+    ("uplink unreachable", "Uplink nicht erreichbar"),
+    ("no gateway", "kein Gateway"),
+    ("signal very weak", "Signal sehr schwach"),
+    ("signal weak", "Signal schwach"),
+    ("signal fair", "Signal mäßig"),
+    ("high drops", "viele Paketverluste"),
+    ("some drops", "einige Paketverluste"),
+    // Header
+    ("Managed by another tool", "Von anderem Werkzeug verwaltet"),
+    ("Deactivating", "Wird getrennt"),
+    (
+        "System bus lost — reconnecting, actions disabled",
+        "Systembus getrennt — verbinde neu, Aktionen gesperrt",
+    ),
+    ("updated {seconds}s ago", "aktualisiert vor {seconds}s"),
+    ("updated {minutes}m ago", "aktualisiert vor {minutes}m"),
+    ("no IP", "keine IP"),
+    ("proxy: {proxy}", "Proxy: {proxy}"),
+    ("Connecting to", "Verbinde mit"),
+    ("Disconnecting…", "Trenne…"),
+    ("Disconnected", "Getrennt"),
+    ("Failed: {reason}", "Fehlgeschlagen: {reason}"),
+    ("Networks", "Netze"),
+    ("Statistics", "Statistik"),
+    ("Survey", "Messung"),
+    ("Unmanaged", "Nicht verwaltet"),
+    ("Unavailable", "Nicht verfügbar"),
+    ("Preparing", "Vorbereitung"),
+    ("Associating", "Assoziiere"),
+    ("Authenticating", "Authentifiziere"),
+    ("Getting IP", "Hole IP"),
+    ("Checking connectivity", "Prüfe Verbindung"),
+    ("Starting secondaries", "Starte Zusatzverbindungen"),
+    ("Activated", "Aktiv"),
+    ("Failed", "Fehlgeschlagen"),
+    ("Esc to cancel", "Esc bricht ab"),
+    (
+        "Terminal too small\nMinimum: 50×12",
+        "Terminal zu klein\nMinimum: 50×12",
+    ),
+    // Confirmation dialog
+    ("Warning", "Warnung"),
+    ("Error", "Fehler"),
+    ("Yes", "Ja"),
+    ("No", "Nein"),
+    (
+        "The connection may briefly drop while it is reapplied.",
+        "Die Verbindung kann beim Neuanwenden kurz abbrechen.",
+    ),
+    (
+        "Reset the MTU to automatic?",
+        "MTU auf automatisch zurücksetzen?",
+    ),
+    ("Set the MTU to {mtu}?", "MTU auf {mtu} setzen?"),
+    (
+        "Apply {count} changes to this connection?",
+        "{count} Änderungen auf diese Verbindung anwenden?",
+    ),
+    (
+        "WiFi is turned off (soft-blocked).\nTurn it on?",
+        "WLAN ist ausgeschaltet (Soft-Block).\nEinschalten?",
+    ),
+    (
+        "Forget \"{ssid}\"?\nThis connection is ACTIVE — forgetting it will disconnect you immediately.",
+        "\"{ssid}\" vergessen?\nDiese Verbindung ist AKTIV — sie wird sofort getrennt.",
+    ),
+    (
+        "Turn on NetworkManager's connectivity check?\nNM will periodically fetch {uri} to detect captive portals and dead uplinks.",
+        "NetworkManagers Konnektivitätsprüfung einschalten?\nNM ruft regelmäßig {uri} ab, um Captive Portals und tote Uplinks zu erkennen.",
+    ),
+    (
+        "Turn off NetworkManager's connectivity check?\nNM stops fetching {uri}; internet state will read \"Checking disabled\".",
+        "NetworkManagers Konnektivitätsprüfung ausschalten?\nNM ruft {uri} nicht mehr ab; der Internetstatus lautet dann \"Prüfung aus\".",
+    ),
+    (
+        "Let NetworkManager manage this device?\nWhatever tool manages it now (e.g. systemd-networkd) may lose control of it.",
+        "Dieses Gerät von NetworkManager verwalten lassen?\nDas Werkzeug, das es jetzt verwaltet (z. B. systemd-networkd), verliert womöglich die Kontrolle.",
+    ),
+    (
+        "Forget \"{ssid}\" and join it again from scratch?\nThe saved profile is deleted, including its password and custom settings.",
+        "\"{ssid}\" vergessen und neu verbinden?\nDas gespeicherte Profil wird gelöscht, samt Passwort und eigenen Einstellungen.",
+    ),
+    (
+        "You will be disconnected meanwhile.",
+        "Die Verbindung wird solange getrennt.",
+    ),
+    (
+        "Disconnect?\nThis connection carries your default route — disconnecting will cut off this SSH session immediately.",
+        "Trennen?\nÜber diese Verbindung läuft die Standardroute — Trennen beendet diese SSH-Sitzung sofort.",
+    ),
+    (
+        "Disconnect?\nThis connection carries your default route — you will lose network access immediately.",
+        "Trennen?\nÜber diese Verbindung läuft die Standardroute — der Netzzugang geht sofort verloren.",
+    ),
+    ("and {count} more", "und {count} weitere"),
+    (
+        "The password for \"{ssid}\" was rejected {failures} times.\nIf it changed, forget the network ({forget}) or rejoin it from scratch ({rejoin}).",
+        "Das Passwort für \"{ssid}\" wurde {failures}-mal abgelehnt.\nFalls es sich geändert hat, das Netz vergessen ({forget}) oder neu verbinden ({rejoin}).",
+    ),
+    (
+        "Forget {count} networks?\n{names}",
+        "{count} Netze vergessen?\n{names}",
+    ),
+    (
+        "One of them is ACTIVE — you will be disconnected.",
+        "Eines davon ist AKTIV — die Verbindung wird getrennt.",
+    ),
+    // Toasts
+    ("Resumed — refreshing", "Aufgewacht — aktualisiere"),
+    ("System bus reconnected", "Systembus wieder verbunden"),
+    ("Signal shown in {unit}", "Signal in {unit}"),
+    ("estimated dBm", "geschätzten dBm"),
+    ("percent", "Prozent"),
+    ("Nothing to undo", "Nichts rückgängig zu machen"),
+    ("Forgetting '{ssid}'…", "Vergesse '{ssid}'…"),
+    ("Turning WiFi on…", "Schalte WLAN ein…"),
+    (
+        "Reverted unsaved changes",
+        "Ungespeicherte Änderungen verworfen",
+    ),
+    (
+        "Set Proxy to auto first — a PAC URL is only used by auto",
+        "Erst Proxy auf automatisch stellen — eine PAC-URL wird nur dann verwendet",
+    ),
+    ("Cleared {tool}", "{tool} geleert"),
+    (
+        "Cleared all diagnostics output",
+        "Alle Diagnoseausgaben geleert",
+    ),
+    ("Site survey stopped", "Standortmessung beendet"),
+    ("Survey statistics reset", "Messstatistik zurückgesetzt"),
+    ("Compact columns", "Kompakte Spalten"),
+    ("Configured columns", "Eingestellte Spalten"),
+    ("Using {interface}", "Verwende {interface}"),
+    (
+        "Already connected to '{ssid}'",
+        "Bereits mit '{ssid}' verbunden",
+    ),
+    ("Keys written to {path}", "Tasten nach {path} geschrieben"),
+    ("Connect command", "Verbindungsbefehl"),
+    (
+        "Share command (password not included)",
+        "Freigabebefehl (ohne Passwort)",
+    ),
+    ("Keyfile path", "Keyfile-Pfad"),
+    ("Keyfile path unknown", "Keyfile-Pfad unbekannt"),
+    (
+        "{what} copied to clipboard",
+        "{what} in die Zwischenablage kopiert",
+    ),
+    ("Copy failed: {error}", "Kopieren fehlgeschlagen: {error}"),
+    (
+        "{interface} is managed by another tool — press [{key}] to hand it to NetworkManager",
+        "{interface} wird von einem anderen Werkzeug verwaltet — [{key}] übergibt es an NetworkManager",
+    ),
+    (
+        "{interface} is disconnecting — wait for it to finish",
+        "{interface} trennt gerade — bitte warten",
+    ),
+    (
+        "Cancelled connecting to {ssid}",
+        "Verbinden mit {ssid} abgebrochen",
+    ),
+    (
+        "Fresh scan: {count} networks, {dropped} stale dropped",
+        "Frische Suche: {count} Netze, {dropped} veraltete verworfen",
+    ),
+    (
+        "Auto-scan on (every {secs}s)",
+        "Auto-Suche an (alle {secs}s)",
+    ),
+    ("Auto-scan off", "Auto-Suche aus"),
+    ("Network is not saved", "Netz ist nicht gespeichert"),
+    (
+        "Network is not saved — connect to it instead",
+        "Netz ist nicht gespeichert — stattdessen verbinden",
+    ),
+    (
+        "Forgot '{ssid}', but it is no longer in range",
+        "'{ssid}' vergessen, aber es ist nicht mehr in Reichweite",
+    ),
+    (
+        "Forgot '{ssid}' — enter the password to rejoin",
+        "'{ssid}' vergessen — Passwort eingeben, um neu zu verbinden",
+    ),
+    (
+        "Forgot '{ssid}' — rejoining…",
+        "'{ssid}' vergessen — verbinde neu…",
+    ),
+    (
+        "Not connected — MTU can only be changed on an active connection",
+        "Nicht verbunden — die MTU lässt sich nur bei aktiver Verbindung ändern",
+    ),
+    (
+        "Not connected — connection settings can only be edited while connected",
+        "Nicht verbunden — Verbindungseinstellungen lassen sich nur verbunden bearbeiten",
+    ),
+    (
+        "Not connected — the connectivity check is read from the active connection",
+        "Nicht verbunden — die Konnektivitätsprüfung wird von der aktiven Verbindung gelesen",
+    ),
+    (
+        "{interface} is already managed by NetworkManager",
+        "{interface} wird bereits von NetworkManager verwaltet",
+    ),
+    (
+        "Not connected — there is no active profile to inspect",
+        "Nicht verbunden — es gibt kein aktives Profil zum Ansehen",
+    ),
+    (
+        "No default gateway to ping — not connected?",
+        "Kein Standard-Gateway zum Pingen — nicht verbunden?",
+    ),
+    (
+        "Nothing to undo — the undo window has expired",
+        "Nichts rückgängig zu machen — die Frist ist abgelaufen",
+    ),
+    ("Restoring '{ssid}'…", "Stelle '{ssid}' wieder her…"),
+    (
+        "Ignoring '{pattern}' ({count} hidden)",
+        "Ignoriere '{pattern}' ({count} ausgeblendet)",
+    ),
+    (
+        "'{pattern}' is already ignored",
+        "'{pattern}' wird bereits ignoriert",
+    ),
+    ("Showing ignored networks", "Zeige ignorierte Netze"),
+    ("Hiding ignored networks", "Verberge ignorierte Netze"),
+    ("Showing weak networks", "Zeige schwache Netze"),
+    (
+        "Hiding unsaved networks below {percent}%",
+        "Verberge ungespeicherte Netze unter {percent}%",
+    ),
+    (
+        "Minimum signal {percent}% ({count} hidden)",
+        "Mindestsignal {percent}% ({count} ausgeblendet)",
+    ),
+    (
+        "Only saved networks can be marked",
+        "Nur gespeicherte Netze lassen sich markieren",
+    ),
+    ("Forgetting {done}/{total}…", "Vergesse {done}/{total}…"),
+    ("Forgot {count} networks", "{count} Netze vergessen"),
+    (
+        "Forgot {done}/{total} — failed: {names}",
+        "{done}/{total} vergessen — fehlgeschlagen: {names}",
+    ),
+    (
+        "Forgot '{ssid}' — press {key} to undo ({secs}s)",
+        "'{ssid}' vergessen — {key} macht es rückgängig ({secs}s)",
+    ),
+    (
+        "password not kept, you'll be asked again",
+        "Passwort nicht behalten, es wird erneut abgefragt",
+    ),
+    (
+        "Bandwidth log stopped — write failed",
+        "Bandbreitenprotokoll gestoppt — Schreiben fehlgeschlagen",
+    ),
+    (
+        "Prometheus textfile stopped — write failed",
+        "Prometheus-Textdatei gestoppt — Schreiben fehlgeschlagen",
+    ),
+    ("{target} is answering again", "{target} antwortet wieder"),
+    (
+        "{target} unreachable for {count} probes, but NetworkManager reports full connectivity",
+        "{target} seit {count} Versuchen unerreichbar, aber NetworkManager meldet volle Konnektivität",
+    ),
+    (
+        "Lost connection to {ssid} — NM will retry automatically",
+        "Verbindung zu {ssid} verloren — NM versucht es automatisch erneut",
+    ),
+    (
+        "Reconnected to {ssid} after {down}",
+        "Nach {down} wieder mit {ssid} verbunden",
+    ),
+    (
+        "Connected to {new} — {ssid} was down for {down}",
+        "Mit {new} verbunden — {ssid} war {down} lang weg",
+    ),
+    (
+        "{interface} is now managed by NetworkManager",
+        "{interface} wird jetzt von NetworkManager verwaltet",
+    ),
+    (
+        "{reason} — retrying ({attempt}/{total})…",
+        "{reason} — neuer Versuch ({attempt}/{total})…",
+    ),
+    (
+        "Exported {count} APs to {path}",
+        "{count} APs nach {path} exportiert",
+    ),
+    (
+        "Unknown layout pages: {names}",
+        "Unbekannte Layout-Seiten: {names}",
+    ),
+    (
+        "{pending} unsaved connection setting(s) — reopen the dialog to apply or revert",
+        "{pending} ungespeicherte Verbindungseinstellung(en) — Dialog erneut öffnen zum Anwenden oder Verwerfen",
+    ),
+    (
+        "'{ssid}' uses 802.1X (enterprise) login — set it up once with nmcli or nm-connection-editor, then connect here",
+        "'{ssid}' nutzt 802.1X-Anmeldung (Enterprise) — einmal mit nmcli oder nm-connection-editor einrichten, dann hier verbinden",
+    ),
+    (
+        "'{ssid}' uses 802.1X (enterprise) login — its profile can't be recreated here, so it is not forgotten",
+        "'{ssid}' nutzt 802.1X-Anmeldung (Enterprise) — das Profil lässt sich hier nicht neu anlegen, daher wird es nicht vergessen",
+    ),
+    (
+        "NetworkManager has no connectivity check URI — set uri= under [connectivity] in NetworkManager.conf",
+        "NetworkManager hat keine URI für die Konnektivitätsprüfung — uri= unter [connectivity] in NetworkManager.conf setzen",
+    ),
+    // Connection settings
+    ("Connection Settings", "Verbindungseinstellungen"),
+    ("Advanced", "Erweitert"),
+    ("modified", "geändert"),
+    ("(was {value})", "(vorher {value})"),
+    ("{count} unsaved", "{count} ungespeichert"),
+    ("No unsaved changes", "Keine ungespeicherten Änderungen"),
+    ("DHCP hostname", "DHCP-Hostname"),
+    ("Send hostname", "Hostname senden"),
+    ("DHCP client ID", "DHCP-Client-ID"),
+    ("Route metric", "Routenmetrik"),
+    ("Band", "Band"),
+    ("Prefer faster band", "Schnelleres Band bevorzugen"),
+    ("Proxy", "Proxy"),
+    ("Proxy PAC URL", "Proxy-PAC-URL"),
+    ("Firewall zone", "Firewall-Zone"),
+    ("Autoconnect retries", "Autoconnect-Versuche"),
+    ("(default)", "(Standard)"),
+    ("(system hostname)", "(System-Hostname)"),
+    ("(any)", "(beliebig)"),
+    ("{band} only", "nur {band}"),
+    ("off", "aus"),
+    ("on ({bssid})", "an ({bssid})"),
+    (
+        "on (fastest band in range, picked on apply)",
+        "an (schnellstes Band in Reichweite, beim Anwenden gewählt)",
+    ),
+    ("on ({count} APs)", "an ({count} APs)"),
+    ("(discover via WPAD)", "(per WPAD ermitteln)"),
+    ("forever", "unbegrenzt"),
+    ("none", "keiner"),
+    ("auto (PAC)", "automatisch (PAC)"),
+    ("auto (WPAD)", "automatisch (WPAD)"),
+    ("yes", "ja"),
+    ("no", "nein"),
+    // Profile changes
+    (
+        "Send \"{name}\" as the DHCP hostname",
+        "\"{name}\" als DHCP-Hostname senden",
+    ),
+    (
+        "Send the system hostname via DHCP",
+        "Den System-Hostnamen per DHCP senden",
+    ),
+    (
+        "Send the hostname via DHCP",
+        "Den Hostnamen per DHCP senden",
+    ),
+    (
+        "Stop sending the hostname via DHCP",
+        "Den Hostnamen nicht mehr per DHCP senden",
+    ),
+    (
+        "Use \"{id}\" as the DHCP client ID",
+        "\"{id}\" als DHCP-Client-ID verwenden",
+    ),
+    (
+        "Use the default DHCP client ID",
+        "Die Standard-DHCP-Client-ID verwenden",
+    ),
+    (
+        "Set the route metric to {metric}",
+        "Die Routenmetrik auf {metric} setzen",
+    ),
+    (
+        "Use the default route metric",
+        "Die Standard-Routenmetrik verwenden",
+    ),
+    (
+        "Only join {band} access points of this network (band \"{nm_band}\")",
+        "Nur {band}-Access-Points dieses Netzes verwenden (Band \"{nm_band}\")",
+    ),
+    (
+        "Let NetworkManager pick any band for this network",
+        "NetworkManager jedes Band dieses Netzes wählen lassen",
+    ),
+    (
+        "Pin this network to its fastest band in range, on all its access points there",
+        "Dieses Netz an sein schnellstes Band in Reichweite binden, mit allen Access Points dort",
+    ),
+    (
+        "Let NetworkManager pick any access point and band",
+        "NetworkManager jeden Access Point und jedes Band wählen lassen",
+    ),
+    (
+        "Configure the proxy automatically (PAC / WPAD)",
+        "Den Proxy automatisch konfigurieren (PAC / WPAD)",
+    ),
+    ("Connect without a proxy", "Ohne Proxy verbinden"),
+    (
+        "Load the proxy configuration from {url}",
+        "Die Proxy-Konfiguration von {url} laden",
+    ),
+    (
+        "Discover the proxy configuration via WPAD",
+        "Die Proxy-Konfiguration per WPAD ermitteln",
+    ),
+    (
+        "Put the connection in firewall zone \"{zone}\"",
+        "Die Verbindung in die Firewall-Zone \"{zone}\" legen",
+    ),
+    (
+        "Use the default firewall zone",
+        "Die Standard-Firewall-Zone verwenden",
+    ),
+    (
+        "Keep retrying to autoconnect, never give up",
+        "Automatisches Verbinden endlos wiederholen",
+    ),
+    (
+        "Give up autoconnecting after {count} failed attempts",
+        "Automatisches Verbinden nach {count} Fehlversuchen aufgeben",
+    ),
+    (
+        "Use the default autoconnect retries",
+        "Die Standardzahl an Autoconnect-Versuchen verwenden",
+    ),
+    // Input dialogs
+    ("Set MTU", "MTU setzen"),
+    ("DHCP Hostname", "DHCP-Hostname"),
+    ("DHCP Client ID", "DHCP-Client-ID"),
+    ("Route Metric", "Routenmetrik"),
+    ("Autoconnect Retries", "Autoconnect-Versuche"),
+    ("Inspect HTTP Headers", "HTTP-Header untersuchen"),
+    ("Path MTU Probe", "Pfad-MTU-Test"),
+    ("Compare Ping", "Ping vergleichen"),
+    ("Nickname", "Spitzname"),
+    ("Note", "Notiz"),
+    ("Ignore Networks", "Netze ignorieren"),
+    ("Hostname", "Hostname"),
+    ("Client ID", "Client-ID"),
+    ("Metric", "Metrik"),
+    ("Retries", "Versuche"),
+    ("Host", "Host"),
+    ("Hosts", "Hosts"),
+    (
+        "(68–9000 bytes, 0 = automatic)",
+        "(68–9000 Byte, 0 = automatisch)",
+    ),
+    ("(empty = system hostname)", "(leer = System-Hostname)"),
+    (
+        "(mac, duid, stable, hex bytes; empty = default)",
+        "(mac, duid, stable, Hex-Bytes; leer = Standard)",
+    ),
+    (
+        "(lower = preferred route; empty = default, 600 for WiFi)",
+        "(kleiner = bevorzugte Route; leer = Standard, 600 für WLAN)",
+    ),
+    (
+        "(0 = retry forever; empty or -1 = default, usually 4)",
+        "(0 = endlos; leer oder -1 = Standard, meist 4)",
+    ),
+    (
+        "(http://, https:// or file:// URL; empty = discover via WPAD)",
+        "(http://-, https://- oder file://-URL; leer = per WPAD ermitteln)",
+    ),
+    (
+        "(http:// shows what a portal or proxy injects)",
+        "(http:// zeigt, was ein Portal oder Proxy einschleust)",
+    ),
+    (
+        "(IPv4 host or address to probe towards)",
+        "(IPv4-Host oder -Adresse als Ziel des Tests)",
+    ),
+    (
+        "(comma-separated; \"gateway\" = the default gateway)",
+        "(durch Kommas getrennt; \"gateway\" = das Standard-Gateway)",
+    ),
+    (
+        "(shown after the SSID, found by /; empty = remove)",
+        "(hinter der SSID angezeigt, mit / auffindbar; leer = entfernen)",
+    ),
+    (
+        "(shown in the detail panel; empty = remove)",
+        "(in den Details angezeigt; leer = entfernen)",
+    ),
+    (
+        "(* = any text, ? = one character, e.g. DIRECT-*)",
+        "(* = beliebiger Text, ? = ein Zeichen, z. B. DIRECT-*)",
+    ),
+    (
+        "Invalid MTU \"{value}\": must be between {min} and {max} (or 0 for automatic)",
+        "Ungültige MTU \"{value}\": muss zwischen {min} und {max} liegen (oder 0 für automatisch)",
+    ),
+    ("Invalid hostname: {error}", "Ungültiger Hostname: {error}"),
+    (
+        "Invalid client ID: it may not contain spaces",
+        "Ungültige Client-ID: sie darf keine Leerzeichen enthalten",
+    ),
+    (
+        "Invalid PAC URL \"{value}\": must be an http://, https:// or file:// URL",
+        "Ungültige PAC-URL \"{value}\": muss eine http://-, https://- oder file://-URL sein",
+    ),
+    (
+        "Invalid URL \"{value}\": must be an http:// or https:// URL",
+        "Ungültige URL \"{value}\": muss eine http://- oder https://-URL sein",
+    ),
+    ("Invalid host \"{host}\"", "Ungültiger Host \"{host}\""),
+    (
+        "Invalid route metric \"{value}\": must be a non-negative number",
+        "Ungültige Routenmetrik \"{value}\": muss eine nicht negative Zahl sein",
+    ),
+    (
+        "Invalid autoconnect retries \"{value}\": must be -1 or 0 to {max}",
+        "Ungültige Autoconnect-Versuche \"{value}\": muss -1 oder 0 bis {max} sein",
+    ),
+    (
+        "Enter between 1 and {max} hosts, separated by commas",
+        "Zwischen 1 und {max} Hosts eingeben, durch Kommas getrennt",
+    ),
+    (
+        "Hostname is longer than 253 characters",
+        "Hostname ist länger als 253 Zeichen",
+    ),
+    (
+        "\"{name}\" has an empty or over-long (>63) label",
+        "\"{name}\" hat ein leeres oder zu langes (>63) Label",
+    ),
+    (
+        "Label \"{label}\" may not start or end with '-'",
+        "Label \"{label}\" darf nicht mit '-' beginnen oder enden",
+    ),
+    (
+        "Hostnames may not contain '{char}'",
+        "Hostnamen dürfen kein '{char}' enthalten",
+    ),
+    // Password dialogs
+    ("Connect to \"{ssid}\"", "Mit \"{ssid}\" verbinden"),
+    (
+        "Connect to Hidden Network",
+        "Mit verborgenem Netz verbinden",
+    ),
+    ("Password", "Passwort"),
+    (
+        "(leave empty for open networks)",
+        "(bei offenen Netzen leer lassen)",
+    ),
+    ("Switch", "Wechseln"),
+    ("Hide", "Verbergen"),
+    ("Show", "Anzeigen"),
+    (
+        "WEP keys are 5 or 13 ASCII characters, or 10 or 26 hex digits",
+        "WEP-Schlüssel haben 5 oder 13 ASCII-Zeichen oder 10 oder 26 Hex-Ziffern",
+    ),
+    (
+        "WPA passwords are at least 8 bytes (got {len})",
+        "WPA-Passwörter haben mindestens 8 Byte (eingegeben: {len})",
+    ),
+    (
+        "WPA passwords are at most 63 bytes",
+        "WPA-Passwörter haben höchstens 63 Byte",
+    ),
+    (
+        "Wrong password for \"{ssid}\" — try again ({attempt} of {max})",
+        "Falsches Passwort für \"{ssid}\" — erneut versuchen ({attempt} von {max})",
+    ),
+    (
+        "No password is stored for \"{ssid}\" — enter it to save it in the profile",
+        "Für \"{ssid}\" ist kein Passwort gespeichert — eingeben, um es im Profil zu speichern",
+    ),
+    // Help
+    ("Keybindings", "Tastenbelegung"),
+    ("Press", "Drücke"),
+    ("or", "oder"),
+    ("to close", "zum Schließen"),
+    ("Network list", "Netzliste"),
+    ("Dialogs", "Dialoge"),
+    ("Move up", "Nach oben"),
+    ("Move down", "Nach unten"),
+    ("Go to top", "Zum Anfang"),
+    ("Go to bottom", "Zum Ende"),
+    (
+        "Focus details (y copy, c connect command, n/N nickname/note)",
+        "Details fokussieren (y kopieren, c Verbindungsbefehl, n/N Spitzname/Notiz)",
+    ),
+    ("Jump to page", "Zur Seite springen"),
+    (
+        "Connect to numbered row",
+        "Mit nummerierter Zeile verbinden",
+    ),
+    (
+        "Search networks, interfaces, actions",
+        "Netze, Schnittstellen, Aktionen suchen",
+    ),
+    ("Recently connected networks", "Zuletzt verbundene Netze"),
+    ("Show/hide password", "Passwort zeigen/verbergen"),
+    ("Switch fields", "Feld wechseln"),
+    ("Close dialog / cancel", "Dialog schließen / abbrechen"),
+    (
+        "Connect to selected network",
+        "Mit gewähltem Netz verbinden",
+    ),
+    (
+        "Disconnect from current network",
+        "Vom aktuellen Netz trennen",
+    ),
+    ("Scan for networks", "Nach Netzen suchen"),
+    (
+        "Fresh scan (drop cached networks)",
+        "Frische Suche (Zwischenspeicher verwerfen)",
+    ),
+    ("Pick WiFi adapter", "WLAN-Adapter wählen"),
+    ("Forget selected network", "Gewähltes Netz vergessen"),
+    ("Undo last forget", "Letztes Vergessen rückgängig machen"),
+    (
+        "Forget and rejoin network",
+        "Netz vergessen und neu verbinden",
+    ),
+    (
+        "Compact / configured columns",
+        "Kompakte / eingestellte Spalten",
+    ),
+    ("Toggle auto-scan", "Auto-Suche umschalten"),
+    ("Export scan results", "Suchergebnisse exportieren"),
+    ("Site survey mode", "Standortmessung"),
+    ("Interface statistics", "Schnittstellenstatistik"),
+    ("Pin / unpin favorite", "Favorit anheften / lösen"),
+    ("Mark for bulk forget", "Zum Sammel-Vergessen markieren"),
+    (
+        "Mark all visible saved",
+        "Alle sichtbaren gespeicherten markieren",
+    ),
+    (
+        "Connect to hidden network",
+        "Mit verborgenem Netz verbinden",
+    ),
+    ("Toggle detail panel", "Details ein-/ausblenden"),
+    (
+        "Signal as percent / est. dBm",
+        "Signal in Prozent / geschätzten dBm",
+    ),
+    ("Refresh connection info", "Verbindungsinfo aktualisieren"),
+    (
+        "Set MTU of active connection",
+        "MTU der aktiven Verbindung setzen",
+    ),
+    (
+        "Let NetworkManager manage device",
+        "Gerät von NetworkManager verwalten lassen",
+    ),
+    (
+        "Edit active connection settings",
+        "Einstellungen der aktiven Verbindung bearbeiten",
+    ),
+    (
+        "nmcli command / keyfile / share",
+        "nmcli-Befehl / Keyfile / Freigabe",
+    ),
+    ("Connection events log", "Verbindungsereignisse"),
+    (
+        "Toggle NM connectivity check",
+        "NM-Konnektivitätsprüfung umschalten",
+    ),
+    (
+        "Diagnose internet connectivity",
+        "Internetverbindung diagnostizieren",
+    ),
+    ("Search / filter networks", "Netze suchen / filtern"),
+    ("Next page", "Nächste Seite"),
+    ("Previous page", "Vorige Seite"),
+    (
+        "Quick actions for selection",
+        "Schnellaktionen für die Auswahl",
+    ),
+    (
+        "Go to view / run action by name",
+        "Ansicht öffnen / Aktion per Name ausführen",
+    ),
+    ("Cycle sort mode", "Sortierung wechseln"),
+    ("Preview theme colors", "Themenfarben ansehen"),
+    ("Ignore SSID / pattern", "SSID / Muster ignorieren"),
+    (
+        "Show/hide ignored networks",
+        "Ignorierte Netze zeigen/verbergen",
+    ),
+    ("Show/hide weak networks", "Schwache Netze zeigen/verbergen"),
+    ("Lower weak threshold", "Schwellwert für schwach senken"),
+    ("Raise weak threshold", "Schwellwert für schwach anheben"),
+    ("Toggle this help", "Diese Hilfe ein-/ausblenden"),
+    ("Quit Nexus", "Nexus beenden"),
+    // Palette and quick actions
+    ("Go to", "Gehe zu"),
+    (
+        "Type to search networks, interfaces and actions",
+        "Tippen, um Netze, Schnittstellen und Aktionen zu suchen",
+    ),
+    ("No matches", "Keine Treffer"),
+    ("view", "Ansicht"),
+    ("action", "Aktion"),
+    ("Interfaces", "Schnittstellen"),
+    ("Views & actions", "Ansichten & Aktionen"),
+    ("Site survey", "Standortmessung"),
+    ("Pin to top", "Oben anheften"),
+    ("Forget", "Vergessen"),
+    ("Show / hide details", "Details zeigen / verbergen"),
+    ("nmcli command", "nmcli-Befehl"),
+    ("Events log", "Ereignisprotokoll"),
+    ("Diagnostics", "Diagnose"),
+    ("Connection settings", "Verbindungseinstellungen"),
+    ("Refresh connection", "Verbindung aktualisieren"),
+    ("Ignored networks", "Ignorierte Netze"),
+    ("Write keys cheatsheet", "Tasten-Spickzettel schreiben"),
+    ("Unpin", "Lösen"),
+    ("Unmark", "Markierung aufheben"),
+    ("already connected", "bereits verbunden"),
+    ("not the active network", "nicht das aktive Netz"),
+    ("not saved", "nicht gespeichert"),
+    // Diagnostics
+    ("Internet Diagnostics", "Internetdiagnose"),
+    (
+        "Checking link, gateway, internet, DNS, HTTP and NM…",
+        "Prüfe Verbindung, Gateway, Internet, DNS, HTTP und NM…",
+    ),
+    (
+        "Connectivity breaks at {layer}",
+        "Verbindung bricht ab bei {layer}",
+    ),
+    (
+        "Internet connection looks healthy",
+        "Internetverbindung sieht gesund aus",
+    ),
+    ("Link", "Verbindung"),
+    ("Internet (L3)", "Internet (L3)"),
+    ("NM connectivity", "NM-Konnektivität"),
+    (
+        "Not connected to WiFi, or NetworkManager is not answering. Connect first.",
+        "Nicht mit einem WLAN verbunden, oder NetworkManager antwortet nicht. Erst verbinden.",
+    ),
+    (
+        "The router is unreachable. Move closer, reconnect, or check the router.",
+        "Der Router ist nicht erreichbar. Näher herangehen, neu verbinden oder den Router prüfen.",
+    ),
+    (
+        "The router is reachable but has no upstream connection. Check the modem/ISP.",
+        "Der Router ist erreichbar, hat aber keine Verbindung nach außen. Modem/Provider prüfen.",
+    ),
+    (
+        "The internet is reachable but names don't resolve. Try a different DNS server.",
+        "Das Internet ist erreichbar, aber Namen werden nicht aufgelöst. Einen anderen DNS-Server versuchen.",
+    ),
+    (
+        "Web traffic is blocked or redirected, likely by a captive portal. Open a browser to log in.",
+        "Webverkehr wird blockiert oder umgeleitet, vermutlich von einem Captive Portal. Zum Anmelden einen Browser öffnen.",
+    ),
+    (
+        "NetworkManager sees no full connectivity and may stop routing through this connection.",
+        "NetworkManager sieht keine volle Konnektivität und leitet womöglich keinen Verkehr mehr über diese Verbindung.",
+    ),
+    ("Ping comparison", "Ping-Vergleich"),
+    ("HTTP Headers", "HTTP-Header"),
+    ("Hop {hop}", "Station {hop}"),
+    ("No response", "Keine Antwort"),
+    ("1 response, no redirects", "1 Antwort, keine Weiterleitung"),
+    (
+        "{count} responses, {redirects} redirect(s) followed",
+        "{count} Antworten, {redirects} Weiterleitung(en) gefolgt",
+    ),
+    ("Fetching…", "Lade…"),
+    ("Towards", "Richtung"),
+    ("don't fragment", "nicht fragmentieren"),
+    ("passed", "durch"),
+    ("too big (mtu {mtu})", "zu groß (MTU {mtu})"),
+    ("too big", "zu groß"),
+    ("no reply", "keine Antwort"),
+    ("bytes", "Byte"),
+    (
+        "Path MTU {mtu} is below the interface MTU {iface}. Larger packets are dropped or fragmented on the way, typical of PPPoE or a VPN. Setting the MTU to {mtu} ([m]) avoids stalls.",
+        "Die Pfad-MTU {mtu} liegt unter der Schnittstellen-MTU {iface}. Größere Pakete werden unterwegs verworfen oder fragmentiert, typisch für PPPoE oder ein VPN. Die MTU auf {mtu} zu setzen ([m]) vermeidet Hänger.",
+    ),
+    (
+        "Path MTU {mtu} — matches the interface MTU",
+        "Pfad-MTU {mtu} — entspricht der Schnittstellen-MTU",
+    ),
+    (
+        "Nothing got through — the host is unreachable or drops pings",
+        "Nichts kam durch — der Host ist unerreichbar oder verwirft Pings",
+    ),
+    ("Probing…", "Teste…"),
+    ("Sent", "Gesendet"),
+    ("Recv", "Empf."),
+    ("Loss", "Verlust"),
+    ("Avg", "Mittel"),
+    ("Last", "Zuletzt"),
+    (
+        "Done — {count} requests per host",
+        "Fertig — {count} Anfragen pro Host",
+    ),
+    (
+        "Pinging every host once a second ({count} requests)…",
+        "Pinge jeden Host einmal pro Sekunde ({count} Anfragen)…",
+    ),
+    // Other dialogs
+    ("Connection Events", "Verbindungsereignisse"),
+    (
+        "No events yet — NetworkManager state changes will appear here",
+        "Noch keine Ereignisse — Zustandswechsel von NetworkManager erscheinen hier",
+    ),
+    ("Recent networks", "Zuletzt verbundene Netze"),
+    ("Reading saved profiles…", "Lese gespeicherte Profile…"),
+    (
+        "No saved network has connected yet",
+        "Noch kein gespeichertes Netz war verbunden",
+    ),
+    ("{span} ago", "vor {span}"),
+    ("not in range", "nicht in Reichweite"),
+    ("Open in Shell", "In der Shell öffnen"),
+    (
+        "(unknown — not stored as a keyfile, or not readable)",
+        "(unbekannt — nicht als Keyfile gespeichert oder nicht lesbar)",
+    ),
+    (
+        "Settings Nexus can't edit for \"{ssid}\":",
+        "Einstellungen für \"{ssid}\", die Nexus nicht bearbeiten kann:",
+    ),
+    ("Command", "Befehl"),
+    ("Keyfile", "Keyfile"),
+    ("Share", "Freigabe"),
+    (
+        "secrets excluded — give the password separately",
+        "ohne Geheimnisse — das Passwort separat weitergeben",
+    ),
+    (
+        "Nothing ignored. Press [a] to add a pattern.",
+        "Nichts ignoriert. [a] fügt ein Muster hinzu.",
+    ),
+    ("{count} in range", "{count} in Reichweite"),
+    ("Theme", "Thema"),
+    ("Selected row", "Gewählte Zeile"),
+    ("Border", "Rahmen"),
+    ("Focused border", "Fokussierter Rahmen"),
+    ("Dimmed text", "Gedimmter Text"),
+    (
+        "Monochrome: colors are not drawn",
+        "Monochrom: Farben werden nicht gezeichnet",
+    ),
+    ("Traffic", "Verkehr"),
+    ("peak {rate}", "Spitze {rate}"),
+    ("WiFi adapter", "WLAN-Adapter"),
+    ("Listing WiFi adapters…", "Liste WLAN-Adapter auf…"),
+    (
+        "NetworkManager lists no WiFi adapter",
+        "NetworkManager kennt keinen WLAN-Adapter",
+    ),
+    ("in use", "in Gebrauch"),
+    // Statistics and survey
+    ("since launch", "seit dem Start"),
+    ("Reading interface counters…", "Lese Schnittstellenzähler…"),
+    ("unreadable", "nicht lesbar"),
+    ("last {range}", "letzte {range}"),
+    ("RX err", "RX-Fehler"),
+    ("TX err", "TX-Fehler"),
+    ("RX drop", "RX-Verlust"),
+    ("TX drop", "TX-Verlust"),
+    ("Site Survey", "Standortmessung"),
+    ("{aps} APs, {scans} scans", "{aps} APs, {scans} Suchläufe"),
+    ("Collecting the first scan…", "Sammle den ersten Suchlauf…"),
+    ("Samples", "Proben"),
+    ("Seen", "Gesehen"),
+    // Errors
+    (
+        "{action} failed: {reason}",
+        "{action} fehlgeschlagen: {reason}",
+    ),
+    ("{action} failed", "{action} fehlgeschlagen"),
+    ("Write keys", "Tasten schreiben"),
+    ("Save labels", "Labels speichern"),
+    ("Save favorites", "Favoriten speichern"),
+    ("Save ignore list", "Ignorierliste speichern"),
+    ("Save view state", "Ansichtszustand speichern"),
+    ("Fresh scan", "Frische Suche"),
+    ("Undo forget", "Vergessen rückgängig machen"),
+    ("WiFi adapters", "WLAN-Adapter"),
+    ("Switch adapter", "Adapter wechseln"),
+    ("Update connection", "Verbindung ändern"),
+    ("Connectivity check", "Konnektivitätsprüfung"),
+    ("Manage device", "Gerät verwalten"),
+    ("Enable WiFi", "WLAN einschalten"),
+    ("Survey scan", "Messsuche"),
+    ("Export", "Export"),
+    (
+        "Invalid password — WPA passwords are 8–63 characters",
+        "Ungültiges Passwort — WPA-Passwörter haben 8–63 Zeichen",
+    ),
+    (
+        "Wrong or missing password",
+        "Falsches oder fehlendes Passwort",
+    ),
+    (
+        "Authentication failed — check the password",
+        "Authentifizierung fehlgeschlagen — Passwort prüfen",
+    ),
+    (
+        "Authentication timed out — the access point did not answer",
+        "Authentifizierung abgelaufen — der Access Point hat nicht geantwortet",
+    ),
+    (
+        "That connection is already active",
+        "Diese Verbindung ist bereits aktiv",
+    ),
+    (
+        "That connection is not active",
+        "Diese Verbindung ist nicht aktiv",
+    ),
+    (
+        "The saved profile is missing a required setting",
+        "Dem gespeicherten Profil fehlt eine nötige Einstellung",
+    ),
+    (
+        "That saved profile no longer exists",
+        "Dieses gespeicherte Profil existiert nicht mehr",
+    ),
+    (
+        "The profile contains an invalid setting",
+        "Das Profil enthält eine ungültige Einstellung",
+    ),
+    (
+        "Permission denied — a polkit agent may be required",
+        "Zugriff verweigert — womöglich ist ein Polkit-Agent nötig",
+    ),
+    (
+        "Couldn't get an IP address — DHCP timed out",
+        "Keine IP-Adresse erhalten — DHCP abgelaufen",
+    ),
+    (
+        "The DHCP lease expired — the network may be flaky",
+        "Die DHCP-Lease ist abgelaufen — das Netz ist womöglich instabil",
+    ),
+    (
+        "NetworkManager is not running",
+        "NetworkManager läuft nicht",
+    ),
+    (
+        "NetworkManager did not respond in time",
+        "NetworkManager hat nicht rechtzeitig geantwortet",
+    ),
+];
+
+impl Language {
+    /// `msg` in this language. English, and anything the catalog lacks
+    /// (an SSID, a raw D-Bus error), comes back unchanged.
+    pub fn tr(self, msg: &str) -> &str {
+        let catalog = match self {
+            Self::En => return msg,
+            Self::De => DE,
+        };
+        catalog
+            .iter()
+            .find(|(en, _)| *en == msg)
+            .map_or(msg, |(_, translated)| translated)
+    }
+
+    /// Translate `template` and replace each `{name}` with its value
+    pub fn fill(self, template: &'static str, args: &[(&str, &dyn Display)]) -> String {
+        let mut text = self.tr(template).to_string();
+        for (name, value) in args {
+            text = text.replace(&format!("{{{name}}}"), &value.to_string());
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use super::*;
+
+    fn placeholders(text: &str) -> BTreeSet<&str> {
+        text.split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+
+    #[test]
+    fn catalog_keeps_placeholders_and_has_no_duplicates() {
+        let mut seen = HashSet::new();
+        for (en, de) in DE {
+            assert!(seen.insert(en), "duplicate catalog entry {en:?}");
+            assert_eq!(placeholders(en), placeholders(de), "placeholders of {en:?}");
+        }
+    }
+
+    #[test]
+    fn fill_translates_then_substitutes() {
+        let args: &[(&str, &dyn Display)] = &[("count", &3)];
+        assert_eq!(Language::De.fill("{count} marked", args), "3 markiert");
+        assert_eq!(Language::En.fill("{count} marked", args), "3 marked");
+        assert_eq!(Language::De.tr("HomeWiFi"), "HomeWiFi");
+    }
+
+    #[test]
+    fn status_bar_is_in_the_catalog() {
+        use crate::config::Config;
+        use crate::network::types::DeviceState;
+        use crate::ui::status_bar;
+        use crate::ui::theme::Theme;
+
+        let theme = Theme::from_config(&Config::default());
+        let hints = status_bar::all_hints(&theme)
+            .into_iter()
+            .filter(|span| span.style == theme.style_key_desc())
+            .map(|span| span.content.trim_end().to_string());
+        let states = (0..=120)
+            .step_by(10)
+            .filter_map(|code| {
+                DeviceState::from_u32(code)
+                    .activation_progress()
+                    .map(|_| code)
+            })
+            .map(|code| DeviceState::from_u32(code).label().to_string());
+        let labels = status_bar::LABELS.iter().map(|label| label.to_string());
+        for text in hints.chain(states).chain(labels) {
+            assert!(
+                DE.iter().any(|(en, _)| *en == text),
+                "{text:?} is missing from the catalog"
+            );
+        }
+    }
+}
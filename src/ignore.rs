@@ -0,0 +1,105 @@
+use std::path::PathBuf;
+
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::config::Config;
+
+/// SSID patterns left out of the network list, persisted as TOML in
+/// ~/.local/state/nexus/ignored.toml. A pattern may use `*` (any run of
+/// characters) and `?` (one character).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IgnoreList {
+    patterns: Vec<String>,
+}
+
+impl IgnoreList {
+    fn path() -> PathBuf {
+        Config::log_dir().join("ignored.toml")
+    }
+
+    /// Load the ignore list; a missing file means an empty one, and a
+    /// corrupt one is logged and ignored (it is replaced on the next edit)
+    pub fn load() -> Self {
+        let path = Self::path();
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        toml::from_str(&text).unwrap_or_else(|e| {
+            warn!("Ignoring unreadable {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// Whether any pattern matches `ssid`
+    pub fn matches(&self, ssid: &str) -> bool {
+        self.patterns
+            .iter()
+            .any(|pattern| glob_match(pattern, ssid))
+    }
+
+    /// Append `pattern` and write the file. Returns false if it was listed.
+    pub fn add(&mut self, pattern: String) -> Result<bool> {
+        if self.patterns.contains(&pattern) {
+            return Ok(false);
+        }
+        self.patterns.push(pattern);
+        self.save()?;
+        Ok(true)
+    }
+
+    /// Drop the pattern at `index` and write the file
+    pub fn remove(&mut self, index: usize) -> Result<()> {
+        if index < self.patterns.len() {
+            self.patterns.remove(index);
+        }
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let text = toml::to_string(self).wrap_err("Failed to encode the ignore list")?;
+        // Same as the favorites: write beside the file and rename over it
+        let path = Self::path();
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, text)
+            .and_then(|()| std::fs::rename(&tmp, &path))
+            .wrap_err_with(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// Case-sensitive match of `text` against a pattern with `*` and `?`
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Where the last `*` was, and the text position it currently covers up to
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => {
+                // Let the last `*` swallow one more character, if there is one
+                let Some((star_p, star_t)) = star else {
+                    return false;
+                };
+                star = Some((star_p, star_t + 1));
+                p = star_p + 1;
+                t = star_t + 1;
+            }
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::config::Config;
+
+/// What the user wrote about one SSID
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Label {
+    /// Shown after the SSID in the network list, and matched by `/`
+    pub nickname: String,
+    /// Free text shown in the detail panel
+    pub note: String,
+}
+
+impl Label {
+    fn is_empty(&self) -> bool {
+        self.nickname.is_empty() && self.note.is_empty()
+    }
+}
+
+/// Nicknames and notes keyed by SSID, persisted as TOML in
+/// ~/.local/state/nexus/labels.toml
+#[derive(Debug, Clone, Default)]
+pub struct Labels {
+    by_ssid: BTreeMap<String, Label>,
+}
+
+impl Labels {
+    fn path() -> PathBuf {
+        Config::log_dir().join("labels.toml")
+    }
+
+    /// Load the saved labels; a missing file means none, and a corrupt one
+    /// is logged and ignored (it is replaced on the next edit)
+    pub fn load() -> Self {
+        let path = Self::path();
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        match toml::from_str(&text) {
+            Ok(by_ssid) => Self { by_ssid },
+            Err(e) => {
+                warn!("Ignoring unreadable {}: {}", path.display(), e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn get(&self, ssid: &str) -> Option<&Label> {
+        self.by_ssid.get(ssid)
+    }
+
+    /// The nickname of `ssid`, if it has one
+    pub fn nickname(&self, ssid: &str) -> Option<&str> {
+        self.get(ssid)
+            .map(|label| label.nickname.as_str())
+            .filter(|nickname| !nickname.is_empty())
+    }
+
+    /// Set or (with an empty string) clear the nickname, and write the file
+    pub fn set_nickname(&mut self, ssid: &str, nickname: String) -> Result<()> {
+        self.update(ssid, |label| label.nickname = nickname)
+    }
+
+    /// Set or (with an empty string) clear the note, and write the file
+    pub fn set_note(&mut self, ssid: &str, note: String) -> Result<()> {
+        self.update(ssid, |label| label.note = note)
+    }
+
+    fn update(&mut self, ssid: &str, edit: impl FnOnce(&mut Label)) -> Result<()> {
+        let label = self.by_ssid.entry(ssid.to_string()).or_default();
+        edit(label);
+        if label.is_empty() {
+            self.by_ssid.remove(ssid);
+        }
+        let text = toml::to_string(&self.by_ssid).wrap_err("Failed to encode labels")?;
+        // Same as the favorites: write beside the file and rename over it
+        let path = Self::path();
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, text)
+            .and_then(|()| std::fs::rename(&tmp, &path))
+            .wrap_err_with(|| format!("Failed to write {}", path.display()))
+    }
+}
@@ -0,0 +1,52 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Prefix of the daily log files (`nexus.log.2024-05-01`)
+const LOG_PREFIX: &str = "nexus.log";
+
+/// What a cleanup removed
+#[derive(Debug, Default)]
+pub struct Pruned {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// Delete daily log files in `dir` older than `retention_days`, then the
+/// oldest ones until the rest fit in `max_bytes` (0 = no limit for either).
+/// The newest file is always kept, since it is the one being written.
+/// Runs before logging starts, so failures are skipped silently.
+pub fn prune(dir: &Path, retention_days: u32, max_bytes: u64) -> Pruned {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Pruned::default();
+    };
+    let mut logs: Vec<(SystemTime, u64, PathBuf)> = entries
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().starts_with(LOG_PREFIX))
+        .filter_map(|e| {
+            let meta = e.metadata().ok().filter(|m| m.is_file())?;
+            Some((meta.modified().ok()?, meta.len(), e.path()))
+        })
+        .collect();
+    // Newest first
+    logs.sort_by_key(|(modified, ..)| std::cmp::Reverse(*modified));
+
+    let cutoff = (retention_days > 0)
+        .then(|| SystemTime::now() - Duration::from_secs(u64::from(retention_days) * 86_400));
+    let mut pruned = Pruned::default();
+    let mut kept_bytes = 0;
+    // Once a file doesn't fit, every older one goes too
+    let mut full = false;
+    for (i, (modified, len, path)) in logs.into_iter().enumerate() {
+        let expired = cutoff.is_some_and(|cutoff| modified < cutoff);
+        full |= i > 0 && max_bytes > 0 && kept_bytes + len > max_bytes;
+        if i == 0 || !(expired || full) {
+            kept_bytes += len;
+            continue;
+        }
+        if std::fs::remove_file(&path).is_ok() {
+            pruned.files += 1;
+            pruned.bytes += len;
+        }
+    }
+    pruned
+}
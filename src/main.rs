@@ -1,32 +1,56 @@
 mod animation;
 mod app;
+mod bandwidth;
+mod cheatsheet;
+mod clipboard;
 mod config;
+mod connect;
+mod control;
+mod crash;
+mod doctor;
+mod error;
 mod event;
+mod export;
+mod favorites;
+mod i18n;
+mod ignore;
+mod labels;
+mod logs;
 mod network;
+mod oneline;
+mod palette;
+mod prometheus;
 mod ui;
+mod view_state;
+mod watch;
 
 use std::io;
 use std::panic;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
 use clap::Parser;
 use color_eyre::eyre::Result;
 use crossterm::{
-    cursor, execute,
+    cursor,
+    event::{DisableFocusChange, EnableFocusChange},
+    execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
 use tracing::info;
 
-use app::{App, AppMode};
-use config::CliArgs;
+use app::{App, AppMode, ToastLevel};
+use config::{CliArgs, Command};
 use event::{Event, EventHandler, NetworkCommand};
 use network::NetworkBackend;
 use network::manager::NmBackend;
+use network::mock::MockBackend;
 use network::types::*;
 use ui::theme::Theme;
+use view_state::ViewState;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -45,12 +69,60 @@ async fn main() -> Result<()> {
     // Load configuration (TOML + CLI overrides)
     let config = config::load(&cli)?;
 
+    if let Some(format) = cli.print_keys {
+        print!("{}", cheatsheet::render(config.keys(), format));
+        return Ok(());
+    }
+
+    // --oneline prints a status line and exits without touching the terminal
+    if cli.oneline {
+        return oneline::run(&cli, &config).await;
+    }
+    match cli.command {
+        Some(Command::Scan { json }) => return export::run(&cli, &config, json).await,
+        Some(Command::Doctor) => {
+            if !doctor::run(&cli, &config).await? {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::Connect {
+            ref ssid,
+            ref password,
+            password_stdin,
+        }) => {
+            let password = match password {
+                Some(password) => connect::Password::Given(password.clone()),
+                None if password_stdin => connect::Password::Stdin,
+                None => connect::Password::None,
+            };
+            if !connect::run(&cli, &config, ssid, password).await? {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::Watch) => return watch::run(&cli, &config).await,
+        None => {}
+    }
+
     // Build the runtime theme from config
     let theme = Theme::from_config(&config);
 
-    // Set up logging to file
+    // Set up logging to file, after clearing out old daily logs
     let log_dir = config::Config::log_dir();
-    let file_appender = tracing_appender::rolling::daily(&log_dir, "nexus.log");
+    let pruned = logs::prune(
+        &log_dir,
+        config.general.log_retention_days,
+        config.general.log_max_mb * 1024 * 1024,
+    );
+    let file_appender = match &cli.log_file {
+        Some(path) => {
+            let dir = path.parent().filter(|p| !p.as_os_str().is_empty());
+            let name = path.file_name().unwrap_or("nexus.log".as_ref());
+            tracing_appender::rolling::never(dir.unwrap_or(".".as_ref()), name)
+        }
+        None => tracing_appender::rolling::daily(&log_dir, "nexus.log"),
+    };
     let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
     tracing_subscriber::fmt()
         .with_env_filter(
@@ -62,6 +134,13 @@ async fn main() -> Result<()> {
         .init();
 
     info!("Nexus starting up");
+    if pruned.files > 0 {
+        info!(
+            "Removed {} old log file(s), {}",
+            pruned.files,
+            network::stats::format_bytes(pruned.bytes)
+        );
+    }
     info!(
         "Config: fps={}, animations={}, nerd_fonts={}, scan_interval={}s, help_key={}",
         config.appearance.fps,
@@ -71,15 +150,32 @@ async fn main() -> Result<()> {
         config.keys().help
     );
 
-    // Install custom panic hook that restores terminal
+    // Install custom panic hook that restores terminal and writes a crash report
     let original_hook = panic::take_hook();
+    let redact = config.general.redact_crash_reports;
     panic::set_hook(Box::new(move |panic_info| {
         // Restore terminal before printing panic
         let _ = disable_raw_mode();
-        let _ = execute!(io::stderr(), LeaveAlternateScreen);
+        let _ = execute!(io::stderr(), DisableFocusChange, LeaveAlternateScreen);
+        let report = crash::write_report(panic_info, redact);
         original_hook(panic_info);
+        if let Some(path) = report {
+            eprintln!("\nCrash report written to {}", path.display());
+        }
     }));
 
+    // Set up event handler (tick rate from config FPS)
+    let events = EventHandler::new(config.tick_rate_ms());
+    let event_tx = events.sender();
+
+    if cli.use_mock_backend() {
+        info!("Using mock backend (demo mode)");
+        let backend = Arc::new(MockBackend::new(event_tx.clone()));
+        network::mock::start_demo_listener(event_tx.clone());
+        crash::set_nm_version("(mock backend)".to_string());
+        return run(config, theme, backend, events, &cli).await;
+    }
+
     // Initialize network backend (shared via Arc — no more re-creating per operation)
     let nm_backend = match NmBackend::new(config.interface()).await {
         Ok(b) => Arc::new(b),
@@ -88,33 +184,74 @@ async fn main() -> Result<()> {
             eprintln!("\nNexus requires NetworkManager to be running.");
             eprintln!("Install: sudo pacman -S networkmanager");
             eprintln!("Start:   sudo systemctl start NetworkManager");
+            eprintln!("Or try:  nexus --demo");
             std::process::exit(1);
         }
     };
-
-    let interface_name = nm_backend.interface_name().to_string();
-
-    // Set up event handler (tick rate from config FPS)
-    let mut events = EventHandler::new(config.tick_rate_ms());
-    let event_tx = events.sender();
+    if let Ok(version) = nm_backend.version().await {
+        crash::set_nm_version(version);
+    }
 
     // Start D-Bus signal listeners — now sends events directly via event_tx
-    let signal_conn = nm_backend.connection().clone();
+    let signal_conn = nm_backend.connection();
     let signal_device = nm_backend.device_path();
+    let signal_iface = nm_backend.interface_name();
 
-    network::signals::start_signal_listener(signal_conn, signal_device, event_tx.clone()).await;
+    let listener = network::signals::start_signal_listener(
+        signal_conn,
+        signal_device,
+        signal_iface,
+        event_tx.clone(),
+    )
+    .await;
+    network::signals::watch_bus(Arc::clone(&nm_backend), listener, event_tx.clone());
+
+    run(config, theme, nm_backend, events, &cli).await
+}
+
+/// Run the TUI against a backend until the user quits
+async fn run<B: NetworkBackend + 'static>(
+    config: config::Config,
+    theme: Theme,
+    nm_backend: Arc<B>,
+    mut events: EventHandler,
+    cli: &CliArgs,
+) -> Result<()> {
+    let interface_name = nm_backend.interface_name();
+    let event_tx = events.sender();
+
+    // Optional external control socket (off unless --control-socket is given).
+    // Only a socket we bound ourselves is removed on exit.
+    let mut control_socket = None;
+    if let Some(ref path) = cli.control_socket {
+        match control::serve(path.clone(), Arc::clone(&nm_backend), event_tx.clone()).await {
+            Ok(()) => control_socket = Some(path.clone()),
+            Err(e) => tracing::warn!("Control socket disabled: {}", e),
+        }
+    }
 
     // Set up terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableFocusChange)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
     terminal.clear()?;
     terminal.hide_cursor()?;
 
+    let retry = ConnectRetry {
+        retries: config.general.connect_retries,
+        epoch: Arc::new(AtomicU64::new(0)),
+    };
+
     // Create app state
-    let mut app = App::new(config, theme, interface_name, event_tx.clone());
+    let mut app = App::new(
+        config,
+        theme,
+        interface_name,
+        ViewState::load(),
+        event_tx.clone(),
+    );
 
     // Perform initial scan
     app.mode = AppMode::Scanning;
@@ -129,7 +266,10 @@ async fn main() -> Result<()> {
                     let _ = tx.send(Event::NetworkScan(networks));
                 }
                 Err(e) => {
-                    let _ = tx.send(Event::Error(format!("Scan failed: {}", e)));
+                    let _ = tx.send(Event::ActionError {
+                        action: "Scan",
+                        error: format!("{e:#}"),
+                    });
                 }
             }
         });
@@ -140,9 +280,14 @@ async fn main() -> Result<()> {
         let nm = Arc::clone(&nm_backend);
         let tx = event_tx.clone();
         tokio::spawn(async move {
+            if let Ok(state) = nm.device_state().await {
+                let _ = tx.send(Event::DeviceStateChanged(state));
+            }
             match nm.current_connection().await {
                 Ok(Some(info)) => {
-                    let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Connected(info)));
+                    let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Connected(
+                        Box::new(info),
+                    )));
                 }
                 Ok(None) => {
                     let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Disconnected));
@@ -157,42 +302,21 @@ async fn main() -> Result<()> {
     // ─── Main Event Loop ────────────────────────────────────────────
     info!("Entering main event loop");
 
+    // Dirty flag: only redraw when an event changed something visible
+    let mut needs_redraw = true;
+
     loop {
         // Render
-        terminal.draw(|frame| ui::render(frame, &app))?;
+        if needs_redraw {
+            crash::record(&app);
+            terminal.draw(|frame| ui::render(frame, &app))?;
+            needs_redraw = false;
+        }
 
         // Wait for next event
         if let Some(event) = events.next().await {
-            match event {
-                Event::Key(key) => {
-                    app.handle_key(key);
-                }
-
-                Event::Tick => {
-                    app.tick();
-                }
-
-                Event::Resize(w, h) => {
-                    tracing::debug!("Terminal resized to {}x{}", w, h);
-                }
-
-                Event::NetworkScan(networks) => {
-                    app.update_networks(networks);
-                }
-
-                Event::ConnectionChanged(status) => {
-                    app.update_connection_status(status);
-                }
-
-                Event::Command(cmd) => {
-                    handle_command(&nm_backend, cmd, &event_tx);
-                }
-
-                Event::Error(msg) => {
-                    app.mode = AppMode::Error(msg);
-                    app.animation.start_dialog_slide();
-                }
-            }
+            needs_redraw = handle_event(&mut app, event, &nm_backend, &event_tx, &retry);
+            events.set_tick_rate(app.tick_interval());
         }
 
         if app.should_quit {
@@ -205,12 +329,20 @@ async fn main() -> Result<()> {
 
     // Stop background event tasks first so they release stdin
     events.stop();
+    if let Some(ref path) = control_socket {
+        control::cleanup(path);
+    }
     // Give tasks a moment to exit
     tokio::time::sleep(Duration::from_millis(50)).await;
 
     // Restore terminal state
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen, cursor::Show)?;
+    execute!(
+        terminal.backend_mut(),
+        DisableFocusChange,
+        LeaveAlternateScreen,
+        cursor::Show
+    )?;
     terminal.show_cursor()?;
 
     // Flush to ensure all escape sequences are written immediately
@@ -220,13 +352,322 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Apply one event to the app, spawning backend work for commands.
+/// Returns whether the screen needs a redraw: ticks decide for
+/// themselves, everything else may mutate state.
+fn handle_event<B: NetworkBackend + 'static>(
+    app: &mut App,
+    event: Event,
+    nm: &Arc<B>,
+    tx: &tokio::sync::mpsc::UnboundedSender<Event>,
+    retry: &ConnectRetry,
+) -> bool {
+    match event {
+        Event::Key(key) => {
+            app.handle_key(key);
+        }
+
+        Event::Tick => {
+            return app.tick();
+        }
+
+        Event::Resize(w, h) => {
+            tracing::debug!("Terminal resized to {}x{}", w, h);
+        }
+
+        Event::FocusGained => {
+            app.on_focus_gained();
+        }
+
+        Event::FocusLost => {
+            app.on_focus_lost();
+        }
+
+        Event::NetworkScan(networks) => {
+            app.update_networks(networks);
+        }
+
+        Event::FreshScan(networks) => {
+            app.on_fresh_scan(networks);
+        }
+
+        Event::FreshScanFailed(error) => {
+            app.on_fresh_scan_failed(&error);
+        }
+
+        Event::ConnectionChanged(status) => {
+            return app.update_connection_status(status);
+        }
+
+        Event::DeviceStateChanged(state) => {
+            return app.update_device_state(state);
+        }
+
+        Event::ConnectRetry {
+            attempt,
+            total,
+            reason,
+        } => {
+            let lang = app.lang();
+            let msg = lang.fill(
+                "{reason} — retrying ({attempt}/{total})…",
+                &[
+                    ("reason", &lang.tr(&reason)),
+                    ("attempt", &attempt),
+                    ("total", &total),
+                ],
+            );
+            app.show_toast(msg, ToastLevel::Warning);
+        }
+
+        Event::NmLog(line) => {
+            app.on_nm_event(line);
+        }
+
+        Event::NetworkForgotten(outcome) => {
+            app.on_network_forgotten(outcome);
+        }
+
+        Event::ForgetFailed(error) => {
+            app.on_forget_failed(&error);
+        }
+
+        Event::SavedProfiles(profiles) => {
+            app.on_saved_profiles(profiles);
+        }
+
+        Event::WifiDevices(devices) => {
+            app.on_wifi_devices(devices);
+        }
+
+        Event::DeviceSwitched(interface) => {
+            app.on_device_switched(interface);
+        }
+
+        Event::BulkProgress { done, total } => {
+            app.on_bulk_progress(done, total);
+        }
+
+        Event::BulkFinished { forgotten, failed } => {
+            app.on_bulk_finished(forgotten, failed);
+        }
+
+        Event::SurveySample(aps) => {
+            app.survey.record(&aps);
+        }
+
+        Event::Exported { count, path } => {
+            let msg = app.lang().fill(
+                "Exported {count} APs to {path}",
+                &[("count", &count), ("path", &path.display())],
+            );
+            app.show_toast(msg, ToastLevel::Success);
+        }
+
+        Event::WatchdogResult {
+            target,
+            reachable,
+            nm_full,
+        } => {
+            app.on_watchdog_result(&target, reachable, nm_full);
+        }
+
+        Event::Diagnostics { run, report } => {
+            app.update_diagnostics(run, report);
+        }
+
+        Event::Resumed => {
+            app.on_resume();
+        }
+
+        Event::HttpHeaderLine { run, line } => {
+            app.on_http_header_line(run, line);
+        }
+
+        Event::PingSample { run, host, sample } => {
+            app.on_ping_sample(run, host, sample);
+        }
+
+        Event::MtuProbeLine { run, line } => {
+            app.on_mtu_probe_line(run, line);
+        }
+
+        // Nothing that needs NM can work until the bus is back
+        Event::Command(cmd) if app.offline && cmd.needs_bus() => {
+            app.settle_offline();
+        }
+
+        Event::Command(cmd) => {
+            if cmd.is_user_action() {
+                app.note_user_action();
+            }
+            handle_command(nm, cmd, tx, retry);
+        }
+
+        Event::BusLost => {
+            app.on_bus_lost();
+        }
+
+        Event::BusRestored => {
+            app.on_bus_restored();
+        }
+
+        Event::ActionError { action, error } => {
+            app.report_error(action, &error);
+        }
+
+        Event::SecretsRequired { ssid, rejected } => {
+            app.on_secrets_required(ssid, rejected);
+        }
+    }
+    true
+}
+
+/// Delay before the first connect retry; doubled for each further one
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(2);
+
+/// Auto-retry policy for activations. `epoch` is bumped by every
+/// user-initiated command, so a retry pending across a backoff can tell
+/// that the user has moved on.
+#[derive(Clone)]
+struct ConnectRetry {
+    retries: u32,
+    epoch: Arc<AtomicU64>,
+}
+
+impl ConnectRetry {
+    /// The policy for one connect, holding the epoch as of now. Taken when
+    /// the command is handled rather than when the task first runs, so a
+    /// cancel queued right behind the connect still counts.
+    fn ticket(&self) -> RetryTicket {
+        RetryTicket {
+            retries: self.retries,
+            issued: self.epoch.load(Ordering::Relaxed),
+            epoch: Arc::clone(&self.epoch),
+        }
+    }
+}
+
+/// See `ConnectRetry::ticket`
+struct RetryTicket {
+    retries: u32,
+    issued: u64,
+    epoch: Arc<AtomicU64>,
+}
+
+impl RetryTicket {
+    /// Whether the user has acted since the connect was issued
+    fn superseded(&self) -> bool {
+        self.epoch.load(Ordering::Relaxed) != self.issued
+    }
+}
+
+/// Activate a network, retrying transient failures (DHCP / supplicant
+/// timeouts) with exponential backoff, then report the outcome
+async fn connect_task<B: NetworkBackend>(
+    nm: Arc<B>,
+    tx: tokio::sync::mpsc::UnboundedSender<Event>,
+    ssid: String,
+    password: Option<String>,
+    ap_path: Option<String>,
+    hidden: bool,
+    retry: RetryTicket,
+) {
+    let total = retry.retries + 1;
+    let mut attempt = 1;
+    let result = loop {
+        let result = if hidden {
+            nm.connect_hidden(&ssid, password.as_deref()).await
+        } else {
+            nm.connect(&ssid, password.as_deref(), ap_path.as_deref())
+                .await
+        };
+        let Err(e) = result else {
+            break Ok(());
+        };
+        let retryable = e
+            .downcast_ref::<ActivationFailed>()
+            .is_some_and(ActivationFailed::is_retryable);
+        if !retryable || attempt == total {
+            break Err(e);
+        }
+
+        attempt += 1;
+        let _ = tx.send(Event::ConnectRetry {
+            attempt,
+            total,
+            reason: error::summarize(&format!("{e:#}")),
+        });
+        tokio::time::sleep(RETRY_BASE_DELAY * 2_u32.pow(attempt - 2)).await;
+        if retry.superseded() {
+            info!("Connect retry for {} cancelled by a newer action", ssid);
+            return;
+        }
+    };
+
+    match result {
+        Ok(()) => {
+            tokio::time::sleep(Duration::from_secs(3)).await;
+            match nm.current_connection().await {
+                Ok(Some(info)) => {
+                    let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Connected(
+                        Box::new(info),
+                    )));
+                }
+                _ => {
+                    let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Disconnected));
+                }
+            }
+            if let Ok(networks) = nm.scan().await {
+                let _ = tx.send(Event::NetworkScan(networks));
+            }
+        }
+        // Cancelled (or overtaken by another action) while NM was still
+        // trying: the failure is the cancel's doing, so it's not reported
+        Err(_) if retry.superseded() => {
+            info!("Connect to {} failed after it was cancelled", ssid);
+        }
+        Err(e) => {
+            let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Failed(
+                error::summarize(&format!("{e:#}")),
+            )));
+            // No password stored, or a wrong one: ask again rather than fail
+            let auth = e
+                .downcast_ref::<ActivationFailed>()
+                .filter(|failed| !hidden && failed.needs_password());
+            if let Some(failed) = auth {
+                // Don't leave NM waiting in NeedAuth for another secret agent
+                if nm.device_state().await.is_ok_and(|s| s.is_connecting()) {
+                    let _ = nm.disconnect().await;
+                }
+                let rejected =
+                    password.is_some() || failed.reason == ActivationFailed::SUPPLICANT_DISCONNECT;
+                let _ = tx.send(Event::SecretsRequired { ssid, rejected });
+                return;
+            }
+            let _ = tx.send(Event::ActionError {
+                action: if hidden {
+                    "Connect to hidden network"
+                } else {
+                    "Connect"
+                },
+                error: format!("{e:#}"),
+            });
+        }
+    }
+}
+
 /// Handle typed network commands dispatched from the UI.
-/// Each command spawns an async task that reuses the shared Arc<NmBackend>.
-fn handle_command(
-    nm: &Arc<NmBackend>,
+/// Each command spawns an async task that reuses the shared backend Arc.
+fn handle_command<B: NetworkBackend + 'static>(
+    nm: &Arc<B>,
     cmd: NetworkCommand,
     tx: &tokio::sync::mpsc::UnboundedSender<Event>,
+    retry: &ConnectRetry,
 ) {
+    if cmd.is_user_action() {
+        retry.epoch.fetch_add(1, Ordering::Relaxed);
+    }
     match cmd {
         NetworkCommand::Scan => {
             let nm = Arc::clone(nm);
@@ -237,120 +678,440 @@ fn handle_command(
                         let _ = tx.send(Event::NetworkScan(networks));
                     }
                     Err(e) => {
-                        let _ = tx.send(Event::Error(format!("Scan failed: {}", e)));
+                        let _ = tx.send(Event::ActionError {
+                            action: "Scan",
+                            error: format!("{e:#}"),
+                        });
                     }
                 }
             });
         }
 
-        NetworkCommand::Connect { ssid, password } => {
+        NetworkCommand::FreshScan => {
             let nm = Arc::clone(nm);
             let tx = tx.clone();
             tokio::spawn(async move {
-                match nm.connect(&ssid, password.as_deref()).await {
+                match nm.fresh_scan().await {
+                    Ok(networks) => {
+                        let _ = tx.send(Event::FreshScan(networks));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Event::FreshScanFailed(format!("{e:#}")));
+                    }
+                }
+            });
+        }
+
+        NetworkCommand::Connect {
+            ssid,
+            password,
+            ap_path,
+        } => {
+            tokio::spawn(connect_task(
+                Arc::clone(nm),
+                tx.clone(),
+                ssid,
+                password,
+                ap_path,
+                false,
+                retry.ticket(),
+            ));
+        }
+
+        NetworkCommand::ConnectHidden { ssid, password } => {
+            tokio::spawn(connect_task(
+                Arc::clone(nm),
+                tx.clone(),
+                ssid,
+                password,
+                None,
+                true,
+                retry.ticket(),
+            ));
+        }
+
+        NetworkCommand::Disconnect => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                match nm.disconnect().await {
                     Ok(()) => {
-                        tokio::time::sleep(Duration::from_secs(3)).await;
-                        match nm.current_connection().await {
-                            Ok(Some(info)) => {
-                                let _ = tx.send(Event::ConnectionChanged(
-                                    ConnectionStatus::Connected(info),
-                                ));
-                            }
-                            _ => {
-                                let _ = tx
-                                    .send(Event::ConnectionChanged(ConnectionStatus::Disconnected));
-                            }
-                        }
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Disconnected));
                         if let Ok(networks) = nm.scan().await {
                             let _ = tx.send(Event::NetworkScan(networks));
                         }
                     }
                     Err(e) => {
                         let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Failed(
-                            format!("{}", e),
+                            error::summarize(&format!("{e:#}")),
                         )));
+                        let _ = tx.send(Event::ActionError {
+                            action: "Disconnect",
+                            error: format!("{e:#}"),
+                        });
                     }
                 }
             });
         }
 
-        NetworkCommand::ConnectHidden { ssid, password } => {
+        NetworkCommand::Forget { ssid } => {
             let nm = Arc::clone(nm);
             let tx = tx.clone();
             tokio::spawn(async move {
-                match nm.connect_hidden(&ssid, password.as_deref()).await {
-                    Ok(()) => {
-                        tokio::time::sleep(Duration::from_secs(3)).await;
-                        match nm.current_connection().await {
-                            Ok(Some(info)) => {
-                                let _ = tx.send(Event::ConnectionChanged(
-                                    ConnectionStatus::Connected(info),
-                                ));
-                            }
-                            _ => {
-                                let _ = tx
-                                    .send(Event::ConnectionChanged(ConnectionStatus::Disconnected));
-                            }
+                match nm.forget_network(&ssid).await {
+                    Ok(outcome) => {
+                        let _ = tx.send(Event::NetworkForgotten(outcome));
+                        if let Ok(networks) = nm.scan().await {
+                            let _ = tx.send(Event::NetworkScan(networks));
                         }
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Event::ForgetFailed(format!("{e:#}")));
+                    }
+                }
+            });
+        }
+
+        NetworkCommand::ForgetMany { ssids } => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let total = ssids.len();
+                let mut forgotten = Vec::new();
+                let mut failed = Vec::new();
+                // Sequential: NM serialises settings writes anyway, and this
+                // keeps the progress count meaningful
+                for (i, ssid) in ssids.into_iter().enumerate() {
+                    let _ = tx.send(Event::BulkProgress { done: i + 1, total });
+                    match nm.forget_network(&ssid).await {
+                        Ok(_) => forgotten.push(ssid),
+                        Err(e) => failed.push((ssid, format!("{e:#}"))),
+                    }
+                }
+                let _ = tx.send(Event::BulkFinished { forgotten, failed });
+                if let Ok(networks) = nm.scan().await {
+                    let _ = tx.send(Event::NetworkScan(networks));
+                }
+            });
+        }
+
+        NetworkCommand::Restore { ssid } => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                match nm.restore_network(&ssid).await {
+                    Ok(()) => {
                         if let Ok(networks) = nm.scan().await {
                             let _ = tx.send(Event::NetworkScan(networks));
                         }
                     }
                     Err(e) => {
-                        let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Failed(
-                            format!("{}", e),
-                        )));
+                        let _ = tx.send(Event::ActionError {
+                            action: "Undo forget",
+                            error: format!("{e:#}"),
+                        });
                     }
                 }
             });
         }
 
-        NetworkCommand::Disconnect => {
+        NetworkCommand::WifiDevices => {
             let nm = Arc::clone(nm);
             let tx = tx.clone();
             tokio::spawn(async move {
-                match nm.disconnect().await {
+                match nm.wifi_devices().await {
+                    Ok(devices) => {
+                        let _ = tx.send(Event::WifiDevices(devices));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Event::ActionError {
+                            action: "WiFi adapters",
+                            error: format!("{e:#}"),
+                        });
+                    }
+                }
+            });
+        }
+
+        NetworkCommand::SwitchDevice { interface } => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                match nm.set_device(&interface).await {
+                    Ok(()) => {
+                        let _ = tx.send(Event::DeviceSwitched(interface));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Event::ActionError {
+                            action: "Switch adapter",
+                            error: format!("{e:#}"),
+                        });
+                    }
+                }
+            });
+        }
+
+        NetworkCommand::SavedProfiles => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                match nm.saved_profiles().await {
+                    Ok(profiles) => {
+                        let _ = tx.send(Event::SavedProfiles(profiles));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Event::ActionError {
+                            action: "Recent networks",
+                            error: format!("{e:#}"),
+                        });
+                    }
+                }
+            });
+        }
+
+        NetworkCommand::SetMtu { mtu } => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                match nm.set_mtu(mtu).await {
                     Ok(()) => {
                         tokio::time::sleep(Duration::from_secs(1)).await;
-                        let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Disconnected));
+                        if let Ok(Some(info)) = nm.current_connection().await {
+                            let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Connected(
+                                Box::new(info),
+                            )));
+                        }
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Event::ActionError {
+                            action: "Set MTU",
+                            error: format!("{e:#}"),
+                        });
+                    }
+                }
+            });
+        }
+
+        NetworkCommand::UpdateProfile(changes) => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                match nm.update_profile(changes).await {
+                    Ok(()) => {
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        if let Ok(Some(info)) = nm.current_connection().await {
+                            let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Connected(
+                                Box::new(info),
+                            )));
+                        }
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Event::ActionError {
+                            action: "Update connection",
+                            error: format!("{e:#}"),
+                        });
+                    }
+                }
+            });
+        }
+
+        NetworkCommand::SetConnectivityCheck { enabled } => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                match nm.set_connectivity_check(enabled).await {
+                    Ok(()) => {
+                        // NM re-evaluates connectivity right after the change
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        if let Ok(Some(info)) = nm.current_connection().await {
+                            let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Connected(
+                                Box::new(info),
+                            )));
+                        }
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Event::ActionError {
+                            action: "Connectivity check",
+                            error: format!("{e:#}"),
+                        });
+                    }
+                }
+            });
+        }
+
+        NetworkCommand::SetManaged => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                match nm.set_managed(true).await {
+                    Ok(()) => {
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        if let Ok(state) = nm.device_state().await {
+                            let _ = tx.send(Event::DeviceStateChanged(state));
+                        }
                         if let Ok(networks) = nm.scan().await {
                             let _ = tx.send(Event::NetworkScan(networks));
                         }
                     }
                     Err(e) => {
-                        let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Failed(
-                            format!("{}", e),
-                        )));
+                        let _ = tx.send(Event::ActionError {
+                            action: "Manage device",
+                            error: format!("{e:#}"),
+                        });
                     }
                 }
             });
         }
 
-        NetworkCommand::Forget { ssid } => {
+        NetworkCommand::EnableWireless => {
             let nm = Arc::clone(nm);
             let tx = tx.clone();
             tokio::spawn(async move {
-                match nm.forget_network(&ssid).await {
+                match nm.set_wireless_enabled(true).await {
                     Ok(()) => {
+                        // The radio takes a moment to come up before it can scan
+                        tokio::time::sleep(Duration::from_secs(2)).await;
+                        if let Ok(state) = nm.device_state().await {
+                            let _ = tx.send(Event::DeviceStateChanged(state));
+                        }
                         if let Ok(networks) = nm.scan().await {
                             let _ = tx.send(Event::NetworkScan(networks));
                         }
                     }
                     Err(e) => {
-                        let _ = tx.send(Event::Error(format!("Failed to forget: {}", e)));
+                        let _ = tx.send(Event::ActionError {
+                            action: "Enable WiFi",
+                            error: format!("{e:#}"),
+                        });
+                    }
+                }
+            });
+        }
+
+        NetworkCommand::SurveyScan => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let result = match nm.scan().await {
+                    Ok(networks) => {
+                        let _ = tx.send(Event::NetworkScan(networks));
+                        nm.access_points().await
+                    }
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(aps) => {
+                        let _ = tx.send(Event::SurveySample(aps));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Event::ActionError {
+                            action: "Survey scan",
+                            error: format!("{e:#}"),
+                        });
                     }
                 }
             });
         }
 
+        NetworkCommand::Export { format } => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let result = match nm.access_points().await {
+                    Ok(aps) => export::write(&aps, format).map(|path| (aps.len(), path)),
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok((count, path)) => {
+                        let _ = tx.send(Event::Exported { count, path });
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Event::ActionError {
+                            action: "Export",
+                            error: format!("{e:#}"),
+                        });
+                    }
+                }
+            });
+        }
+
+        NetworkCommand::Diagnose { run } => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let report = network::diagnostics::diagnose(nm.as_ref()).await;
+                let _ = tx.send(Event::Diagnostics { run, report });
+            });
+        }
+
+        NetworkCommand::WatchdogProbe { target } => {
+            let nm = Arc::clone(nm);
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let reachable = network::diagnostics::probe(&target).await;
+                let nm_full = matches!(nm.connectivity().await, Ok(Connectivity::Full));
+                let _ = tx.send(Event::WatchdogResult {
+                    target,
+                    reachable,
+                    nm_full,
+                });
+            });
+        }
+
+        NetworkCommand::HttpHeaders { url, run } => {
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                network::diagnostics::inspect_headers(&url, |line| {
+                    let _ = tx.send(Event::HttpHeaderLine { run, line });
+                })
+                .await;
+            });
+        }
+
+        NetworkCommand::PingCompare { hosts, run } => {
+            for (index, host) in hosts.into_iter().enumerate() {
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    let count = network::diagnostics::PING_COMPARE_COUNT;
+                    network::diagnostics::ping_stream(&host, count, |sample| {
+                        let _ = tx.send(Event::PingSample {
+                            run,
+                            host: index,
+                            sample,
+                        });
+                    })
+                    .await;
+                });
+            }
+        }
+
+        NetworkCommand::MtuProbe {
+            host,
+            iface_mtu,
+            run,
+        } => {
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                network::diagnostics::probe_path_mtu(&host, iface_mtu, |line| {
+                    let _ = tx.send(Event::MtuProbeLine { run, line });
+                })
+                .await;
+            });
+        }
+
         NetworkCommand::RefreshConnection => {
             let nm = Arc::clone(nm);
             let tx = tx.clone();
             tokio::spawn(async move {
+                if let Ok(state) = nm.device_state().await {
+                    let _ = tx.send(Event::DeviceStateChanged(state));
+                }
                 match nm.current_connection().await {
                     Ok(Some(info)) => {
-                        let _ =
-                            tx.send(Event::ConnectionChanged(ConnectionStatus::Connected(info)));
+                        let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Connected(
+                            Box::new(info),
+                        )));
                     }
                     Ok(None) => {
                         let _ = tx.send(Event::ConnectionChanged(ConnectionStatus::Disconnected));
@@ -363,3 +1124,269 @@ fn handle_command(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent};
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::favorites::Favorites;
+    use crate::ignore::IgnoreList;
+    use crate::labels::Labels;
+
+    /// The app and the mock backend wired together through the real
+    /// event dispatch, without a terminal
+    struct Harness {
+        app: App,
+        nm: Arc<MockBackend>,
+        tx: mpsc::UnboundedSender<Event>,
+        rx: mpsc::UnboundedReceiver<Event>,
+        retry: ConnectRetry,
+    }
+
+    impl Harness {
+        fn new() -> Self {
+            let (tx, rx) = mpsc::unbounded_channel();
+            let nm = Arc::new(MockBackend::new(tx.clone()));
+            let config = config::Config::default();
+            let theme = Theme::from_config(&config);
+            let retry = ConnectRetry {
+                retries: config.general.connect_retries,
+                epoch: Arc::new(AtomicU64::new(0)),
+            };
+            let mut app = App::new(
+                config,
+                theme,
+                nm.interface_name(),
+                ViewState::default(),
+                tx.clone(),
+            );
+            // Keep the user's own state files out of the picture
+            app.favorites = Favorites::default();
+            app.labels = Labels::default();
+            app.ignore = IgnoreList::default();
+            Self {
+                app,
+                nm,
+                tx,
+                rx,
+                retry,
+            }
+        }
+
+        fn dispatch(&mut self, event: Event) {
+            handle_event(&mut self.app, event, &self.nm, &self.tx, &self.retry);
+        }
+
+        fn key(&mut self, code: KeyCode) {
+            self.dispatch(Event::Key(KeyEvent::from(code)));
+        }
+
+        fn type_text(&mut self, text: &str) {
+            for c in text.chars() {
+                self.key(KeyCode::Char(c));
+            }
+        }
+
+        /// Feed backend events to the app until `done` holds. The clock is
+        /// paused, so the mock's latencies pass instantly.
+        async fn run_until(&mut self, done: impl Fn(&App) -> bool) {
+            let wait = async {
+                while !done(&self.app) {
+                    let event = self.rx.recv().await.expect("event channel closed");
+                    self.dispatch(event);
+                }
+            };
+            tokio::time::timeout(Duration::from_secs(120), wait)
+                .await
+                .expect("condition not reached");
+        }
+
+        /// Scan and wait for the results to land in the list
+        async fn scan(&mut self) {
+            self.dispatch(Event::Command(NetworkCommand::Scan));
+            self.run_until(|app| !app.networks.is_empty()).await;
+        }
+
+        fn network(&self, ssid: &str) -> &WiFiNetwork {
+            self.app
+                .networks
+                .iter()
+                .find(|n| n.ssid == ssid)
+                .unwrap_or_else(|| panic!("{ssid} not listed"))
+        }
+
+        /// Filter the list down to `query` and leave search mode
+        fn search(&mut self, query: &str) {
+            self.key(KeyCode::Char('/'));
+            self.type_text(query);
+            self.key(KeyCode::Enter);
+        }
+    }
+
+    fn connected_to(app: &App, ssid: &str) -> bool {
+        matches!(&app.connection_status, ConnectionStatus::Connected(info) if info.ssid == ssid)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn scan_lists_mock_networks() {
+        let mut h = Harness::new();
+        h.scan().await;
+
+        assert!(h.network("HomeWiFi").is_active);
+        assert!(h.network("Corp-Secure").is_saved);
+        assert!(!h.network("Neighbor-6E").is_saved);
+        // The active network sorts first
+        assert_eq!(h.app.selected_network().unwrap().ssid, "HomeWiFi");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn filter_narrows_the_list() {
+        let mut h = Harness::new();
+        h.scan().await;
+
+        h.search("homewifi");
+        let visible: Vec<&str> = h.app.visible_networks().map(|n| n.ssid.as_str()).collect();
+        assert_eq!(visible.len(), 2);
+        assert!(visible.iter().all(|ssid| ssid.starts_with("HomeWiFi")));
+        assert!(matches!(h.app.mode, AppMode::Normal));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn connect_to_saved_network() {
+        let mut h = Harness::new();
+        h.scan().await;
+
+        h.search("Corp");
+        h.key(KeyCode::Enter);
+        assert!(matches!(h.app.mode, AppMode::Connecting));
+
+        h.run_until(|app| connected_to(app, "Corp-Secure")).await;
+        h.run_until(|app| {
+            app.networks
+                .iter()
+                .any(|n| n.ssid == "Corp-Secure" && n.is_active)
+        })
+        .await;
+        assert!(!h.network("HomeWiFi").is_active);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn connect_to_new_network_asks_for_password() {
+        let mut h = Harness::new();
+        h.scan().await;
+
+        h.search("Neighbor");
+        h.key(KeyCode::Enter);
+        assert!(matches!(h.app.mode, AppMode::PasswordInput { .. }));
+        h.type_text("correct horse");
+        h.key(KeyCode::Enter);
+
+        h.run_until(|app| connected_to(app, "Neighbor-6E")).await;
+        h.run_until(|app| {
+            app.networks
+                .iter()
+                .any(|n| n.ssid == "Neighbor-6E" && n.is_saved)
+        })
+        .await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_connect_shows_error() {
+        let mut h = Harness::new();
+        h.scan().await;
+
+        // The mock's DHCP never answers on this network
+        h.search("Coffee");
+        h.key(KeyCode::Enter);
+
+        h.run_until(|app| {
+            app.toast.as_ref().is_some_and(|t| {
+                t.level == ToastLevel::Error && t.message.starts_with("Connect failed")
+            })
+        })
+        .await;
+        assert!(matches!(
+            h.app.connection_status,
+            ConnectionStatus::Failed(_)
+        ));
+        assert!(!matches!(h.app.mode, AppMode::Connecting));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancelled_connect_reports_no_failure() {
+        let mut h = Harness::new();
+        h.scan().await;
+
+        h.search("Coffee");
+        h.key(KeyCode::Enter);
+        h.run_until(|app| matches!(app.mode, AppMode::Connecting))
+            .await;
+        h.key(KeyCode::Esc);
+        h.run_until(|app| matches!(app.connection_status, ConnectionStatus::Disconnected))
+            .await;
+
+        // Let the abandoned attempt run into its DHCP failure
+        let drain = async {
+            while let Some(event) = h.rx.recv().await {
+                h.dispatch(event);
+            }
+        };
+        let _ = tokio::time::timeout(Duration::from_secs(30), drain).await;
+        assert!(!matches!(
+            h.app.connection_status,
+            ConnectionStatus::Failed(_)
+        ));
+        assert!(
+            h.app
+                .toast
+                .as_ref()
+                .is_none_or(|t| t.level != ToastLevel::Error)
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn forget_saved_network() {
+        let mut h = Harness::new();
+        h.scan().await;
+
+        h.search("Corp");
+        h.key(KeyCode::Char('f'));
+
+        h.run_until(|app| {
+            app.networks
+                .iter()
+                .any(|n| n.ssid == "Corp-Secure" && !n.is_saved)
+        })
+        .await;
+        assert!(h.network("HomeWiFi").is_saved);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn forget_active_network_needs_confirmation() {
+        let mut h = Harness::new();
+        h.scan().await;
+
+        h.key(KeyCode::Char('f'));
+        assert!(matches!(h.app.mode, AppMode::Confirm(_)));
+        h.key(KeyCode::Char('n'));
+        assert!(matches!(h.app.mode, AppMode::Normal));
+        assert!(
+            h.nm.saved_profiles()
+                .await
+                .unwrap()
+                .iter()
+                .any(|p| p.ssid == "HomeWiFi")
+        );
+
+        h.key(KeyCode::Char('f'));
+        h.key(KeyCode::Char('y'));
+        h.run_until(|app| {
+            app.networks
+                .iter()
+                .any(|n| n.ssid == "HomeWiFi" && !n.is_saved)
+        })
+        .await;
+    }
+}
@@ -0,0 +1,1075 @@
+use std::future::Future;
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::process::Command;
+use tracing::debug;
+
+use super::NetworkBackend;
+use super::types::{ConnectionInfo, Connectivity};
+
+/// Well-known public address used to test routing past the gateway
+pub const PUBLIC_IP: &str = "1.1.1.1";
+/// Host resolved to test DNS
+const DNS_PROBE_HOST: &str = "example.com";
+/// NetworkManager's own connectivity-check endpoint (plain HTTP on purpose,
+/// so captive portals can intercept it and be detected)
+const HTTP_PROBE_HOST: &str = "nmcheck.gnome.org";
+const HTTP_PROBE_PATH: &str = "/check_network_status.txt";
+const HTTP_PROBE_BODY: &str = "NetworkManager is online";
+/// Upper bound for any single step
+const STEP_TIMEOUT: Duration = Duration::from_secs(4);
+/// Redirects followed by the header inspector
+const MAX_REDIRECTS: u32 = 5;
+/// Upper bound for a whole header inspection, redirects included
+const HEADERS_TIMEOUT_SECS: u32 = 15;
+/// IPv4 + ICMP header bytes on top of a ping payload
+const ICMP_OVERHEAD: u32 = 28;
+/// Smallest MTU every IPv4 path must carry
+const MIN_PATH_MTU: u32 = 576;
+/// Assumed interface MTU when the real one is unknown
+const DEFAULT_MTU: u32 = 1500;
+/// Echo requests sent to the gateway and the public IP by a diagnosis
+const DIAG_PING_COUNT: u32 = 5;
+/// Echo requests sent to each host by the ping comparison
+pub const PING_COMPARE_COUNT: u32 = 20;
+/// Headers captive portals and intercepting proxies tend to add
+const PORTAL_HEADERS: &[&str] = &[
+    "refresh",
+    "via",
+    "x-cache",
+    "x-squid-error",
+    "proxy-authenticate",
+    "proxy-connection",
+    "x-forwarded-for",
+    "set-cookie",
+];
+
+/// The layer a diagnostic step exercises, in the order they are checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagLayer {
+    /// Be associated with a network NetworkManager reports as active
+    Link,
+    /// Reach the default gateway (link + local network)
+    Gateway,
+    /// Reach a public IP (routing past the gateway)
+    Internet,
+    /// Resolve a hostname
+    Dns,
+    /// Fetch a known page over HTTP
+    Http,
+    /// NetworkManager's own connectivity verdict
+    NmConnectivity,
+}
+
+impl DiagLayer {
+    /// Step name shown in the report
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Link => "Link",
+            Self::Gateway => "Gateway",
+            Self::Internet => "Internet (L3)",
+            Self::Dns => "DNS",
+            Self::Http => "HTTP",
+            Self::NmConnectivity => "NM connectivity",
+        }
+    }
+
+    /// What to try when this is the first step that fails
+    pub fn advice(self) -> &'static str {
+        match self {
+            Self::Link => {
+                "Not connected to WiFi, or NetworkManager is not answering. Connect first."
+            }
+            Self::Gateway => {
+                "The router is unreachable. Move closer, reconnect, or check the router."
+            }
+            Self::Internet => {
+                "The router is reachable but has no upstream connection. Check the modem/ISP."
+            }
+            Self::Dns => {
+                "The internet is reachable but names don't resolve. Try a different DNS server."
+            }
+            Self::Http => {
+                "Web traffic is blocked or redirected, likely by a captive portal. Open a browser to log in."
+            }
+            Self::NmConnectivity => {
+                "NetworkManager sees no full connectivity and may stop routing through this connection."
+            }
+        }
+    }
+}
+
+/// Outcome of one diagnostic step
+#[derive(Debug, Clone)]
+pub struct DiagStep {
+    pub layer: DiagLayer,
+    pub passed: bool,
+    /// Short detail: latency, resolved address, or the failure reason
+    pub detail: String,
+    /// How long the step took, timeout included
+    pub elapsed: Duration,
+    /// Loss and round-trip times of the ping steps
+    pub ping: Option<PingSummary>,
+}
+
+impl DiagStep {
+    fn new(layer: DiagLayer, passed: bool, detail: String) -> Self {
+        Self {
+            layer,
+            passed,
+            detail,
+            elapsed: Duration::ZERO,
+            ping: None,
+        }
+    }
+
+    /// "12 ms" / "4.0 s"
+    pub fn timing(&self) -> String {
+        let ms = self.elapsed.as_millis();
+        if ms < 1000 {
+            format!("{ms} ms")
+        } else {
+            format!("{:.1} s", self.elapsed.as_secs_f64())
+        }
+    }
+}
+
+/// Result of a full "diagnose internet" run
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticReport {
+    pub steps: Vec<DiagStep>,
+}
+
+impl DiagnosticReport {
+    /// The lowest layer that failed — where connectivity breaks
+    pub fn first_failure(&self) -> Option<&DiagStep> {
+        self.steps.iter().find(|s| !s.passed)
+    }
+
+    /// One-line verdict for the top of the report
+    pub fn summary(&self) -> String {
+        match self.first_failure() {
+            Some(step) => format!("Connectivity breaks at {}", step.layer.label()),
+            None => "Internet connection looks healthy".to_string(),
+        }
+    }
+}
+
+/// Run every step in order: link state, gateway ping, public IP ping, DNS
+/// lookup, an HTTP check and NM's connectivity verdict. Each step has its own
+/// timeout and all of them run even after a failure, so the report shows the
+/// full picture.
+pub async fn diagnose<B: NetworkBackend>(backend: &B) -> DiagnosticReport {
+    let start = Instant::now();
+    let (mut link, info) = link_state(backend).await;
+    link.elapsed = start.elapsed();
+
+    let gateway = match info.and_then(|info| info.gateway) {
+        Some(gw) => timed(ping(DiagLayer::Gateway, &gw, DIAG_PING_COUNT)).await,
+        None => DiagStep::new(DiagLayer::Gateway, false, "No default gateway".to_string()),
+    };
+
+    let steps = vec![
+        link,
+        gateway,
+        timed(ping(DiagLayer::Internet, PUBLIC_IP, DIAG_PING_COUNT)).await,
+        timed(resolve()).await,
+        timed(http_check()).await,
+        // Read last: the HTTP check may have prompted NM to re-check
+        timed(nm_connectivity(backend)).await,
+    ];
+    debug!("Diagnostics finished: {:?}", steps);
+
+    DiagnosticReport { steps }
+}
+
+/// Run a step and record how long it took
+async fn timed(step: impl Future<Output = DiagStep>) -> DiagStep {
+    let start = Instant::now();
+    let mut step = step.await;
+    step.elapsed = start.elapsed();
+    step
+}
+
+/// The active connection as NetworkManager reports it
+async fn link_state<B: NetworkBackend>(backend: &B) -> (DiagStep, Option<ConnectionInfo>) {
+    match tokio::time::timeout(STEP_TIMEOUT, backend.current_connection()).await {
+        Ok(Ok(Some(info))) => {
+            let mut detail = format!("{} — {}% signal", info.ssid, info.signal);
+            if let Some(ref ip) = info.ip4 {
+                detail.push_str(&format!(", {ip}"));
+            }
+            (DiagStep::new(DiagLayer::Link, true, detail), Some(info))
+        }
+        Ok(Ok(None)) => (
+            DiagStep::new(DiagLayer::Link, false, "Not connected".to_string()),
+            None,
+        ),
+        Ok(Err(e)) => (
+            DiagStep::new(DiagLayer::Link, false, format!("{e:#}")),
+            None,
+        ),
+        Err(_) => (
+            DiagStep::new(
+                DiagLayer::Link,
+                false,
+                "NetworkManager timed out".to_string(),
+            ),
+            None,
+        ),
+    }
+}
+
+/// NM's connectivity state; with its check turned off there is no verdict
+/// to fail on
+async fn nm_connectivity<B: NetworkBackend>(backend: &B) -> DiagStep {
+    let (passed, detail) =
+        match tokio::time::timeout(STEP_TIMEOUT, backend.current_connection()).await {
+            Ok(Ok(Some(info))) => match info.connectivity {
+                Connectivity::Full => (true, "Full".to_string()),
+                Connectivity::Unknown if !info.connectivity_check.is_active() => {
+                    (true, "Checking disabled — not verified".to_string())
+                }
+                state => (false, state.to_string()),
+            },
+            Ok(Ok(None)) => (false, "Not connected".to_string()),
+            Ok(Err(e)) => (false, format!("{e:#}")),
+            Err(_) => (false, "NetworkManager timed out".to_string()),
+        };
+    DiagStep::new(DiagLayer::NmConnectivity, passed, detail)
+}
+
+/// Send a single ICMP echo with the system `ping`
+async fn ping(layer: DiagLayer, host: &str, count: u32) -> DiagStep {
+    // The deadline keeps a burst to lost replies inside STEP_TIMEOUT
+    let output = Command::new("ping")
+        .args([
+            "-c",
+            &count.to_string(),
+            "-i",
+            "0.2",
+            "-W",
+            "2",
+            "-w",
+            "3",
+            "--",
+            host,
+        ])
+        .kill_on_drop(true)
+        .output();
+
+    let mut summary = None;
+    let (passed, detail) = match tokio::time::timeout(STEP_TIMEOUT, output).await {
+        Ok(Ok(out)) => {
+            let stdout = String::from_utf8_lossy(&out.stdout);
+            summary = parse_ping_summary(&stdout);
+            // ping exits non-zero on partial loss with a deadline, so go by
+            // the summary when there is one
+            let answered = summary
+                .as_ref()
+                .map_or(out.status.success(), |s| s.received > 0);
+            if answered {
+                let rtt = summary
+                    .as_ref()
+                    .and_then(|s| s.avg)
+                    .map(|avg| format!("{avg:.1} ms"))
+                    .or_else(|| parse_ping_time(&stdout))
+                    .unwrap_or_else(|| "reply".to_string());
+                (true, format!("{host} — {rtt}"))
+            } else {
+                (false, format!("{host} — no reply"))
+            }
+        }
+        Ok(Err(e)) => (false, format!("ping unavailable: {e}")),
+        Err(_) => (false, format!("{host} — timed out")),
+    };
+
+    DiagStep {
+        ping: summary,
+        ..DiagStep::new(layer, passed, detail)
+    }
+}
+
+/// Whether `host` answers a single ping, for the connectivity watchdog
+pub async fn probe(host: &str) -> bool {
+    ping(DiagLayer::Gateway, host, 1).await.passed
+}
+
+/// Statistics from the summary ping prints when it finishes
+#[derive(Debug, Clone, PartialEq)]
+pub struct PingSummary {
+    pub transmitted: u32,
+    pub received: u32,
+    pub loss_percent: f64,
+    pub min: Option<f64>,
+    pub avg: Option<f64>,
+    pub max: Option<f64>,
+    /// Mean deviation of the round-trip times, i.e. jitter (iputils only)
+    pub mdev: Option<f64>,
+}
+
+impl PingSummary {
+    /// "0% loss · rtt 1.9/2.4/3.2 ms · jitter 0.4 ms"
+    pub fn describe(&self) -> String {
+        let mut out = format!(
+            "{}% loss ({}/{})",
+            trim_float(self.loss_percent),
+            self.received,
+            self.transmitted
+        );
+        if let (Some(min), Some(avg), Some(max)) = (self.min, self.avg, self.max) {
+            out.push_str(&format!(" · rtt {min:.1}/{avg:.1}/{max:.1} ms"));
+        }
+        if let Some(mdev) = self.mdev {
+            out.push_str(&format!(" · jitter {mdev:.1} ms"));
+        }
+        out
+    }
+}
+
+/// "0", "33.3"
+fn trim_float(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+/// Parse the summary of iputils or busybox ping:
+///
+/// ```text
+/// 5 packets transmitted, 5 received, 0% packet loss, time 803ms
+/// rtt min/avg/max/mdev = 1.912/2.405/3.180/0.443 ms
+///
+/// 5 packets transmitted, 5 packets received, 0% packet loss
+/// round-trip min/avg/max = 1.912/2.405/3.180 ms
+/// ```
+pub fn parse_ping_summary(output: &str) -> Option<PingSummary> {
+    let mut summary: Option<PingSummary> = None;
+    for line in output.lines() {
+        let line = line.trim();
+        if line.contains("packets transmitted") {
+            let mut parts = line.split(',');
+            let count = |part: Option<&str>| -> Option<u32> {
+                part?.split_whitespace().next()?.parse().ok()
+            };
+            let transmitted = count(parts.next())?;
+            let received = count(parts.next())?;
+            let loss_percent = line
+                .split(',')
+                .find(|part| part.contains("packet loss"))?
+                .split_whitespace()
+                .next()?
+                .trim_end_matches('%')
+                .parse()
+                .ok()?;
+            summary = Some(PingSummary {
+                transmitted,
+                received,
+                loss_percent,
+                min: None,
+                avg: None,
+                max: None,
+                mdev: None,
+            });
+        } else if (line.starts_with("rtt") || line.starts_with("round-trip"))
+            && let Some(ref mut summary) = summary
+            && let Some((_, values)) = line.split_once('=')
+        {
+            // iputils may append ", pipe 2" or ", ipg/ewma …"
+            let values: Vec<f64> = values
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .split('/')
+                .filter_map(|v| v.parse().ok())
+                .collect();
+            summary.min = values.first().copied();
+            summary.avg = values.get(1).copied();
+            summary.max = values.get(2).copied();
+            summary.mdev = values.get(3).copied();
+        }
+    }
+    summary
+}
+
+/// Extract "12.3 ms" from a ping reply line
+fn parse_ping_time(output: &str) -> Option<String> {
+    let start = output.find("time=")? + "time=".len();
+    let rest = &output[start..];
+    let end = rest.find('\n').unwrap_or(rest.len());
+    Some(rest[..end].trim().to_string())
+}
+
+/// Sequence number of a ping reply line: "icmp_seq=3" (iputils) or
+/// "seq=3" (busybox)
+fn parse_ping_seq(line: &str) -> Option<u32> {
+    let start = line.find("seq=")? + "seq=".len();
+    let digits: String = line[start..]
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+/// Resolve a well-known hostname through the system resolver
+async fn resolve() -> DiagStep {
+    let lookup = tokio::net::lookup_host((DNS_PROBE_HOST, 80));
+    let (passed, detail) = match tokio::time::timeout(STEP_TIMEOUT, lookup).await {
+        Ok(Ok(mut addrs)) => match addrs.next() {
+            Some(addr) => (true, format!("{DNS_PROBE_HOST} → {}", addr.ip())),
+            None => (false, format!("{DNS_PROBE_HOST} — no addresses")),
+        },
+        Ok(Err(e)) => (false, format!("{DNS_PROBE_HOST} — {e}")),
+        Err(_) => (false, format!("{DNS_PROBE_HOST} — timed out")),
+    };
+
+    DiagStep::new(DiagLayer::Dns, passed, detail)
+}
+
+/// One line of a header inspection, streamed as curl prints it
+#[derive(Debug, Clone)]
+pub enum HeaderLine {
+    /// Status line opening hop `hop` (1-based)
+    Status {
+        hop: usize,
+        line: String,
+    },
+    Header {
+        name: String,
+        value: String,
+        /// Location, Server, or something a portal or proxy injects
+        notable: bool,
+    },
+    Error(String),
+    /// The inspection finished after `hops` responses
+    Done {
+        hops: usize,
+    },
+}
+
+impl HeaderLine {
+    /// Sort a `Name: value` header line, flagging the interesting ones
+    fn header(name: &str, value: &str) -> Self {
+        let lower = name.to_ascii_lowercase();
+        let notable = lower == "location"
+            || lower == "server"
+            || lower.contains("portal")
+            || lower.contains("captive")
+            || PORTAL_HEADERS.contains(&lower.as_str());
+        Self::Header {
+            name: name.to_string(),
+            value: value.trim().to_string(),
+            notable,
+        }
+    }
+}
+
+/// URL the header inspector offers first: plain HTTP, so a portal or
+/// transparent proxy on the path can intercept it
+pub fn default_header_url() -> String {
+    format!("http://{HTTP_PROBE_HOST}{HTTP_PROBE_PATH}")
+}
+
+/// Fetch `url` with curl, following up to `MAX_REDIRECTS` redirects, and
+/// hand the status line and headers of every hop to `emit` as they arrive.
+/// Always ends with `HeaderLine::Done`.
+pub async fn inspect_headers(url: &str, mut emit: impl FnMut(HeaderLine)) {
+    let child = Command::new("curl")
+        .args([
+            "--silent",
+            "--show-error",
+            "--location",
+            "--output",
+            "/dev/null",
+        ])
+        .args(["--dump-header", "-"])
+        .args(["--max-redirs", &MAX_REDIRECTS.to_string()])
+        .args(["--max-time", &HEADERS_TIMEOUT_SECS.to_string()])
+        // The URL is typed by the user; never let it read as an option
+        .args(["--", url])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            emit(HeaderLine::Error(format!("curl unavailable: {e}")));
+            emit(HeaderLine::Done { hops: 0 });
+            return;
+        }
+    };
+
+    let mut hops = 0;
+    if let Some(stdout) = child.stdout.take() {
+        let mut lines = BufReader::new(stdout).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            let line = line.trim_end();
+            if line.starts_with("HTTP/") {
+                hops += 1;
+                emit(HeaderLine::Status {
+                    hop: hops,
+                    line: line.to_string(),
+                });
+            } else if let Some((name, value)) = line.split_once(':') {
+                emit(HeaderLine::header(name, value));
+            }
+        }
+    }
+
+    match child.wait_with_output().await {
+        Ok(out) if out.status.success() => {}
+        Ok(out) => {
+            let stderr = String::from_utf8_lossy(&out.stderr);
+            let reason = stderr.trim().trim_start_matches("curl: ");
+            emit(HeaderLine::Error(if reason.is_empty() {
+                format!("curl failed ({})", out.status)
+            } else {
+                reason.to_string()
+            }));
+        }
+        Err(e) => emit(HeaderLine::Error(format!("curl failed: {e}"))),
+    }
+    debug!("Header inspection of {} finished after {} hops", url, hops);
+    emit(HeaderLine::Done { hops });
+}
+
+/// How a single don't-fragment ping of a given size fared
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProbeOutcome {
+    Passed,
+    /// Refused as too large, locally or by a hop; `reported` is the MTU
+    /// named in the error, when there was one
+    TooBig {
+        reported: Option<u32>,
+    },
+    /// Silently dropped (or the host doesn't answer at all)
+    NoReply,
+}
+
+/// One line of a path MTU probe, streamed as the search runs
+#[derive(Debug, Clone)]
+pub enum MtuProbeLine {
+    /// A probe of a `size`-byte IP packet
+    Probe {
+        size: u32,
+        outcome: ProbeOutcome,
+    },
+    Error(String),
+    /// The search finished; `path_mtu` is None when nothing got through
+    Done {
+        path_mtu: Option<u32>,
+        iface_mtu: u32,
+    },
+}
+
+/// Find the path MTU towards `host` by pinging it with the don't-fragment
+/// bit set, from the interface MTU (`iface_mtu`, 0 = unknown) down to the
+/// IPv4 minimum. Every probe is handed to `emit`; always ends with
+/// `MtuProbeLine::Done`.
+pub async fn probe_path_mtu(host: &str, iface_mtu: u32, mut emit: impl FnMut(MtuProbeLine)) {
+    let iface_mtu = if iface_mtu == 0 {
+        DEFAULT_MTU
+    } else {
+        iface_mtu
+    };
+    let max = iface_mtu.max(MIN_PATH_MTU);
+    let result = search_path_mtu(
+        MIN_PATH_MTU,
+        max,
+        |size| ping_df(host, size),
+        |size, outcome| {
+            emit(MtuProbeLine::Probe {
+                size,
+                outcome: outcome.clone(),
+            })
+        },
+    )
+    .await;
+    let path_mtu = result.unwrap_or_else(|e| {
+        emit(MtuProbeLine::Error(e));
+        None
+    });
+    debug!("Path MTU towards {}: {:?}", host, path_mtu);
+    emit(MtuProbeLine::Done {
+        path_mtu,
+        iface_mtu,
+    });
+}
+
+/// Binary-search the largest packet size in `min..=max` that `probe` gets
+/// through, assuming every size up to the path MTU passes and every size
+/// above it doesn't. `max` is tried first since it usually passes. None
+/// when even `min` fails; Err when the prober itself can't run.
+async fn search_path_mtu<F, Fut>(
+    min: u32,
+    max: u32,
+    probe: F,
+    mut report: impl FnMut(u32, &ProbeOutcome),
+) -> Result<Option<u32>, String>
+where
+    F: Fn(u32) -> Fut,
+    Fut: Future<Output = Result<ProbeOutcome, String>>,
+{
+    let mut passes = async |size: u32| -> Result<bool, String> {
+        let outcome = probe(size).await?;
+        report(size, &outcome);
+        Ok(outcome == ProbeOutcome::Passed)
+    };
+
+    if passes(max).await? {
+        return Ok(Some(max));
+    }
+    if max <= min || !passes(min).await? {
+        return Ok(None);
+    }
+    // `good` always passes, `bad` never does
+    let (mut good, mut bad) = (min, max);
+    while bad - good > 1 {
+        let mid = good + (bad - good) / 2;
+        if passes(mid).await? {
+            good = mid;
+        } else {
+            bad = mid;
+        }
+    }
+    Ok(Some(good))
+}
+
+/// Ping `host` once with a `size`-byte IPv4 packet and fragmentation
+/// prohibited
+async fn ping_df(host: &str, size: u32) -> Result<ProbeOutcome, String> {
+    let payload = size.saturating_sub(ICMP_OVERHEAD).to_string();
+    let output = Command::new("ping")
+        .args([
+            "-4", "-n", "-M", "do", "-c", "1", "-W", "2", "-s", &payload, "--", host,
+        ])
+        .kill_on_drop(true)
+        .output();
+
+    match tokio::time::timeout(STEP_TIMEOUT, output).await {
+        Ok(Ok(out)) if out.status.success() => Ok(ProbeOutcome::Passed),
+        Ok(Ok(out)) => {
+            let text = format!(
+                "{}{}",
+                String::from_utf8_lossy(&out.stdout),
+                String::from_utf8_lossy(&out.stderr)
+            )
+            .to_ascii_lowercase();
+            if text.contains("message too long") || text.contains("frag needed") {
+                Ok(ProbeOutcome::TooBig {
+                    reported: parse_reported_mtu(&text),
+                })
+            } else if text.contains("unknown host") || text.contains("name or service") {
+                Err(format!("{host} — cannot resolve"))
+            } else {
+                Ok(ProbeOutcome::NoReply)
+            }
+        }
+        Ok(Err(e)) => Err(format!("ping unavailable: {e}")),
+        Err(_) => Ok(ProbeOutcome::NoReply),
+    }
+}
+
+/// The MTU in "…message too long, mtu=1500" / "Frag needed … (mtu = 1400)"
+fn parse_reported_mtu(text: &str) -> Option<u32> {
+    let rest = &text[text.find("mtu")? + "mtu".len()..];
+    let rest = rest.trim_start_matches([' ', '=']);
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+/// One observation from a host's ping stream
+#[derive(Debug, Clone, PartialEq)]
+pub enum PingSample {
+    /// Echo reply with its round-trip time in milliseconds
+    Reply(f64),
+    /// A request went unanswered
+    Lost,
+    /// ping could not run or gave up (unknown host, no route, …)
+    Failed(String),
+    /// ping exited
+    Finished,
+}
+
+/// Running statistics of one host in the ping comparison
+#[derive(Debug, Clone, Default)]
+pub struct PingStats {
+    pub host: String,
+    pub sent: u32,
+    pub received: u32,
+    rtt_sum: f64,
+    pub min_rtt: Option<f64>,
+    pub max_rtt: Option<f64>,
+    pub last_rtt: Option<f64>,
+    pub error: Option<String>,
+    pub finished: bool,
+}
+
+impl PingStats {
+    pub fn new(host: String) -> Self {
+        Self {
+            host,
+            ..Self::default()
+        }
+    }
+
+    pub fn record(&mut self, sample: PingSample) {
+        match sample {
+            PingSample::Reply(rtt) => {
+                self.sent += 1;
+                self.received += 1;
+                self.rtt_sum += rtt;
+                self.min_rtt = Some(self.min_rtt.map_or(rtt, |m| m.min(rtt)));
+                self.max_rtt = Some(self.max_rtt.map_or(rtt, |m| m.max(rtt)));
+                self.last_rtt = Some(rtt);
+            }
+            PingSample::Lost => {
+                self.sent += 1;
+                self.last_rtt = None;
+            }
+            PingSample::Failed(reason) => self.error = Some(reason),
+            PingSample::Finished => self.finished = true,
+        }
+    }
+
+    /// Share of requests without a reply, 0–100
+    pub fn loss_percent(&self) -> Option<f64> {
+        (self.sent > 0).then(|| f64::from(self.sent - self.received) * 100.0 / f64::from(self.sent))
+    }
+
+    pub fn avg_rtt(&self) -> Option<f64> {
+        (self.received > 0).then(|| self.rtt_sum / f64::from(self.received))
+    }
+}
+
+/// Ping `host` `count` times, one request a second, and hand every reply
+/// and every unanswered request to `emit` as it happens. Always ends with
+/// `PingSample::Finished`.
+pub async fn ping_stream(host: &str, count: u32, mut emit: impl FnMut(PingSample)) {
+    let child = Command::new("ping")
+        .args(["-n", "-W", "2", "-c", &count.to_string(), "--", host])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            emit(PingSample::Failed(format!("ping unavailable: {e}")));
+            emit(PingSample::Finished);
+            return;
+        }
+    };
+
+    // Neither iputils nor busybox reports an unanswered request as it
+    // happens, so a gap in the sequence numbers stands in, and the summary
+    // accounts for any losses at the start or end
+    let mut counted = 0;
+    if let Some(stdout) = child.stdout.take() {
+        let mut lines = BufReader::new(stdout).lines();
+        let mut last_seq = None;
+        while let Ok(Some(line)) = lines.next_line().await {
+            if let Some(summary) = parse_ping_summary(&line) {
+                for _ in counted..summary.transmitted {
+                    emit(PingSample::Lost);
+                }
+                counted = counted.max(summary.transmitted);
+            } else if let Some(rtt) = parse_ping_time(&line)
+                .and_then(|t| t.trim_end_matches("ms").trim().parse::<f64>().ok())
+                && !line.contains("DUP!")
+            {
+                let seq = parse_ping_seq(&line);
+                if let (Some(last), Some(seq)) = (last_seq, seq) {
+                    for _ in last + 1..seq {
+                        emit(PingSample::Lost);
+                        counted += 1;
+                    }
+                }
+                last_seq = seq.or(last_seq);
+                emit(PingSample::Reply(rtt));
+                counted += 1;
+            }
+        }
+    }
+
+    if let Ok(out) = child.wait_with_output().await
+        && let Some(reason) = String::from_utf8_lossy(&out.stderr)
+            .lines()
+            .next()
+            .map(|l| l.trim_start_matches("ping: ").trim().to_string())
+            .filter(|l| !l.is_empty())
+    {
+        emit(PingSample::Failed(reason));
+    }
+    emit(PingSample::Finished);
+}
+
+/// Fetch the connectivity-check page and verify its contents
+async fn http_check() -> DiagStep {
+    let (passed, detail) = match tokio::time::timeout(STEP_TIMEOUT, fetch_probe()).await {
+        Ok(Ok(response)) => {
+            let status = response.lines().next().unwrap_or_default().to_string();
+            if status.contains(" 200") && response.contains(HTTP_PROBE_BODY) {
+                (true, format!("{HTTP_PROBE_HOST} — OK"))
+            } else if status.is_empty() {
+                (false, format!("{HTTP_PROBE_HOST} — empty response"))
+            } else {
+                (
+                    false,
+                    format!("Unexpected response ({status}) — captive portal?"),
+                )
+            }
+        }
+        Ok(Err(e)) => (false, format!("{HTTP_PROBE_HOST} — {e}")),
+        Err(_) => (false, format!("{HTTP_PROBE_HOST} — timed out")),
+    };
+
+    DiagStep::new(DiagLayer::Http, passed, detail)
+}
+
+/// Minimal HTTP/1.1 GET — no client library needed for a single probe
+async fn fetch_probe() -> std::io::Result<String> {
+    let mut stream = TcpStream::connect((HTTP_PROBE_HOST, 80)).await?;
+    let request = format!(
+        "GET {HTTP_PROBE_PATH} HTTP/1.1\r\nHost: {HTTP_PROBE_HOST}\r\nConnection: close\r\n\r\n"
+    );
+    stream.write_all(request.as_bytes()).await?;
+
+    let mut buf = Vec::with_capacity(1024);
+    stream.take(8192).read_to_end(&mut buf).await?;
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Search with a fake prober for which every size up to `path_mtu`
+    /// passes; returns the result and the sizes probed, in order
+    async fn search(min: u32, max: u32, path_mtu: u32) -> (Result<Option<u32>, String>, Vec<u32>) {
+        let mut probed = Vec::new();
+        let result = search_path_mtu(
+            min,
+            max,
+            |size| async move {
+                Ok(if size <= path_mtu {
+                    ProbeOutcome::Passed
+                } else {
+                    ProbeOutcome::TooBig {
+                        reported: Some(path_mtu),
+                    }
+                })
+            },
+            |size, _| probed.push(size),
+        )
+        .await;
+        (result, probed)
+    }
+
+    #[tokio::test]
+    async fn mtu_max_passes_first_try() {
+        let (result, probed) = search(MIN_PATH_MTU, 1500, 1500).await;
+        assert_eq!(result, Ok(Some(1500)));
+        assert_eq!(probed, [1500]);
+    }
+
+    #[tokio::test]
+    async fn mtu_found_between_bounds() {
+        for path_mtu in [MIN_PATH_MTU, 577, 1280, 1400, 1492, 1499] {
+            let (result, probed) = search(MIN_PATH_MTU, 1500, path_mtu).await;
+            assert_eq!(result, Ok(Some(path_mtu)), "path MTU {path_mtu}");
+            // max, min, then a binary search over the 924 sizes between
+            assert!(probed.len() <= 2 + 10, "{path_mtu}: {probed:?}");
+            assert_eq!(probed[..2], [1500, MIN_PATH_MTU]);
+        }
+    }
+
+    #[tokio::test]
+    async fn mtu_none_when_min_fails() {
+        let (result, probed) = search(MIN_PATH_MTU, 1500, 500).await;
+        assert_eq!(result, Ok(None));
+        assert_eq!(probed, [1500, MIN_PATH_MTU]);
+    }
+
+    #[tokio::test]
+    async fn mtu_none_when_max_at_min_fails() {
+        let (result, probed) = search(MIN_PATH_MTU, MIN_PATH_MTU, 500).await;
+        assert_eq!(result, Ok(None));
+        assert_eq!(probed, [MIN_PATH_MTU]);
+    }
+
+    #[tokio::test]
+    async fn mtu_silent_drops_count_as_too_big() {
+        let result = search_path_mtu(
+            MIN_PATH_MTU,
+            1500,
+            |size| async move {
+                Ok(if size <= 1472 {
+                    ProbeOutcome::Passed
+                } else {
+                    ProbeOutcome::NoReply
+                })
+            },
+            |_, _| {},
+        )
+        .await;
+        assert_eq!(result, Ok(Some(1472)));
+    }
+
+    #[tokio::test]
+    async fn mtu_prober_error_stops_the_search() {
+        let mut probed = Vec::new();
+        let result = search_path_mtu(
+            MIN_PATH_MTU,
+            1500,
+            |size| async move {
+                if size < 1500 {
+                    Err("ping unavailable".to_string())
+                } else {
+                    Ok(ProbeOutcome::NoReply)
+                }
+            },
+            |size, _| probed.push(size),
+        )
+        .await;
+        assert_eq!(result, Err("ping unavailable".to_string()));
+        assert_eq!(probed, [1500]);
+    }
+
+    const IPUTILS_OK: &str = "\
+PING 192.168.1.1 (192.168.1.1) 56(84) bytes of data.
+64 bytes from 192.168.1.1: icmp_seq=1 ttl=64 time=1.91 ms
+64 bytes from 192.168.1.1: icmp_seq=2 ttl=64 time=3.18 ms
+
+--- 192.168.1.1 ping statistics ---
+5 packets transmitted, 5 received, 0% packet loss, time 803ms
+rtt min/avg/max/mdev = 1.912/2.405/3.180/0.443 ms
+";
+
+    const IPUTILS_PARTIAL: &str = "\
+--- 1.1.1.1 ping statistics ---
+3 packets transmitted, 2 received, 33.3333% packet loss, time 2003ms
+rtt min/avg/max/mdev = 12.101/14.550/17.000/2.449 ms, pipe 2
+";
+
+    const IPUTILS_LOST: &str = "\
+PING 10.0.0.1 (10.0.0.1) 56(84) bytes of data.
+From 192.168.1.42 icmp_seq=1 Destination Host Unreachable
+
+--- 10.0.0.1 ping statistics ---
+3 packets transmitted, 0 received, +3 errors, 100% packet loss, time 2047ms
+pipe 3
+";
+
+    const BUSYBOX_OK: &str = "\
+PING 192.168.1.1 (192.168.1.1): 56 data bytes
+64 bytes from 192.168.1.1: seq=0 ttl=64 time=1.912 ms
+
+--- 192.168.1.1 ping statistics ---
+5 packets transmitted, 5 packets received, 0% packet loss
+round-trip min/avg/max = 1.912/2.405/3.180 ms
+";
+
+    const BUSYBOX_LOST: &str = "\
+PING 10.0.0.1 (10.0.0.1): 56 data bytes
+
+--- 10.0.0.1 ping statistics ---
+3 packets transmitted, 0 packets received, 100% packet loss
+";
+
+    #[test]
+    fn ping_summary_iputils() {
+        assert_eq!(
+            parse_ping_summary(IPUTILS_OK),
+            Some(PingSummary {
+                transmitted: 5,
+                received: 5,
+                loss_percent: 0.0,
+                min: Some(1.912),
+                avg: Some(2.405),
+                max: Some(3.18),
+                mdev: Some(0.443),
+            })
+        );
+    }
+
+    #[test]
+    fn ping_summary_partial_loss_with_pipe() {
+        let summary = parse_ping_summary(IPUTILS_PARTIAL).unwrap();
+        assert_eq!((summary.transmitted, summary.received), (3, 2));
+        assert_eq!(summary.loss_percent, 33.3333);
+        assert_eq!(summary.mdev, Some(2.449));
+        assert_eq!(
+            summary.describe(),
+            "33.3% loss (2/3) · rtt 12.1/14.6/17.0 ms · jitter 2.4 ms"
+        );
+    }
+
+    #[test]
+    fn ping_summary_total_loss() {
+        for output in [IPUTILS_LOST, BUSYBOX_LOST] {
+            let summary = parse_ping_summary(output).unwrap();
+            assert_eq!((summary.transmitted, summary.received), (3, 0));
+            assert_eq!(summary.loss_percent, 100.0);
+            assert_eq!((summary.min, summary.avg, summary.max), (None, None, None));
+            assert_eq!(summary.mdev, None);
+        }
+    }
+
+    #[test]
+    fn ping_reply_sequence_numbers() {
+        let seqs: Vec<u32> = [IPUTILS_OK, BUSYBOX_OK]
+            .iter()
+            .flat_map(|output| output.lines())
+            .filter_map(parse_ping_seq)
+            .collect();
+        assert_eq!(seqs, [1, 2, 0]);
+        assert_eq!(parse_ping_seq("3 packets transmitted, 2 received"), None);
+    }
+
+    #[test]
+    fn ping_summary_busybox() {
+        assert_eq!(
+            parse_ping_summary(BUSYBOX_OK),
+            Some(PingSummary {
+                transmitted: 5,
+                received: 5,
+                loss_percent: 0.0,
+                min: Some(1.912),
+                avg: Some(2.405),
+                max: Some(3.18),
+                mdev: None,
+            })
+        );
+    }
+
+    #[test]
+    fn ping_summary_missing() {
+        assert_eq!(parse_ping_summary(""), None);
+        assert_eq!(
+            parse_ping_summary("ping: unknown host example.invalid\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn reported_mtu_from_ping_errors() {
+        assert_eq!(
+            parse_reported_mtu("ping: local error: message too long, mtu=1500"),
+            Some(1500)
+        );
+        assert_eq!(
+            parse_reported_mtu("from 10.0.0.1 icmp_seq=1 frag needed and df set (mtu = 1400)"),
+            Some(1400)
+        );
+        assert_eq!(parse_reported_mtu("message too long"), None);
+    }
+}
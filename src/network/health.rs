@@ -0,0 +1,179 @@
+use super::stats::Counters;
+use super::types::{ConnectionInfo, Connectivity};
+use crate::i18n::Language;
+
+/// One 0–100 figure for how well the active connection is doing, with the
+/// problems that cost it points
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Health {
+    pub score: u8,
+    /// Short explanations, worst first
+    pub problems: Vec<&'static str>,
+}
+
+/// Coarse reading of a health score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthGrade {
+    Good,
+    Fair,
+    Poor,
+}
+
+impl Health {
+    /// Weigh NM's connectivity verdict, the signal, the interface's share of
+    /// errored and dropped packets over the last minute, and whether the
+    /// uplink watchdog currently gets no answer from the gateway (or its
+    /// target)
+    pub fn assess(info: &ConnectionInfo, faults: Option<&Counters>, uplink_down: bool) -> Self {
+        let mut penalties: Vec<(u8, &'static str)> = Vec::new();
+
+        match info.connectivity {
+            Connectivity::None => penalties.push((60, "no internet")),
+            Connectivity::Limited => penalties.push((40, "limited connectivity")),
+            Connectivity::Portal => penalties.push((30, "captive portal")),
+            Connectivity::Full | Connectivity::Unknown => {}
+        }
+        if uplink_down {
+            penalties.push((40, "uplink unreachable"));
+        } else if info.gateway.is_none() {
+            penalties.push((20, "no gateway"));
+        }
+        match info.signal {
+            0..30 => penalties.push((40, "signal very weak")),
+            30..50 => penalties.push((25, "signal weak")),
+            50..70 => penalties.push((10, "signal fair")),
+            _ => {}
+        }
+        match faults.and_then(Counters::fault_ratio) {
+            Some(ratio) if ratio > 0.05 => penalties.push((30, "high drops")),
+            Some(ratio) if ratio > 0.01 => penalties.push((15, "some drops")),
+            _ => {}
+        }
+
+        penalties.sort_by_key(|&(cost, _)| std::cmp::Reverse(cost));
+        let lost: u32 = penalties.iter().map(|&(cost, _)| u32::from(cost)).sum();
+        Self {
+            score: 100_u32.saturating_sub(lost) as u8,
+            problems: penalties.into_iter().map(|(_, problem)| problem).collect(),
+        }
+    }
+
+    pub fn grade(&self) -> HealthGrade {
+        match self.score {
+            80.. => HealthGrade::Good,
+            50..80 => HealthGrade::Fair,
+            _ => HealthGrade::Poor,
+        }
+    }
+
+    /// The problems, or "good" when there are none
+    pub fn summary(&self, lang: Language) -> String {
+        if self.problems.is_empty() {
+            lang.tr("good").to_string()
+        } else {
+            let problems: Vec<&str> = self.problems.iter().map(|p| lang.tr(p)).collect();
+            problems.join(", ")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(connectivity: Connectivity, signal: u8) -> ConnectionInfo {
+        ConnectionInfo {
+            gateway: Some("192.168.1.1".to_string()),
+            connectivity,
+            signal,
+            ..Default::default()
+        }
+    }
+
+    /// `faults` errored or dropped packets out of 1000
+    fn faults(faults: u64) -> Counters {
+        Counters {
+            rx_packets: 1000 - faults,
+            rx_dropped: faults,
+            ..Default::default()
+        }
+    }
+
+    fn score(info: &ConnectionInfo, faults: Option<&Counters>, uplink_down: bool) -> u8 {
+        Health::assess(info, faults, uplink_down).score
+    }
+
+    #[test]
+    fn connectivity_penalties() {
+        assert_eq!(score(&info(Connectivity::Full, 90), None, false), 100);
+        assert_eq!(score(&info(Connectivity::Unknown, 90), None, false), 100);
+        assert_eq!(score(&info(Connectivity::None, 90), None, false), 40);
+        assert_eq!(score(&info(Connectivity::Limited, 90), None, false), 60);
+        assert_eq!(score(&info(Connectivity::Portal, 90), None, false), 70);
+    }
+
+    #[test]
+    fn uplink_down_replaces_the_gateway_penalty() {
+        let mut no_gateway = info(Connectivity::Full, 90);
+        no_gateway.gateway = None;
+        assert_eq!(score(&no_gateway, None, false), 80);
+        assert_eq!(score(&no_gateway, None, true), 60);
+        assert_eq!(score(&info(Connectivity::Full, 90), None, true), 60);
+    }
+
+    #[test]
+    fn signal_thresholds() {
+        let at = |signal| score(&info(Connectivity::Full, signal), None, false);
+        assert_eq!(at(29), 60);
+        assert_eq!(at(30), 75);
+        assert_eq!(at(49), 75);
+        assert_eq!(at(50), 90);
+        assert_eq!(at(69), 90);
+        assert_eq!(at(70), 100);
+    }
+
+    #[test]
+    fn drop_ratio_thresholds() {
+        let full = info(Connectivity::Full, 90);
+        assert_eq!(score(&full, Some(&faults(10)), false), 100);
+        assert_eq!(score(&full, Some(&faults(11)), false), 85);
+        assert_eq!(score(&full, Some(&faults(50)), false), 85);
+        assert_eq!(score(&full, Some(&faults(51)), false), 70);
+        // No traffic in the window says nothing about drops
+        assert_eq!(score(&full, Some(&Counters::default()), false), 100);
+    }
+
+    #[test]
+    fn problems_worst_first_and_score_floors_at_zero() {
+        let mut bad = info(Connectivity::None, 10);
+        bad.gateway = None;
+        let health = Health::assess(&bad, Some(&faults(100)), true);
+        assert_eq!(health.score, 0);
+        assert_eq!(
+            health.problems,
+            [
+                "no internet",
+                "uplink unreachable",
+                "signal very weak",
+                "high drops"
+            ]
+        );
+        assert_eq!(health.grade(), HealthGrade::Poor);
+    }
+
+    #[test]
+    fn grades() {
+        let grade = |score| {
+            Health {
+                score,
+                problems: Vec::new(),
+            }
+            .grade()
+        };
+        assert_eq!(grade(100), HealthGrade::Good);
+        assert_eq!(grade(80), HealthGrade::Good);
+        assert_eq!(grade(79), HealthGrade::Fair);
+        assert_eq!(grade(50), HealthGrade::Fair);
+        assert_eq!(grade(49), HealthGrade::Poor);
+    }
+}
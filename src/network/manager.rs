@@ -1,25 +1,71 @@
 use std::collections::HashMap;
-use std::time::Duration;
+use std::sync::{Mutex, RwLock};
+
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
 
 use eyre::{Context, Result, bail};
 use tracing::{debug, info};
 use zbus::Connection;
-use zbus::zvariant::{ObjectPath, OwnedObjectPath, OwnedValue, Value};
+use zbus::proxy::SignalStream;
+use zbus::zvariant::{ObjectPath, OwnedObjectPath, OwnedValue, Str, Value};
 
 use super::NetworkBackend;
+use super::routes::default_route_metric;
 use super::types::*;
 
+/// A full connection settings dict as returned by `GetSettings`
+type SettingsDict = HashMap<String, HashMap<String, OwnedValue>>;
+
+/// How many forgotten profiles are kept for undo
+const MAX_FORGOTTEN: usize = 3;
+
+/// Give up waiting for an activation to succeed or fail after this long
+const ACTIVATION_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// How long an activation may sit in NeedAuth. NM waits there for a secret
+/// agent (e.g. a desktop password dialog) after a wrong password; Nexus
+/// gives up and asks itself instead.
+const NEED_AUTH_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Give up waiting for a fresh scan's LastScan to advance after this long
+const FRESH_SCAN_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// A deleted profile kept in memory so the deletion can be undone
+struct ForgottenProfile {
+    ssid: String,
+    settings: SettingsDict,
+    forgotten_at: Instant,
+}
+
 /// NetworkManager D-Bus backend
 pub struct NmBackend {
-    conn: Connection,
-    wifi_device_path: OwnedObjectPath,
-    interface: String,
+    /// Replaced by `reconnect` when the system bus comes back
+    conn: RwLock<Connection>,
+    wifi_device_path: RwLock<OwnedObjectPath>,
+    /// Replaced along with the device path by `set_device`
+    interface: RwLock<String>,
+    forgotten: Mutex<Vec<ForgottenProfile>>,
+    /// Woken by `set_device`, so the signal listener follows the new device
+    device_switched: Notify,
 }
 
 impl NmBackend {
     /// Create a new NM backend, connecting to the system D-Bus.
     /// Auto-detects a WiFi device unless `interface` is specified.
     pub async fn new(interface: Option<&str>) -> Result<Self> {
+        let (conn, device_path, iface_name) = Self::open(interface).await?;
+        Ok(Self {
+            conn: RwLock::new(conn),
+            wifi_device_path: RwLock::new(device_path),
+            interface: RwLock::new(iface_name),
+            forgotten: Mutex::new(Vec::new()),
+            device_switched: Notify::new(),
+        })
+    }
+
+    /// Connect to the system bus, check NM is there and find the device
+    async fn open(interface: Option<&str>) -> Result<(Connection, OwnedObjectPath, String)> {
         let conn = Connection::system()
             .await
             .wrap_err("Failed to connect to system D-Bus. Is D-Bus running?")?;
@@ -48,22 +94,42 @@ impl NmBackend {
         let (device_path, iface_name) = Self::find_wifi_device(&conn, interface).await?;
 
         info!("Using WiFi interface: {} ({})", iface_name, device_path);
-
-        Ok(Self {
-            conn,
-            wifi_device_path: device_path,
-            interface: iface_name,
-        })
+        Ok((conn, device_path, iface_name))
     }
 
     /// Get the D-Bus connection (for signal subscriptions)
-    pub fn connection(&self) -> &Connection {
-        &self.conn
+    pub fn connection(&self) -> Connection {
+        self.conn.read().unwrap().clone()
     }
 
     /// Get the WiFi device path
     pub fn device_path(&self) -> OwnedObjectPath {
-        self.wifi_device_path.clone()
+        self.wifi_device_path.read().unwrap().clone()
+    }
+
+    /// NetworkManager's version string, e.g. "1.46.0"
+    pub async fn version(&self) -> Result<String> {
+        Self::get_property(
+            &self.connection(),
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.NetworkManager",
+            "Version",
+        )
+        .await
+    }
+
+    /// Wait until `set_device` switches to another adapter
+    pub async fn device_switched(&self) {
+        self.device_switched.notified().await;
+    }
+
+    /// Open a fresh bus connection after the old one died, looking the
+    /// device up again since NM may have restarted along with the bus
+    pub async fn reconnect(&self) -> Result<()> {
+        let (conn, device_path, _) = Self::open(Some(&self.interface_name())).await?;
+        *self.conn.write().unwrap() = conn;
+        *self.wifi_device_path.write().unwrap() = device_path;
+        Ok(())
     }
 
     /// Call a method on the NetworkManager D-Bus interface
@@ -113,11 +179,8 @@ impl NmBackend {
         R::try_from(val).map_err(|e| eyre::eyre!("Property conversion failed for {property}: {e}"))
     }
 
-    /// Find a WiFi-capable network device
-    async fn find_wifi_device(
-        conn: &Connection,
-        preferred_interface: Option<&str>,
-    ) -> Result<(OwnedObjectPath, String)> {
+    /// Every WiFi device NM knows, as (object path, interface name)
+    async fn wifi_device_paths(conn: &Connection) -> Result<Vec<(OwnedObjectPath, String)>> {
         let devices: Vec<OwnedObjectPath> = Self::call_nm_method(
             conn,
             "/org/freedesktop/NetworkManager",
@@ -128,7 +191,8 @@ impl NmBackend {
         .await
         .wrap_err("Failed to list network devices")?;
 
-        for device_path in &devices {
+        let mut wifi = Vec::new();
+        for device_path in devices {
             let path_str = device_path.as_str();
 
             // Get device type: 2 = WiFi
@@ -157,15 +221,52 @@ impl NmBackend {
             )
             .await
             .unwrap_or_default();
+            wifi.push((device_path, iface));
+        }
+        Ok(wifi)
+    }
+
+    /// Find a WiFi-capable network device. Without a preference and with
+    /// several adapters, the one with an active connection wins, so scans
+    /// and connects both go through the radio already in use.
+    async fn find_wifi_device(
+        conn: &Connection,
+        preferred_interface: Option<&str>,
+    ) -> Result<(OwnedObjectPath, String)> {
+        let mut found = Vec::new();
+        for (device_path, iface) in Self::wifi_device_paths(conn).await? {
+            let path_str = device_path.as_str();
 
             // If user specified an interface, only match that one
-            if let Some(preferred) = preferred_interface
-                && iface != preferred
-            {
-                continue;
+            if let Some(preferred) = preferred_interface {
+                if iface != preferred {
+                    continue;
+                }
+                return Ok((device_path, iface));
             }
 
-            return Ok((device_path.clone(), iface));
+            let active: OwnedObjectPath = Self::get_property(
+                conn,
+                path_str,
+                "org.freedesktop.NetworkManager.Device",
+                "ActiveConnection",
+            )
+            .await
+            .unwrap_or_else(|_| OwnedObjectPath::try_from("/").unwrap());
+            found.push((device_path, iface, active.as_str() != "/"));
+        }
+
+        if found.len() > 1 {
+            let names: Vec<&str> = found.iter().map(|(_, iface, _)| iface.as_str()).collect();
+            info!(
+                "Several WiFi adapters ({}); pick one with --interface or at runtime",
+                names.join(", ")
+            );
+        }
+        let pick = found.iter().position(|(_, _, active)| *active).unwrap_or(0);
+        if pick < found.len() {
+            let (path, iface, _) = found.swap_remove(pick);
+            return Ok((path, iface));
         }
 
         if let Some(iface) = preferred_interface {
@@ -183,8 +284,18 @@ impl NmBackend {
 
     /// Get a list of saved connection profile SSIDs
     async fn get_saved_ssids(&self) -> Result<Vec<String>> {
+        Ok(self
+            .saved_wifi_profiles()
+            .await?
+            .into_iter()
+            .map(|p| p.ssid)
+            .collect())
+    }
+
+    /// Every saved WiFi profile with its `connection.timestamp`
+    async fn saved_wifi_profiles(&self) -> Result<Vec<SavedProfile>> {
         let conn_paths: Vec<OwnedObjectPath> = Self::call_nm_method(
-            &self.conn,
+            &self.connection(),
             "/org/freedesktop/NetworkManager/Settings",
             "org.freedesktop.NetworkManager.Settings",
             "ListConnections",
@@ -193,11 +304,11 @@ impl NmBackend {
         .await
         .unwrap_or_default();
 
-        let mut ssids = Vec::new();
+        let mut profiles = Vec::new();
 
         for conn_path in &conn_paths {
             let settings: HashMap<String, HashMap<String, OwnedValue>> = match Self::call_nm_method(
-                &self.conn,
+                &self.connection(),
                 conn_path.as_str(),
                 "org.freedesktop.NetworkManager.Settings.Connection",
                 "GetSettings",
@@ -210,17 +321,19 @@ impl NmBackend {
             };
 
             // Check if it's a WiFi connection
-            if let Some(conn_section) = settings.get("connection") {
-                let conn_type: Option<String> = conn_section
-                    .get("type")
-                    .and_then(|v| String::try_from(v.clone()).ok());
-
-                if conn_type.as_deref() != Some("802-11-wireless") {
-                    continue;
-                }
-            } else {
+            let Some(conn_section) = settings.get("connection") else {
+                continue;
+            };
+            let conn_type: Option<String> = conn_section
+                .get("type")
+                .and_then(|v| String::try_from(v.clone()).ok());
+            if conn_type.as_deref() != Some("802-11-wireless") {
                 continue;
             }
+            let last_connected = conn_section
+                .get("timestamp")
+                .and_then(|v| u64::try_from(v.clone()).ok())
+                .filter(|&t| t > 0);
 
             // Get the SSID
             if let Some(wireless) = settings.get("802-11-wireless")
@@ -229,89 +342,53 @@ impl NmBackend {
             {
                 let ssid = String::from_utf8_lossy(&ssid_bytes).to_string();
                 if !ssid.is_empty() {
-                    ssids.push(ssid);
+                    profiles.push(SavedProfile {
+                        ssid,
+                        last_connected,
+                    });
                 }
             }
         }
 
-        Ok(ssids)
+        Ok(profiles)
     }
 
-    /// Parse an access point D-Bus object into a WiFiNetwork
+    /// Parse an access point D-Bus object into a WiFiNetwork. All of its
+    /// properties come in one `GetAll` round trip.
     async fn parse_access_point(
         &self,
         ap_path: &str,
         saved_ssids: &[String],
         active_ssid: Option<&str>,
+        clock: BootClock,
     ) -> Option<WiFiNetwork> {
-        let ssid_bytes: Vec<u8> = Self::get_property(
-            &self.conn,
+        let props: HashMap<String, OwnedValue> = Self::call_nm_method(
+            &self.connection(),
             ap_path,
-            "org.freedesktop.NetworkManager.AccessPoint",
-            "Ssid",
+            "org.freedesktop.DBus.Properties",
+            "GetAll",
+            &("org.freedesktop.NetworkManager.AccessPoint",),
         )
         .await
-        .unwrap_or_default();
+        .ok()?;
 
+        let ssid_bytes: Vec<u8> = prop(&props, "Ssid").unwrap_or_default();
         let ssid = String::from_utf8_lossy(&ssid_bytes).to_string();
         // Skip hidden/empty SSIDs in the normal list
         if ssid.is_empty() {
             return None;
         }
 
-        let bssid: String = Self::get_property(
-            &self.conn,
-            ap_path,
-            "org.freedesktop.NetworkManager.AccessPoint",
-            "HwAddress",
-        )
-        .await
-        .unwrap_or_default();
-
-        let strength: u8 = Self::get_property(
-            &self.conn,
-            ap_path,
-            "org.freedesktop.NetworkManager.AccessPoint",
-            "Strength",
-        )
-        .await
-        .unwrap_or(0);
-
-        let frequency: u32 = Self::get_property(
-            &self.conn,
-            ap_path,
-            "org.freedesktop.NetworkManager.AccessPoint",
-            "Frequency",
-        )
-        .await
-        .unwrap_or(0);
-
-        let flags: u32 = Self::get_property(
-            &self.conn,
-            ap_path,
-            "org.freedesktop.NetworkManager.AccessPoint",
-            "Flags",
-        )
-        .await
-        .unwrap_or(0);
-
-        let wpa_flags: u32 = Self::get_property(
-            &self.conn,
-            ap_path,
-            "org.freedesktop.NetworkManager.AccessPoint",
-            "WpaFlags",
-        )
-        .await
-        .unwrap_or(0);
-
-        let rsn_flags: u32 = Self::get_property(
-            &self.conn,
-            ap_path,
-            "org.freedesktop.NetworkManager.AccessPoint",
-            "RsnFlags",
-        )
-        .await
-        .unwrap_or(0);
+        let bssid: String = prop(&props, "HwAddress").unwrap_or_default();
+        let strength: u8 = prop(&props, "Strength").unwrap_or(0);
+        let frequency: u32 = prop(&props, "Frequency").unwrap_or(0);
+        let max_bitrate: u32 = prop(&props, "MaxBitrate").unwrap_or(0);
+        let flags: u32 = prop(&props, "Flags").unwrap_or(0);
+        let wpa_flags: u32 = prop(&props, "WpaFlags").unwrap_or(0);
+        let rsn_flags: u32 = prop(&props, "RsnFlags").unwrap_or(0);
+        // NM keeps APs cached for a while after they vanish; LastSeen says
+        // when a scan really reported this one (-1 = never)
+        let nm_last_seen: i32 = prop(&props, "LastSeen").unwrap_or(-1);
 
         let security = SecurityType::from_flags(flags, wpa_flags, rsn_flags);
         let is_saved = saved_ssids.contains(&ssid);
@@ -322,19 +399,83 @@ impl NmBackend {
             bssid,
             signal_strength: strength,
             frequency,
+            max_bitrate: max_bitrate / 1000, // kbit/s → Mbit/s
             security,
+            security_flags: SecurityFlags {
+                wpa: wpa_flags,
+                rsn: rsn_flags,
+            },
             is_saved,
             is_active,
             ap_path: ap_path.to_string(),
             seen_ticks: 0,
             display_signal: strength as f32,
+            last_seen: clock.instant(nm_last_seen),
+            missed_scans: 0,
         })
     }
 
+    /// Subscribe to the WiFi device's `StateChanged` signal. Called *before*
+    /// activating so a quick failure can't slip past.
+    async fn device_state_changes(&self) -> Result<SignalStream<'static>> {
+        let proxy = zbus::Proxy::new(
+            &self.connection(),
+            "org.freedesktop.NetworkManager",
+            self.device_path(),
+            "org.freedesktop.NetworkManager.Device",
+        )
+        .await?;
+        Ok(proxy.receive_signal("StateChanged").await?)
+    }
+
+    /// Follow the device through an activation until it is `Activated`, or
+    /// fails with NM's reason as an `ActivationFailed`. Stalling in NeedAuth
+    /// counts as missing secrets.
+    async fn wait_for_activation(mut changes: SignalStream<'static>) -> Result<()> {
+        use futures::StreamExt;
+
+        let settle = async {
+            let mut started = false;
+            let mut need_auth = false;
+            loop {
+                let next = if need_auth {
+                    tokio::time::timeout(NEED_AUTH_TIMEOUT, changes.next())
+                        .await
+                        .map_err(|_| ActivationFailed {
+                            reason: ActivationFailed::NO_SECRETS,
+                        })?
+                } else {
+                    changes.next().await
+                };
+                let Some(msg) = next else {
+                    break;
+                };
+                let (new_state, _old, reason): (u32, u32, u32) = msg.body().deserialize()?;
+                match DeviceState::from_u32(new_state) {
+                    DeviceState::Activated => return Ok(()),
+                    DeviceState::Failed => return Err(ActivationFailed { reason }.into()),
+                    // Torn down mid-activation (disconnect, another connect)
+                    DeviceState::Disconnected if started => {
+                        return Err(ActivationFailed { reason }.into());
+                    }
+                    state => {
+                        started |= state.is_connecting();
+                        need_auth = state == DeviceState::NeedAuth;
+                    }
+                }
+            }
+            bail!("NetworkManager stopped reporting device state changes")
+        };
+
+        tokio::time::timeout(ACTIVATION_TIMEOUT, settle)
+            .await
+            .map_err(|_| eyre::eyre!("Activation timed out"))?
+    }
+
     /// Find the connection profile path for a given SSID
     async fn find_connection_for_ssid(&self, ssid: &str) -> Result<Option<OwnedObjectPath>> {
         let conn_paths: Vec<OwnedObjectPath> = Self::call_nm_method(
-            &self.conn,
+            &self.connection(),
             "/org/freedesktop/NetworkManager/Settings",
             "org.freedesktop.NetworkManager.Settings",
             "ListConnections",
@@ -345,7 +486,7 @@ impl NmBackend {
 
         for conn_path in &conn_paths {
             let settings: HashMap<String, HashMap<String, OwnedValue>> = match Self::call_nm_method(
-                &self.conn,
+                &self.connection(),
                 conn_path.as_str(),
                 "org.freedesktop.NetworkManager.Settings.Connection",
                 "GetSettings",
@@ -415,8 +556,8 @@ impl NmBackend {
     /// Get the SSID of the currently active WiFi connection
     async fn get_active_ssid(&self) -> Option<String> {
         let active_conn: OwnedObjectPath = Self::get_property(
-            &self.conn,
-            self.wifi_device_path.as_str(),
+            &self.connection(),
+            self.device_path().as_str(),
             "org.freedesktop.NetworkManager.Device",
             "ActiveConnection",
         )
@@ -429,7 +570,7 @@ impl NmBackend {
 
         // Get the connection settings path
         let conn_path: OwnedObjectPath = Self::get_property(
-            &self.conn,
+            &self.connection(),
             active_conn.as_str(),
             "org.freedesktop.NetworkManager.Connection.Active",
             "Connection",
@@ -438,7 +579,7 @@ impl NmBackend {
         .ok()?;
 
         let settings: HashMap<String, HashMap<String, OwnedValue>> = Self::call_nm_method(
-            &self.conn,
+            &self.connection(),
             conn_path.as_str(),
             "org.freedesktop.NetworkManager.Settings.Connection",
             "GetSettings",
@@ -452,16 +593,396 @@ impl NmBackend {
         let ssid_bytes = <Vec<u8>>::try_from(ssid_val.clone()).ok()?;
         Some(String::from_utf8_lossy(&ssid_bytes).to_string())
     }
+
+    /// First non-link-local IPv6 address of the WiFi device, with prefix
+    async fn global_ip6(&self) -> Option<String> {
+        let ip6_path: OwnedObjectPath = Self::get_property(
+            &self.connection(),
+            self.device_path().as_str(),
+            "org.freedesktop.NetworkManager.Device",
+            "Ip6Config",
+        )
+        .await
+        .ok()?;
+        if ip6_path.as_str() == "/" {
+            return None;
+        }
+
+        let addr_data: Vec<HashMap<String, OwnedValue>> = Self::get_property(
+            &self.connection(),
+            ip6_path.as_str(),
+            "org.freedesktop.NetworkManager.IP6Config",
+            "AddressData",
+        )
+        .await
+        .ok()?;
+
+        addr_data.iter().find_map(|a| {
+            let address = String::try_from(a.get("address")?.clone()).ok()?;
+            // fe80::/10 is always present and says nothing about connectivity
+            if address.to_ascii_lowercase().starts_with("fe80:") {
+                return None;
+            }
+            let prefix = a.get("prefix").and_then(|v| u32::try_from(v.clone()).ok());
+            Some(match prefix {
+                Some(prefix) => format!("{address}/{prefix}"),
+                None => address,
+            })
+        })
+    }
+
+    /// The WiFi device's DHCP lease behind `property` (`Dhcp4Config` or
+    /// `Dhcp6Config`), None when that family isn't configured by DHCP
+    async fn dhcp_lease(&self, property: &str, interface: &str) -> Option<DhcpLease> {
+        let config_path: OwnedObjectPath = Self::get_property(
+            &self.connection(),
+            self.device_path().as_str(),
+            "org.freedesktop.NetworkManager.Device",
+            property,
+        )
+        .await
+        .ok()?;
+        if config_path.as_str() == "/" {
+            return None;
+        }
+
+        let options: HashMap<String, OwnedValue> = Self::get_property(
+            &self.connection(),
+            config_path.as_str(),
+            interface,
+            "Options",
+        )
+        .await
+        .ok()?;
+        let options = options
+            .into_iter()
+            .filter_map(|(key, value)| Some((key, String::try_from(value).ok()?)))
+            .collect();
+        Some(DhcpLease::from_options(&options))
+    }
+
+    /// Get the settings path of the profile currently active on the WiFi device
+    async fn active_settings_path(&self) -> Result<OwnedObjectPath> {
+        let active_conn: OwnedObjectPath = Self::get_property(
+            &self.connection(),
+            self.device_path().as_str(),
+            "org.freedesktop.NetworkManager.Device",
+            "ActiveConnection",
+        )
+        .await
+        .wrap_err("Failed to read active connection")?;
+
+        if active_conn.as_str() == "/" {
+            bail!("No active WiFi connection");
+        }
+
+        Self::get_property(
+            &self.connection(),
+            active_conn.as_str(),
+            "org.freedesktop.NetworkManager.Connection.Active",
+            "Connection",
+        )
+        .await
+        .wrap_err("Failed to resolve active connection profile")
+    }
+
+    /// Read the editable settings of the active profile (defaults if there
+    /// is no active connection or the settings can't be read)
+    async fn active_profile_settings(&self) -> ProfileSettings {
+        let Ok(conn_path) = self.active_settings_path().await else {
+            return ProfileSettings::default();
+        };
+        let settings: HashMap<String, HashMap<String, OwnedValue>> = match Self::call_nm_method(
+            &self.connection(),
+            conn_path.as_str(),
+            "org.freedesktop.NetworkManager.Settings.Connection",
+            "GetSettings",
+            &(),
+        )
+        .await
+        {
+            Ok(s) => s,
+            Err(_) => return ProfileSettings::default(),
+        };
+
+        let uuid = settings
+            .get("connection")
+            .and_then(|c| c.get("uuid"))
+            .and_then(|v| String::try_from(v.clone()).ok());
+        let zone = settings
+            .get("connection")
+            .and_then(|c| c.get("zone"))
+            .and_then(|v| String::try_from(v.clone()).ok())
+            .filter(|z| !z.is_empty());
+        let autoconnect_retries = settings
+            .get("connection")
+            .and_then(|c| c.get("autoconnect-retries"))
+            .and_then(|v| i32::try_from(v.clone()).ok())
+            .and_then(|n| u32::try_from(n).ok());
+        // `Filename` exists since NM 1.12; fall back to searching the keyfile dir
+        let filename: Option<String> = Self::get_property(
+            &self.connection(),
+            conn_path.as_str(),
+            "org.freedesktop.NetworkManager.Settings.Connection",
+            "Filename",
+        )
+        .await
+        .ok()
+        .filter(|f: &String| !f.is_empty());
+        let keyfile = filename.or_else(|| {
+            uuid.as_deref()
+                .and_then(keyfile_for_uuid)
+                .map(|p| p.display().to_string())
+        });
+
+        let ipv4_method = settings
+            .get("ipv4")
+            .and_then(|ipv4| ipv4.get("method"))
+            .and_then(|v| String::try_from(v.clone()).ok());
+        let ipv6_method = settings
+            .get("ipv6")
+            .and_then(|ipv6| ipv6.get("method"))
+            .and_then(|v| String::try_from(v.clone()).ok());
+
+        let band = settings
+            .get("802-11-wireless")
+            .and_then(|wireless| wireless.get("band"))
+            .and_then(|v| String::try_from(v.clone()).ok())
+            .and_then(|b| FrequencyBand::from_nm_band(&b));
+        let bssid = settings
+            .get("802-11-wireless")
+            .and_then(|wireless| wireless.get("bssid"))
+            .and_then(|v| <Vec<u8>>::try_from(v.clone()).ok())
+            .filter(|bytes| bytes.len() == 6)
+            .map(|bytes| {
+                bytes
+                    .iter()
+                    .map(|b| format!("{b:02X}"))
+                    .collect::<Vec<_>>()
+                    .join(":")
+            });
+
+        let fast_band_bssids = settings
+            .get("user")
+            .and_then(|user| user.get("data"))
+            .and_then(|v| <HashMap<String, String>>::try_from(v.clone()).ok())
+            .and_then(|data| data.get(FAST_BAND_KEY).cloned())
+            .map(|list| list.split(',').map(str::to_string).collect())
+            .unwrap_or_default();
+
+        let proxy = settings.get("proxy");
+        let proxy_auto = proxy
+            .and_then(|p| p.get("method"))
+            .and_then(|v| i32::try_from(v.clone()).ok())
+            == Some(1);
+        let proxy_pac_url = proxy
+            .and_then(|p| p.get("pac-url"))
+            .and_then(|v| String::try_from(v.clone()).ok())
+            .filter(|url| !url.is_empty());
+
+        let key_mgmt = settings
+            .get("802-11-wireless-security")
+            .and_then(|security| security.get("key-mgmt"))
+            .and_then(|v| String::try_from(v.clone()).ok());
+        let hidden = settings
+            .get("802-11-wireless")
+            .and_then(|wireless| wireless.get("hidden"))
+            .and_then(|v| bool::try_from(v.clone()).ok())
+            .unwrap_or(false);
+
+        let mut profile = ProfileSettings {
+            uuid,
+            keyfile,
+            key_mgmt,
+            hidden,
+            ipv4_method,
+            ipv6_method,
+            band,
+            bssid,
+            fast_band_bssids,
+            proxy_auto,
+            proxy_pac_url,
+            zone,
+            autoconnect_retries,
+            ..ProfileSettings::default()
+        };
+        if let Some(ipv4) = settings.get("ipv4") {
+            let string = |key: &str| {
+                ipv4.get(key)
+                    .and_then(|v| String::try_from(v.clone()).ok())
+                    .filter(|s| !s.is_empty())
+            };
+            profile.dhcp_hostname = string("dhcp-hostname");
+            profile.dhcp_client_id = string("dhcp-client-id");
+            if let Some(send) = ipv4
+                .get("dhcp-send-hostname")
+                .and_then(|v| bool::try_from(v.clone()).ok())
+            {
+                profile.dhcp_send_hostname = send;
+            }
+            profile.route_metric = ipv4
+                .get("route-metric")
+                .and_then(|v| i64::try_from(v.clone()).ok())
+                .and_then(|m| u32::try_from(m).ok());
+        }
+        profile
+    }
+
+    /// Fetch a profile's settings merged with its wireless secrets. The flag
+    /// is false when the profile has secrets that couldn't be read (e.g.
+    /// they are owned by a user secret agent rather than stored by NM).
+    async fn settings_with_secrets(
+        &self,
+        conn_path: &OwnedObjectPath,
+    ) -> Result<(SettingsDict, bool)> {
+        let mut settings: SettingsDict = Self::call_nm_method(
+            &self.connection(),
+            conn_path.as_str(),
+            "org.freedesktop.NetworkManager.Settings.Connection",
+            "GetSettings",
+            &(),
+        )
+        .await
+        .wrap_err("Failed to read connection settings")?;
+
+        if !settings.contains_key("802-11-wireless-security") {
+            return Ok((settings, true));
+        }
+
+        let secrets: Result<SettingsDict> = Self::call_nm_method(
+            &self.connection(),
+            conn_path.as_str(),
+            "org.freedesktop.NetworkManager.Settings.Connection",
+            "GetSecrets",
+            &("802-11-wireless-security",),
+        )
+        .await;
+        let mut preserved = false;
+        match secrets {
+            Ok(secrets) => {
+                for (section, values) in secrets {
+                    preserved |= !values.is_empty();
+                    settings.entry(section).or_default().extend(values);
+                }
+            }
+            Err(e) => debug!("Could not read secrets: {}", e),
+        }
+        Ok((settings, preserved))
+    }
+
+    /// Read-modify-write a saved connection profile.
+    ///
+    /// Fetches the current settings (plus wireless secrets, so `Update`
+    /// doesn't drop the stored PSK), lets `edit` adjust them, then writes the
+    /// whole dict back with `Settings.Connection.Update`.
+    async fn update_connection_settings<F>(
+        &self,
+        conn_path: &OwnedObjectPath,
+        edit: F,
+    ) -> Result<()>
+    where
+        F: FnOnce(&mut SettingsDict),
+    {
+        let (mut settings, _) = self.settings_with_secrets(conn_path).await?;
+
+        edit(&mut settings);
+
+        let _: () = Self::call_nm_method(
+            &self.connection(),
+            conn_path.as_str(),
+            "org.freedesktop.NetworkManager.Settings.Connection",
+            "Update",
+            &(settings,),
+        )
+        .await
+        .wrap_err("Failed to update connection settings")?;
+
+        Ok(())
+    }
+
+    /// Store a password in a saved profile that has none (e.g. one imported
+    /// without secrets), so activation finds it. The flags are reset to
+    /// "stored by NetworkManager", or NM would drop the secret again.
+    async fn store_wifi_secret(&self, conn_path: &OwnedObjectPath, password: &str) -> Result<()> {
+        self.update_connection_settings(conn_path, |settings| {
+            let security = settings
+                .entry("802-11-wireless-security".to_string())
+                .or_default();
+            let wep = security
+                .get("key-mgmt")
+                .and_then(|v| String::try_from(v.clone()).ok())
+                .is_some_and(|mgmt| mgmt == "none");
+            let (key, flags) = if wep {
+                ("wep-key0", "wep-key-flags")
+            } else {
+                ("psk", "psk-flags")
+            };
+            security.insert(key.to_string(), OwnedValue::from(Str::from(password)));
+            security.insert(flags.to_string(), OwnedValue::from(0_u32));
+        })
+        .await
+        .wrap_err("Failed to store the password in the profile")
+    }
+
+    /// Push the updated profile onto the live device. Falls back to a full
+    /// re-activation when NM refuses to reapply the changed properties.
+    async fn reapply_active(&self, conn_path: &OwnedObjectPath) -> Result<()> {
+        let reapply: Result<()> = Self::call_nm_method(
+            &self.connection(),
+            self.device_path().as_str(),
+            "org.freedesktop.NetworkManager.Device",
+            "Reapply",
+            &(
+                HashMap::<String, HashMap<String, OwnedValue>>::new(),
+                0u64,
+                0u32,
+            ),
+        )
+        .await;
+
+        if let Err(e) = reapply {
+            debug!("Reapply rejected ({}), re-activating instead", e);
+            let _: OwnedObjectPath = Self::call_nm_method(
+                &self.connection(),
+                "/org/freedesktop/NetworkManager",
+                "org.freedesktop.NetworkManager",
+                "ActivateConnection",
+                &(
+                    conn_path,
+                    &self.device_path(),
+                    ObjectPath::try_from("/").unwrap(),
+                ),
+            )
+            .await
+            .wrap_err("Failed to re-activate connection")?;
+        }
+
+        Ok(())
+    }
+
+    /// Every access point of the active network on its fastest band in the
+    /// last scan, strongest first. Fails if none is above 2.4 GHz.
+    async fn fast_band_access_points(&self) -> Result<Vec<WiFiNetwork>> {
+        let Some(ssid) = self.get_active_ssid().await else {
+            bail!("No active WiFi connection");
+        };
+        let aps = self.access_points().await?;
+        let fast: Vec<WiFiNetwork> = fast_band_aps(&aps, &ssid).into_iter().cloned().collect();
+        if fast.is_empty() {
+            bail!("No 5 or 6 GHz access point of '{ssid}' is in range");
+        }
+        Ok(fast)
+    }
 }
 
 impl NetworkBackend for NmBackend {
     async fn scan(&self) -> Result<Vec<WiFiNetwork>> {
-        debug!("Requesting WiFi scan on {}", self.interface);
+        debug!("Requesting WiFi scan on {}", self.interface_name());
 
         // Request a scan (may fail silently if one is already in progress)
         let scan_result: Result<()> = Self::call_nm_method(
-            &self.conn,
-            self.wifi_device_path.as_str(),
+            &self.connection(),
+            self.device_path().as_str(),
             "org.freedesktop.NetworkManager.Device.Wireless",
             "RequestScan",
             &HashMap::<String, OwnedValue>::new(),
@@ -475,10 +996,65 @@ impl NetworkBackend for NmBackend {
         // Wait for scan to complete
         tokio::time::sleep(Duration::from_secs(2)).await;
 
-        // Get APs
+        let networks = by_ssid(self.access_points().await?);
+        info!("Scan complete: {} networks found", networks.len());
+        Ok(networks)
+    }
+
+    async fn fresh_scan(&self) -> Result<Vec<WiFiNetwork>> {
+        debug!("Requesting fresh WiFi scan on {}", self.interface_name());
+        let wireless = "org.freedesktop.NetworkManager.Device.Wireless";
+        let last_scan = || async {
+            Self::get_property::<i64>(
+                &self.connection(),
+                self.device_path().as_str(),
+                wireless,
+                "LastScan",
+            )
+            .await
+        };
+
+        let before = last_scan().await?;
+        let started = Instant::now();
+        // Unlike a normal scan, a refused request is an error here: the
+        // point is to not fall back to the cache
+        let _: () = Self::call_nm_method(
+            &self.connection(),
+            self.device_path().as_str(),
+            wireless,
+            "RequestScan",
+            &HashMap::<String, OwnedValue>::new(),
+        )
+        .await
+        .wrap_err("NetworkManager refused the scan request")?;
+
+        let deadline = started + FRESH_SCAN_TIMEOUT;
+        while last_scan().await? <= before {
+            if Instant::now() >= deadline {
+                eyre::bail!("The scan did not finish within {FRESH_SCAN_TIMEOUT:?}");
+            }
+            tokio::time::sleep(Duration::from_millis(250)).await;
+        }
+
+        // LastSeen has whole-second resolution, so allow for rounding
+        let since = started
+            .checked_sub(Duration::from_secs(1))
+            .unwrap_or(started);
+        let mut aps = self.access_points().await?;
+        let cached = aps.len();
+        aps.retain(|ap| ap.is_active || ap.last_seen >= since);
+        info!(
+            "Fresh scan: {} access points seen, {} cached ones dropped",
+            aps.len(),
+            cached - aps.len()
+        );
+        Ok(by_ssid(aps))
+    }
+
+    async fn access_points(&self) -> Result<Vec<WiFiNetwork>> {
         let ap_paths: Vec<OwnedObjectPath> = Self::call_nm_method(
-            &self.conn,
-            self.wifi_device_path.as_str(),
+            &self.connection(),
+            self.device_path().as_str(),
             "org.freedesktop.NetworkManager.Device.Wireless",
             "GetAllAccessPoints",
             &(),
@@ -489,56 +1065,47 @@ impl NetworkBackend for NmBackend {
         let saved = self.get_saved_ssids().await.unwrap_or_default();
         let active_ssid = self.get_active_ssid().await;
 
-        let mut networks = Vec::new();
-        let mut seen_ssids = std::collections::HashSet::new();
-
+        let clock = BootClock::now();
+        let mut aps = Vec::with_capacity(ap_paths.len());
         for ap_path in &ap_paths {
             if let Some(net) = self
-                .parse_access_point(ap_path.as_str(), &saved, active_ssid.as_deref())
+                .parse_access_point(ap_path.as_str(), &saved, active_ssid.as_deref(), clock)
                 .await
             {
-                // Deduplicate by SSID — keep the strongest signal
-                if let Some(existing) = networks
-                    .iter_mut()
-                    .find(|n: &&mut WiFiNetwork| n.ssid == net.ssid)
-                {
-                    if net.signal_strength > existing.signal_strength {
-                        *existing = net;
-                    }
-                } else {
-                    seen_ssids.insert(net.ssid.clone());
-                    networks.push(net);
-                }
+                aps.push(net);
             }
         }
-
-        // Sort: active first, then by signal strength descending
-        networks.sort_by(|a, b| {
-            b.is_active
-                .cmp(&a.is_active)
-                .then(b.signal_strength.cmp(&a.signal_strength))
-        });
-
-        info!("Scan complete: {} networks found", networks.len());
-        Ok(networks)
+        Ok(aps)
     }
 
-    async fn connect(&self, ssid: &str, password: Option<&str>) -> Result<()> {
+    async fn connect(
+        &self,
+        ssid: &str,
+        password: Option<&str>,
+        ap_path: Option<&str>,
+    ) -> Result<()> {
         info!("Connecting to network: {}", ssid);
 
+        let changes = self.device_state_changes().await?;
+        // "/" leaves the choice of BSSID to NM
+        let specific_object = ap_path
+            .filter(|path| !path.is_empty())
+            .and_then(|path| ObjectPath::try_from(path).ok())
+            .unwrap_or_else(|| ObjectPath::try_from("/").unwrap());
+
         // Check if we have a saved connection
         if let Some(conn_path) = self.find_connection_for_ssid(ssid).await? {
             debug!("Using saved connection profile for {}", ssid);
+            // A password for a saved profile answers a missing-secrets failure
+            if let Some(pwd) = password {
+                self.store_wifi_secret(&conn_path, pwd).await?;
+            }
             let _: OwnedObjectPath = Self::call_nm_method(
-                &self.conn,
+                &self.connection(),
                 "/org/freedesktop/NetworkManager",
                 "org.freedesktop.NetworkManager",
                 "ActivateConnection",
-                &(
-                    &conn_path,
-                    &self.wifi_device_path,
-                    ObjectPath::try_from("/").unwrap(),
-                ),
+                &(&conn_path, &self.device_path(), &specific_object),
             )
             .await
             .wrap_err_with(|| format!("Failed to activate saved connection for '{ssid}'"))?;
@@ -547,29 +1114,27 @@ impl NetworkBackend for NmBackend {
             let settings = Self::build_connection_settings(ssid, password, false);
             let (_conn_path, _active_conn): (OwnedObjectPath, OwnedObjectPath) =
                 Self::call_nm_method(
-                    &self.conn,
+                    &self.connection(),
                     "/org/freedesktop/NetworkManager",
                     "org.freedesktop.NetworkManager",
                     "AddAndActivateConnection",
-                    &(
-                        settings,
-                        &self.wifi_device_path,
-                        ObjectPath::try_from("/").unwrap(),
-                    ),
+                    &(settings, &self.device_path(), &specific_object),
                 )
                 .await
                 .wrap_err_with(|| format!("Failed to connect to '{ssid}'"))?;
         }
 
-        Ok(())
+        Self::wait_for_activation(changes)
+            .await
+            .wrap_err_with(|| format!("Failed to activate '{ssid}'"))
     }
 
     async fn disconnect(&self) -> Result<()> {
         info!("Disconnecting WiFi");
 
         let active_conn: OwnedObjectPath = Self::get_property(
-            &self.conn,
-            self.wifi_device_path.as_str(),
+            &self.connection(),
+            self.device_path().as_str(),
             "org.freedesktop.NetworkManager.Device",
             "ActiveConnection",
         )
@@ -581,7 +1146,7 @@ impl NetworkBackend for NmBackend {
         }
 
         let _: () = Self::call_nm_method(
-            &self.conn,
+            &self.connection(),
             "/org/freedesktop/NetworkManager",
             "org.freedesktop.NetworkManager",
             "DeactivateConnection",
@@ -593,7 +1158,7 @@ impl NetworkBackend for NmBackend {
         Ok(())
     }
 
-    async fn forget_network(&self, ssid: &str) -> Result<()> {
+    async fn forget_network(&self, ssid: &str) -> Result<ForgetOutcome> {
         info!("Forgetting network: {}", ssid);
 
         let conn_path = self
@@ -601,8 +1166,11 @@ impl NetworkBackend for NmBackend {
             .await?
             .ok_or_else(|| eyre::eyre!("No saved profile found for '{}'", ssid))?;
 
+        // Keep a copy for undo before the profile is gone
+        let (settings, secrets_preserved) = self.settings_with_secrets(&conn_path).await?;
+
         let _: () = Self::call_nm_method(
-            &self.conn,
+            &self.connection(),
             conn_path.as_str(),
             "org.freedesktop.NetworkManager.Settings.Connection",
             "Delete",
@@ -611,13 +1179,68 @@ impl NetworkBackend for NmBackend {
         .await
         .wrap_err_with(|| format!("Failed to delete connection profile for '{ssid}'"))?;
 
+        let mut forgotten = self.forgotten.lock().unwrap_or_else(|e| e.into_inner());
+        forgotten.retain(|p| p.forgotten_at.elapsed() < UNDO_WINDOW && p.ssid != ssid);
+        if forgotten.len() >= MAX_FORGOTTEN {
+            forgotten.remove(0);
+        }
+        forgotten.push(ForgottenProfile {
+            ssid: ssid.to_string(),
+            settings,
+            forgotten_at: Instant::now(),
+        });
+
+        Ok(ForgetOutcome {
+            ssid: ssid.to_string(),
+            secrets_preserved,
+        })
+    }
+
+    async fn saved_profiles(&self) -> Result<Vec<SavedProfile>> {
+        self.saved_wifi_profiles().await
+    }
+
+    async fn restore_network(&self, ssid: &str) -> Result<()> {
+        info!("Restoring forgotten network: {}", ssid);
+
+        let profile = {
+            let mut forgotten = self.forgotten.lock().unwrap_or_else(|e| e.into_inner());
+            forgotten.retain(|p| p.forgotten_at.elapsed() < UNDO_WINDOW);
+            let index = forgotten.iter().position(|p| p.ssid == ssid);
+            index.map(|i| forgotten.remove(i))
+        };
+        let Some(ForgottenProfile { mut settings, .. }) = profile else {
+            bail!("Nothing to undo for '{ssid}' — the undo window has expired");
+        };
+
+        // A fresh UUID so the restored profile never collides with the old one
+        let uuid = std::fs::read_to_string("/proc/sys/kernel/random/uuid")
+            .wrap_err("Failed to generate a connection UUID")?;
+        settings
+            .entry("connection".to_string())
+            .or_default()
+            .insert(
+                "uuid".to_string(),
+                OwnedValue::from(Str::from(uuid.trim().to_string())),
+            );
+
+        let _: OwnedObjectPath = Self::call_nm_method(
+            &self.connection(),
+            "/org/freedesktop/NetworkManager/Settings",
+            "org.freedesktop.NetworkManager.Settings",
+            "AddConnection",
+            &(settings,),
+        )
+        .await
+        .wrap_err_with(|| format!("Failed to restore connection profile for '{ssid}'"))?;
+
         Ok(())
     }
 
     async fn current_connection(&self) -> Result<Option<ConnectionInfo>> {
         let active_conn_path: OwnedObjectPath = match Self::get_property(
-            &self.conn,
-            self.wifi_device_path.as_str(),
+            &self.connection(),
+            self.device_path().as_str(),
             "org.freedesktop.NetworkManager.Device",
             "ActiveConnection",
         )
@@ -635,8 +1258,8 @@ impl NetworkBackend for NmBackend {
 
         // Get IP4 config
         let ip4_path: OwnedObjectPath = Self::get_property(
-            &self.conn,
-            self.wifi_device_path.as_str(),
+            &self.connection(),
+            self.device_path().as_str(),
             "org.freedesktop.NetworkManager.Device",
             "Ip4Config",
         )
@@ -646,7 +1269,7 @@ impl NetworkBackend for NmBackend {
         let ip4 = if ip4_path.as_str() != "/" {
             // Get address data
             let addr_data: Vec<HashMap<String, OwnedValue>> = Self::get_property(
-                &self.conn,
+                &self.connection(),
                 ip4_path.as_str(),
                 "org.freedesktop.NetworkManager.IP4Config",
                 "AddressData",
@@ -662,9 +1285,11 @@ impl NetworkBackend for NmBackend {
             None
         };
 
+        let ip6 = self.global_ip6().await;
+
         let gateway: Option<String> = if ip4_path.as_str() != "/" {
             Self::get_property(
-                &self.conn,
+                &self.connection(),
                 ip4_path.as_str(),
                 "org.freedesktop.NetworkManager.IP4Config",
                 "Gateway",
@@ -677,8 +1302,8 @@ impl NetworkBackend for NmBackend {
 
         // Get HW address
         let mac: String = Self::get_property(
-            &self.conn,
-            self.wifi_device_path.as_str(),
+            &self.connection(),
+            self.device_path().as_str(),
             "org.freedesktop.NetworkManager.Device",
             "HwAddress",
         )
@@ -687,8 +1312,8 @@ impl NetworkBackend for NmBackend {
 
         // Get active AP for signal & frequency
         let active_ap: OwnedObjectPath = Self::get_property(
-            &self.conn,
-            self.wifi_device_path.as_str(),
+            &self.connection(),
+            self.device_path().as_str(),
             "org.freedesktop.NetworkManager.Device.Wireless",
             "ActiveAccessPoint",
         )
@@ -697,7 +1322,7 @@ impl NetworkBackend for NmBackend {
 
         let (signal, frequency, bssid) = if active_ap.as_str() != "/" {
             let sig: u8 = Self::get_property(
-                &self.conn,
+                &self.connection(),
                 active_ap.as_str(),
                 "org.freedesktop.NetworkManager.AccessPoint",
                 "Strength",
@@ -706,7 +1331,7 @@ impl NetworkBackend for NmBackend {
             .unwrap_or(0);
 
             let freq: u32 = Self::get_property(
-                &self.conn,
+                &self.connection(),
                 active_ap.as_str(),
                 "org.freedesktop.NetworkManager.AccessPoint",
                 "Frequency",
@@ -715,7 +1340,7 @@ impl NetworkBackend for NmBackend {
             .unwrap_or(0);
 
             let bss: String = Self::get_property(
-                &self.conn,
+                &self.connection(),
                 active_ap.as_str(),
                 "org.freedesktop.NetworkManager.AccessPoint",
                 "HwAddress",
@@ -728,10 +1353,19 @@ impl NetworkBackend for NmBackend {
             (0, 0, String::new())
         };
 
+        let mtu: u32 = Self::get_property(
+            &self.connection(),
+            self.device_path().as_str(),
+            "org.freedesktop.NetworkManager.Device",
+            "Mtu",
+        )
+        .await
+        .unwrap_or(0);
+
         // Get bitrate
         let speed: u32 = Self::get_property(
-            &self.conn,
-            self.wifi_device_path.as_str(),
+            &self.connection(),
+            self.device_path().as_str(),
             "org.freedesktop.NetworkManager.Device.Wireless",
             "Bitrate",
         )
@@ -743,39 +1377,455 @@ impl NetworkBackend for NmBackend {
             ssid,
             bssid,
             ip4,
-            ip6: None,
+            ip6,
             gateway,
             dns: Vec::new(),
             mac,
             speed,
+            mtu,
             frequency,
             signal,
-            interface: self.interface.clone(),
+            interface: self.interface_name(),
+            profile: self.active_profile_settings().await,
+            connectivity: self.connectivity().await.unwrap_or_default(),
+            connectivity_check: self.connectivity_check().await.unwrap_or_default(),
+            dhcp4: self
+                .dhcp_lease("Dhcp4Config", "org.freedesktop.NetworkManager.DHCP4Config")
+                .await,
+            dhcp6: self
+                .dhcp_lease("Dhcp6Config", "org.freedesktop.NetworkManager.DHCP6Config")
+                .await,
+            route_metric: default_route_metric(&self.interface_name()),
         }))
     }
 
     async fn connect_hidden(&self, ssid: &str, password: Option<&str>) -> Result<()> {
         info!("Connecting to hidden network: {}", ssid);
 
+        let changes = self.device_state_changes().await?;
         let settings = Self::build_connection_settings(ssid, password, true);
         let (_conn_path, _active_conn): (OwnedObjectPath, OwnedObjectPath) = Self::call_nm_method(
-            &self.conn,
+            &self.connection(),
             "/org/freedesktop/NetworkManager",
             "org.freedesktop.NetworkManager",
             "AddAndActivateConnection",
             &(
                 settings,
-                &self.wifi_device_path,
+                &self.device_path(),
                 ObjectPath::try_from("/").unwrap(),
             ),
         )
         .await
         .wrap_err_with(|| format!("Failed to connect to hidden network '{ssid}'"))?;
 
+        Self::wait_for_activation(changes)
+            .await
+            .wrap_err_with(|| format!("Failed to activate hidden network '{ssid}'"))
+    }
+
+    async fn set_mtu(&self, mtu: u32) -> Result<()> {
+        info!("Setting MTU of active connection to {}", mtu);
+
+        let conn_path = self.active_settings_path().await?;
+        self.update_connection_settings(&conn_path, |settings| {
+            settings
+                .entry("802-11-wireless".to_string())
+                .or_default()
+                .insert("mtu".to_string(), OwnedValue::from(mtu));
+        })
+        .await?;
+        self.reapply_active(&conn_path).await
+    }
+
+    async fn update_profile(&self, changes: Vec<ProfileChange>) -> Result<()> {
+        for change in &changes {
+            info!("Updating active profile: {}", change);
+        }
+
+        // Steering needs the target access points before the settings are read
+        let fast = if changes.contains(&ProfileChange::FastBand(true)) {
+            self.fast_band_access_points().await?
+        } else {
+            Vec::new()
+        };
+
+        let conn_path = self.active_settings_path().await?;
+        self.update_connection_settings(&conn_path, |settings| {
+            let band_value = |band: Option<FrequencyBand>| -> Option<OwnedValue> {
+                band.and_then(FrequencyBand::nm_band)
+                    .map(|b| OwnedValue::from(Str::from(b)))
+            };
+            for change in changes {
+                let edits: Vec<(&str, &str, Option<OwnedValue>)> = match change {
+                    ProfileChange::Hostname(name) => vec![(
+                        "ipv4",
+                        "dhcp-hostname",
+                        name.map(|n| OwnedValue::from(Str::from(n))),
+                    )],
+                    ProfileChange::SendHostname(send) => {
+                        vec![("ipv4", "dhcp-send-hostname", Some(OwnedValue::from(send)))]
+                    }
+                    ProfileChange::ClientId(id) => vec![(
+                        "ipv4",
+                        "dhcp-client-id",
+                        id.map(|i| OwnedValue::from(Str::from(i))),
+                    )],
+                    // -1 is NM's "use the device type's default" (600 for WiFi)
+                    ProfileChange::RouteMetric(metric) => {
+                        let metric = metric.map_or(-1, i64::from);
+                        vec![
+                            ("ipv4", "route-metric", Some(OwnedValue::from(metric))),
+                            ("ipv6", "route-metric", Some(OwnedValue::from(metric))),
+                        ]
+                    }
+                    ProfileChange::Band(band) => {
+                        vec![("802-11-wireless", "band", band_value(band))]
+                    }
+                    // The band keeps NM on any of the network's 5 GHz access
+                    // points. 6 GHz has no `band` value, and NM takes only one
+                    // BSSID, so there the strongest is locked.
+                    ProfileChange::FastBand(steer) => {
+                        let (band, bssid, pinned) = match fast.first().filter(|_| steer) {
+                            Some(ap) => (
+                                band_value(Some(ap.band())),
+                                parse_bssid(&ap.bssid)
+                                    .filter(|_| ap.band().nm_band().is_none())
+                                    .and_then(|b| OwnedValue::try_from(Value::from(b)).ok()),
+                                Some(
+                                    fast.iter()
+                                        .map(|ap| ap.bssid.as_str())
+                                        .collect::<Vec<_>>()
+                                        .join(","),
+                                ),
+                            ),
+                            None => (None, None, None),
+                        };
+                        set_user_data(settings, FAST_BAND_KEY, pinned);
+                        vec![
+                            ("802-11-wireless", "band", band),
+                            ("802-11-wireless", "bssid", bssid),
+                        ]
+                    }
+                    // NMSettingProxyMethod: 0 = none, 1 = auto
+                    ProfileChange::ProxyAuto(auto) => {
+                        vec![("proxy", "method", Some(OwnedValue::from(i32::from(auto))))]
+                    }
+                    ProfileChange::PacUrl(url) => vec![(
+                        "proxy",
+                        "pac-url",
+                        url.map(|u| OwnedValue::from(Str::from(u))),
+                    )],
+                    ProfileChange::Zone(zone) => vec![(
+                        "connection",
+                        "zone",
+                        zone.map(|z| OwnedValue::from(Str::from(z))),
+                    )],
+                    // int32 in NM; -1 is "use the global default"
+                    ProfileChange::AutoconnectRetries(retries) => {
+                        let retries = retries.and_then(|n| i32::try_from(n).ok()).unwrap_or(-1);
+                        vec![(
+                            "connection",
+                            "autoconnect-retries",
+                            Some(OwnedValue::from(retries)),
+                        )]
+                    }
+                };
+                for (section, key, value) in edits {
+                    let section = settings.entry(section.to_string()).or_default();
+                    match value {
+                        Some(value) => {
+                            section.insert(key.to_string(), value);
+                        }
+                        None => {
+                            section.remove(key);
+                        }
+                    }
+                }
+            }
+        })
+        .await?;
+        self.reapply_active(&conn_path).await
+    }
+
+    async fn device_state(&self) -> Result<DeviceState> {
+        let state: u32 = Self::get_property(
+            &self.connection(),
+            self.device_path().as_str(),
+            "org.freedesktop.NetworkManager.Device",
+            "State",
+        )
+        .await?;
+        Ok(DeviceState::from_u32(state))
+    }
+
+    async fn set_managed(&self, managed: bool) -> Result<()> {
+        info!("Setting Managed={} on {}", managed, self.interface_name());
+
+        let _: () = Self::call_nm_method(
+            &self.connection(),
+            self.device_path().as_str(),
+            "org.freedesktop.DBus.Properties",
+            "Set",
+            &(
+                "org.freedesktop.NetworkManager.Device",
+                "Managed",
+                Value::from(managed),
+            ),
+        )
+        .await
+        .wrap_err("Failed to change the device's managed state")?;
+
+        Ok(())
+    }
+
+    async fn set_wireless_enabled(&self, enabled: bool) -> Result<()> {
+        info!("Setting WirelessEnabled={}", enabled);
+
+        let hardware_enabled: bool = Self::get_property(
+            &self.connection(),
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.NetworkManager",
+            "WirelessHardwareEnabled",
+        )
+        .await
+        .unwrap_or(true);
+        if enabled && !hardware_enabled {
+            bail!("WiFi is hard-blocked — flip the hardware switch or airplane-mode key");
+        }
+
+        let _: () = Self::call_nm_method(
+            &self.connection(),
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.DBus.Properties",
+            "Set",
+            &(
+                "org.freedesktop.NetworkManager",
+                "WirelessEnabled",
+                Value::from(enabled),
+            ),
+        )
+        .await
+        .wrap_err("Failed to change the WiFi radio state")?;
+
+        Ok(())
+    }
+
+    async fn connectivity(&self) -> Result<Connectivity> {
+        let state: u32 = Self::get_property(
+            &self.connection(),
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.NetworkManager",
+            "Connectivity",
+        )
+        .await?;
+        Ok(Connectivity::from_u32(state))
+    }
+
+    async fn connectivity_check(&self) -> Result<ConnectivityCheck> {
+        let enabled: bool = Self::get_property(
+            &self.connection(),
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.NetworkManager",
+            "ConnectivityCheckEnabled",
+        )
+        .await?;
+        // Both exist since NM 1.20
+        let available: bool = Self::get_property(
+            &self.connection(),
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.NetworkManager",
+            "ConnectivityCheckAvailable",
+        )
+        .await
+        .unwrap_or(true);
+        let uri: Option<String> = Self::get_property(
+            &self.connection(),
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.NetworkManager",
+            "ConnectivityCheckUri",
+        )
+        .await
+        .ok()
+        .filter(|uri: &String| !uri.is_empty());
+        Ok(ConnectivityCheck {
+            available,
+            enabled,
+            uri,
+        })
+    }
+
+    async fn set_connectivity_check(&self, enabled: bool) -> Result<()> {
+        info!("Setting ConnectivityCheckEnabled={}", enabled);
+
+        let _: () = Self::call_nm_method(
+            &self.connection(),
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.DBus.Properties",
+            "Set",
+            &(
+                "org.freedesktop.NetworkManager",
+                "ConnectivityCheckEnabled",
+                Value::from(enabled),
+            ),
+        )
+        .await
+        .wrap_err("Failed to change the connectivity check")?;
+
+        Ok(())
+    }
+
+    async fn wifi_devices(&self) -> Result<Vec<WifiDevice>> {
+        let conn = self.connection();
+        let mut devices = Vec::new();
+        for (path, interface) in Self::wifi_device_paths(&conn).await? {
+            let driver: String = Self::get_property(
+                &conn,
+                path.as_str(),
+                "org.freedesktop.NetworkManager.Device",
+                "Driver",
+            )
+            .await
+            .unwrap_or_default();
+            let state: u32 = Self::get_property(
+                &conn,
+                path.as_str(),
+                "org.freedesktop.NetworkManager.Device",
+                "State",
+            )
+            .await
+            .unwrap_or_default();
+            devices.push(WifiDevice {
+                interface,
+                driver,
+                state: DeviceState::from_u32(state),
+            });
+        }
+        Ok(devices)
+    }
+
+    async fn set_device(&self, interface: &str) -> Result<()> {
+        let (path, interface) = Self::find_wifi_device(&self.connection(), Some(interface)).await?;
+        info!("Switching to WiFi interface: {} ({})", interface, path);
+        *self.wifi_device_path.write().unwrap() = path;
+        *self.interface.write().unwrap() = interface;
+        self.device_switched.notify_one();
         Ok(())
     }
 
-    fn interface_name(&self) -> &str {
-        &self.interface
+    fn interface_name(&self) -> String {
+        self.interface.read().unwrap().clone()
+    }
+}
+
+/// One entry per SSID from per-BSSID access points, active first, then
+/// strongest first
+fn by_ssid(aps: Vec<WiFiNetwork>) -> Vec<WiFiNetwork> {
+    let mut networks: Vec<WiFiNetwork> = Vec::new();
+    for net in aps {
+        // Keep the strongest AP, with its own BSSID and last sighting
+        if let Some(existing) = networks.iter_mut().find(|n| n.ssid == net.ssid) {
+            if net.signal_strength > existing.signal_strength {
+                *existing = net;
+            }
+        } else {
+            networks.push(net);
+        }
+    }
+    networks.sort_by(|a, b| {
+        b.is_active
+            .cmp(&a.is_active)
+            .then(b.signal_strength.cmp(&a.signal_strength))
+    });
+    networks
+}
+
+/// One property out of a `GetAll` reply
+fn prop<R: TryFrom<OwnedValue>>(props: &HashMap<String, OwnedValue>, name: &str) -> Option<R> {
+    R::try_from(props.get(name)?.try_clone().ok()?).ok()
+}
+
+/// The boot clock NM stamps `LastSeen` with, read once per scan
+#[derive(Debug, Clone, Copy)]
+struct BootClock {
+    now: Instant,
+    /// Seconds since boot per /proc/uptime, which counts on CLOCK_BOOTTIME
+    uptime: Option<u64>,
+}
+
+impl BootClock {
+    fn now() -> Self {
+        let uptime = std::fs::read_to_string("/proc/uptime")
+            .ok()
+            .and_then(|s| s.split_whitespace().next()?.parse::<f64>().ok());
+        Self {
+            now: Instant::now(),
+            uptime: uptime.map(|u| u as u64),
+        }
+    }
+
+    /// Map an AP's `LastSeen` (CLOCK_BOOTTIME seconds) onto an `Instant`.
+    /// When either is unknown the AP is taken as seen just now.
+    fn instant(self, nm_last_seen: i32) -> Instant {
+        match (u64::try_from(nm_last_seen), self.uptime) {
+            (Ok(seen), Some(uptime)) => {
+                let age = Duration::from_secs(uptime.saturating_sub(seen));
+                self.now.checked_sub(age).unwrap_or(self.now)
+            }
+            _ => self.now,
+        }
+    }
+}
+
+/// `user.data` key holding the BSSIDs "prefer faster band" pinned, comma
+/// separated
+const FAST_BAND_KEY: &str = "nexus.fast-band-bssids";
+
+/// Set (or with None, remove) one `user.data` entry, keeping the others
+fn set_user_data(settings: &mut SettingsDict, key: &str, value: Option<String>) {
+    let user = settings.entry("user".to_string()).or_default();
+    let mut data: HashMap<String, String> = user
+        .get("data")
+        .and_then(|v| HashMap::try_from(v.try_clone().ok()?).ok())
+        .unwrap_or_default();
+    match value {
+        Some(value) => {
+            data.insert(key.to_string(), value);
+        }
+        None => {
+            data.remove(key);
+        }
     }
+    if data.is_empty() {
+        user.remove("data");
+    } else if let Ok(data) = OwnedValue::try_from(Value::from(data)) {
+        user.insert("data".to_string(), data);
+    }
+    if user.is_empty() {
+        settings.remove("user");
+    }
+}
+
+/// "AA:BB:CC:DD:EE:FF" → the 6 bytes NM stores in `802-11-wireless.bssid`
+fn parse_bssid(bssid: &str) -> Option<Vec<u8>> {
+    let bytes = bssid
+        .split(':')
+        .map(|octet| u8::from_str_radix(octet, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    (bytes.len() == 6).then_some(bytes)
+}
+
+/// Directory NetworkManager's keyfile plugin stores system profiles in
+const KEYFILE_DIR: &str = "/etc/NetworkManager/system-connections";
+
+/// Find the keyfile whose `uuid=` matches. The files are root-only, so this
+/// usually finds nothing unless Nexus runs as root.
+fn keyfile_for_uuid(uuid: &str) -> Option<std::path::PathBuf> {
+    let needle = format!("uuid={uuid}");
+    std::fs::read_dir(KEYFILE_DIR)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .find(|path| {
+            std::fs::read_to_string(path)
+                .is_ok_and(|text| text.lines().any(|line| line.trim() == needle))
+        })
 }
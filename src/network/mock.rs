@@ -0,0 +1,578 @@
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use eyre::{Result, bail};
+use tokio::sync::mpsc;
+use tracing::{debug, info};
+
+use super::NetworkBackend;
+use super::types::*;
+use crate::event::{Event, NetworkCommand};
+
+/// A canned access point served by the mock backend
+struct MockAp {
+    ssid: &'static str,
+    bssid: &'static str,
+    base_strength: u8,
+    frequency: u32,
+    max_bitrate: u32,
+    security: SecurityType,
+}
+
+/// Fake adapters as (interface, driver): a built-in card and a USB dongle
+const MOCK_DEVICES: &[(&str, &str)] = &[("wlan0", "iwlwifi"), ("wlan1", "mt7921u")];
+
+/// The fake neighbourhood. Long and unicode names are deliberate so layout
+/// edge cases show up during UI work.
+const MOCK_APS: &[MockAp] = &[
+    MockAp {
+        ssid: "HomeWiFi",
+        bssid: "A4:2B:B0:11:22:33",
+        base_strength: 82,
+        frequency: 5180,
+        max_bitrate: 1_201_000,
+        security: SecurityType::WPA2,
+    },
+    MockAp {
+        ssid: "HomeWiFi_2G",
+        bssid: "A4:2B:B0:11:22:34",
+        base_strength: 74,
+        frequency: 2437,
+        max_bitrate: 144_000,
+        security: SecurityType::WPA2,
+    },
+    MockAp {
+        ssid: "Neighbor-6E",
+        bssid: "3C:84:6A:9F:00:01",
+        base_strength: 41,
+        frequency: 6115,
+        max_bitrate: 2_402_000,
+        security: SecurityType::WPA3,
+    },
+    MockAp {
+        ssid: "CoffeeShop_Guest",
+        bssid: "00:1A:2B:3C:4D:5E",
+        base_strength: 55,
+        frequency: 2462,
+        max_bitrate: 54_000,
+        security: SecurityType::Open,
+    },
+    MockAp {
+        ssid: "Corp-Secure",
+        bssid: "F0:9F:C2:AA:BB:CC",
+        base_strength: 38,
+        frequency: 5745,
+        max_bitrate: 866_000,
+        security: SecurityType::WPA2Enterprise,
+    },
+    MockAp {
+        ssid: "Café Ünïcødé ☕ Free WiFi",
+        bssid: "DE:AD:BE:EF:00:42",
+        base_strength: 29,
+        frequency: 2412,
+        max_bitrate: 300_000,
+        security: SecurityType::Wpa,
+    },
+    MockAp {
+        ssid: "This Is A Really Long Network Name For Testing Truncation",
+        bssid: "12:34:56:78:9A:BC",
+        base_strength: 22,
+        frequency: 5500,
+        max_bitrate: 433_000,
+        security: SecurityType::WPA2,
+    },
+    MockAp {
+        ssid: "DIRECT-7F-HP OfficeJet",
+        bssid: "9C:B6:D0:01:02:03",
+        base_strength: 12,
+        frequency: 2437,
+        max_bitrate: 72_000,
+        security: SecurityType::WPA2,
+    },
+    MockAp {
+        ssid: "OldRouter",
+        bssid: "00:0F:66:AA:00:01",
+        base_strength: 8,
+        frequency: 2422,
+        max_bitrate: 54_000,
+        security: SecurityType::Wep,
+    },
+];
+
+/// Lease duration the mock DHCP server hands out, three quarters left
+const MOCK_LEASE_TIME: u64 = 86_400;
+
+/// SSID whose activation always times out in IP configuration
+const MOCK_DHCP_FAILURE_SSID: &str = "CoffeeShop_Guest";
+
+/// Mutable state behind the mock backend
+struct MockState {
+    scans: u64,
+    active: Option<String>,
+    saved: Vec<String>,
+    /// Saved profiles with no password stored, as if imported without secrets
+    secretless: Vec<String>,
+    /// Profiles removed by forget, restorable by undo
+    forgotten: Vec<String>,
+    device_state: DeviceState,
+    mtu: u32,
+    profile: ProfileSettings,
+    connectivity_check: bool,
+}
+
+/// In-memory backend with fake APs, moving signal strengths and scripted
+/// connect/disconnect latencies. Selected with `--demo` or
+/// `NEXUS_BACKEND=mock` so UI work doesn't need NetworkManager or WiFi hardware.
+pub struct MockBackend {
+    state: Mutex<MockState>,
+    event_tx: mpsc::UnboundedSender<Event>,
+    interface: RwLock<String>,
+}
+
+impl MockBackend {
+    /// Create the mock backend. `event_tx` receives the device state
+    /// transitions NM would normally announce over D-Bus.
+    pub fn new(event_tx: mpsc::UnboundedSender<Event>) -> Self {
+        Self {
+            state: Mutex::new(MockState {
+                scans: 0,
+                active: Some("HomeWiFi".to_string()),
+                saved: vec![
+                    "HomeWiFi".to_string(),
+                    "HomeWiFi_2G".to_string(),
+                    "Corp-Secure".to_string(),
+                    // Saved but never in range, for the recent networks list
+                    "Airport_Lounge".to_string(),
+                ],
+                secretless: vec!["HomeWiFi_2G".to_string()],
+                forgotten: Vec::new(),
+                device_state: DeviceState::Activated,
+                mtu: 1500,
+                profile: ProfileSettings::default(),
+                connectivity_check: true,
+            }),
+            event_tx,
+            interface: RwLock::new(MOCK_DEVICES[0].0.to_string()),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Move the fake device to a new state and announce it
+    fn set_device_state(&self, state: DeviceState) {
+        let old = std::mem::replace(&mut self.lock().device_state, state);
+        let _ = self.event_tx.send(Event::DeviceStateChanged(state));
+        let _ = self.event_tx.send(Event::NmLog(format!(
+            "{}: {old:?}→{state:?}",
+            self.interface_name()
+        )));
+    }
+
+    /// Deterministic jitter so signal bars visibly move between scans
+    fn jittered(base: u8, scan: u64, index: usize) -> u8 {
+        let wobble = ((scan * 7 + index as u64 * 13) % 11) as i16 - 5;
+        (base as i16 + wobble).clamp(0, 100) as u8
+    }
+
+    /// Flags a typical AP of each security type advertises
+    fn security_flags(security: &SecurityType) -> SecurityFlags {
+        // CCMP pairwise + group, plus the key management bit
+        let (wpa, rsn) = match security {
+            SecurityType::Wpa => (0x144, 0), // TKIP, PSK
+            SecurityType::WPA2 => (0, 0x188),
+            SecurityType::WPA3 => (0, 0x488),
+            SecurityType::WPA2Enterprise => (0, 0x288),
+            SecurityType::Open | SecurityType::Wep | SecurityType::Unknown => (0, 0),
+        };
+        SecurityFlags { wpa, rsn }
+    }
+
+    fn find_ap(ssid: &str) -> Option<&'static MockAp> {
+        MOCK_APS.iter().find(|ap| ap.ssid == ssid)
+    }
+
+    /// Walk through NM's activation states with realistic pauses
+    async fn activate(&self, ssid: &str, password: Option<&str>) -> Result<()> {
+        let ap = Self::find_ap(ssid);
+
+        self.set_device_state(DeviceState::Prepare);
+        tokio::time::sleep(Duration::from_millis(400)).await;
+        self.set_device_state(DeviceState::Config);
+        tokio::time::sleep(Duration::from_millis(700)).await;
+
+        if ap.is_some_and(|ap| ap.security.needs_password()) {
+            self.set_device_state(DeviceState::NeedAuth);
+            tokio::time::sleep(Duration::from_millis(700)).await;
+            let stored = {
+                let state = self.lock();
+                state.saved.iter().any(|s| s == ssid) && !state.secretless.iter().any(|s| s == ssid)
+            };
+            if !stored && password.is_none_or(|p| p.len() < 8) {
+                self.set_device_state(DeviceState::Failed);
+                self.set_device_state(DeviceState::Disconnected);
+                return Err(ActivationFailed {
+                    reason: ActivationFailed::NO_SECRETS,
+                }
+                .into());
+            }
+        }
+
+        self.set_device_state(DeviceState::IpConfig);
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        if ssid == MOCK_DHCP_FAILURE_SSID {
+            self.set_device_state(DeviceState::Failed);
+            self.set_device_state(DeviceState::Disconnected);
+            return Err(ActivationFailed {
+                reason: ActivationFailed::IP_CONFIG_UNAVAILABLE,
+            }
+            .into());
+        }
+
+        self.set_device_state(DeviceState::IpCheck);
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        {
+            let mut state = self.lock();
+            state.active = Some(ssid.to_string());
+            state.secretless.retain(|s| s != ssid);
+            if !state.saved.iter().any(|s| s == ssid) {
+                state.saved.push(ssid.to_string());
+            }
+        }
+        self.set_device_state(DeviceState::Activated);
+        Ok(())
+    }
+}
+
+impl NetworkBackend for MockBackend {
+    async fn scan(&self) -> Result<Vec<WiFiNetwork>> {
+        debug!("Mock scan");
+        tokio::time::sleep(Duration::from_millis(800)).await;
+
+        self.lock().scans += 1;
+
+        // Mock SSIDs are unique, so every AP is its own network
+        let mut networks = self.access_points().await?;
+        networks.sort_by(|a, b| {
+            b.is_active
+                .cmp(&a.is_active)
+                .then(b.signal_strength.cmp(&a.signal_strength))
+        });
+        Ok(networks)
+    }
+
+    async fn fresh_scan(&self) -> Result<Vec<WiFiNetwork>> {
+        // The mock has no cache to bypass
+        self.scan().await
+    }
+
+    async fn access_points(&self) -> Result<Vec<WiFiNetwork>> {
+        let (scan, active, saved) = {
+            let state = self.lock();
+            (state.scans, state.active.clone(), state.saved.clone())
+        };
+
+        let networks: Vec<WiFiNetwork> = MOCK_APS
+            .iter()
+            .enumerate()
+            .map(|(i, ap)| {
+                let strength = Self::jittered(ap.base_strength, scan, i);
+                WiFiNetwork {
+                    ssid: ap.ssid.to_string(),
+                    bssid: ap.bssid.to_string(),
+                    signal_strength: strength,
+                    frequency: ap.frequency,
+                    max_bitrate: ap.max_bitrate / 1000,
+                    security: ap.security.clone(),
+                    security_flags: Self::security_flags(&ap.security),
+                    is_saved: saved.iter().any(|s| s == ap.ssid),
+                    is_active: active.as_deref() == Some(ap.ssid),
+                    ap_path: format!("/org/freedesktop/NetworkManager/AccessPoint/{}", i + 1),
+                    seen_ticks: 0,
+                    display_signal: strength as f32,
+                    last_seen: Instant::now(),
+                    missed_scans: 0,
+                }
+            })
+            .collect();
+        Ok(networks)
+    }
+
+    async fn connect(
+        &self,
+        ssid: &str,
+        password: Option<&str>,
+        _ap_path: Option<&str>,
+    ) -> Result<()> {
+        info!("Mock connect to {}", ssid);
+        if Self::find_ap(ssid).is_none() {
+            bail!("No network with SSID '{ssid}' found");
+        }
+        self.activate(ssid, password).await
+    }
+
+    async fn disconnect(&self) -> Result<()> {
+        if self.lock().active.is_none() {
+            bail!("No active WiFi connection");
+        }
+        self.set_device_state(DeviceState::Deactivating);
+        tokio::time::sleep(Duration::from_millis(600)).await;
+        self.lock().active = None;
+        self.set_device_state(DeviceState::Disconnected);
+        Ok(())
+    }
+
+    async fn forget_network(&self, ssid: &str) -> Result<ForgetOutcome> {
+        let mut state = self.lock();
+        let before = state.saved.len();
+        state.saved.retain(|s| s != ssid);
+        if state.saved.len() == before {
+            bail!("No saved profile found for '{}'", ssid);
+        }
+        state.forgotten.push(ssid.to_string());
+        Ok(ForgetOutcome {
+            ssid: ssid.to_string(),
+            secrets_preserved: true,
+        })
+    }
+
+    async fn saved_profiles(&self) -> Result<Vec<SavedProfile>> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let state = self.lock();
+        // The active profile connected just now, the rest a day or more apart
+        Ok(state
+            .saved
+            .iter()
+            .enumerate()
+            .map(|(i, ssid)| SavedProfile {
+                ssid: ssid.clone(),
+                last_connected: Some(if state.active.as_ref() == Some(ssid) {
+                    now
+                } else {
+                    now - (i as u64 + 1) * 26 * 3600
+                }),
+            })
+            .collect())
+    }
+
+    async fn restore_network(&self, ssid: &str) -> Result<()> {
+        let mut state = self.lock();
+        let Some(index) = state.forgotten.iter().position(|s| s == ssid) else {
+            bail!("Nothing to undo for '{ssid}' — the undo window has expired");
+        };
+        let ssid = state.forgotten.remove(index);
+        state.saved.push(ssid);
+        Ok(())
+    }
+
+    async fn current_connection(&self) -> Result<Option<ConnectionInfo>> {
+        let (active, scan, mtu, mut profile) = {
+            let state = self.lock();
+            (
+                state.active.clone(),
+                state.scans,
+                state.mtu,
+                state.profile.clone(),
+            )
+        };
+        let connectivity = self.connectivity().await?;
+        let connectivity_check = self.connectivity_check().await?;
+        let Some(ssid) = active else {
+            return Ok(None);
+        };
+        let Some((index, ap)) = MOCK_APS.iter().enumerate().find(|(_, ap)| ap.ssid == ssid) else {
+            return Ok(None);
+        };
+
+        profile.ipv4_method = Some("auto".to_string());
+        profile.ipv6_method = Some("auto".to_string());
+        profile.uuid = Some(format!("4e455855-0000-4000-8000-{index:012x}"));
+        profile.keyfile = Some(format!(
+            "/etc/NetworkManager/system-connections/{ssid}.nmconnection"
+        ));
+
+        Ok(Some(ConnectionInfo {
+            ssid,
+            bssid: ap.bssid.to_string(),
+            ip4: Some("192.168.1.42/24".to_string()),
+            ip6: Some("fd00::42/64".to_string()),
+            gateway: Some("192.168.1.1".to_string()),
+            dns: vec!["192.168.1.1".to_string(), "1.1.1.1".to_string()],
+            mac: "02:00:00:C0:FF:EE".to_string(),
+            speed: ap.max_bitrate / 2000,
+            mtu,
+            frequency: ap.frequency,
+            signal: Self::jittered(ap.base_strength, scan, index),
+            interface: self.interface_name(),
+            profile,
+            connectivity,
+            connectivity_check,
+            dhcp4: Some(DhcpLease {
+                server: Some("192.168.1.1".to_string()),
+                lease_time: Some(MOCK_LEASE_TIME),
+                expiry: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .ok()
+                    .map(|now| now.as_secs() + MOCK_LEASE_TIME * 3 / 4),
+                ntp_servers: vec!["192.168.1.1".to_string()],
+                domain: Some("lan".to_string()),
+            }),
+            dhcp6: None,
+            // NM's default for WiFi
+            route_metric: Some(600),
+        }))
+    }
+
+    async fn set_mtu(&self, mtu: u32) -> Result<()> {
+        if self.lock().active.is_none() {
+            bail!("No active WiFi connection");
+        }
+        self.lock().mtu = if mtu == 0 { 1500 } else { mtu };
+        Ok(())
+    }
+
+    async fn update_profile(&self, changes: Vec<ProfileChange>) -> Result<()> {
+        let aps = self.access_points().await?;
+        let mut state = self.lock();
+        if state.active.is_none() {
+            bail!("No active WiFi connection");
+        }
+        for change in changes {
+            match change {
+                ProfileChange::Hostname(name) => state.profile.dhcp_hostname = name,
+                ProfileChange::SendHostname(send) => state.profile.dhcp_send_hostname = send,
+                ProfileChange::ClientId(id) => state.profile.dhcp_client_id = id,
+                ProfileChange::RouteMetric(metric) => state.profile.route_metric = metric,
+                ProfileChange::Band(band) => state.profile.band = band,
+                ProfileChange::FastBand(true) => {
+                    let ssid = state.active.clone().unwrap_or_default();
+                    let fast = fast_band_aps(&aps, &ssid);
+                    let Some(strongest) = fast.first() else {
+                        bail!("No 5 or 6 GHz access point of this network is in range");
+                    };
+                    let band = strongest.band();
+                    state.profile.band = Some(band).filter(|band| band.nm_band().is_some());
+                    state.profile.bssid = band.nm_band().is_none().then(|| strongest.bssid.clone());
+                    state.profile.fast_band_bssids =
+                        fast.iter().map(|ap| ap.bssid.clone()).collect();
+                }
+                ProfileChange::FastBand(false) => {
+                    state.profile.band = None;
+                    state.profile.bssid = None;
+                    state.profile.fast_band_bssids.clear();
+                }
+                ProfileChange::ProxyAuto(auto) => state.profile.proxy_auto = auto,
+                ProfileChange::PacUrl(url) => state.profile.proxy_pac_url = url,
+                ProfileChange::Zone(zone) => state.profile.zone = zone,
+                ProfileChange::AutoconnectRetries(retries) => {
+                    state.profile.autoconnect_retries = retries
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn device_state(&self) -> Result<DeviceState> {
+        Ok(self.lock().device_state)
+    }
+
+    async fn set_managed(&self, managed: bool) -> Result<()> {
+        let state = if managed {
+            DeviceState::Disconnected
+        } else {
+            DeviceState::Unmanaged
+        };
+        self.set_device_state(state);
+        Ok(())
+    }
+
+    async fn set_wireless_enabled(&self, enabled: bool) -> Result<()> {
+        let state = if enabled {
+            DeviceState::Disconnected
+        } else {
+            DeviceState::Unavailable
+        };
+        self.set_device_state(state);
+        Ok(())
+    }
+
+    async fn connect_hidden(&self, ssid: &str, password: Option<&str>) -> Result<()> {
+        info!("Mock connect to hidden network {}", ssid);
+        self.activate(ssid, password).await
+    }
+
+    async fn connectivity(&self) -> Result<Connectivity> {
+        let state = self.lock();
+        Ok(if !state.connectivity_check {
+            Connectivity::Unknown
+        } else if state.active.is_some() {
+            Connectivity::Full
+        } else {
+            Connectivity::None
+        })
+    }
+
+    async fn connectivity_check(&self) -> Result<ConnectivityCheck> {
+        Ok(ConnectivityCheck {
+            available: true,
+            enabled: self.lock().connectivity_check,
+            uri: Some("http://nmcheck.gnome.org/check_network_status.txt".to_string()),
+        })
+    }
+
+    async fn set_connectivity_check(&self, enabled: bool) -> Result<()> {
+        self.lock().connectivity_check = enabled;
+        Ok(())
+    }
+
+    async fn wifi_devices(&self) -> Result<Vec<WifiDevice>> {
+        let current = self.interface_name();
+        let state = self.lock().device_state;
+        Ok(MOCK_DEVICES
+            .iter()
+            .map(|&(interface, driver)| WifiDevice {
+                interface: interface.to_string(),
+                driver: driver.to_string(),
+                state: if interface == current {
+                    state
+                } else {
+                    DeviceState::Disconnected
+                },
+            })
+            .collect())
+    }
+
+    async fn set_device(&self, interface: &str) -> Result<()> {
+        if !MOCK_DEVICES.iter().any(|&(name, _)| name == interface) {
+            bail!("WiFi interface '{interface}' not found");
+        }
+        *self.interface.write().unwrap() = interface.to_string();
+        Ok(())
+    }
+
+    fn interface_name(&self) -> String {
+        self.interface.read().unwrap().clone()
+    }
+}
+
+/// Stand-in for the D-Bus signal listener: periodically asks the main loop to
+/// refresh so the fake signal strengths keep moving.
+pub fn start_demo_listener(event_tx: mpsc::UnboundedSender<Event>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(5));
+        loop {
+            interval.tick().await;
+            if event_tx
+                .send(Event::Command(NetworkCommand::RefreshConnection))
+                .is_err()
+                || event_tx.send(Event::Command(NetworkCommand::Scan)).is_err()
+            {
+                return;
+            }
+        }
+    });
+}
@@ -1,31 +1,106 @@
+pub mod diagnostics;
+pub mod health;
 pub mod manager;
+pub mod mock;
+pub mod routes;
 pub mod signals;
+pub mod stats;
+pub mod survey;
 pub mod types;
 
+use std::future::Future;
+
 use eyre::Result;
-use types::{ConnectionInfo, WiFiNetwork};
+use types::{
+    ConnectionInfo, Connectivity, ConnectivityCheck, DeviceState, ForgetOutcome, ProfileChange,
+    SavedProfile, WiFiNetwork, WifiDevice,
+};
 
 /// Abstract network backend trait.
 /// Allows swapping implementations (NetworkManager, iwd, mock) cleanly.
+///
+/// Methods return `Send` futures so callers can `tokio::spawn` them through a
+/// generic `Arc<B>`; implementations can still use plain `async fn`.
 pub trait NetworkBackend: Send + Sync {
     /// Trigger a WiFi scan and return discovered networks
-    async fn scan(&self) -> Result<Vec<WiFiNetwork>>;
+    fn scan(&self) -> impl Future<Output = Result<Vec<WiFiNetwork>>> + Send;
+
+    /// Scan, wait until NM reports the scan finished, and return only the
+    /// networks that scan saw, none of NM's cached APs
+    fn fresh_scan(&self) -> impl Future<Output = Result<Vec<WiFiNetwork>>> + Send;
 
-    /// Connect to a network by SSID, optionally with a password
-    async fn connect(&self, ssid: &str, password: Option<&str>) -> Result<()>;
+    /// Every access point from the most recent scan, one entry per BSSID
+    /// (no SSID deduplication, no new scan)
+    fn access_points(&self) -> impl Future<Output = Result<Vec<WiFiNetwork>>> + Send;
+
+    /// Connect to a network by SSID, optionally with a password. With
+    /// `ap_path`, activate on that access point rather than letting the
+    /// backend pick one of the SSID's BSSIDs.
+    fn connect(
+        &self,
+        ssid: &str,
+        password: Option<&str>,
+        ap_path: Option<&str>,
+    ) -> impl Future<Output = Result<()>> + Send;
 
     /// Disconnect from the currently active WiFi connection
-    async fn disconnect(&self) -> Result<()>;
+    fn disconnect(&self) -> impl Future<Output = Result<()>> + Send;
+
+    /// Forget (delete) a saved network profile, keeping a copy in memory for
+    /// `UNDO_WINDOW` so it can be restored
+    fn forget_network(&self, ssid: &str) -> impl Future<Output = Result<ForgetOutcome>> + Send;
 
-    /// Forget (delete) a saved network profile
-    async fn forget_network(&self, ssid: &str) -> Result<()>;
+    /// Every saved WiFi profile with when it last connected
+    fn saved_profiles(&self) -> impl Future<Output = Result<Vec<SavedProfile>>> + Send;
+
+    /// Re-create a profile removed by `forget_network` within the undo window
+    fn restore_network(&self, ssid: &str) -> impl Future<Output = Result<()>> + Send;
 
     /// Get current active WiFi connection info (None if disconnected)
-    async fn current_connection(&self) -> Result<Option<ConnectionInfo>>;
+    fn current_connection(&self) -> impl Future<Output = Result<Option<ConnectionInfo>>> + Send;
+
+    /// Set the MTU on the active connection's profile and reapply it
+    fn set_mtu(&self, mtu: u32) -> impl Future<Output = Result<()>> + Send;
+
+    /// Write a batch of edits to the active connection's saved profile in one
+    /// update, then reapply it once
+    fn update_profile(
+        &self,
+        changes: Vec<ProfileChange>,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// Current NM state of the WiFi device
+    fn device_state(&self) -> impl Future<Output = Result<DeviceState>> + Send;
+
+    /// Set whether NetworkManager manages the WiFi device
+    fn set_managed(&self, managed: bool) -> impl Future<Output = Result<()>> + Send;
+
+    /// Turn the WiFi radio on (lift the soft block) or off
+    fn set_wireless_enabled(&self, enabled: bool) -> impl Future<Output = Result<()>> + Send;
 
     /// Connect to a hidden network
-    async fn connect_hidden(&self, ssid: &str, password: Option<&str>) -> Result<()>;
+    fn connect_hidden(
+        &self,
+        ssid: &str,
+        password: Option<&str>,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// NM's current connectivity verdict (from its own periodic check)
+    fn connectivity(&self) -> impl Future<Output = Result<Connectivity>> + Send;
+
+    /// Whether NM's connectivity check is configured and enabled, and its URI
+    fn connectivity_check(&self) -> impl Future<Output = Result<ConnectivityCheck>> + Send;
+
+    /// Turn NM's periodic connectivity check on or off (needs polkit authorization)
+    fn set_connectivity_check(&self, enabled: bool) -> impl Future<Output = Result<()>> + Send;
+
+    /// Every WiFi adapter, for picking another one at runtime
+    fn wifi_devices(&self) -> impl Future<Output = Result<Vec<WifiDevice>>> + Send;
+
+    /// Send scans, connects and every other device call to the adapter
+    /// `interface` from now on
+    fn set_device(&self, interface: &str) -> impl Future<Output = Result<()>> + Send;
 
     /// Get the interface name being used
-    fn interface_name(&self) -> &str;
+    fn interface_name(&self) -> String;
 }
@@ -0,0 +1,27 @@
+/// Whether `interface` carries the IPv4 default route, per `/proc/net/route`.
+/// Returns false if the routing table can't be read.
+pub fn carries_default_route(interface: &str) -> bool {
+    let Ok(table) = std::fs::read_to_string("/proc/net/route") else {
+        return false;
+    };
+    // Columns: Iface Destination Gateway Flags ... (hex, header on line 1)
+    table.lines().skip(1).any(|line| {
+        let mut cols = line.split_whitespace();
+        cols.next() == Some(interface) && cols.next() == Some("00000000")
+    })
+}
+
+/// Metric of `interface`'s IPv4 default route (what the kernel actually
+/// uses to pick between interfaces; lower wins)
+pub fn default_route_metric(interface: &str) -> Option<u32> {
+    let table = std::fs::read_to_string("/proc/net/route").ok()?;
+    // Columns: Iface Destination Gateway Flags RefCnt Use Metric ...
+    table.lines().skip(1).find_map(|line| {
+        let cols: Vec<&str> = line.split_whitespace().collect();
+        if cols.first() == Some(&interface) && cols.get(1) == Some(&"00000000") {
+            cols.get(6)?.parse().ok()
+        } else {
+            None
+        }
+    })
+}
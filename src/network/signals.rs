@@ -1,60 +1,293 @@
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 
 use tokio::sync::mpsc;
-use tracing::{debug, warn};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
 use zbus::Connection;
 use zbus::zvariant::OwnedObjectPath;
 
 use crate::event::Event;
+use crate::network::NetworkBackend;
+use crate::network::manager::NmBackend;
+use crate::network::types::DeviceState;
+
+const ACTIVE_INTERFACE: &str = "org.freedesktop.NetworkManager.Connection.Active";
+
+/// Interval of the fallback poll that refreshes the connection even when no
+/// signal arrives; data older than twice this means the listener stalled
+pub const REFRESH_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How often the wall clock is compared against the monotonic clock
+const RESUME_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+/// The wall clock running ahead of the monotonic clock by more than this
+/// between two checks means the machine was suspended in between
+const SUSPEND_GAP: Duration = Duration::from_secs(10);
+
+/// First wait before reconnecting to a lost system bus; doubled per attempt
+const BUS_RETRY_MIN: Duration = Duration::from_secs(1);
+
+/// Longest wait between system bus reconnect attempts
+const BUS_RETRY_MAX: Duration = Duration::from_secs(30);
+
+/// Listener tasks, aborted when dropped so that listeners of a dead
+/// connection never keep running next to their replacements
+struct Listeners(Vec<JoinHandle<()>>);
+
+impl Drop for Listeners {
+    fn drop(&mut self) {
+        for task in &self.0 {
+            task.abort();
+        }
+    }
+}
 
 /// Start listening for NetworkManager D-Bus signals and forward them as Events.
 /// Uses zbus `MessageStream` to get real-time property change notifications
 /// from NetworkManager instead of blind polling.
+///
+/// The listeners are torn down and subscribed afresh after every resume from
+/// suspend, which is then announced with `Event::Resumed`. Some drivers come
+/// back as a new NM device, so its path is looked up again first.
+///
+/// Aborting the returned task stops every listener.
 pub async fn start_signal_listener(
     conn: Connection,
-    device_path: OwnedObjectPath,
+    mut device_path: OwnedObjectPath,
+    interface: String,
+    event_tx: mpsc::UnboundedSender<Event>,
+) -> JoinHandle<()> {
+    let mut listeners =
+        Listeners(spawn_listeners(&conn, &device_path, &interface, &event_tx).await);
+    let mut resumes = watch_resume(&conn).await;
+
+    tokio::spawn(async move {
+        while resumes.recv().await.is_some() {
+            debug!("Resumed from suspend, restarting signal listeners");
+            for task in listeners.0.drain(..) {
+                task.abort();
+            }
+            match device_by_interface(&conn, &interface).await {
+                Ok(path) if path != device_path => {
+                    debug!("{} is now {} (was {})", interface, path, device_path);
+                    device_path = path;
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Could not look up {} after resume: {}", interface, e),
+            }
+            listeners.0 = spawn_listeners(&conn, &device_path, &interface, &event_tx).await;
+            if event_tx.send(Event::Resumed).is_err() {
+                return;
+            }
+        }
+    })
+}
+
+/// Watch the backend's system bus connection. When it drops (dbus-daemon
+/// restarted, say) every call would fail, so announce `Event::BusLost`,
+/// reconnect with backoff, restart `listener` on the new connection and
+/// announce `Event::BusRestored`. The listener is also restarted when the
+/// backend switches to another WiFi adapter.
+pub fn watch_bus(
+    backend: Arc<NmBackend>,
+    mut listener: JoinHandle<()>,
     event_tx: mpsc::UnboundedSender<Event>,
 ) {
+    use futures::StreamExt;
+
+    tokio::spawn(async move {
+        loop {
+            // The stream yields an error and ends once the socket is gone
+            let mut messages = zbus::MessageStream::from(backend.connection());
+            let switched = loop {
+                tokio::select! {
+                    msg = messages.next() => {
+                        if !matches!(msg, Some(Ok(_))) {
+                            break false;
+                        }
+                    }
+                    () = backend.device_switched() => break true,
+                }
+            };
+            drop(messages);
+
+            if switched {
+                debug!("WiFi device switched, restarting signal listeners");
+                listener.abort();
+                listener = start_signal_listener(
+                    backend.connection(),
+                    backend.device_path(),
+                    backend.interface_name(),
+                    event_tx.clone(),
+                )
+                .await;
+                continue;
+            }
+
+            warn!("System bus connection lost, reconnecting");
+            listener.abort();
+            if event_tx.send(Event::BusLost).is_err() {
+                return;
+            }
+            let mut delay = BUS_RETRY_MIN;
+            loop {
+                tokio::time::sleep(delay).await;
+                match backend.reconnect().await {
+                    Ok(()) => break,
+                    Err(e) => {
+                        debug!("Bus reconnect failed ({:#}), retrying in {:?}", e, delay);
+                        delay = (delay * 2).min(BUS_RETRY_MAX);
+                    }
+                }
+            }
+
+            info!("System bus connection restored");
+            listener = start_signal_listener(
+                backend.connection(),
+                backend.device_path(),
+                backend.interface_name(),
+                event_tx.clone(),
+            )
+            .await;
+            if event_tx.send(Event::BusRestored).is_err() {
+                return;
+            }
+        }
+    });
+}
+
+/// NM's device object for the network interface `interface`
+async fn device_by_interface(conn: &Connection, interface: &str) -> zbus::Result<OwnedObjectPath> {
+    let reply = conn
+        .call_method(
+            Some("org.freedesktop.NetworkManager"),
+            "/org/freedesktop/NetworkManager",
+            Some("org.freedesktop.NetworkManager"),
+            "GetDeviceByIpIface",
+            &(interface,),
+        )
+        .await?;
+    reply.body().deserialize()
+}
+
+/// Subscribe to the device's signals, or fall back to polling. Returns the
+/// spawned tasks so they can be restarted.
+async fn spawn_listeners(
+    conn: &Connection,
+    device_path: &OwnedObjectPath,
+    interface: &str,
+    event_tx: &mpsc::UnboundedSender<Event>,
+) -> Vec<JoinHandle<()>> {
     debug!("Starting NetworkManager signal listener");
 
     // Attempt to subscribe to PropertiesChanged signals on our WiFi device.
     // If subscription fails, fall back to periodic polling.
-    let sub_result =
-        subscribe_device_signals(conn.clone(), device_path.clone(), event_tx.clone()).await;
-
-    if let Err(e) = sub_result {
-        warn!(
-            "D-Bus signal subscription failed ({}), falling back to polling",
-            e
-        );
-        let tx = event_tx.clone();
-        tokio::spawn(async move {
-            let mut interval = tokio::time::interval(Duration::from_secs(5));
-            loop {
-                interval.tick().await;
-                // Signal the main loop to refresh connection state
-                if tx
-                    .send(Event::Command(
-                        crate::event::NetworkCommand::RefreshConnection,
-                    ))
-                    .is_err()
-                {
-                    return;
+    let sub_result = subscribe_device_signals(
+        conn.clone(),
+        device_path.clone(),
+        interface.to_string(),
+        event_tx.clone(),
+    )
+    .await;
+
+    match sub_result {
+        Ok(tasks) => {
+            debug!("Signal listeners started");
+            tasks
+        }
+        Err(e) => {
+            warn!(
+                "D-Bus signal subscription failed ({}), falling back to polling",
+                e
+            );
+            let tx = event_tx.clone();
+            vec![tokio::spawn(async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(5));
+                loop {
+                    interval.tick().await;
+                    // Signal the main loop to refresh connection state
+                    if tx
+                        .send(Event::Command(
+                            crate::event::NetworkCommand::RefreshConnection,
+                        ))
+                        .is_err()
+                    {
+                        return;
+                    }
                 }
-            }
-        });
+            })]
+        }
+    }
+}
+
+/// Report resumes from suspend: logind's `PrepareForSleep(false)` where
+/// logind runs, plus a clock check that catches the rest (the monotonic clock
+/// stops during suspend, the wall clock doesn't). A single resume may be
+/// reported twice.
+async fn watch_resume(conn: &Connection) -> mpsc::UnboundedReceiver<()> {
+    use futures::StreamExt;
+
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    let sleep_signals = async {
+        let proxy = zbus::Proxy::new(
+            conn,
+            "org.freedesktop.login1",
+            "/org/freedesktop/login1",
+            "org.freedesktop.login1.Manager",
+        )
+        .await?;
+        proxy.receive_signal("PrepareForSleep").await
+    };
+    match sleep_signals.await {
+        Ok(mut stream) => {
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                while let Some(msg) = stream.next().await {
+                    // true before suspending, false after waking up
+                    if msg.body().deserialize::<bool>().is_ok_and(|start| !start)
+                        && tx.send(()).is_err()
+                    {
+                        return;
+                    }
+                }
+            });
+        }
+        Err(e) => debug!("No logind sleep signals ({}), relying on clock jumps", e),
     }
 
-    debug!("Signal listeners started");
+    tokio::spawn(async move {
+        let mut last = (Instant::now(), SystemTime::now());
+        loop {
+            tokio::time::sleep(RESUME_CHECK_INTERVAL).await;
+            // Stop along with the listener that asked
+            if tx.is_closed() {
+                return;
+            }
+            let now = (Instant::now(), SystemTime::now());
+            let monotonic = now.0.duration_since(last.0);
+            let wall = now.1.duration_since(last.1).unwrap_or_default();
+            last = now;
+            if wall.saturating_sub(monotonic) > SUSPEND_GAP && tx.send(()).is_err() {
+                return;
+            }
+        }
+    });
+
+    rx
 }
 
 /// Subscribe to D-Bus PropertiesChanged signals on the WiFi device.
-/// Sends a RefreshConnection command whenever a property change is detected.
+/// Sends a RefreshConnection command whenever a property change is detected,
+/// and forwards every device `StateChanged` signal (undebounced) so the UI
+/// can track activation progress. Device and active-connection state changes
+/// are also forwarded as one-line `NmLog` lines for the connection events log.
 async fn subscribe_device_signals(
     conn: Connection,
     device_path: OwnedObjectPath,
+    interface: String,
     event_tx: mpsc::UnboundedSender<Event>,
-) -> eyre::Result<()> {
+) -> eyre::Result<Vec<JoinHandle<()>>> {
     use futures::StreamExt;
     use zbus::MatchRule;
 
@@ -65,13 +298,29 @@ async fn subscribe_device_signals(
         .path(device_path.as_str())?
         .build();
 
+    let state_rule = MatchRule::builder()
+        .msg_type(zbus::message::Type::Signal)
+        .interface("org.freedesktop.NetworkManager.Device")?
+        .member("StateChanged")?
+        .path(device_path.as_str())?
+        .build();
+
+    // Every active connection (not just ours), so the log also shows wired/VPN
+    let active_rule = MatchRule::builder()
+        .msg_type(zbus::message::Type::Signal)
+        .interface(ACTIVE_INTERFACE)?
+        .member("StateChanged")?
+        .build();
+
     let proxy = zbus::fdo::DBusProxy::new(&conn).await?;
     proxy.add_match_rule(rule).await?;
+    proxy.add_match_rule(state_rule).await?;
+    proxy.add_match_rule(active_rule).await?;
 
     let mut stream = zbus::MessageStream::from(&conn);
     let tx = event_tx.clone();
 
-    tokio::spawn(async move {
+    let signals = tokio::spawn(async move {
         // Debounce: don't send more than one refresh per 2 seconds
         let mut last_signal = tokio::time::Instant::now();
         let debounce = Duration::from_secs(2);
@@ -80,10 +329,54 @@ async fn subscribe_device_signals(
             if let Ok(msg) = msg {
                 // Check if it's a signal related to our device
                 let header = msg.header();
+
+                let is_active_changed = header
+                    .interface()
+                    .is_some_and(|i| i.as_str() == ACTIVE_INTERFACE)
+                    && header
+                        .member()
+                        .is_some_and(|m| m.as_str() == "StateChanged");
+                if is_active_changed {
+                    if let (Some(path), Ok((state, _reason))) =
+                        (header.path(), msg.body().deserialize::<(u32, u32)>())
+                    {
+                        let name = active_connection_name(&conn, path.as_str()).await;
+                        let line = format!("{name}: {}", active_state_label(state));
+                        if tx.send(Event::NmLog(line)).is_err() {
+                            return;
+                        }
+                    }
+                    continue;
+                }
+
                 let is_props_changed = header
                     .member()
                     .is_some_and(|m| m.as_str() == "PropertiesChanged");
 
+                // Device state transitions drive the activation progress gauge
+                let is_state_changed = header
+                    .member()
+                    .is_some_and(|m| m.as_str() == "StateChanged")
+                    && header
+                        .path()
+                        .is_some_and(|p| p.as_str() == device_path.as_str());
+                if is_state_changed {
+                    if let Ok((new_state, old_state, _reason)) =
+                        msg.body().deserialize::<(u32, u32, u32)>()
+                    {
+                        let state = DeviceState::from_u32(new_state);
+                        let old = DeviceState::from_u32(old_state);
+                        debug!("Device state changed: {:?}", state);
+                        let line = format!("{interface}: {old:?}→{state:?}");
+                        if tx.send(Event::DeviceStateChanged(state)).is_err()
+                            || tx.send(Event::NmLog(line)).is_err()
+                        {
+                            return;
+                        }
+                    }
+                    continue;
+                }
+
                 if is_props_changed && last_signal.elapsed() >= debounce {
                     last_signal = tokio::time::Instant::now();
                     debug!("D-Bus PropertiesChanged signal received, refreshing");
@@ -103,8 +396,8 @@ async fn subscribe_device_signals(
     // Also keep a slower fallback poll for changes that don't trigger signals
     // (e.g., AP list changes after roaming)
     let tx2 = event_tx.clone();
-    tokio::spawn(async move {
-        let mut interval = tokio::time::interval(Duration::from_secs(15));
+    let poll = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(REFRESH_POLL_INTERVAL);
         loop {
             interval.tick().await;
             if tx2
@@ -118,5 +411,36 @@ async fn subscribe_device_signals(
         }
     });
 
-    Ok(())
+    Ok(vec![signals, poll])
+}
+
+/// Profile name (`Id`) of an active connection, or its object path number
+/// once NM has already removed it (typical for the final `Deactivated`)
+async fn active_connection_name(conn: &Connection, path: &str) -> String {
+    let id = match zbus::Proxy::new(
+        conn,
+        "org.freedesktop.NetworkManager",
+        path,
+        ACTIVE_INTERFACE,
+    )
+    .await
+    {
+        Ok(proxy) => proxy.get_property::<String>("Id").await.ok(),
+        Err(_) => None,
+    };
+    id.unwrap_or_else(|| {
+        let num = path.rsplit('/').next().unwrap_or(path);
+        format!("connection {num}")
+    })
+}
+
+/// `NMActiveConnectionState` as a word
+fn active_state_label(state: u32) -> &'static str {
+    match state {
+        1 => "Activating",
+        2 => "Activated",
+        3 => "Deactivating",
+        4 => "Deactivated",
+        _ => "Unknown",
+    }
 }
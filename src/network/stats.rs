@@ -0,0 +1,344 @@
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::time::Instant;
+
+/// Cumulative kernel counters of an interface
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counters {
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+}
+
+impl Counters {
+    /// Growth since `base`; counters that went backwards (interface reset)
+    /// count as zero
+    pub fn since(&self, base: &Self) -> Self {
+        Self {
+            rx_bytes: self.rx_bytes.saturating_sub(base.rx_bytes),
+            tx_bytes: self.tx_bytes.saturating_sub(base.tx_bytes),
+            rx_packets: self.rx_packets.saturating_sub(base.rx_packets),
+            tx_packets: self.tx_packets.saturating_sub(base.tx_packets),
+            rx_errors: self.rx_errors.saturating_sub(base.rx_errors),
+            tx_errors: self.tx_errors.saturating_sub(base.tx_errors),
+            rx_dropped: self.rx_dropped.saturating_sub(base.rx_dropped),
+            tx_dropped: self.tx_dropped.saturating_sub(base.tx_dropped),
+        }
+    }
+
+    /// Share of packets that errored or were dropped; None before any
+    /// traffic
+    pub fn fault_ratio(&self) -> Option<f64> {
+        let faults = self.rx_errors + self.tx_errors + self.rx_dropped + self.tx_dropped;
+        let packets = self.rx_packets + self.tx_packets + faults;
+        (packets > 0).then(|| faults as f64 / packets as f64)
+    }
+}
+
+/// Counters of an interface at one point in time
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    counters: Counters,
+    at: Instant,
+}
+
+/// Throughput between two polls, in bytes per second
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Rates {
+    pub rx: f64,
+    pub tx: f64,
+}
+
+/// Reads the kernel's per-interface byte counters from sysfs and turns
+/// consecutive polls into rx/tx rates.
+pub struct StatsPoller {
+    dir: PathBuf,
+    last: Option<Sample>,
+}
+
+impl StatsPoller {
+    pub fn new(interface: &str) -> Self {
+        Self {
+            dir: PathBuf::from("/sys/class/net")
+                .join(interface)
+                .join("statistics"),
+            last: None,
+        }
+    }
+
+    /// Take a sample. Returns the rates since the previous poll, or None on
+    /// the first poll or when the counters can't be read.
+    pub fn poll(&mut self) -> Option<Rates> {
+        let sample = self.read()?;
+        let previous = self.last.replace(sample)?;
+
+        let secs = sample.at.duration_since(previous.at).as_secs_f64();
+        if secs <= 0.0 {
+            return None;
+        }
+        // Counters reset when the interface goes down; treat that as zero
+        let grown = sample.counters.since(&previous.counters);
+        Some(Rates {
+            rx: grown.rx_bytes as f64 / secs,
+            tx: grown.tx_bytes as f64 / secs,
+        })
+    }
+
+    /// Forget the previous sample, so the next poll starts a fresh rate.
+    /// The monotonic clock stands still during suspend while the counters
+    /// may not, which would otherwise show as a rate spike after resume.
+    pub fn restart(&mut self) {
+        self.last = None;
+    }
+
+    /// Raw (rx, tx) byte counters of the most recent poll
+    pub fn counters(&self) -> Option<(u64, u64)> {
+        self.last
+            .map(|s| (s.counters.rx_bytes, s.counters.tx_bytes))
+    }
+
+    /// Every counter of the most recent poll
+    pub fn latest(&self) -> Option<Counters> {
+        self.last.map(|s| s.counters)
+    }
+
+    fn read(&self) -> Option<Sample> {
+        let counter = |name: &str| -> Option<u64> {
+            std::fs::read_to_string(self.dir.join(name))
+                .ok()?
+                .trim()
+                .parse()
+                .ok()
+        };
+        // Packet, error and drop counters are missing on some virtual devices
+        Some(Sample {
+            counters: Counters {
+                rx_bytes: counter("rx_bytes")?,
+                tx_bytes: counter("tx_bytes")?,
+                rx_packets: counter("rx_packets").unwrap_or_default(),
+                tx_packets: counter("tx_packets").unwrap_or_default(),
+                rx_errors: counter("rx_errors").unwrap_or_default(),
+                tx_errors: counter("tx_errors").unwrap_or_default(),
+                rx_dropped: counter("rx_dropped").unwrap_or_default(),
+                tx_dropped: counter("tx_dropped").unwrap_or_default(),
+            },
+            at: Instant::now(),
+        })
+    }
+}
+
+/// Every network interface except loopback, sorted by name
+pub fn interfaces() -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir("/sys/class/net") else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name != "lo")
+        .collect();
+    names.sort();
+    names
+}
+
+/// Format a byte rate compactly: "812B/s", "120KiB/s", "1.2MiB/s"
+pub fn format_rate(bytes_per_sec: f64) -> String {
+    format_scaled(bytes_per_sec, "/s")
+}
+
+/// Format a byte count compactly: "812B", "120KiB", "1.2GiB"
+pub fn format_bytes(bytes: u64) -> String {
+    format_scaled(bytes as f64, "")
+}
+
+fn format_scaled(bytes: f64, suffix: &str) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes.max(0.0);
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 || value >= 100.0 {
+        format!("{:.0}{}{suffix}", value, UNITS[unit])
+    } else {
+        format!("{:.1}{}{suffix}", value, UNITS[unit])
+    }
+}
+
+/// Number of samples kept for the traffic graph (one per second)
+pub const HISTORY_LEN: usize = 60;
+/// Samples kept per interface for the statistics view: one hour
+pub const LONG_HISTORY_LEN: usize = 3600;
+/// Polls the health score's drop share is measured over: the last minute
+pub const FAULT_WINDOW: usize = 60;
+
+/// Rolling window of recent rx/tx rates for the traffic graph
+#[derive(Debug, Clone, Default)]
+pub struct TrafficHistory {
+    pub rx: VecDeque<f64>,
+    pub tx: VecDeque<f64>,
+}
+
+impl TrafficHistory {
+    pub fn push(&mut self, rates: Rates) {
+        self.push_within(rates, HISTORY_LEN);
+    }
+
+    /// Append a sample, keeping at most `len`
+    pub fn push_within(&mut self, rates: Rates, len: usize) {
+        if self.rx.len() >= len {
+            self.rx.pop_front();
+            self.tx.pop_front();
+        }
+        self.rx.push_back(rates.rx);
+        self.tx.push_back(rates.tx);
+    }
+
+    pub fn clear(&mut self) {
+        self.rx.clear();
+        self.tx.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rx.is_empty()
+    }
+
+    /// Peak rate across both directions, so RX and TX share one scale
+    pub fn peak(&self) -> f64 {
+        self.rx.iter().chain(&self.tx).copied().fold(0.0, f64::max)
+    }
+}
+
+/// Time span shown by the statistics graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeRange {
+    #[default]
+    Minute,
+    TenMinutes,
+    Hour,
+}
+
+impl TimeRange {
+    /// Samples in the span, at one per second
+    pub fn samples(self) -> usize {
+        match self {
+            Self::Minute => 60,
+            Self::TenMinutes => 600,
+            Self::Hour => LONG_HISTORY_LEN,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Minute => "1 min",
+            Self::TenMinutes => "10 min",
+            Self::Hour => "1 h",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Minute => Self::TenMinutes,
+            Self::TenMinutes => Self::Hour,
+            Self::Hour => Self::Minute,
+        }
+    }
+}
+
+/// Sort key of the statistics table; the rates sort busiest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatsSort {
+    #[default]
+    Name,
+    RxRate,
+    TxRate,
+}
+
+impl StatsSort {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Name => Self::RxRate,
+            Self::RxRate => Self::TxRate,
+            Self::TxRate => Self::Name,
+        }
+    }
+
+    /// Order `stats` by this key, by name among equals
+    pub fn sort(self, stats: &mut [InterfaceStats]) {
+        let rate = |values: &VecDeque<f64>| values.back().copied().unwrap_or_default();
+        stats.sort_by(|a, b| {
+            let by_rate = match self {
+                Self::Name => std::cmp::Ordering::Equal,
+                Self::RxRate => rate(&b.history.rx).total_cmp(&rate(&a.history.rx)),
+                Self::TxRate => rate(&b.history.tx).total_cmp(&rate(&a.history.tx)),
+            };
+            by_rate.then_with(|| a.name.cmp(&b.name))
+        });
+    }
+}
+
+/// Counters and rate history of one interface since Nexus started
+pub struct InterfaceStats {
+    pub name: String,
+    /// Counters at the first successful poll
+    pub baseline: Option<Counters>,
+    pub history: TrafficHistory,
+    /// Counters of the last `FAULT_WINDOW` polls, oldest first
+    recent: VecDeque<Counters>,
+    poller: StatsPoller,
+}
+
+impl InterfaceStats {
+    pub fn new(name: String) -> Self {
+        Self {
+            poller: StatsPoller::new(&name),
+            name,
+            baseline: None,
+            history: TrafficHistory::default(),
+            recent: VecDeque::new(),
+        }
+    }
+
+    /// Take one sample; the first only sets the baseline
+    pub fn poll(&mut self) {
+        let rates = self.poller.poll();
+        if self.baseline.is_none() {
+            self.baseline = self.poller.latest();
+        }
+        if let Some(rates) = rates {
+            self.history.push_within(rates, LONG_HISTORY_LEN);
+        }
+        if let Some(counters) = self.poller.latest() {
+            // One more than the window, so the oldest is its starting point
+            if self.recent.len() > FAULT_WINDOW {
+                self.recent.pop_front();
+            }
+            self.recent.push_back(counters);
+        }
+    }
+
+    pub fn latest(&self) -> Option<Counters> {
+        self.poller.latest()
+    }
+
+    /// See `StatsPoller::restart`; the baseline is kept
+    pub fn restart(&mut self) {
+        self.poller.restart();
+    }
+
+    /// Growth of every counter since the baseline
+    pub fn since_launch(&self) -> Option<Counters> {
+        Some(self.latest()?.since(self.baseline.as_ref()?))
+    }
+
+    /// Growth of every counter over the last `FAULT_WINDOW` polls, so an
+    /// old burst of drops stops counting against the connection
+    pub fn recent(&self) -> Option<Counters> {
+        Some(self.recent.back()?.since(self.recent.front()?))
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use super::types::{FrequencyBand, WiFiNetwork};
+
+/// Signal statistics of one BSSID across survey samples
+#[derive(Debug, Clone)]
+pub struct ApStats {
+    pub ssid: String,
+    pub bssid: String,
+    pub frequency: u32,
+    pub samples: u32,
+    pub min: u8,
+    pub max: u8,
+    sum: u64,
+    pub last_seen: Instant,
+}
+
+impl ApStats {
+    pub fn avg(&self) -> f64 {
+        self.sum as f64 / f64::from(self.samples.max(1))
+    }
+
+    pub fn band(&self) -> FrequencyBand {
+        FrequencyBand::from_mhz(self.frequency)
+    }
+}
+
+/// Accumulates per-BSSID min/avg/max signal over repeated scans, for
+/// walking around and finding where coverage drops off
+#[derive(Debug, Clone, Default)]
+pub struct Survey {
+    aps: HashMap<String, ApStats>,
+    /// Number of scans recorded
+    pub rounds: u32,
+}
+
+impl Survey {
+    /// Fold one scan (one entry per BSSID) into the statistics
+    pub fn record(&mut self, aps: &[WiFiNetwork]) {
+        let now = Instant::now();
+        self.rounds += 1;
+        for ap in aps {
+            let strength = ap.signal_strength;
+            self.aps
+                .entry(ap.bssid.clone())
+                .and_modify(|s| {
+                    s.samples += 1;
+                    s.min = s.min.min(strength);
+                    s.max = s.max.max(strength);
+                    s.sum += u64::from(strength);
+                    s.last_seen = now;
+                })
+                .or_insert_with(|| ApStats {
+                    ssid: ap.ssid.clone(),
+                    bssid: ap.bssid.clone(),
+                    frequency: ap.frequency,
+                    samples: 1,
+                    min: strength,
+                    max: strength,
+                    sum: u64::from(strength),
+                    last_seen: now,
+                });
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn len(&self) -> usize {
+        self.aps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.aps.is_empty()
+    }
+
+    /// Rows sorted by average strength, strongest first
+    pub fn rows(&self) -> Vec<&ApStats> {
+        let mut rows: Vec<&ApStats> = self.aps.values().collect();
+        rows.sort_by(|a, b| b.avg().total_cmp(&a.avg()).then(a.ssid.cmp(&b.ssid)));
+        rows
+    }
+}
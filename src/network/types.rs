@@ -1,4 +1,8 @@
+use std::collections::HashMap;
 use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::i18n::Language;
 
 /// Security type of a WiFi network
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
@@ -57,6 +61,132 @@ impl SecurityType {
     }
 }
 
+// NM_802_11_AP_SEC_* bits of an AP's WpaFlags / RsnFlags
+const SEC_PAIR_WEP40: u32 = 0x1;
+const SEC_PAIR_WEP104: u32 = 0x2;
+const SEC_PAIR_TKIP: u32 = 0x4;
+const SEC_PAIR_CCMP: u32 = 0x8;
+const SEC_GROUP_WEP40: u32 = 0x10;
+const SEC_GROUP_WEP104: u32 = 0x20;
+const SEC_GROUP_TKIP: u32 = 0x40;
+const SEC_GROUP_CCMP: u32 = 0x80;
+const SEC_KEY_MGMT_PSK: u32 = 0x100;
+const SEC_KEY_MGMT_802_1X: u32 = 0x200;
+const SEC_KEY_MGMT_SAE: u32 = 0x400;
+const SEC_KEY_MGMT_OWE: u32 = 0x800;
+const SEC_KEY_MGMT_OWE_TM: u32 = 0x1000;
+const SEC_KEY_MGMT_EAP_SUITE_B_192: u32 = 0x2000;
+
+/// The raw WPA and RSN (WPA2/WPA3) capability flags an AP advertises
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SecurityFlags {
+    pub wpa: u32,
+    pub rsn: u32,
+}
+
+impl SecurityFlags {
+    /// Key management, ciphers and PMF, e.g. "WPA2-PSK (CCMP), PMF
+    /// optional". None for open and WEP networks, which advertise neither.
+    pub fn describe(self) -> Option<String> {
+        if self.wpa == 0 && self.rsn == 0 {
+            return None;
+        }
+        let mut suites = Vec::new();
+        if self.wpa & SEC_KEY_MGMT_PSK != 0 {
+            suites.push("WPA-PSK");
+        }
+        if self.wpa & SEC_KEY_MGMT_802_1X != 0 {
+            suites.push("WPA-EAP");
+        }
+        let rsn_suites = [
+            (SEC_KEY_MGMT_PSK, "WPA2-PSK"),
+            (SEC_KEY_MGMT_802_1X, "WPA2-EAP"),
+            (SEC_KEY_MGMT_SAE, "WPA3-SAE"),
+            (SEC_KEY_MGMT_EAP_SUITE_B_192, "WPA3-EAP-192"),
+            (SEC_KEY_MGMT_OWE, "OWE"),
+            (SEC_KEY_MGMT_OWE_TM, "OWE transition"),
+        ];
+        suites.extend(
+            rsn_suites
+                .iter()
+                .filter(|&&(bit, _)| self.rsn & bit != 0)
+                .map(|&(_, name)| name),
+        );
+
+        let flags = self.wpa | self.rsn;
+        let pairwise = cipher_names(
+            flags,
+            [
+                SEC_PAIR_CCMP,
+                SEC_PAIR_TKIP,
+                SEC_PAIR_WEP104,
+                SEC_PAIR_WEP40,
+            ],
+        );
+        let group = cipher_names(
+            flags,
+            [
+                SEC_GROUP_CCMP,
+                SEC_GROUP_TKIP,
+                SEC_GROUP_WEP104,
+                SEC_GROUP_WEP40,
+            ],
+        );
+        let mut ciphers = pairwise.join("+");
+        if !group.is_empty() && group != pairwise {
+            ciphers.push_str(&format!(", group {}", group.join("+")));
+        }
+
+        let mut text = if suites.is_empty() {
+            "WPA".to_string()
+        } else {
+            suites.join(" / ")
+        };
+        if !ciphers.is_empty() {
+            text.push_str(&format!(" ({ciphers})"));
+        }
+        if let Some(pmf) = self.pmf() {
+            text.push_str(&format!(", PMF {pmf}"));
+        }
+        Some(text)
+    }
+
+    /// NM doesn't report the RSN capabilities, so management frame
+    /// protection is inferred: WPA3 and OWE mandate it, and a WPA2/WPA3
+    /// transition network must offer it to its WPA3 clients
+    fn pmf(self) -> Option<&'static str> {
+        let mandatory = SEC_KEY_MGMT_SAE | SEC_KEY_MGMT_OWE | SEC_KEY_MGMT_EAP_SUITE_B_192;
+        if self.rsn & mandatory == 0 {
+            return None;
+        }
+        if self.rsn & (SEC_KEY_MGMT_PSK | SEC_KEY_MGMT_802_1X) != 0 {
+            Some("optional")
+        } else {
+            Some("required")
+        }
+    }
+
+    /// Pairwise and group ciphers include TKIP or WEP
+    pub fn has_weak_cipher(self) -> bool {
+        let weak = SEC_PAIR_TKIP
+            | SEC_PAIR_WEP40
+            | SEC_PAIR_WEP104
+            | SEC_GROUP_TKIP
+            | SEC_GROUP_WEP40
+            | SEC_GROUP_WEP104;
+        (self.wpa | self.rsn) & weak != 0
+    }
+}
+
+/// Names of the ciphers among `bits` (CCMP, TKIP, WEP-104, WEP-40 order)
+fn cipher_names(flags: u32, bits: [u32; 4]) -> Vec<&'static str> {
+    bits.into_iter()
+        .zip(["CCMP", "TKIP", "WEP-104", "WEP-40"])
+        .filter(|&(bit, _)| flags & bit != 0)
+        .map(|(_, name)| name)
+        .collect()
+}
+
 /// Frequency band
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FrequencyBand {
@@ -75,6 +205,23 @@ impl FrequencyBand {
             _ => Self::Unknown,
         }
     }
+
+    /// Value of NM's `802-11-wireless.band` that locks a profile to this band
+    pub fn nm_band(self) -> Option<&'static str> {
+        match self {
+            Self::TwoGhz => Some("bg"),
+            Self::FiveGhz => Some("a"),
+            Self::SixGhz | Self::Unknown => None,
+        }
+    }
+
+    pub fn from_nm_band(band: &str) -> Option<Self> {
+        match band {
+            "bg" => Some(Self::TwoGhz),
+            "a" => Some(Self::FiveGhz),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for FrequencyBand {
@@ -88,6 +235,63 @@ impl fmt::Display for FrequencyBand {
     }
 }
 
+/// Wi-Fi generation, inferred from band and advertised max bitrate.
+/// NM doesn't expose HT/VHT/HE capabilities directly, so this is a best guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WifiGeneration {
+    /// 802.11a/b/g (≤ 54 Mbps)
+    Legacy,
+    Wifi4,
+    Wifi5,
+    Wifi6,
+    Wifi6E,
+    Unknown,
+}
+
+/// Top rates (Mbit/s, rounded down) only 802.11ax reaches: MCS 11 with
+/// 1–4 streams at 20–160 MHz. 600.5 (1 stream @ 80 MHz) is left out, as HT
+/// 4x4 and VHT 3x3 @ 40 MHz top out at 600 too.
+const HE_RATES: [u32; 11] = [143, 286, 430, 573, 860, 1147, 1201, 1801, 2402, 3603, 4803];
+
+/// 802.11ac's highest rate: 4 streams @ 160 MHz
+const VHT_MAX_RATE: u32 = 3466;
+
+impl WifiGeneration {
+    /// Infer the generation from the AP's band and `MaxBitrate` (Mbit/s)
+    pub fn infer(band: FrequencyBand, max_bitrate: u32) -> Self {
+        match band {
+            // Only 802.11ax (and later) radios can operate on 6 GHz
+            FrequencyBand::SixGhz => Self::Wifi6E,
+            _ if max_bitrate == 0 => Self::Unknown,
+            // VHT's rates in between (866, 1300, 1733, 2340, …) never match
+            // an HE one
+            FrequencyBand::TwoGhz | FrequencyBand::FiveGhz
+                if HE_RATES.contains(&max_bitrate) || max_bitrate > VHT_MAX_RATE =>
+            {
+                Self::Wifi6
+            }
+            // HT maxes out at 600 Mbps; VHT 1x1 @ 80 MHz is 433
+            FrequencyBand::FiveGhz if max_bitrate > 600 || max_bitrate == 433 => Self::Wifi5,
+            FrequencyBand::TwoGhz | FrequencyBand::FiveGhz if max_bitrate > 54 => Self::Wifi4,
+            FrequencyBand::TwoGhz | FrequencyBand::FiveGhz => Self::Legacy,
+            FrequencyBand::Unknown => Self::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for WifiGeneration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Legacy => write!(f, "Legacy (a/b/g)"),
+            Self::Wifi4 => write!(f, "Wi-Fi 4"),
+            Self::Wifi5 => write!(f, "Wi-Fi 5"),
+            Self::Wifi6 => write!(f, "Wi-Fi 6"),
+            Self::Wifi6E => write!(f, "Wi-Fi 6E"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
 /// Compute WiFi channel from frequency in MHz
 pub fn channel_from_frequency(freq: u32) -> u32 {
     match freq {
@@ -120,7 +324,11 @@ pub struct WiFiNetwork {
     pub bssid: String,
     pub signal_strength: u8,
     pub frequency: u32,
+    /// Highest bitrate the AP advertises, in Mbit/s (0 = unknown)
+    pub max_bitrate: u32,
     pub security: SecurityType,
+    /// Ciphers and key management behind `security`
+    pub security_flags: SecurityFlags,
     pub is_saved: bool,
     pub is_active: bool,
     /// D-Bus object path for the AP
@@ -129,9 +337,18 @@ pub struct WiFiNetwork {
     pub seen_ticks: u16,
     /// Smoothed signal strength for animation
     pub display_signal: f32,
+    /// When a scan last heard this BSSID
+    pub last_seen: Instant,
+    /// Scans in a row that have not heard this BSSID
+    pub missed_scans: u32,
 }
 
 impl WiFiNetwork {
+    /// Whether the last scan missed this network (it is shown greyed out)
+    pub fn is_stale(&self) -> bool {
+        self.missed_scans > 0
+    }
+
     pub fn channel(&self) -> u32 {
         channel_from_frequency(self.frequency)
     }
@@ -139,10 +356,166 @@ impl WiFiNetwork {
     pub fn band(&self) -> FrequencyBand {
         FrequencyBand::from_mhz(self.frequency)
     }
+
+    /// Best-effort Wi-Fi generation of this AP
+    pub fn generation(&self) -> WifiGeneration {
+        WifiGeneration::infer(self.band(), self.max_bitrate)
+    }
+
+    /// An `nmcli` command that joins this network through this particular
+    /// AP. A hidden network has no SSID to give, so its BSSID stands in.
+    pub fn connect_command(&self) -> String {
+        if self.ssid.is_empty() {
+            return format!("nmcli device wifi connect {}", self.bssid);
+        }
+        format!(
+            "nmcli device wifi connect {} bssid {}",
+            shell_quote(&self.ssid),
+            self.bssid
+        )
+    }
+}
+
+#[cfg(test)]
+impl WiFiNetwork {
+    /// A WPA2 network on channel 6 heard just now, for tests; set any
+    /// other field with struct update syntax
+    pub fn test(ssid: &str, signal: u8) -> Self {
+        Self {
+            ssid: ssid.to_string(),
+            bssid: String::new(),
+            signal_strength: signal,
+            frequency: 2437,
+            max_bitrate: 0,
+            security: SecurityType::WPA2,
+            security_flags: SecurityFlags::default(),
+            is_saved: false,
+            is_active: false,
+            ap_path: String::new(),
+            seen_ticks: 0,
+            display_signal: signal as f32,
+            last_seen: Instant::now(),
+            missed_scans: 0,
+        }
+    }
+}
+
+/// NetworkManager's global connectivity verdict (`NMConnectivityState`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Connectivity {
+    #[default]
+    Unknown,
+    None,
+    Portal,
+    Limited,
+    Full,
+}
+
+impl Connectivity {
+    pub fn from_u32(state: u32) -> Self {
+        match state {
+            1 => Self::None,
+            2 => Self::Portal,
+            3 => Self::Limited,
+            4 => Self::Full,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Connectivity {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown",
+            Self::None => "None",
+            Self::Portal => "Captive portal",
+            Self::Limited => "Limited",
+            Self::Full => "Full",
+        }
+    }
+}
+
+impl fmt::Display for Connectivity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// NetworkManager's connectivity-check setup. The URI and interval come from
+/// NetworkManager.conf; only `enabled` can be changed over D-Bus.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectivityCheck {
+    /// `ConnectivityCheckAvailable`: a check URI is configured at all
+    pub available: bool,
+    /// `ConnectivityCheckEnabled`
+    pub enabled: bool,
+    /// `ConnectivityCheckUri`
+    pub uri: Option<String>,
+}
+
+impl ConnectivityCheck {
+    /// Whether NM is actually probing, i.e. its verdict means something
+    pub fn is_active(&self) -> bool {
+        self.available && self.enabled
+    }
+}
+
+/// An activation that ended without connecting. `reason` is the
+/// `NMDeviceStateReason` NM gave with the device's `Failed` transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActivationFailed {
+    pub reason: u32,
+}
+
+impl ActivationFailed {
+    pub const IP_CONFIG_UNAVAILABLE: u32 = 5;
+    pub const IP_CONFIG_EXPIRED: u32 = 6;
+    pub const NO_SECRETS: u32 = 7;
+    pub const SUPPLICANT_DISCONNECT: u32 = 8;
+    pub const SUPPLICANT_TIMEOUT: u32 = 11;
+
+    /// Transient failures that are worth another attempt (DHCP and
+    /// supplicant timeouts). Authentication errors never are.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.reason,
+            Self::IP_CONFIG_UNAVAILABLE | Self::IP_CONFIG_EXPIRED | Self::SUPPLICANT_TIMEOUT
+        )
+    }
+
+    /// The network wants a password that wasn't given or was rejected
+    pub fn needs_password(&self) -> bool {
+        matches!(self.reason, Self::NO_SECRETS | Self::SUPPLICANT_DISCONNECT)
+    }
 }
 
+impl fmt::Display for ActivationFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Wording of the common ones matches the patterns in `error::KNOWN_ERRORS`
+        match self.reason {
+            4 => write!(f, "The connection configuration failed"),
+            Self::IP_CONFIG_UNAVAILABLE => {
+                write!(f, "IP configuration could not be reserved (DHCP timed out)")
+            }
+            Self::IP_CONFIG_EXPIRED => write!(f, "The DHCP lease expired"),
+            Self::NO_SECRETS => write!(f, "Secrets were required, but not provided"),
+            Self::SUPPLICANT_DISCONNECT => {
+                write!(f, "The supplicant disconnected during authentication")
+            }
+            9 => write!(f, "The supplicant configuration failed"),
+            10 => write!(f, "The supplicant failed"),
+            Self::SUPPLICANT_TIMEOUT => write!(f, "The supplicant timed out during authentication"),
+            39 => write!(f, "The activation was cancelled"),
+            53 => write!(f, "The network could not be found"),
+            reason => write!(f, "Activation failed (NetworkManager reason {reason})"),
+        }
+    }
+}
+
+impl std::error::Error for ActivationFailed {}
+
 /// Information about the current active connection
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ConnectionInfo {
     pub ssid: String,
     pub bssid: String,
@@ -152,15 +525,480 @@ pub struct ConnectionInfo {
     pub dns: Vec<String>,
     pub mac: String,
     pub speed: u32,
+    pub mtu: u32,
     pub frequency: u32,
     pub signal: u8,
     pub interface: String,
+    /// Editable settings of the active saved profile
+    pub profile: ProfileSettings,
+    /// NM's global internet verdict
+    pub connectivity: Connectivity,
+    pub connectivity_check: ConnectivityCheck,
+    /// Lease NM's DHCP client holds for the connection, per family
+    pub dhcp4: Option<DhcpLease>,
+    pub dhcp6: Option<DhcpLease>,
+    /// Metric of the interface's IPv4 default route in the kernel's table
+    pub route_metric: Option<u32>,
+}
+
+/// What a DHCP server handed out, from the options NM exposes on its
+/// `DHCP4Config` / `DHCP6Config` objects
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DhcpLease {
+    pub server: Option<String>,
+    /// Lease duration in seconds
+    pub lease_time: Option<u64>,
+    /// When the lease runs out, in seconds since the Unix epoch
+    pub expiry: Option<u64>,
+    pub ntp_servers: Vec<String>,
+    pub domain: Option<String>,
+}
+
+impl DhcpLease {
+    /// Read the options dict. NM names most of them after dhclient's
+    /// environment variables, with `dhcp6_` variants for IPv6; the
+    /// `requested_*` entries are what was asked for, not what was offered.
+    pub fn from_options(options: &HashMap<String, String>) -> Self {
+        let get = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|key| options.get(*key))
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        Self {
+            server: get(&["dhcp_server_identifier", "dhcp6_server_id"]),
+            lease_time: get(&["dhcp_lease_time", "max_life"]).and_then(|s| s.parse().ok()),
+            expiry: get(&["expiry"]).and_then(|s| s.parse().ok()),
+            ntp_servers: get(&["ntp_servers", "dhcp6_ntp_servers"])
+                .map(|s| s.split_whitespace().map(str::to_string).collect())
+                .unwrap_or_default(),
+            domain: get(&["domain_name", "dhcp6_domain_search"]),
+        }
+    }
+
+    /// Seconds left on the lease at `now` (seconds since the Unix epoch)
+    pub fn remaining(&self, now: u64) -> Option<u64> {
+        Some(self.expiry?.saturating_sub(now))
+    }
+}
+
+impl ConnectionInfo {
+    /// Which IP families have an address: "v4", "v6", "v4+v6", or None
+    pub fn ip_stack(&self) -> Option<&'static str> {
+        match (self.ip4.is_some(), self.ip6.is_some()) {
+            (true, true) => Some("v4+v6"),
+            (true, false) => Some("v4"),
+            (false, true) => Some("v6"),
+            (false, false) => None,
+        }
+    }
+
+    /// How the profile gets its IPv6 address, from `ipv6.method`. "auto"
+    /// follows the router advertisement, which is SLAAC unless it sets the
+    /// managed flag, so that case is labelled as SLAAC.
+    pub fn ipv6_source(&self) -> Option<&'static str> {
+        match self.profile.ipv6_method.as_deref()? {
+            "auto" => Some("SLAAC (auto)"),
+            "dhcp" => Some("DHCPv6"),
+            "manual" => Some("static"),
+            "link-local" => Some("link-local only"),
+            "ignore" | "disabled" => Some("disabled"),
+            _ => None,
+        }
+    }
+
+    /// How the IPv4 address was configured, from `ipv4.method`; NM's "auto"
+    /// means DHCP
+    pub fn ipv4_method_label(&self) -> Option<&'static str> {
+        method_label(self.profile.ipv4_method.as_deref()?, "DHCP")
+    }
+
+    /// How the IPv6 address was configured, from `ipv6.method`
+    pub fn ipv6_method_label(&self) -> Option<&'static str> {
+        method_label(self.profile.ipv6_method.as_deref()?, "Auto")
+    }
+
+    /// NM's connectivity verdict, except that an Unknown caused by checking
+    /// being turned off says so rather than looking like a failure
+    pub fn internet_label(&self) -> &'static str {
+        match self.connectivity {
+            Connectivity::Unknown if !self.connectivity_check.is_active() => "Checking disabled",
+            state => state.label(),
+        }
+    }
+
+    /// An `nmcli` command that recreates the active profile on another
+    /// machine. Secrets are never included: the password has to be added
+    /// (`wifi-sec.psk …`) or entered on first connect.
+    pub fn share_command(&self) -> String {
+        let p = &self.profile;
+        let ssid = shell_quote(&self.ssid);
+        let mut args = vec![format!(
+            "nmcli connection add type wifi con-name {ssid} ssid {ssid}"
+        )];
+        if p.hidden {
+            args.push("802-11-wireless.hidden yes".into());
+        }
+        if let Some(key_mgmt) = &p.key_mgmt {
+            args.push(format!("wifi-sec.key-mgmt {}", shell_quote(key_mgmt)));
+        }
+        if let Some(band) = p.band.and_then(FrequencyBand::nm_band) {
+            args.push(format!("802-11-wireless.band {band}"));
+        }
+        if let Some(bssid) = &p.bssid {
+            args.push(format!("802-11-wireless.bssid {bssid}"));
+        }
+        if let Some(method) = &p.ipv6_method {
+            args.push(format!("ipv6.method {}", shell_quote(method)));
+        }
+        if let Some(hostname) = &p.dhcp_hostname {
+            args.push(format!("ipv4.dhcp-hostname {}", shell_quote(hostname)));
+        }
+        if !p.dhcp_send_hostname {
+            args.push("ipv4.dhcp-send-hostname no".into());
+        }
+        if let Some(client_id) = &p.dhcp_client_id {
+            args.push(format!("ipv4.dhcp-client-id {}", shell_quote(client_id)));
+        }
+        if let Some(metric) = p.route_metric {
+            args.push(format!(
+                "ipv4.route-metric {metric} ipv6.route-metric {metric}"
+            ));
+        }
+        if let Some(zone) = &p.zone {
+            args.push(format!("connection.zone {}", shell_quote(zone)));
+        }
+        if p.proxy_auto {
+            args.push("proxy.method auto".into());
+            if let Some(url) = &p.proxy_pac_url {
+                args.push(format!("proxy.pac-url {}", shell_quote(url)));
+            }
+        }
+        args.join(" ")
+    }
+}
+
+/// Rough RSSI for an NM signal strength. NM only exposes its 0–100 quality,
+/// so this inverts the usual `quality = 2 * (dBm + 100)` mapping.
+pub fn approx_dbm(strength: u8) -> i32 {
+    i32::from(strength.min(100)) / 2 - 100
+}
+
+/// Short name of an `ipv4.method` / `ipv6.method` value; `auto` differs
+/// per family
+fn method_label(method: &str, auto: &'static str) -> Option<&'static str> {
+    match method {
+        "auto" => Some(auto),
+        "dhcp" => Some("DHCP"),
+        "manual" => Some("Static"),
+        "link-local" => Some("Link-local"),
+        "shared" => Some("Shared"),
+        "ignore" | "disabled" => Some("Disabled"),
+        _ => None,
+    }
+}
+
+/// Quote `s` for a POSIX shell, leaving plain words as they are
+fn shell_quote(s: &str) -> String {
+    let plain = !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':' | '/'));
+    if plain {
+        s.to_string()
+    } else {
+        format!("'{}'", s.replace('\'', "'\\''"))
+    }
+}
+
+/// How long a forgotten profile can be restored with undo
+pub const UNDO_WINDOW: Duration = Duration::from_secs(30);
+
+/// A saved WiFi profile and when it was last used
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedProfile {
+    pub ssid: String,
+    /// `connection.timestamp`: Unix seconds of the last successful
+    /// activation; None if it never connected
+    pub last_connected: Option<u64>,
+}
+
+/// What `forget_network` kept for a later undo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForgetOutcome {
+    pub ssid: String,
+    /// False when the profile's secrets couldn't be read before deletion,
+    /// so restoring it will prompt for the password again
+    pub secrets_preserved: bool,
+}
+
+/// The active connection's saved profile: what Nexus can edit, plus where
+/// to find it from a shell for everything else
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileSettings {
+    /// `connection.uuid`
+    pub uuid: Option<String>,
+    /// On-disk keyfile, when NM stores the profile in one
+    pub keyfile: Option<String>,
+    /// `ipv4.method` ("auto", "manual", "link-local", "shared", "disabled")
+    pub ipv4_method: Option<String>,
+    /// `ipv6.method` ("auto", "dhcp", "manual", "link-local", "ignore", …)
+    pub ipv6_method: Option<String>,
+    /// `ipv4.dhcp-hostname` (None = the system hostname)
+    pub dhcp_hostname: Option<String>,
+    /// `ipv4.dhcp-send-hostname`
+    pub dhcp_send_hostname: bool,
+    /// `ipv4.dhcp-client-id` (None = NetworkManager's default)
+    pub dhcp_client_id: Option<String>,
+    /// `ipv4.route-metric` / `ipv6.route-metric` (None = NM's default, -1)
+    pub route_metric: Option<u32>,
+    /// `802-11-wireless.band` (None = any band)
+    pub band: Option<FrequencyBand>,
+    /// `802-11-wireless.bssid` as "AA:BB:CC:DD:EE:FF" (None = any access point)
+    pub bssid: Option<String>,
+    /// Access points "prefer faster band" pinned the profile to, kept in its
+    /// `user.data`. NM itself only holds the band (or, for 6 GHz, which has
+    /// no band value, the single strongest BSSID).
+    pub fast_band_bssids: Vec<String>,
+    /// `proxy.method` is auto (1) rather than none (0)
+    pub proxy_auto: bool,
+    /// `proxy.pac-url`; with auto and no URL, NM discovers one via WPAD
+    pub proxy_pac_url: Option<String>,
+    /// `802-11-wireless-security.key-mgmt` (None = open network)
+    pub key_mgmt: Option<String>,
+    /// `802-11-wireless.hidden`
+    pub hidden: bool,
+    /// `connection.zone`, the firewalld zone (None = firewalld's default)
+    pub zone: Option<String>,
+    /// `connection.autoconnect-retries`; 0 retries forever (None = NM's
+    /// default, -1, which is 4 unless NetworkManager.conf says otherwise)
+    pub autoconnect_retries: Option<u32>,
+}
+
+impl ProfileSettings {
+    /// Whether the profile is pinned to a faster band. A BSSID lock set
+    /// outside Nexus counts too, as it is cleared the same way.
+    pub fn fast_band(&self) -> bool {
+        !self.fast_band_bssids.is_empty() || self.bssid.is_some()
+    }
+
+    /// "auto (PAC)" / "auto (WPAD)" when the profile proxies traffic
+    pub fn proxy_summary(&self) -> Option<&'static str> {
+        match (self.proxy_auto, &self.proxy_pac_url) {
+            (false, _) => None,
+            (true, Some(_)) => Some("auto (PAC)"),
+            (true, None) => Some("auto (WPAD)"),
+        }
+    }
+}
+
+impl Default for ProfileSettings {
+    fn default() -> Self {
+        Self {
+            uuid: None,
+            keyfile: None,
+            ipv4_method: None,
+            ipv6_method: None,
+            dhcp_hostname: None,
+            dhcp_send_hostname: true,
+            dhcp_client_id: None,
+            route_metric: None,
+            band: None,
+            bssid: None,
+            fast_band_bssids: Vec::new(),
+            proxy_auto: false,
+            proxy_pac_url: None,
+            key_mgmt: None,
+            hidden: false,
+            zone: None,
+            autoconnect_retries: None,
+        }
+    }
+}
+
+/// A single edit to the active connection's saved profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileChange {
+    Hostname(Option<String>),
+    SendHostname(bool),
+    ClientId(Option<String>),
+    /// Applied to both IPv4 and IPv6 so the preference is consistent
+    RouteMetric(Option<u32>),
+    /// Lock the profile to 2.4 or 5 GHz, or let NM pick (None)
+    Band(Option<FrequencyBand>),
+    /// Pin the profile to the fastest band the network has in range, on
+    /// every access point it has there, or clear the pin
+    FastBand(bool),
+    /// `proxy.method`: auto (true) or none
+    ProxyAuto(bool),
+    /// `proxy.pac-url` (None = discover via WPAD)
+    PacUrl(Option<String>),
+    /// `connection.zone` (None = firewalld's default zone)
+    Zone(Option<String>),
+    /// `connection.autoconnect-retries`: a count, 0 = forever (None = NM's default)
+    AutoconnectRetries(Option<u32>),
+}
+
+impl ProfileChange {
+    /// What the change does, as a sentence for the confirm dialog
+    pub fn describe(&self, lang: Language) -> String {
+        match self {
+            Self::Hostname(Some(name)) => {
+                lang.fill("Send \"{name}\" as the DHCP hostname", &[("name", name)])
+            }
+            Self::Hostname(None) => lang.tr("Send the system hostname via DHCP").to_string(),
+            Self::SendHostname(true) => lang.tr("Send the hostname via DHCP").to_string(),
+            Self::SendHostname(false) => lang.tr("Stop sending the hostname via DHCP").to_string(),
+            Self::ClientId(Some(id)) => {
+                lang.fill("Use \"{id}\" as the DHCP client ID", &[("id", id)])
+            }
+            Self::ClientId(None) => lang.tr("Use the default DHCP client ID").to_string(),
+            Self::RouteMetric(Some(metric)) => {
+                lang.fill("Set the route metric to {metric}", &[("metric", metric)])
+            }
+            Self::RouteMetric(None) => lang.tr("Use the default route metric").to_string(),
+            Self::Band(Some(band)) => lang.fill(
+                "Only join {band} access points of this network (band \"{nm_band}\")",
+                &[
+                    ("band", band),
+                    ("nm_band", &band.nm_band().unwrap_or_default()),
+                ],
+            ),
+            Self::Band(None) => lang
+                .tr("Let NetworkManager pick any band for this network")
+                .to_string(),
+            Self::FastBand(true) => lang
+                .tr("Pin this network to its fastest band in range, on all its access points there")
+                .to_string(),
+            Self::FastBand(false) => lang
+                .tr("Let NetworkManager pick any access point and band")
+                .to_string(),
+            Self::ProxyAuto(true) => lang
+                .tr("Configure the proxy automatically (PAC / WPAD)")
+                .to_string(),
+            Self::ProxyAuto(false) => lang.tr("Connect without a proxy").to_string(),
+            Self::PacUrl(Some(url)) => {
+                lang.fill("Load the proxy configuration from {url}", &[("url", url)])
+            }
+            Self::PacUrl(None) => lang
+                .tr("Discover the proxy configuration via WPAD")
+                .to_string(),
+            Self::Zone(Some(zone)) => lang.fill(
+                "Put the connection in firewall zone \"{zone}\"",
+                &[("zone", zone)],
+            ),
+            Self::Zone(None) => lang.tr("Use the default firewall zone").to_string(),
+            Self::AutoconnectRetries(Some(0)) => lang
+                .tr("Keep retrying to autoconnect, never give up")
+                .to_string(),
+            Self::AutoconnectRetries(Some(n)) => lang.fill(
+                "Give up autoconnecting after {count} failed attempts",
+                &[("count", n)],
+            ),
+            Self::AutoconnectRetries(None) => {
+                lang.tr("Use the default autoconnect retries").to_string()
+            }
+        }
+    }
+}
+
+impl fmt::Display for ProfileChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe(Language::En))
+    }
+}
+
+/// The access points of `ssid` on its fastest band in `aps` (6 GHz over
+/// 5 GHz; 2.4 GHz never counts), strongest first
+pub fn fast_band_aps<'a>(aps: &'a [WiFiNetwork], ssid: &str) -> Vec<&'a WiFiNetwork> {
+    let on = |band: FrequencyBand| -> Vec<&WiFiNetwork> {
+        let mut on: Vec<&WiFiNetwork> = aps
+            .iter()
+            .filter(|ap| ap.ssid == ssid && ap.band() == band)
+            .collect();
+        on.sort_by_key(|ap| std::cmp::Reverse(ap.signal_strength));
+        on
+    };
+    let six = on(FrequencyBand::SixGhz);
+    if six.is_empty() {
+        on(FrequencyBand::FiveGhz)
+    } else {
+        six
+    }
+}
+
+/// Reject passwords NetworkManager is certain to refuse for `security`,
+/// before a connection attempt is made. Lengths are in bytes, as NM counts
+/// them. With `Unknown` security (a hidden network) the key is checked as
+/// WPA, since hidden networks are always joined with a WPA-PSK profile.
+pub fn validate_password(
+    security: &SecurityType,
+    password: &str,
+    lang: Language,
+) -> Result<(), String> {
+    let hex = |len: usize| password.len() == len && password.chars().all(|c| c.is_ascii_hexdigit());
+    // 40/104-bit keys as ASCII (5/13 chars) or hex (10/26 digits)
+    let wep_ok = (password.is_ascii() && matches!(password.len(), 5 | 13)) || hex(10) || hex(26);
+    let wpa = || {
+        let len = password.len();
+        // A 64-digit hex string is a raw PSK
+        if (8..=63).contains(&len) || hex(64) {
+            Ok(())
+        } else if len < 8 {
+            Err(lang.fill(
+                "WPA passwords are at least 8 bytes (got {len})",
+                &[("len", &len)],
+            ))
+        } else {
+            Err(lang.tr("WPA passwords are at most 63 bytes").to_string())
+        }
+    };
+    match security {
+        SecurityType::Wep if wep_ok => Ok(()),
+        SecurityType::Wep => Err(lang
+            .tr("WEP keys are 5 or 13 ASCII characters, or 10 or 26 hex digits")
+            .to_string()),
+        SecurityType::Wpa | SecurityType::WPA2 | SecurityType::WPA3 | SecurityType::Unknown => {
+            wpa()
+        }
+        SecurityType::Open | SecurityType::WPA2Enterprise => Ok(()),
+    }
+}
+
+/// Check a DHCP hostname against RFC 1123: dot-separated labels of 1–63
+/// letters, digits or hyphens, not starting or ending with a hyphen.
+pub fn validate_hostname(name: &str, lang: Language) -> Result<(), String> {
+    if name.len() > 253 {
+        return Err(lang
+            .tr("Hostname is longer than 253 characters")
+            .to_string());
+    }
+    for label in name.split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(lang.fill(
+                "\"{name}\" has an empty or over-long (>63) label",
+                &[("name", &name)],
+            ));
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err(lang.fill(
+                "Label \"{label}\" may not start or end with '-'",
+                &[("label", &label)],
+            ));
+        }
+        if let Some(c) = label
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || *c == '-'))
+        {
+            return Err(lang.fill("Hostnames may not contain '{char}'", &[("char", &c)]));
+        }
+    }
+    Ok(())
 }
 
 /// Overall connection status
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub enum ConnectionStatus {
-    Connected(ConnectionInfo),
+    Connected(Box<ConnectionInfo>),
     Connecting(String),
     Disconnecting,
     #[default]
@@ -177,3 +1015,278 @@ impl ConnectionStatus {
         matches!(self, Self::Connecting(_) | Self::Disconnecting)
     }
 }
+
+/// A WiFi adapter NetworkManager knows about
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WifiDevice {
+    pub interface: String,
+    /// Kernel driver, e.g. "iwlwifi" (empty when NM doesn't say)
+    pub driver: String,
+    pub state: DeviceState,
+}
+
+/// NetworkManager device state (`NMDeviceState`), as reported by the
+/// device's `State` property and `StateChanged` signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeviceState {
+    #[default]
+    Unknown,
+    Unmanaged,
+    Unavailable,
+    Disconnected,
+    Prepare,
+    Config,
+    NeedAuth,
+    IpConfig,
+    IpCheck,
+    Secondaries,
+    Activated,
+    Deactivating,
+    Failed,
+}
+
+impl DeviceState {
+    pub fn from_u32(state: u32) -> Self {
+        match state {
+            10 => Self::Unmanaged,
+            20 => Self::Unavailable,
+            30 => Self::Disconnected,
+            40 => Self::Prepare,
+            50 => Self::Config,
+            60 => Self::NeedAuth,
+            70 => Self::IpConfig,
+            80 => Self::IpCheck,
+            90 => Self::Secondaries,
+            100 => Self::Activated,
+            110 => Self::Deactivating,
+            120 => Self::Failed,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// True while the device is somewhere in the activation pipeline
+    pub fn is_connecting(&self) -> bool {
+        matches!(
+            self,
+            Self::Prepare
+                | Self::Config
+                | Self::NeedAuth
+                | Self::IpConfig
+                | Self::IpCheck
+                | Self::Secondaries
+        )
+    }
+
+    /// Approximate activation progress (0.0 - 1.0) for the connecting states.
+    /// Returns None outside the activation pipeline.
+    pub fn activation_progress(&self) -> Option<f64> {
+        match self {
+            Self::Prepare => Some(0.15),
+            Self::Config => Some(0.3),
+            Self::NeedAuth => Some(0.45),
+            Self::IpConfig => Some(0.6),
+            Self::IpCheck => Some(0.8),
+            Self::Secondaries => Some(0.9),
+            Self::Activated => Some(1.0),
+            _ => None,
+        }
+    }
+
+    /// Short human-readable label for the current stage
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown",
+            Self::Unmanaged => "Unmanaged",
+            Self::Unavailable => "Unavailable",
+            Self::Disconnected => "Disconnected",
+            Self::Prepare => "Preparing",
+            Self::Config => "Associating",
+            Self::NeedAuth => "Authenticating",
+            Self::IpConfig => "Getting IP",
+            Self::IpCheck => "Checking connectivity",
+            Self::Secondaries => "Starting secondaries",
+            Self::Activated => "Activated",
+            Self::Deactivating => "Deactivating",
+            Self::Failed => "Failed",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Privacy bit of NM80211ApFlags
+    const PRIVACY: u32 = 0x1;
+
+    #[test]
+    fn security_type_from_flags() {
+        let cases = [
+            (0, 0, 0, SecurityType::Open),
+            (PRIVACY, 0, 0, SecurityType::Wep),
+            (PRIVACY, 0x144, 0, SecurityType::Wpa),
+            (PRIVACY, 0x244, 0, SecurityType::WPA2Enterprise),
+            (PRIVACY, 0, 0x188, SecurityType::WPA2),
+            (PRIVACY, 0x14c, 0x18c, SecurityType::WPA2),
+            (PRIVACY, 0, 0x488, SecurityType::WPA3),
+            // WPA2/WPA3 transition
+            (PRIVACY, 0, 0x588, SecurityType::WPA3),
+            (PRIVACY, 0, 0x288, SecurityType::WPA2Enterprise),
+        ];
+        for (flags, wpa, rsn, expected) in cases {
+            assert_eq!(
+                SecurityType::from_flags(flags, wpa, rsn),
+                expected,
+                "flags {flags:#x} wpa {wpa:#x} rsn {rsn:#x}"
+            );
+        }
+    }
+
+    #[test]
+    fn security_flags_describe() {
+        let cases = [
+            (0, 0x188, "WPA2-PSK (CCMP)"),
+            (0x144, 0, "WPA-PSK (TKIP)"),
+            (0x14c, 0x18c, "WPA-PSK / WPA2-PSK (CCMP+TKIP)"),
+            (0, 0x148, "WPA2-PSK (CCMP, group TKIP)"),
+            (0, 0x288, "WPA2-EAP (CCMP)"),
+            (0, 0x488, "WPA3-SAE (CCMP), PMF required"),
+            (0, 0x588, "WPA2-PSK / WPA3-SAE (CCMP), PMF optional"),
+            (0, 0x2288, "WPA2-EAP / WPA3-EAP-192 (CCMP), PMF optional"),
+            (0, 0x888, "OWE (CCMP), PMF required"),
+            // Ciphers without a key management bit
+            (0, 0x88, "WPA (CCMP)"),
+        ];
+        for (wpa, rsn, expected) in cases {
+            let flags = SecurityFlags { wpa, rsn };
+            assert_eq!(flags.describe().as_deref(), Some(expected), "{flags:?}");
+        }
+        assert_eq!(SecurityFlags::default().describe(), None);
+    }
+
+    #[test]
+    fn weak_ciphers() {
+        let weak = |wpa, rsn| SecurityFlags { wpa, rsn }.has_weak_cipher();
+        assert!(weak(0x144, 0));
+        assert!(weak(0x14c, 0x18c));
+        // CCMP pairwise with a TKIP group cipher is still weak
+        assert!(weak(0, 0x148));
+        assert!(!weak(0, 0x188));
+        assert!(!weak(0, 0x488));
+        assert!(!weak(0, 0));
+    }
+
+    #[test]
+    fn cipher_names_in_strength_order() {
+        let pairwise = [
+            SEC_PAIR_CCMP,
+            SEC_PAIR_TKIP,
+            SEC_PAIR_WEP104,
+            SEC_PAIR_WEP40,
+        ];
+        assert_eq!(
+            cipher_names(0xf, pairwise),
+            ["CCMP", "TKIP", "WEP-104", "WEP-40"]
+        );
+        assert_eq!(
+            cipher_names(SEC_PAIR_TKIP | SEC_GROUP_CCMP, pairwise),
+            ["TKIP"]
+        );
+        assert!(cipher_names(0xf0, pairwise).is_empty());
+    }
+
+    #[test]
+    fn generation_from_boundary_rates() {
+        use FrequencyBand::{FiveGhz, SixGhz, TwoGhz};
+        use WifiGeneration::*;
+
+        let cases = [
+            // 5 GHz: VHT rates stay Wi-Fi 5 however high they go
+            (FiveGhz, 433, Wifi5),
+            (FiveGhz, 866, Wifi5),
+            (FiveGhz, 1300, Wifi5),
+            (FiveGhz, 1733, Wifi5),
+            (FiveGhz, 2340, Wifi5),
+            (FiveGhz, 3466, Wifi5),
+            // HE rates on either side of them
+            (FiveGhz, 573, Wifi6),
+            (FiveGhz, 1201, Wifi6),
+            (FiveGhz, 2402, Wifi6),
+            (FiveGhz, 4803, Wifi6),
+            (FiveGhz, 4804, Wifi6),
+            // HT, and the 600 every standard shares
+            (FiveGhz, 300, Wifi4),
+            (FiveGhz, 600, Wifi4),
+            (FiveGhz, 54, Legacy),
+            (FiveGhz, 0, Unknown),
+            // 2.4 GHz
+            (TwoGhz, 54, Legacy),
+            (TwoGhz, 144, Wifi4),
+            (TwoGhz, 143, Wifi6),
+            (TwoGhz, 286, Wifi6),
+            (TwoGhz, 300, Wifi4),
+            (TwoGhz, 573, Wifi6),
+            (TwoGhz, 600, Wifi4),
+            // 6 GHz is 802.11ax whatever the rate
+            (SixGhz, 0, Wifi6E),
+            (SixGhz, 1201, Wifi6E),
+            (FrequencyBand::Unknown, 1201, WifiGeneration::Unknown),
+        ];
+        for (band, rate, expected) in cases {
+            assert_eq!(
+                WifiGeneration::infer(band, rate),
+                expected,
+                "{band:?} @ {rate} Mbit/s"
+            );
+        }
+    }
+
+    #[test]
+    fn password_lengths() {
+        let check =
+            |security, password: &str| validate_password(&security, password, Language::En).is_ok();
+        assert!(check(SecurityType::Wep, "abcde"));
+        assert!(check(SecurityType::Wep, "0123456789"));
+        assert!(!check(SecurityType::Wep, "abcdef"));
+        assert!(check(SecurityType::WPA2, "12345678"));
+        assert!(!check(SecurityType::WPA2, "1234567"));
+        assert!(check(SecurityType::WPA2, &"f".repeat(64)));
+        assert!(!check(SecurityType::WPA2, &"x".repeat(64)));
+        // 7 characters, but 8 bytes
+        assert!(check(SecurityType::WPA2, "pässwor"));
+        assert!(!check(SecurityType::WPA2, &"ä".repeat(32)));
+        assert!(!check(SecurityType::Wep, "äbcd"));
+        // Hidden networks are joined as WPA, so WEP-only keys are refused
+        assert!(!check(SecurityType::Unknown, "abcde"));
+        assert!(check(SecurityType::Unknown, "0123456789abcdef0123456789"));
+        assert!(check(SecurityType::Unknown, "12345678"));
+    }
+
+    #[test]
+    fn fast_band_takes_every_ap_on_the_fastest_band() {
+        let ap = |ssid: &str, bssid: &str, frequency, signal| WiFiNetwork {
+            bssid: bssid.to_string(),
+            frequency,
+            is_saved: true,
+            ..WiFiNetwork::test(ssid, signal)
+        };
+        let bssids = |aps: &[WiFiNetwork]| -> Vec<String> {
+            fast_band_aps(aps, "Home")
+                .iter()
+                .map(|ap| ap.bssid.clone())
+                .collect()
+        };
+        let mut aps = vec![
+            ap("Home", "aa", 2437, 90),
+            ap("Home", "bb", 5180, 40),
+            ap("Home", "cc", 5500, 70),
+            ap("Other", "dd", 5180, 99),
+        ];
+        assert_eq!(bssids(&aps), ["cc", "bb"]);
+
+        aps.push(ap("Home", "ee", 5975, 20));
+        assert_eq!(bssids(&aps), ["ee"]);
+
+        assert!(bssids(&aps[..1]).is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use eyre::Result;
+use tokio::sync::mpsc;
+
+use crate::config::{CliArgs, Config};
+use crate::network::NetworkBackend;
+use crate::network::manager::NmBackend;
+use crate::network::mock::MockBackend;
+use crate::network::stats::{Rates, StatsPoller, format_rate};
+use crate::network::types::ConnectionInfo;
+
+/// Default `--format` template
+pub const DEFAULT_FORMAT: &str = "{ssid} {signal}% ↓{rx} ↑{tx}";
+
+/// How long to sample traffic counters for the rx/tx rates
+const SAMPLE_WINDOW: Duration = Duration::from_secs(1);
+
+/// `--oneline`: print a single status line for prompts and status bars, then
+/// exit. Placeholders: {ssid} {signal} {ip} {iface} {rx} {tx}.
+pub async fn run(cli: &CliArgs, config: &Config) -> Result<()> {
+    let template = cli.format.as_deref().unwrap_or(DEFAULT_FORMAT);
+
+    let line = if cli.use_mock_backend() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        status_line(&MockBackend::new(tx), template).await?
+    } else {
+        status_line(&NmBackend::new(config.interface()).await?, template).await?
+    };
+
+    println!("{line}");
+    Ok(())
+}
+
+/// Render the template against the current connection
+async fn status_line<B: NetworkBackend>(backend: &B, template: &str) -> Result<String> {
+    let Some(info) = backend.current_connection().await? else {
+        return Ok("disconnected".to_string());
+    };
+
+    let mut poller = StatsPoller::new(&info.interface);
+    poller.poll();
+    tokio::time::sleep(SAMPLE_WINDOW).await;
+    let rates = poller.poll().unwrap_or_default();
+
+    Ok(render(template, &info, rates))
+}
+
+fn render(template: &str, info: &ConnectionInfo, rates: Rates) -> String {
+    let ip = info
+        .ip4
+        .as_deref()
+        .or(info.ip6.as_deref())
+        .unwrap_or("")
+        .split('/')
+        .next()
+        .unwrap_or("");
+
+    template
+        .replace("{ssid}", &info.ssid)
+        .replace("{signal}", &info.signal.to_string())
+        .replace("{ip}", ip)
+        .replace("{iface}", &info.interface)
+        .replace("{rx}", &format_rate(rates.rx))
+        .replace("{tx}", &format_rate(rates.tx))
+}
@@ -0,0 +1,270 @@
+use std::cmp::Reverse;
+
+use crate::app::View;
+
+/// Most hits listed per group in the global search
+const GROUP_LIMIT: usize = 5;
+
+/// A view or action reachable from the command palette or the quick
+/// actions menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// One of the `[layout] pages`
+    Page(View),
+    // Per-network actions, offered by the quick actions menu
+    Connect,
+    TogglePin,
+    ToggleMark,
+    Forget,
+    ToggleDetails,
+    ShellEscape,
+    Events,
+    Diagnostics,
+    ConnectionSettings,
+    Help,
+    Scan,
+    Disconnect,
+    Refresh,
+    IgnoreList,
+    WriteKeys,
+}
+
+impl Action {
+    /// Everything but the pages, which come from the layout
+    const OTHERS: [Self; 9] = [
+        Self::Events,
+        Self::Diagnostics,
+        Self::ConnectionSettings,
+        Self::Help,
+        Self::Scan,
+        Self::Disconnect,
+        Self::Refresh,
+        Self::IgnoreList,
+        Self::WriteKeys,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Page(View::Survey) => "Site survey",
+            Self::Page(view) => view.label(),
+            Self::Connect => "Connect",
+            Self::TogglePin => "Pin to top",
+            Self::ToggleMark => "Mark for bulk forget",
+            Self::Forget => "Forget",
+            Self::ToggleDetails => "Show / hide details",
+            Self::ShellEscape => "nmcli command",
+            Self::Events => "Events log",
+            Self::Diagnostics => "Diagnostics",
+            Self::ConnectionSettings => "Connection settings",
+            Self::Help => "Help",
+            Self::Scan => "Scan",
+            Self::Disconnect => "Disconnect",
+            Self::Refresh => "Refresh connection",
+            Self::IgnoreList => "Ignored networks",
+            Self::WriteKeys => "Write keys cheatsheet",
+        }
+    }
+
+    /// Other words the entry answers to
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Self::Page(View::Networks) => &["wifi", "list"],
+            Self::Page(View::Statistics) => &["stats", "traffic", "interfaces"],
+            Self::Page(View::Survey) => &["signal"],
+            Self::Connect
+            | Self::TogglePin
+            | Self::ToggleMark
+            | Self::Forget
+            | Self::ToggleDetails
+            | Self::ShellEscape => &[],
+            Self::Events => &["log", "nm"],
+            Self::Diagnostics => &["diag", "health", "doctor"],
+            Self::ConnectionSettings => &["edit", "profile"],
+            Self::Help => &["keys"],
+            Self::Scan => &["rescan"],
+            Self::Disconnect => &[],
+            Self::Refresh => &["reload"],
+            Self::IgnoreList => &["ignore", "hide"],
+            Self::WriteKeys => &["keymap", "bindings"],
+        }
+    }
+
+    /// Views are switched to; everything else runs once
+    pub fn is_view(self) -> bool {
+        matches!(
+            self,
+            Self::Page(_)
+                | Self::Events
+                | Self::Diagnostics
+                | Self::ConnectionSettings
+                | Self::Help
+                | Self::IgnoreList
+        )
+    }
+
+    fn score(self, query: &str) -> Option<u32> {
+        std::iter::once(self.label())
+            .chain(self.aliases().iter().copied())
+            .filter_map(|name| fuzzy_score(query, name))
+            .max()
+    }
+}
+
+/// Entries matching `query`, best first: the layout's pages, then the other
+/// views and actions. An empty query lists them all.
+pub fn matches(query: &str, pages: &[View]) -> Vec<Action> {
+    let query = query.trim();
+    let entries = pages
+        .iter()
+        .map(|&view| Action::Page(view))
+        .chain(Action::OTHERS);
+    ranked(entries.map(|entry| (entry.score(query), entry)), usize::MAX)
+}
+
+/// A result of the global search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hit {
+    Network(String),
+    Interface(String),
+    Action(Action),
+}
+
+impl Hit {
+    /// Heading the hit is listed under
+    pub fn group(&self) -> &'static str {
+        match self {
+            Self::Network(_) => "Networks",
+            Self::Interface(_) => "Interfaces",
+            Self::Action(_) => "Views & actions",
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Network(name) | Self::Interface(name) => name,
+            Self::Action(action) => action.label(),
+        }
+    }
+}
+
+/// Fuzzy-search SSIDs, interface names and the palette's views and actions
+/// at once. Hits are grouped in that order, best first within a group. An
+/// empty query finds nothing.
+pub fn search<'a>(
+    query: &str,
+    ssids: impl IntoIterator<Item = &'a str>,
+    interfaces: impl IntoIterator<Item = &'a str>,
+    pages: &[View],
+) -> Vec<Hit> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let named = |names: Vec<&str>, hit: fn(String) -> Hit| {
+        ranked(
+            names
+                .into_iter()
+                .map(|name| (fuzzy_score(query, name), hit(name.to_string()))),
+            GROUP_LIMIT,
+        )
+    };
+    let mut hits = named(ssids.into_iter().collect(), Hit::Network);
+    hits.extend(named(interfaces.into_iter().collect(), Hit::Interface));
+    hits.extend(
+        matches(query, pages)
+            .into_iter()
+            .take(GROUP_LIMIT)
+            .map(Hit::Action),
+    );
+    hits
+}
+
+/// The scored items, best first and at most `limit` of them. The sort is
+/// stable, so equal scores keep the listing order.
+fn ranked<T>(scored: impl Iterator<Item = (Option<u32>, T)>, limit: usize) -> Vec<T> {
+    let mut scored: Vec<(u32, T)> = scored
+        .filter_map(|(score, item)| Some((score?, item)))
+        .collect();
+    scored.sort_by_key(|&(score, _)| Reverse(score));
+    scored
+        .into_iter()
+        .take(limit)
+        .map(|(_, item)| item)
+        .collect()
+}
+
+/// Score `query` as a case-insensitive subsequence of `target`. Runs of
+/// consecutive characters and matches at word starts score higher, so
+/// "diag" ranks "Diagnostics" above "Disconnect".
+fn fuzzy_score(query: &str, target: &str) -> Option<u32> {
+    let target: Vec<char> = target.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut previous: Option<usize> = None;
+    for c in query.to_lowercase().chars() {
+        let found = pos + target[pos..].iter().position(|&t| t == c)?;
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == found) {
+            score += 5;
+        }
+        if found == 0 || matches!(target[found - 1], ' ' | '-') {
+            score += 10;
+        }
+        previous = Some(found);
+        pos = found + 1;
+    }
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsequence_matches_case_insensitively() {
+        assert!(fuzzy_score("diag", "Diagnostics").is_some());
+        assert!(fuzzy_score("DIAG", "diagnostics").is_some());
+        assert!(fuzzy_score("fsc", "Fresh scan").is_some());
+        assert_eq!(fuzzy_score("", "Anything"), Some(0));
+    }
+
+    #[test]
+    fn non_matches() {
+        assert_eq!(fuzzy_score("diag", "Disconnect"), None);
+        // Every character must appear, in order
+        assert_eq!(fuzzy_score("nacs", "Scan"), None);
+        assert_eq!(fuzzy_score("scann", "Scan"), None);
+        assert_eq!(fuzzy_score("x", ""), None);
+    }
+
+    #[test]
+    fn word_starts_rank_higher() {
+        let connect = fuzzy_score("con", "Connect").unwrap();
+        let disconnect = fuzzy_score("con", "Disconnect").unwrap();
+        assert!(connect > disconnect);
+
+        let hyphen = fuzzy_score("n", "wifi-network").unwrap();
+        let inner = fuzzy_score("n", "wifinetwork").unwrap();
+        assert!(hyphen > inner);
+    }
+
+    #[test]
+    fn consecutive_runs_rank_higher() {
+        let run = fuzzy_score("can", "Rescan").unwrap();
+        let scattered = fuzzy_score("can", "Recharging").unwrap();
+        assert!(run > scattered);
+    }
+
+    #[test]
+    fn ranked_orders_best_first_and_keeps_ties_stable() {
+        let items = ["Disconnect", "Connect", "Diagnostics", "Connectivity check"];
+        let scored = items.iter().map(|item| (fuzzy_score("con", item), *item));
+        assert_eq!(
+            ranked(scored, 10),
+            ["Connect", "Connectivity check", "Disconnect"]
+        );
+
+        let scored = items.iter().map(|item| (fuzzy_score("con", item), *item));
+        assert_eq!(ranked(scored, 1), ["Connect"]);
+    }
+}
@@ -0,0 +1,194 @@
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use eyre::{Result, WrapErr};
+
+use crate::network::stats::{Counters, InterfaceStats};
+use crate::network::types::ConnectionInfo;
+
+/// Kernel counters exported per interface, as (metric suffix, help), in
+/// the order `counter_values` returns them
+const COUNTERS: [(&str, &str); 8] = [
+    ("receive_bytes_total", "Bytes received on the interface."),
+    ("transmit_bytes_total", "Bytes sent on the interface."),
+    (
+        "receive_packets_total",
+        "Packets received on the interface.",
+    ),
+    ("transmit_packets_total", "Packets sent on the interface."),
+    ("receive_errs_total", "Receive errors on the interface."),
+    ("transmit_errs_total", "Transmit errors on the interface."),
+    (
+        "receive_drop_total",
+        "Received packets dropped on the interface.",
+    ),
+    (
+        "transmit_drop_total",
+        "Outgoing packets dropped on the interface.",
+    ),
+];
+
+fn counter_values(c: &Counters) -> [u64; 8] {
+    [
+        c.rx_bytes,
+        c.tx_bytes,
+        c.rx_packets,
+        c.tx_packets,
+        c.rx_errors,
+        c.tx_errors,
+        c.rx_dropped,
+        c.tx_dropped,
+    ]
+}
+
+/// Interface counters and the active AP's signal, rewritten after every
+/// stats poll as a file for node_exporter's textfile collector
+/// (`[stats] prometheus_textfile`)
+pub struct TextfileExporter {
+    path: PathBuf,
+}
+
+impl TextfileExporter {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Replace the file. It is written beside the target and renamed over
+    /// it, so the collector never reads half a file; the temporary name
+    /// doesn't end in `.prom`, so the collector skips it.
+    pub fn write(
+        &self,
+        interfaces: &[InterfaceStats],
+        wifi: Option<&ConnectionInfo>,
+    ) -> Result<()> {
+        let sampled: Vec<(&str, Counters)> = interfaces
+            .iter()
+            .filter_map(|s| Some((s.name.as_str(), s.latest()?)))
+            .collect();
+        let tmp = self.path.with_extension("prom.tmp");
+        std::fs::write(&tmp, render(&sampled, wifi))
+            .and_then(|()| std::fs::rename(&tmp, &self.path))
+            .wrap_err_with(|| format!("Failed to write {}", self.path.display()))
+    }
+}
+
+/// The metrics in Prometheus text exposition format, from the latest
+/// counters of each sampled interface
+fn render(sampled: &[(&str, Counters)], wifi: Option<&ConnectionInfo>) -> String {
+    let sampled: Vec<(&str, [u64; 8])> = sampled
+        .iter()
+        .map(|(name, counters)| (*name, counter_values(counters)))
+        .collect();
+
+    let mut out = String::new();
+    for (i, (suffix, help)) in COUNTERS.iter().enumerate() {
+        let _ = writeln!(out, "# HELP nexus_network_{suffix} {help}");
+        let _ = writeln!(out, "# TYPE nexus_network_{suffix} counter");
+        for (name, values) in &sampled {
+            let _ = writeln!(
+                out,
+                "nexus_network_{suffix}{{device=\"{}\"}} {}",
+                escape_label(name),
+                values[i]
+            );
+        }
+    }
+
+    let _ = writeln!(
+        out,
+        "# HELP nexus_wifi_signal_strength Signal quality of the active access point, 0-100."
+    );
+    let _ = writeln!(out, "# TYPE nexus_wifi_signal_strength gauge");
+    if let Some(info) = wifi {
+        let _ = writeln!(
+            out,
+            "nexus_wifi_signal_strength{{device=\"{}\",ssid=\"{}\",bssid=\"{}\"}} {}",
+            escape_label(&info.interface),
+            escape_label(&info.ssid),
+            escape_label(&info.bssid),
+            info.signal
+        );
+    }
+    out
+}
+
+/// Escape a label value: backslash, double quote and line feed
+fn escape_label(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counters(rx_bytes: u64) -> Counters {
+        Counters {
+            rx_bytes,
+            tx_bytes: 2,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn every_metric_has_help_and_type() {
+        let out = render(&[], None);
+        for (suffix, _) in COUNTERS {
+            assert!(out.contains(&format!("# HELP nexus_network_{suffix} ")));
+            assert!(out.contains(&format!("# TYPE nexus_network_{suffix} counter\n")));
+        }
+        assert!(out.contains("# HELP nexus_wifi_signal_strength "));
+        assert!(out.contains("# TYPE nexus_wifi_signal_strength gauge\n"));
+        // No samples without interfaces or a connection
+        assert!(out.lines().all(|l| l.starts_with('#')));
+    }
+
+    #[test]
+    fn one_sample_per_interface() {
+        let out = render(&[("wlan0", counters(100)), ("eth0", counters(7))], None);
+        let rx: Vec<&str> = out
+            .lines()
+            .filter(|l| l.starts_with("nexus_network_receive_bytes_total{"))
+            .collect();
+        assert_eq!(
+            rx,
+            [
+                "nexus_network_receive_bytes_total{device=\"wlan0\"} 100",
+                "nexus_network_receive_bytes_total{device=\"eth0\"} 7",
+            ]
+        );
+        let samples = out.lines().filter(|l| !l.starts_with('#')).count();
+        assert_eq!(samples, COUNTERS.len() * 2);
+    }
+
+    #[test]
+    fn signal_gauge_for_active_connection() {
+        let info = ConnectionInfo {
+            ssid: "Home".into(),
+            bssid: "AA:BB:CC:DD:EE:FF".into(),
+            interface: "wlan0".into(),
+            signal: 72,
+            ..Default::default()
+        };
+        let out = render(&[], Some(&info));
+        assert!(out.contains(
+            "nexus_wifi_signal_strength{device=\"wlan0\",ssid=\"Home\",bssid=\"AA:BB:CC:DD:EE:FF\"} 72\n"
+        ));
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        assert_eq!(escape_label("plain"), "plain");
+        assert_eq!(escape_label(r"a\b"), r"a\\b");
+        assert_eq!(escape_label("say \"hi\""), r#"say \"hi\""#);
+        assert_eq!(escape_label("two\nlines"), r"two\nlines");
+    }
+}
@@ -0,0 +1,78 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::app::{App, ConfirmAction};
+
+/// Render the yes/no confirmation modal
+pub fn render(frame: &mut Frame, app: &App, area: Rect, action: &ConfirmAction) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let width = 56_u16.min(area.width.saturating_sub(4));
+    let height = 9_u16.min(area.height.saturating_sub(4));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    // Actions that can cut the user's own connection get the error palette
+    let (title, title_style, border_style) = if action.is_dangerous() {
+        (
+            format!(" ⚠ {} ", lang.tr("Warning")),
+            t.style_error(),
+            t.style_error(),
+        )
+    } else {
+        (
+            format!(" {} ", lang.tr("Confirm")),
+            t.style_accent_bold(),
+            t.style_warning(),
+        )
+    };
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(title, title_style)]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(border_style)
+        .style(t.style_default());
+
+    frame.render_widget(block, dialog);
+
+    // Prompt text
+    let prompt_area = Rect {
+        x: dialog.x + 3,
+        y: dialog.y + 2,
+        width: dialog.width.saturating_sub(6),
+        height: height.saturating_sub(5),
+    };
+    frame.render_widget(
+        Paragraph::new(action.prompt(lang))
+            .style(t.style_default())
+            .wrap(Wrap { trim: true }),
+        prompt_area,
+    );
+
+    // Hints
+    let hint_area = Rect {
+        x: dialog.x + 3,
+        y: dialog.y + height.saturating_sub(2),
+        width: dialog.width.saturating_sub(6),
+        height: 1,
+    };
+
+    let hints = Line::from(vec![
+        Span::styled("[y]", t.style_key_hint()),
+        Span::styled(format!(" {}  ", lang.tr("Yes")), t.style_key_desc()),
+        Span::styled("[n/Esc]", t.style_key_hint()),
+        Span::styled(format!(" {} ", lang.tr("No")), t.style_key_desc()),
+    ]);
+
+    frame.render_widget(Paragraph::new(hints).alignment(Alignment::Left), hint_area);
+}
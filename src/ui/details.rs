@@ -1,160 +1,521 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Rect};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-use super::theme;
-use crate::app::App;
-use crate::network::types::{ConnectionStatus, FrequencyBand, channel_from_frequency};
+use super::theme::{self, Page};
+use crate::app::{App, AppMode, ProfileField};
+use crate::i18n::Language;
+use crate::network::types::{
+    ConnectionStatus, DhcpLease, FrequencyBand, SecurityType, WifiGeneration, approx_dbm,
+    channel_from_frequency,
+};
 use crate::ui::theme::Theme;
 
+/// A copyable key/value row of the detail panel
+pub struct DetailField {
+    /// Rendered lines of the row (several when the value wraps)
+    lines: std::ops::Range<usize>,
+    pub label: String,
+    /// The raw value, unwrapped and untruncated
+    pub value: String,
+}
+
+/// Rendered lines of the detail panel plus the fields they show, so a
+/// cursor over fields can be mapped back to lines and raw values
+struct DetailView {
+    lines: Vec<Line<'static>>,
+    fields: Vec<DetailField>,
+    lang: Language,
+}
+
+impl DetailView {
+    fn blank(&mut self) {
+        self.lines.push(Line::from(""));
+    }
+
+    /// A key/value row with a catalog label, indented by `depth` levels
+    fn row(&mut self, t: &Theme, width: usize, depth: usize, label: &'static str, value: &str) {
+        let label = format!("{}{}", "  ".repeat(depth), self.lang.tr(label));
+        self.field(t, width, &label, value);
+    }
+
+    /// Padded label of a row with its own styling
+    fn label(&self, label: &'static str) -> String {
+        format!("  {:<12}", self.lang.tr(label))
+    }
+
+    /// A plain key/value row
+    fn field(&mut self, t: &Theme, width: usize, label: &str, value: &str) {
+        let lines = detail_line(t, width, label, value);
+        self.custom(lines, label, value);
+    }
+
+    /// A row with its own styling that still copies as `value`
+    fn custom(&mut self, lines: Vec<Line<'static>>, label: &str, value: &str) {
+        let start = self.lines.len();
+        self.lines.extend(lines);
+        self.fields.push(DetailField {
+            lines: start..self.lines.len(),
+            label: label.trim().to_string(),
+            value: value.to_string(),
+        });
+    }
+}
+
+/// The fields the detail panel shows for the selected network, in order
+pub fn fields(app: &App) -> Vec<DetailField> {
+    build(app, usize::MAX / 2).map_or_else(Vec::new, |view| view.fields)
+}
+
 /// Render the network detail panel (right side)
 pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     let nerd = app.config.nerd_fonts();
     let t = &app.theme;
     let info_icon = if nerd { theme::ICON_INFO } else { "(i) " };
+    let focused = matches!(app.mode, AppMode::DetailFocus);
 
     let block = Block::default()
         .title(Line::from(vec![
-            Span::styled(format!(" {info_icon}"), t.style_accent()),
-            Span::styled("Details ", t.style_accent_bold()),
+            Span::styled(format!(" {info_icon}"), t.style_page_title(Page::Details)),
+            Span::styled(
+                format!("{} ", app.lang().tr("Details")),
+                t.style_page_title(Page::Details),
+            ),
         ]))
         .borders(Borders::ALL)
         .border_type(t.border_type)
-        .border_style(t.style_border())
+        .border_style(if focused {
+            t.style_border_focused()
+        } else {
+            t.style_border()
+        })
         .style(t.style_default());
 
-    if app.networks.is_empty() {
-        let para = Paragraph::new("No network selected")
+    // Inside the borders
+    let width = area.width.saturating_sub(2) as usize;
+
+    let Some(mut view) = build(app, width) else {
+        let para = Paragraph::new(app.lang().tr("No network selected"))
             .block(block)
             .style(t.style_dim())
             .alignment(Alignment::Center);
         frame.render_widget(para, area);
         return;
+    };
+
+    // Highlight the focused field and keep it scrolled into view
+    let mut scroll = 0;
+    if focused && !view.fields.is_empty() {
+        let cursor = app.detail_cursor.min(view.fields.len() - 1);
+        let rows = view.fields[cursor].lines.clone();
+        for line in &mut view.lines[rows.clone()] {
+            for span in &mut line.spans {
+                span.style = span.style.patch(t.style_selected());
+            }
+        }
+        let height = area.height.saturating_sub(2) as usize;
+        scroll = rows.end.saturating_sub(height).min(rows.start) as u16;
     }
 
-    let selected = match app.selected_network() {
-        Some(net) => net,
-        None => {
-            let para = Paragraph::new("No network selected")
-                .block(block)
-                .style(t.style_dim())
-                .alignment(Alignment::Center);
-            frame.render_widget(para, area);
-            return;
+    let para = Paragraph::new(view.lines)
+        .block(block)
+        .style(t.style_default())
+        .scroll((scroll, 0));
+
+    frame.render_widget(para, area);
+}
+
+/// Lay out the detail rows of the selected network for a panel `width`
+/// columns wide. None when nothing is selected.
+fn build(app: &App, width: usize) -> Option<DetailView> {
+    let t = &app.theme;
+    let lang = app.lang();
+    let selected = app.selected_network()?;
+
+    let mut view = DetailView {
+        lines: vec![Line::from("")],
+        fields: Vec::new(),
+        lang,
+    };
+    view.row(t, width, 1, "SSID", &selected.ssid);
+    if let Some(label) = app.labels.get(&selected.ssid) {
+        if !label.nickname.is_empty() {
+            view.row(t, width, 1, "Nickname", &label.nickname);
         }
+        if !label.note.is_empty() {
+            view.row(t, width, 1, "Note", &label.note);
+        }
+    }
+    view.row(t, width, 1, "BSSID", &selected.bssid);
+    view.row(t, width, 1, "AP Path", &selected.ap_path);
+    let seen = selected.last_seen.elapsed().as_secs();
+    let seen = if seen < 2 {
+        lang.tr("just now").to_string()
+    } else {
+        lang.fill("{seconds}s ago", &[("seconds", &seen)])
     };
-
-    let mut lines: Vec<Line> = vec![
-        Line::from(""),
-        detail_line(t, "  SSID", &selected.ssid),
-        detail_line(t, "  BSSID", &selected.bssid),
-        detail_line(t, "  AP Path", &selected.ap_path),
-        Line::from(""),
-    ];
+    view.row(t, width, 1, "Last Seen", &seen);
+    view.blank();
 
     // Signal
     let sig_color = t.signal_color(selected.signal_strength);
-    lines.push(Line::from(vec![
-        Span::styled("  Signal      ", t.style_dim()),
-        Span::styled(
-            format!("{}%", selected.signal_strength),
-            ratatui::style::Style::default().fg(sig_color),
-        ),
-        Span::styled(
-            format!("  {}", signal_bar(selected.signal_strength)),
-            ratatui::style::Style::default().fg(sig_color),
-        ),
-    ]));
+    let signal = signal_label(app, selected.signal_strength);
+    view.custom(
+        vec![Line::from(vec![
+            Span::styled(view.label("Signal"), t.style_dim()),
+            Span::styled(
+                signal.clone(),
+                ratatui::style::Style::default().fg(sig_color),
+            ),
+            Span::styled(
+                format!("  {}", signal_bar(selected.signal_strength)),
+                ratatui::style::Style::default().fg(sig_color),
+            ),
+        ])],
+        lang.tr("Signal"),
+        &signal,
+    );
 
     // Frequency & Channel
     let band = selected.band();
     let channel = selected.channel();
     let freq_str = format!("{} MHz ({})", selected.frequency, band);
-    lines.push(detail_line(t, "  Frequency", &freq_str));
-    let chan_str = format!("{}", channel);
-    lines.push(detail_line(t, "  Channel", &chan_str));
-    lines.push(Line::from(""));
+    view.row(t, width, 1, "Frequency", &freq_str);
+    view.row(t, width, 1, "Channel", &channel.to_string());
+    let gen_str = match selected.generation() {
+        WifiGeneration::Unknown => lang.tr("unknown").to_string(),
+        generation => lang.fill("{generation} (inferred)", &[("generation", &generation)]),
+    };
+    view.row(t, width, 1, "Standard", &gen_str);
+    if selected.max_bitrate > 0 {
+        let rate_str = format!("{} Mbps", selected.max_bitrate);
+        view.row(t, width, 1, "Max Rate", &rate_str);
+    }
+    view.blank();
 
     // Security
-    let sec_style = if selected.security == crate::network::types::SecurityType::Open {
+    let sec_style = if selected.security == SecurityType::Open {
         t.style_warning()
     } else {
         t.style_default()
     };
-    lines.push(Line::from(vec![
-        Span::styled("  Security    ", t.style_dim()),
-        Span::styled(selected.security.to_string(), sec_style),
-    ]));
+    let security = selected.security.to_string();
+    view.custom(
+        vec![Line::from(vec![
+            Span::styled(view.label("Security"), t.style_dim()),
+            Span::styled(security.clone(), sec_style),
+        ])],
+        lang.tr("Security"),
+        &security,
+    );
+    if let Some(suite) = selected.security_flags.describe() {
+        let style = if selected.security_flags.has_weak_cipher() {
+            t.style_warning()
+        } else {
+            t.style_default()
+        };
+        view.custom(
+            vec![Line::from(vec![
+                Span::styled(view.label("Suite"), t.style_dim()),
+                Span::styled(suite.clone(), style),
+            ])],
+            lang.tr("Suite"),
+            &suite,
+        );
+    }
 
     // Saved
-    lines.push(detail_line(
-        t,
-        "  Saved",
-        if selected.is_saved { "Yes" } else { "No" },
-    ));
-    lines.push(detail_line(
-        t,
-        "  Status",
-        if selected.is_active {
-            "Connected"
-        } else {
-            "Not connected"
-        },
-    ));
+    let saved = lang.tr(if selected.is_saved { "Yes" } else { "No" });
+    view.row(t, width, 1, "Saved", saved);
+    let status = lang.tr(if selected.is_active {
+        "Connected"
+    } else {
+        "Not connected"
+    });
+    view.row(t, width, 1, "Status", status);
 
     // Active connection details
     if selected.is_active
         && let ConnectionStatus::Connected(ref info) = app.connection_status
     {
-        lines.push(Line::from(""));
-        lines.push(Line::from(Span::styled(
-            "  ── Connection Info ──",
+        view.blank();
+        view.lines.push(Line::from(Span::styled(
+            format!("  ── {} ──", lang.tr("Connection Info")),
             ratatui::style::Style::default().fg(t.accent2),
         )));
-        lines.push(Line::from(""));
+        view.blank();
 
+        if let Some(health) = app.health() {
+            let value = format!("{}/100 · {}", health.score, health.summary(lang));
+            view.custom(
+                vec![Line::from(vec![
+                    Span::styled(view.label("Health"), t.style_dim()),
+                    Span::styled(value.clone(), t.style_health(health.grade())),
+                ])],
+                lang.tr("Health"),
+                &value,
+            );
+        }
         if let Some(ref ip) = info.ip4 {
-            lines.push(detail_line(t, "  IPv4", ip));
+            let label = match info.ipv4_method_label() {
+                Some(method) => format!("  IPv4 ({})", lang.tr(method)),
+                None => "  IPv4".to_string(),
+            };
+            view.field(t, width, &label, ip);
         }
         if let Some(ref ip6) = info.ip6 {
-            lines.push(detail_line(t, "  IPv6", ip6));
+            let label = match info.ipv6_method_label() {
+                Some(method) => format!("  IPv6 ({})", lang.tr(method)),
+                None => "  IPv6".to_string(),
+            };
+            view.field(t, width, &label, ip6);
+        }
+        if let Some(stack) = info.ip_stack() {
+            let stack = match info.ipv6_source() {
+                Some(source) if info.ip6.is_some() => {
+                    let via = lang.fill("IPv6 via {source}", &[("source", &lang.tr(source))]);
+                    format!("{stack} · {via}")
+                }
+                _ => stack.to_string(),
+            };
+            view.row(t, width, 1, "Stack", &stack);
         }
         if let Some(ref gw) = info.gateway {
-            lines.push(detail_line(t, "  Gateway", gw));
+            view.row(t, width, 1, "Gateway", gw);
         }
         if !info.dns.is_empty() {
-            lines.push(detail_line(t, "  DNS", &info.dns.join(", ")));
+            view.row(t, width, 1, "DNS", &info.dns.join(", "));
+        }
+        if let Some(ref lease) = info.dhcp4 {
+            lease_fields(&mut view, t, width, "DHCPv4", lease);
+        }
+        if let Some(ref lease) = info.dhcp6 {
+            lease_fields(&mut view, t, width, "DHCPv6", lease);
+        }
+        view.row(t, width, 1, "MAC", &info.mac);
+        view.row(t, width, 1, "BSSID", &info.bssid);
+        view.row(t, width, 1, "Interface", &info.interface);
+        if let Some(ref uuid) = info.profile.uuid {
+            view.row(t, width, 1, "UUID", uuid);
         }
-        lines.push(detail_line(t, "  MAC", &info.mac));
-        lines.push(detail_line(t, "  BSSID", &info.bssid));
-        lines.push(detail_line(t, "  Interface", &info.interface));
         if info.speed > 0 {
             let speed_str = format!("{} Mbps", info.speed);
-            lines.push(detail_line(t, "  Speed", &speed_str));
+            view.row(t, width, 1, "Speed", &speed_str);
+        }
+        if info.mtu > 0 {
+            view.row(t, width, 1, "MTU", &info.mtu.to_string());
+        }
+        let profile = |field: ProfileField| field.value(&info.profile, lang);
+        view.row(t, width, 1, "DHCP Host", &profile(ProfileField::Hostname));
+        view.row(
+            t,
+            width,
+            1,
+            "Send Host",
+            &profile(ProfileField::SendHostname),
+        );
+        view.row(t, width, 1, "Client ID", &profile(ProfileField::ClientId));
+        // Effective metric from the routing table next to the configured one
+        let metric = match info.route_metric {
+            Some(m) => lang.fill(
+                "{metric} (profile: {profile})",
+                &[
+                    ("metric", &m),
+                    ("profile", &profile(ProfileField::RouteMetric)),
+                ],
+            ),
+            None => profile(ProfileField::RouteMetric),
+        };
+        view.row(t, width, 1, "Metric", &metric);
+        view.row(t, width, 1, "Band Pref", &profile(ProfileField::Band));
+        if let Some(ref bssid) = info.profile.bssid {
+            view.row(t, width, 1, "Locked AP", bssid);
+        }
+        if !info.profile.fast_band_bssids.is_empty() {
+            view.row(
+                t,
+                width,
+                1,
+                "Fast Band",
+                &info.profile.fast_band_bssids.join(", "),
+            );
         }
+        view.row(t, width, 1, "Proxy", &profile(ProfileField::Proxy));
+        if let Some(ref url) = info.profile.proxy_pac_url {
+            view.row(t, width, 1, "PAC URL", url);
+        }
+        view.row(t, width, 1, "Zone", &profile(ProfileField::Zone));
+        view.row(
+            t,
+            width,
+            1,
+            "Retries",
+            &profile(ProfileField::AutoconnectRetries),
+        );
+        view.row(t, width, 1, "Internet", lang.tr(info.internet_label()));
+        let check = &info.connectivity_check;
+        let check_state = match (&check.uri, check.is_active()) {
+            (Some(uri), true) => uri.clone(),
+            (_, false) if !check.available => lang.tr("(no URI configured)").to_string(),
+            _ => lang.tr("off").to_string(),
+        };
+        view.row(t, width, 1, "NM Check", &check_state);
         if info.frequency > 0 {
             let band = FrequencyBand::from_mhz(info.frequency);
             let ch = channel_from_frequency(info.frequency);
             let freq_str = format!("{} MHz ({}, ch {})", info.frequency, band, ch);
-            lines.push(detail_line(t, "  Frequency", &freq_str));
+            view.row(t, width, 1, "Frequency", &freq_str);
         }
         if info.signal > 0 {
-            lines.push(detail_line(t, "  Signal", &format!("{}%", info.signal)));
+            view.row(t, width, 1, "Signal", &signal_label(app, info.signal));
         }
     }
 
-    let para = Paragraph::new(lines).block(block).style(t.style_default());
+    Some(view)
+}
 
-    frame.render_widget(para, area);
+/// Lease time left, the server and the offered NTP servers and domain of a
+/// DHCP lease. Rows the server didn't offer are left out.
+fn lease_fields(
+    view: &mut DetailView,
+    t: &Theme,
+    width: usize,
+    family: &'static str,
+    lease: &DhcpLease,
+) {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let lease_time = match (lease.remaining(now), lease.lease_time) {
+        (Some(left), Some(total)) => view.lang.fill(
+            "{left} left of {total}",
+            &[("left", &format_span(left)), ("total", &format_span(total))],
+        ),
+        (Some(left), None) => view
+            .lang
+            .fill("{left} left", &[("left", &format_span(left))]),
+        (None, Some(total)) => format_span(total),
+        (None, None) => view.lang.tr("lease held").to_string(),
+    };
+    view.row(t, width, 1, family, &lease_time);
+    if let Some(ref server) = lease.server {
+        view.row(t, width, 2, "Server", server);
+    }
+    if !lease.ntp_servers.is_empty() {
+        view.row(t, width, 2, "NTP", &lease.ntp_servers.join(", "));
+    }
+    if let Some(ref domain) = lease.domain {
+        view.row(t, width, 2, "Domain", domain);
+    }
+}
+
+/// "82%", or "≈ -59 dBm (82%)" when showing dBm; NM has no raw RSSI, so
+/// the dBm value is only an estimate and the percentage stays alongside
+fn signal_label(app: &App, strength: u8) -> String {
+    if app.signal_dbm {
+        format!("≈ {} dBm ({strength}%)", approx_dbm(strength))
+    } else {
+        format!("{strength}%")
+    }
+}
+
+/// A duration in its two largest units: "2d 4h", "3h 12m", "45s"
+pub(super) fn format_span(secs: u64) -> String {
+    let (d, h, m, s) = (secs / 86_400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    match (d, h, m) {
+        (0, 0, 0) => format!("{s}s"),
+        (0, 0, _) => format!("{m}m {s}s"),
+        (0, _, _) => format!("{h}h {m}m"),
+        _ => format!("{d}d {h}h"),
+    }
+}
+
+/// Display width of the label column (fits "  IPv4 (Static)")
+const LABEL_WIDTH: usize = 16;
+
+/// Narrower than this, values are truncated instead of wrapped
+const MIN_WRAP_WIDTH: usize = 8;
+
+/// Build a key-value detail row (owns its data). Values wider than the
+/// panel wrap onto continuation lines aligned with the value column.
+fn detail_line(t: &Theme, width: usize, label: &str, value: &str) -> Vec<Line<'static>> {
+    // A longer label still keeps a space before its value
+    let pad = LABEL_WIDTH.saturating_sub(label.width()).max(1);
+    let label = format!("{label}{}", " ".repeat(pad));
+    let value_width = width.saturating_sub(label.width());
+
+    let chunks = if value_width < MIN_WRAP_WIDTH {
+        vec![truncate(value, value_width)]
+    } else {
+        wrap(value, value_width)
+    };
+
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(i, chunk)| {
+            let head = if i == 0 {
+                label.clone()
+            } else {
+                " ".repeat(LABEL_WIDTH)
+            };
+            Line::from(vec![
+                Span::styled(head, t.style_dim()),
+                Span::styled(chunk, t.style_default()),
+            ])
+        })
+        .collect()
+}
+
+/// Greedy wrap by display width, breaking after a space or comma when one
+/// falls on the line, otherwise mid-word (long paths, MACs, UUIDs)
+fn wrap(value: &str, max: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut rest = value;
+    while rest.width() > max {
+        let mut used = 0;
+        let mut cut = 0;
+        let mut soft_cut = None;
+        for (i, ch) in rest.char_indices() {
+            let w = ch.width().unwrap_or(0);
+            if used + w > max {
+                break;
+            }
+            used += w;
+            cut = i + ch.len_utf8();
+            if ch == ' ' || ch == ',' {
+                soft_cut = Some(cut);
+            }
+        }
+        let cut = soft_cut.unwrap_or(cut).max(1);
+        lines.push(rest[..cut].trim_end().to_string());
+        rest = rest[cut..].trim_start();
+    }
+    lines.push(rest.to_string());
+    lines
 }
 
-/// Build a key-value detail line (owns its data)
-fn detail_line(t: &Theme, label: &str, value: &str) -> Line<'static> {
-    Line::from(vec![
-        Span::styled(format!("{:<14}", label), t.style_dim()),
-        Span::styled(value.to_string(), t.style_default()),
-    ])
+/// Cut to `max` display columns, ending in an ellipsis when shortened
+fn truncate(value: &str, max: usize) -> String {
+    if value.width() <= max {
+        return value.to_string();
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for ch in value.chars() {
+        let w = ch.width().unwrap_or(0);
+        if used + w + 1 > max {
+            break;
+        }
+        out.push(ch);
+        used += w;
+    }
+    out.push('…');
+    out
 }
 
 /// Generate a text-based signal strength bar
@@ -0,0 +1,112 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::animation::spinner;
+use crate::app::App;
+use crate::ui::theme::Page;
+
+/// Render the internet diagnostics report modal
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let width = 72_u16.min(area.width.saturating_sub(4));
+    let height = 18_u16.min(area.height.saturating_sub(2));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", lang.tr("Internet Diagnostics")),
+            t.style_page_title(Page::Diagnostics),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_page_border(Page::Diagnostics))
+        .style(t.style_default());
+
+    frame.render_widget(block, dialog);
+
+    let inner = Rect {
+        x: dialog.x + 3,
+        y: dialog.y + 2,
+        width: dialog.width.saturating_sub(6),
+        height: dialog.height.saturating_sub(3),
+    };
+
+    let Some(report) = &app.diagnostics else {
+        let spin = spinner::spinner_frame(app.animation.tick_count);
+        let line = Line::from(vec![
+            Span::styled(format!("{spin} "), t.style_accent()),
+            Span::styled(
+                lang.tr("Checking link, gateway, internet, DNS, HTTP and NM…"),
+                t.style_dim(),
+            ),
+        ]);
+        frame.render_widget(Paragraph::new(line), inner);
+        return;
+    };
+
+    let mut lines = Vec::with_capacity(report.steps.len() + 4);
+    for step in &report.steps {
+        let (mark, style) = if step.passed {
+            ("✓", t.style_connected())
+        } else {
+            ("✗", t.style_error())
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{mark} "), style),
+            Span::styled(
+                format!("{:<16}", lang.tr(step.layer.label())),
+                t.style_default(),
+            ),
+            Span::styled(step.detail.as_str(), t.style_dim()),
+            Span::styled(format!("  {}", step.timing()), t.style_dim()),
+        ]));
+        if let Some(ref ping) = step.ping {
+            let style = if ping.loss_percent > 20.0 {
+                t.style_error()
+            } else if ping.loss_percent > 0.0 {
+                t.style_warning()
+            } else {
+                t.style_connected()
+            };
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(format!("{:<16}", ""), t.style_default()),
+                Span::styled(ping.describe(), style),
+            ]));
+        }
+    }
+
+    lines.push(Line::from(""));
+    match report.first_failure() {
+        Some(step) => {
+            let layer = lang.tr(step.layer.label());
+            lines.push(Line::from(Span::styled(
+                lang.fill("Connectivity breaks at {layer}", &[("layer", &layer)]),
+                t.style_error(),
+            )));
+            lines.push(Line::from(Span::styled(
+                lang.tr(step.layer.advice()),
+                t.style_dim(),
+            )));
+        }
+        None => {
+            lines.push(Line::from(Span::styled(
+                lang.tr("Internet connection looks healthy"),
+                t.style_connected(),
+            )));
+        }
+    }
+
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), inner);
+}
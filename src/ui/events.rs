@@ -0,0 +1,78 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::App;
+use crate::ui::theme::Page;
+
+/// Render the connection events log: NM state changes as they arrive,
+/// newest at the bottom, stamped with the time since Nexus started
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let width = 72_u16.min(area.width.saturating_sub(4));
+    let height = 20_u16.min(area.height.saturating_sub(2));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", lang.tr("Connection Events")),
+            t.style_page_title(Page::Events),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_page_border(Page::Events))
+        .style(t.style_default());
+
+    frame.render_widget(block, dialog);
+
+    let inner = Rect {
+        x: dialog.x + 2,
+        y: dialog.y + 1,
+        width: dialog.width.saturating_sub(4),
+        height: dialog.height.saturating_sub(2),
+    };
+
+    if app.nm_events.is_empty() {
+        let msg = Line::from(Span::styled(
+            lang.tr("No events yet — NetworkManager state changes will appear here"),
+            t.style_dim(),
+        ));
+        frame.render_widget(Paragraph::new(msg), inner);
+        return;
+    }
+
+    // Follow the tail: only the newest lines that fit
+    let skip = app.nm_events.len().saturating_sub(inner.height as usize);
+    let lines: Vec<Line> = app
+        .nm_events
+        .iter()
+        .skip(skip)
+        .map(|(at, text)| {
+            let secs = at.saturating_duration_since(app.started).as_secs();
+            Line::from(vec![
+                Span::styled(
+                    format!(
+                        "+{:02}:{:02}:{:02}  ",
+                        secs / 3600,
+                        secs / 60 % 60,
+                        secs % 60
+                    ),
+                    t.style_dim(),
+                ),
+                Span::styled(text.clone(), t.style_default()),
+            ])
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
@@ -1,3 +1,5 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Rect};
 use ratatui::text::{Line, Span};
@@ -6,7 +8,8 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 use super::theme;
 use crate::animation::spinner;
 use crate::app::App;
-use crate::network::types::{ConnectionStatus, FrequencyBand};
+use crate::network::signals::REFRESH_POLL_INTERVAL;
+use crate::network::types::{ConnectionStatus, DeviceState, FrequencyBand};
 
 /// Render the application header bar
 pub fn render(frame: &mut Frame, app: &App, area: Rect) {
@@ -27,8 +30,10 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     // Build connection status (right side)
     let status_spans = build_status_spans(app, nerd);
 
+    // Clock and data age sit in the top border, clear of the tabs
     let block = Block::default()
         .title(title)
+        .title(Line::from(build_clock_spans(app)).right_aligned())
         .title_alignment(Alignment::Left)
         .borders(Borders::ALL)
         .border_type(t.border_type)
@@ -50,19 +55,165 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     let status = Paragraph::new(status_line).alignment(Alignment::Right);
     frame.render_widget(status, inner);
 
-    // Render interface name on the left inside the block
-    let iface = Line::from(vec![Span::styled(
+    // Render interface name and the page tabs on the left inside the block
+    let mut left = vec![Span::styled(
         format!("  {}", app.interface_name),
         t.style_dim(),
-    )]);
+    )];
+    if app.pages.len() > 1 {
+        left.push(Span::styled("  │", t.style_border()));
+        let current = app.current_view();
+        for (i, view) in app.pages.iter().enumerate() {
+            let style = if *view == current {
+                t.style_accent_bold()
+            } else {
+                t.style_dim()
+            };
+            let label = app.lang().tr(view.label());
+            left.push(Span::styled(format!(" {} {label} ", i + 1), style));
+        }
+    }
+    let iface = Line::from(left);
     let iface_para = Paragraph::new(iface).alignment(Alignment::Left);
     frame.render_widget(iface_para, inner);
 }
 
+/// "updated 3s ago" and "14:05" for the top border, each when enabled
+fn build_clock_spans(app: &App) -> Vec<Span<'static>> {
+    let t = &app.theme;
+    let mut spans = Vec::new();
+    if app.config.appearance.show_data_age
+        && let Some(at) = app.last_refresh
+    {
+        let age = at.elapsed();
+        // Twice the background refresh without news: the listener is stuck
+        let stale = age > 2 * REFRESH_POLL_INTERVAL;
+        let style = if stale {
+            t.style_warning()
+        } else {
+            t.style_dim()
+        };
+        let secs = age.as_secs();
+        let lang = app.lang();
+        let mut text = if secs < 60 {
+            format!(
+                " {} ",
+                lang.fill("updated {seconds}s ago", &[("seconds", &secs)])
+            )
+        } else {
+            let minutes = secs / 60;
+            format!(
+                " {} ",
+                lang.fill("updated {minutes}m ago", &[("minutes", &minutes)])
+            )
+        };
+        if stale && t.mono {
+            text.push_str(&format!("({}) ", lang.tr("stale")));
+        }
+        spans.push(Span::styled(text, style));
+    }
+    if app.config.appearance.show_clock {
+        spans.push(Span::styled(
+            format!(" {} ", local_clock(SystemTime::now(), app.utc_offset)),
+            t.style_accent(),
+        ));
+    }
+    spans
+}
+
+/// `now` as "HH:MM", `utc_offset` seconds east of UTC
+fn local_clock(now: SystemTime, utc_offset: i64) -> String {
+    let Ok(since_epoch) = now.duration_since(UNIX_EPOCH) else {
+        return "--:--".to_string();
+    };
+    let minutes = (since_epoch.as_secs() as i64 + utc_offset).div_euclid(60);
+    let minute_of_day = minutes.rem_euclid(24 * 60);
+    format!("{:02}:{:02}", minute_of_day / 60, minute_of_day % 60)
+}
+
+/// The local time zone's UTC offset in seconds, from `date +%z`; 0 (UTC)
+/// if it can't be run. The tick re-reads it once a minute, so DST changes
+/// are picked up without the header ever waiting on a process.
+pub fn read_utc_offset() -> i64 {
+    std::process::Command::new("date")
+        .arg("+%z")
+        .output()
+        .ok()
+        .and_then(|out| parse_utc_offset(std::str::from_utf8(&out.stdout).ok()?))
+        .unwrap_or(0)
+}
+
+/// "+0530" / "-0800" → seconds east of UTC
+fn parse_utc_offset(text: &str) -> Option<i64> {
+    let text = text.trim();
+    let (sign, digits) = match text.split_at_checked(1)? {
+        ("+", digits) => (1, digits),
+        ("-", digits) => (-1, digits),
+        _ => return None,
+    };
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i64 = digits[..2].parse().ok()?;
+    let minutes: i64 = digits[2..].parse().ok()?;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Compact health score, colored by grade
+fn health_span(app: &App) -> Span<'static> {
+    match app.health() {
+        Some(health) => Span::styled(
+            format!(" ♥{}", health.score),
+            app.theme.style_health(health.grade()),
+        ),
+        None => Span::raw(""),
+    }
+}
+
 /// Build status indicator spans based on connection state
-fn build_status_spans(app: &App, nerd: bool) -> Vec<Span<'static>> {
+fn build_status_spans(app: &App, nerd: bool) -> Vec<Span<'_>> {
     let tick = app.animation.tick_count;
     let t = &app.theme;
+    let lang = app.lang();
+
+    if app.offline {
+        let warn_icon = if nerd { theme::ICON_ERROR } else { "[!] " };
+        return vec![
+            Span::styled(warn_icon, t.style_error()),
+            Span::styled(
+                format!(
+                    "{} ",
+                    lang.tr("System bus lost — reconnecting, actions disabled")
+                ),
+                t.style_error(),
+            ),
+        ];
+    }
+
+    if app.device_state == DeviceState::Unmanaged {
+        let warn_icon = if nerd { theme::ICON_ERROR } else { "[!] " };
+        return vec![
+            Span::styled(warn_icon, t.style_warning()),
+            Span::styled(
+                format!("{} ", lang.tr("Managed by another tool")),
+                t.style_warning(),
+            ),
+        ];
+    }
+
+    // NM is tearing the connection down (ours or someone else's disconnect)
+    if app.device_state == DeviceState::Deactivating {
+        let bar = spinner::bar_frame(tick);
+        let mut spans = vec![
+            Span::styled(format!("{bar} "), t.style_warning()),
+            Span::styled(lang.tr("Deactivating"), t.style_dim()),
+        ];
+        if let ConnectionStatus::Connected(info) = &app.connection_status {
+            spans.push(Span::styled(format!(" {}", info.ssid), t.style_warning()));
+        }
+        spans.push(Span::styled("… ", t.style_dim()));
+        return spans;
+    }
 
     match &app.connection_status {
         ConnectionStatus::Connected(info) => {
@@ -79,11 +230,11 @@ fn build_status_spans(app: &App, nerd: bool) -> Vec<Span<'static>> {
             };
             vec![
                 Span::styled(format!("{connected_icon}{pulse} "), t.style_connected()),
-                Span::styled(info.ssid.clone(), t.style_connected()),
+                Span::styled(info.ssid.as_str(), t.style_connected()),
                 Span::styled(
                     format!(
                         " ({}{}{})",
-                        info.ip4.as_deref().unwrap_or("no IP"),
+                        info.ip4.as_deref().unwrap_or(lang.tr("no IP")),
                         if info.speed > 0 {
                             format!(" • {} Mbps", info.speed)
                         } else {
@@ -93,23 +244,48 @@ fn build_status_spans(app: &App, nerd: bool) -> Vec<Span<'static>> {
                     ),
                     t.style_dim(),
                 ),
+                Span::styled(
+                    info.ip_stack()
+                        .map_or_else(String::new, |stack| format!(" [{stack}]")),
+                    t.style_accent(),
+                ),
+                // Proxying is easy to miss and surprising, so flag it
+                Span::styled(
+                    info.profile
+                        .proxy_summary()
+                        .map_or_else(String::new, |proxy| {
+                            format!(
+                                " {}",
+                                lang.fill("proxy: {proxy}", &[("proxy", &lang.tr(proxy))])
+                            )
+                        }),
+                    t.style_warning(),
+                ),
+                health_span(app),
                 Span::styled(" ", t.style_default()),
             ]
         }
         ConnectionStatus::Connecting(ssid) => {
             let spin = spinner::spinner_frame(tick);
-            vec![
+            let mut spans = vec![
                 Span::styled(format!("{spin} "), t.style_accent()),
-                Span::styled("Connecting to ", t.style_dim()),
-                Span::styled(ssid.clone(), t.style_accent()),
+                Span::styled(format!("{} ", lang.tr("Connecting to")), t.style_dim()),
+                Span::styled(ssid.as_str(), t.style_accent()),
                 Span::styled("… ", t.style_dim()),
-            ]
+            ];
+            if app.device_state.is_connecting() {
+                spans.push(Span::styled(
+                    format!("({}) ", lang.tr(app.device_state.label())),
+                    t.style_dim(),
+                ));
+            }
+            spans
         }
         ConnectionStatus::Disconnecting => {
             let bar = spinner::bar_frame(tick);
             vec![
                 Span::styled(format!("{bar} "), t.style_warning()),
-                Span::styled("Disconnecting… ", t.style_dim()),
+                Span::styled(format!("{} ", lang.tr("Disconnecting…")), t.style_dim()),
             ]
         }
         ConnectionStatus::Disconnected => {
@@ -119,16 +295,49 @@ fn build_status_spans(app: &App, nerd: bool) -> Vec<Span<'static>> {
                 theme::PLAIN_WIFI_OFF
             };
             vec![
-                Span::styled(wifi_off.to_string(), t.style_dim()),
-                Span::styled("Disconnected ", t.style_dim()),
+                Span::styled(wifi_off, t.style_dim()),
+                Span::styled(format!("{} ", lang.tr("Disconnected")), t.style_dim()),
             ]
         }
         ConnectionStatus::Failed(msg) => {
             let err_icon = if nerd { theme::ICON_ERROR } else { "[!] " };
             vec![
-                Span::styled(err_icon.to_string(), t.style_error()),
-                Span::styled(format!("Failed: {} ", msg), t.style_error()),
+                Span::styled(err_icon, t.style_error()),
+                Span::styled(
+                    format!(
+                        "{} ",
+                        lang.fill("Failed: {reason}", &[("reason", &lang.tr(msg))])
+                    ),
+                    t.style_error(),
+                ),
             ]
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn utc_offsets() {
+        assert_eq!(parse_utc_offset("+0000\n"), Some(0));
+        assert_eq!(parse_utc_offset("+0530"), Some(5 * 3600 + 30 * 60));
+        assert_eq!(parse_utc_offset("-0800"), Some(-8 * 3600));
+        assert_eq!(parse_utc_offset("0800"), None);
+        assert_eq!(parse_utc_offset("+08:00"), None);
+        assert_eq!(parse_utc_offset(""), None);
+    }
+
+    #[test]
+    fn clock_wraps_around_midnight() {
+        // 2024-05-01 23:45:30 UTC
+        let now = UNIX_EPOCH + Duration::from_secs(1_714_607_130);
+        assert_eq!(local_clock(now, 0), "23:45");
+        assert_eq!(local_clock(now, 5 * 3600 + 30 * 60), "05:15");
+        assert_eq!(local_clock(now, -8 * 3600), "15:45");
+        assert_eq!(local_clock(UNIX_EPOCH, -3600), "23:00");
+    }
+}
@@ -4,34 +4,17 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::app::App;
-
-/// Keybinding entries: (key, description)
-const KEYBINDINGS: &[(&str, &str)] = &[
-    ("↑ / k", "Move up"),
-    ("↓ / j", "Move down"),
-    ("g", "Go to top"),
-    ("G", "Go to bottom"),
-    ("Enter", "Connect to selected network"),
-    ("d", "Disconnect from current network"),
-    ("s", "Scan for networks"),
-    ("f", "Forget selected network"),
-    ("h", "Connect to hidden network"),
-    ("i", "Toggle detail panel"),
-    ("r", "Refresh connection info"),
-    ("/", "Search / filter networks"),
-    ("S", "Cycle sort mode"),
-    ("Ctrl+H", "Show/hide password"),
-    ("Tab", "Switch fields (in dialogs)"),
-    ("Esc", "Close dialog / cancel"),
-    ("?", "Toggle this help"),
-    ("q", "Quit Nexus"),
-];
+use crate::cheatsheet;
 
 /// Render the help overlay
 pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
+    let lang = app.lang();
+    // Built from the resolved `[keys]`, so remapped keys show as remapped
+    let groups = cheatsheet::groups(app.config.keys());
+    let rows: usize = groups.iter().map(|(_, entries)| entries.len() + 2).sum();
     let width = 52_u16.min(area.width.saturating_sub(4));
-    let height = (KEYBINDINGS.len() as u16 + 6).min(area.height.saturating_sub(2));
+    let height = (rows as u16 + 4).min(area.height.saturating_sub(2));
 
     let dialog = super::centered_rect_fixed(width, height, area);
     frame.render_widget(Clear, dialog);
@@ -39,7 +22,10 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .title(Line::from(vec![
             Span::styled("  ", t.style_accent()),
-            Span::styled(" Keybindings ", t.style_accent_bold()),
+            Span::styled(
+                format!(" {} ", lang.tr("Keybindings")),
+                t.style_accent_bold(),
+            ),
         ]))
         .borders(Borders::ALL)
         .border_type(t.border_type)
@@ -47,22 +33,27 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
         .style(t.style_default());
 
     let mut lines: Vec<Line> = Vec::new();
-    lines.push(Line::from(""));
-
-    for (key, desc) in KEYBINDINGS {
-        lines.push(Line::from(vec![
-            Span::styled(format!("  {:<12}", key), t.style_key_hint()),
-            Span::styled(*desc, t.style_default()),
-        ]));
+    for (heading, entries) in groups {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  {}", lang.tr(heading)),
+            t.style_accent_bold(),
+        )));
+        for (key, desc) in entries {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<12}", key), t.style_key_hint()),
+                Span::styled(lang.tr(desc), t.style_default()),
+            ]));
+        }
     }
 
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
-        Span::styled("  Press ", t.style_dim()),
+        Span::styled(format!("  {} ", lang.tr("Press")), t.style_dim()),
         Span::styled("?", t.style_key_hint()),
-        Span::styled(" or ", t.style_dim()),
+        Span::styled(format!(" {} ", lang.tr("or")), t.style_dim()),
         Span::styled("Esc", t.style_key_hint()),
-        Span::styled(" to close", t.style_dim()),
+        Span::styled(format!(" {}", lang.tr("to close")), t.style_dim()),
     ]));
 
     let para = Paragraph::new(lines).block(block);
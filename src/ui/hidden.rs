@@ -9,6 +9,7 @@ use crate::app::App;
 /// Render the hidden network connection modal
 pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
+    let lang = app.lang();
     let width = 56_u16.min(area.width.saturating_sub(4));
     let height = 11_u16.min(area.height.saturating_sub(4));
 
@@ -31,7 +32,10 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .title(Line::from(vec![
             Span::styled(format!(" {icon}"), t.style_accent()),
-            Span::styled(" Connect to Hidden Network ", t.style_accent_bold()),
+            Span::styled(
+                format!(" {} ", lang.tr("Connect to Hidden Network")),
+                t.style_accent_bold(),
+            ),
         ]))
         .borders(Borders::ALL)
         .border_type(t.border_type)
@@ -61,8 +65,8 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     };
 
     let ssid_line = Line::from(vec![
-        Span::styled("SSID:     ", ssid_label_style),
-        Span::styled(app.hidden_ssid_input.clone(), t.style_default()),
+        Span::styled(format!("{:<10}", "SSID:"), ssid_label_style),
+        Span::styled(app.hidden_ssid_input.as_str(), t.style_default()),
         if app.hidden_field_focus == 0 {
             Span::styled(cursor_char.to_string(), t.style_accent())
         } else {
@@ -92,7 +96,10 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     };
 
     let pwd_line = Line::from(vec![
-        Span::styled("Password: ", pwd_label_style),
+        Span::styled(
+            format!("{:<10}", format!("{}:", lang.tr("Password"))),
+            pwd_label_style,
+        ),
         Span::styled(pwd_display, t.style_default()),
         if app.hidden_field_focus == 1 {
             Span::styled(cursor_char.to_string(), t.style_accent())
@@ -111,7 +118,7 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     };
     frame.render_widget(
         Paragraph::new(Span::styled(
-            "(leave empty for open networks)",
+            lang.tr("(leave empty for open networks)"),
             t.style_dim(),
         )),
         opt_area,
@@ -127,11 +134,11 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
 
     let hints = Line::from(vec![
         Span::styled("[Tab]", t.style_key_hint()),
-        Span::styled(" Switch  ", t.style_key_desc()),
+        Span::styled(format!(" {}  ", lang.tr("Switch")), t.style_key_desc()),
         Span::styled("[Enter]", t.style_key_hint()),
-        Span::styled(" Connect  ", t.style_key_desc()),
+        Span::styled(format!(" {}  ", lang.tr("Connect")), t.style_key_desc()),
         Span::styled("[Esc]", t.style_key_hint()),
-        Span::styled(" Cancel ", t.style_key_desc()),
+        Span::styled(format!(" {} ", lang.tr("Cancel")), t.style_key_desc()),
     ]);
 
     frame.render_widget(
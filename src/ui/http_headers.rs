@@ -0,0 +1,132 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::animation::spinner;
+use crate::app::App;
+use crate::network::diagnostics::HeaderLine;
+use crate::ui::theme::{Page, Theme};
+
+/// Render the HTTP header inspection: every hop's status line and headers,
+/// with redirect targets and proxy / portal headers highlighted
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let width = 90_u16.min(area.width.saturating_sub(4));
+    let height = 24_u16.min(area.height.saturating_sub(2));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", lang.tr("HTTP Headers")),
+            t.style_page_title(Page::Diagnostics),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_page_border(Page::Diagnostics))
+        .style(t.style_default());
+
+    frame.render_widget(block, dialog);
+
+    let inner = Rect {
+        x: dialog.x + 2,
+        y: dialog.y + 1,
+        width: dialog.width.saturating_sub(4),
+        height: dialog.height.saturating_sub(2),
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("GET ", t.style_dim()),
+            Span::styled(app.http_headers_url.clone(), t.style_default()),
+        ]),
+        Line::from(""),
+    ];
+    for entry in &app.http_headers {
+        match entry {
+            HeaderLine::Status { hop, line } => {
+                if *hop > 1 {
+                    lines.push(Line::from(""));
+                }
+                lines.push(Line::from(Span::styled(
+                    format!("── {} ──", lang.fill("Hop {hop}", &[("hop", hop)])),
+                    Style::default().fg(t.accent2),
+                )));
+                lines.push(Line::from(Span::styled(
+                    line.clone(),
+                    status_style(t, line).add_modifier(ratatui::style::Modifier::BOLD),
+                )));
+            }
+            HeaderLine::Header {
+                name,
+                value,
+                notable,
+            } => {
+                let (name_style, value_style) = if *notable {
+                    (t.style_warning(), t.style_warning())
+                } else {
+                    (t.style_dim(), t.style_default())
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {name}: "), name_style),
+                    Span::styled(value.clone(), value_style),
+                ]));
+            }
+            HeaderLine::Error(msg) => {
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(
+                    format!("✗ {msg}"),
+                    t.style_error(),
+                )));
+            }
+            HeaderLine::Done { hops } => {
+                let summary = match hops {
+                    0 => lang.tr("No response").to_string(),
+                    1 => lang.tr("1 response, no redirects").to_string(),
+                    n => lang.fill(
+                        "{count} responses, {redirects} redirect(s) followed",
+                        &[("count", n), ("redirects", &(n - 1))],
+                    ),
+                };
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(summary, t.style_dim())));
+            }
+        }
+    }
+    if !app.http_headers_finished() {
+        let spin = spinner::spinner_frame(app.animation.tick_count);
+        lines.push(Line::from(vec![
+            Span::styled(format!("{spin} "), t.style_accent()),
+            Span::styled(lang.tr("Fetching…"), t.style_dim()),
+        ]));
+    }
+
+    let para = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((app.http_headers_scroll, 0));
+    frame.render_widget(para, inner);
+}
+
+/// Green for 2xx, amber for redirects, red for errors
+fn status_style(t: &Theme, line: &str) -> Style {
+    match line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|c| c.chars().next())
+    {
+        Some('2') => t.style_connected(),
+        Some('3') => t.style_warning(),
+        Some('4' | '5') => t.style_error(),
+        _ => t.style_default(),
+    }
+}
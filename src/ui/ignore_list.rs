@@ -0,0 +1,73 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::App;
+
+/// Render the ignored SSID patterns with how many scanned networks each hides
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let patterns = app.ignore.patterns();
+    let width = 56_u16.min(area.width.saturating_sub(4));
+    let height = (patterns.len().max(1) as u16 + 4).min(area.height.saturating_sub(4));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", lang.tr("Ignored networks")),
+            t.style_accent_bold(),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_accent())
+        .style(t.style_default());
+    let inner = block.inner(dialog);
+    frame.render_widget(block, dialog);
+
+    if patterns.is_empty() {
+        let para = Paragraph::new(format!(
+            "\n{}",
+            lang.tr("Nothing ignored. Press [a] to add a pattern.")
+        ))
+        .style(t.style_dim())
+        .alignment(Alignment::Center);
+        frame.render_widget(para, inner);
+        return;
+    }
+
+    let mut lines = vec![Line::default()];
+    for (i, pattern) in patterns.iter().enumerate() {
+        let selected = i == app.ignore_selected;
+        let marker = if selected { "▸" } else { " " };
+        let style = if selected {
+            t.style_selected()
+        } else {
+            t.style_default()
+        };
+        let hits = app
+            .networks
+            .iter()
+            .filter(|net| crate::ignore::glob_match(pattern, &net.ssid))
+            .count();
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {marker} {pattern:<34}"), style),
+            Span::styled(
+                lang.fill("{count} in range", &[("count", &format!("{hits:>3}"))]),
+                t.style_dim(),
+            ),
+        ]));
+    }
+
+    let scroll = (app.ignore_selected + 2).saturating_sub(inner.height as usize);
+    frame.render_widget(Paragraph::new(lines).scroll((scroll as u16, 0)), inner);
+}
@@ -0,0 +1,90 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::app::{App, InputKind};
+
+/// Render the generic single-line input modal
+pub fn render(frame: &mut Frame, app: &App, area: Rect, kind: InputKind) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let width = 56_u16.min(area.width.saturating_sub(4));
+    let height = 9_u16.min(area.height.saturating_sub(4));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", lang.tr(kind.title())),
+            t.style_accent_bold(),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_accent())
+        .style(t.style_default());
+
+    frame.render_widget(block, dialog);
+
+    let cursor_char = if app.animation.cursor_visible() {
+        "█"
+    } else {
+        " "
+    };
+
+    // Input field
+    let field_area = Rect {
+        x: dialog.x + 3,
+        y: dialog.y + 2,
+        width: dialog.width.saturating_sub(6),
+        height: 1,
+    };
+
+    let input_line = Line::from(vec![
+        Span::styled(format!("{}: ", lang.tr(kind.label())), t.style_dim()),
+        Span::styled(app.input_buffer.as_str(), t.style_default()),
+        Span::styled(cursor_char, t.style_accent()),
+    ]);
+    frame.render_widget(Paragraph::new(input_line), field_area);
+
+    // Helper text
+    let hint_text_area = Rect {
+        x: dialog.x + 3,
+        y: dialog.y + 3,
+        width: dialog.width.saturating_sub(6),
+        height: 1,
+    };
+    frame.render_widget(
+        Paragraph::new(Span::styled(lang.tr(kind.hint()), t.style_dim())),
+        hint_text_area,
+    );
+
+    // Hints
+    let hint_area = Rect {
+        x: dialog.x + 3,
+        y: dialog.y + height.saturating_sub(3),
+        width: dialog.width.saturating_sub(6),
+        height: 1,
+    };
+
+    let hints = Line::from(vec![
+        Span::styled("[Enter]", t.style_key_hint()),
+        Span::styled(format!(" {}  ", lang.tr("Apply")), t.style_key_desc()),
+        Span::styled("[Esc]", t.style_key_hint()),
+        Span::styled(format!(" {} ", lang.tr("Cancel")), t.style_key_desc()),
+    ]);
+
+    frame.render_widget(
+        Paragraph::new(hints)
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: true }),
+        hint_area,
+    );
+}
@@ -1,14 +1,33 @@
+pub mod confirm;
 pub mod details;
+pub mod diagnostics;
+pub mod events;
 pub mod header;
 pub mod help;
 pub mod hidden;
+pub mod http_headers;
+pub mod ignore_list;
+pub mod input;
+pub mod mtu_probe;
 pub mod network_list;
+pub mod palette;
 pub mod password;
+pub mod ping_compare;
+pub mod profile;
+pub mod quick_actions;
+pub mod recent;
+pub mod shell;
+pub mod statistics;
 pub mod status_bar;
+pub mod survey;
 pub mod theme;
+pub mod theme_preview;
+pub mod traffic;
+pub mod wifi_devices;
 
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
 
 use crate::app::{App, AppMode};
 
@@ -22,6 +41,11 @@ pub fn render(frame: &mut Frame, app: &App) {
         return;
     }
 
+    if app.dimmed && app.config.appearance.idle_minimal {
+        render_idle_line(frame, app, area);
+        return;
+    }
+
     // Main vertical layout: header | body | footer
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -35,16 +59,29 @@ pub fn render(frame: &mut Frame, app: &App) {
     // Render header
     header::render(frame, app, chunks[0]);
 
-    // Body: network list (+ optional detail panel)
+    // Body: survey table, statistics, or network list (+ optional detail panel)
     let show_details = app.detail_visible && area.width > 90;
-    if show_details {
+    if matches!(app.mode, AppMode::Survey) {
+        survey::render(frame, app, chunks[1]);
+    } else if matches!(app.mode, AppMode::Statistics) {
+        statistics::render(frame, app, chunks[1]);
+    } else if show_details {
         let body_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
             .split(chunks[1]);
 
         network_list::render(frame, app, body_chunks[0]);
-        details::render(frame, app, body_chunks[1]);
+        if app.traffic.is_empty() || body_chunks[1].height < 24 {
+            details::render(frame, app, body_chunks[1]);
+        } else {
+            let side = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(16), Constraint::Length(8)])
+                .split(body_chunks[1]);
+            details::render(frame, app, side[0]);
+            traffic::render(frame, app, side[1]);
+        }
     } else {
         network_list::render(frame, app, chunks[1]);
     }
@@ -54,9 +91,8 @@ pub fn render(frame: &mut Frame, app: &App) {
 
     // Render overlays (modals) on top
     match &app.mode {
-        AppMode::PasswordInput { ssid } => {
-            let ssid = ssid.clone();
-            password::render(frame, app, area, &ssid);
+        AppMode::PasswordInput { ssid, .. } => {
+            password::render(frame, app, area, ssid);
         }
         AppMode::Hidden => {
             hidden::render(frame, app, area);
@@ -64,11 +100,101 @@ pub fn render(frame: &mut Frame, app: &App) {
         AppMode::Help => {
             help::render(frame, app, area);
         }
+        AppMode::Input(kind) => {
+            input::render(frame, app, area, *kind);
+        }
+        AppMode::Confirm(action) => {
+            confirm::render(frame, app, area, action);
+        }
+        AppMode::ProfileEdit => {
+            profile::render(frame, app, area);
+        }
+        AppMode::Diagnostics => {
+            diagnostics::render(frame, app, area);
+        }
+        AppMode::HttpHeaders => {
+            http_headers::render(frame, app, area);
+        }
+        AppMode::MtuProbe => {
+            mtu_probe::render(frame, app, area);
+        }
+        AppMode::PingCompare => {
+            ping_compare::render(frame, app, area);
+        }
+        AppMode::ShellEscape => {
+            shell::render(frame, app, area);
+        }
+        AppMode::Events => {
+            events::render(frame, app, area);
+        }
+        AppMode::Palette | AppMode::GlobalSearch => {
+            palette::render(frame, app, area);
+        }
+        AppMode::QuickActions => {
+            quick_actions::render(frame, app, area);
+        }
+        AppMode::Recent => {
+            recent::render(frame, app, area);
+        }
+        AppMode::IgnoreList => {
+            ignore_list::render(frame, app, area);
+        }
+        AppMode::ThemePreview => {
+            theme_preview::render(frame, app, area);
+        }
+        AppMode::WifiDevices => {
+            wifi_devices::render(frame, app, area);
+        }
         AppMode::Error(msg) => {
             render_error_dialog(frame, app, area, msg);
         }
         _ => {}
     }
+
+    if app.dimmed {
+        frame
+            .buffer_mut()
+            .set_style(area, Style::default().add_modifier(Modifier::DIM));
+    }
+}
+
+/// While dimmed with `idle_minimal`: the connection on one centered line
+fn render_idle_line(frame: &mut Frame, app: &App, area: Rect) {
+    use ratatui::layout::Alignment;
+    use ratatui::widgets::Paragraph;
+
+    use crate::network::stats::format_rate;
+    use crate::network::types::ConnectionStatus;
+
+    let lang = app.lang();
+    let text = match &app.connection_status {
+        ConnectionStatus::Connected(info) => {
+            let rx = app.traffic.rx.back().copied().unwrap_or_default();
+            let tx = app.traffic.tx.back().copied().unwrap_or_default();
+            format!(
+                "● {}  {}%  ↓{} ↑{}",
+                info.ssid,
+                info.signal,
+                format_rate(rx),
+                format_rate(tx)
+            )
+        }
+        ConnectionStatus::Connecting(ssid) => format!("{} {ssid}…", lang.tr("Connecting to")),
+        ConnectionStatus::Disconnecting => lang.tr("Disconnecting…").to_string(),
+        ConnectionStatus::Disconnected => lang.tr("Disconnected").to_string(),
+        ConnectionStatus::Failed(reason) => {
+            lang.fill("Failed: {reason}", &[("reason", &lang.tr(reason))])
+        }
+    };
+    let line = Rect {
+        y: area.y + area.height / 2,
+        height: 1,
+        ..area
+    };
+    let para = Paragraph::new(text)
+        .style(app.theme.style_dim())
+        .alignment(Alignment::Center);
+    frame.render_widget(para, line);
 }
 
 /// Render a "terminal too small" message
@@ -77,50 +203,88 @@ fn render_too_small(frame: &mut Frame, app: &App, area: Rect) {
     use ratatui::widgets::Paragraph;
 
     let msg = Text::styled(
-        "Terminal too small\nMinimum: 50×12",
+        app.lang().tr("Terminal too small\nMinimum: 50×12"),
         app.theme.style_warning(),
     );
     let para = Paragraph::new(msg).alignment(ratatui::layout::Alignment::Center);
     frame.render_widget(para, area);
 }
 
-/// Render an error dialog overlay
+/// Render the error dialog: first line as a summary, the rest as
+/// scrollable details (raw D-Bus errors can be several lines long once wrapped)
 fn render_error_dialog(frame: &mut Frame, app: &App, area: Rect, message: &str) {
     use ratatui::text::{Line, Span};
     use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+    use unicode_width::UnicodeWidthStr;
 
-    let dialog = centered_rect(60, 30, area);
+    let dialog = centered_rect(60, 40, area);
     frame.render_widget(Clear, dialog);
 
     let block = Block::default()
         .title(Line::from(vec![
             Span::styled(" ", app.theme.style_error()),
-            Span::styled(" Error ", app.theme.style_error()),
+            Span::styled(
+                format!(" {} ", app.lang().tr("Error")),
+                app.theme.style_error(),
+            ),
         ]))
         .borders(Borders::ALL)
         .border_type(app.theme.border_type)
         .border_style(app.theme.style_error())
         .style(app.theme.style_default());
+    let inner = block.inner(dialog);
+    frame.render_widget(block, dialog);
 
-    let para = Paragraph::new(message.to_string())
-        .block(block)
-        .wrap(Wrap { trim: true })
-        .style(app.theme.style_default());
+    let (summary, details) = match message.split_once('\n') {
+        Some((summary, details)) => (summary, details.trim()),
+        None => (message, ""),
+    };
 
-    frame.render_widget(para, dialog);
+    let body_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Summary + spacer
+            Constraint::Min(1),    // Details
+            Constraint::Length(1), // Hints
+        ])
+        .split(inner);
 
-    // Footer hint inside dialog
-    let hint_area = Rect {
-        x: dialog.x + 2,
-        y: dialog.y + dialog.height - 2,
-        width: dialog.width.saturating_sub(4),
-        height: 1,
-    };
-    let hint = ratatui::text::Line::from(vec![
+    frame.render_widget(
+        Paragraph::new(Span::styled(summary, app.theme.style_error())).wrap(Wrap { trim: true }),
+        body_chunks[0],
+    );
+
+    if !details.is_empty() {
+        // Clamp the scroll so the last page of details stays in view
+        let width = body_chunks[1].width.max(1) as usize;
+        let wrapped: usize = details
+            .lines()
+            .map(|line| line.width().div_ceil(width).max(1))
+            .sum();
+        let max_scroll = wrapped.saturating_sub(body_chunks[1].height as usize) as u16;
+
+        frame.render_widget(
+            Paragraph::new(details)
+                .wrap(Wrap { trim: false })
+                .scroll((app.error_scroll.min(max_scroll), 0))
+                .style(app.theme.style_default()),
+            body_chunks[1],
+        );
+    }
+
+    let hint = Line::from(vec![
+        Span::styled("[y]", app.theme.style_key_hint()),
+        Span::styled(
+            format!(" {}  ", app.lang().tr("Copy")),
+            app.theme.style_key_desc(),
+        ),
         Span::styled("[Esc]", app.theme.style_key_hint()),
-        Span::styled(" Close", app.theme.style_key_desc()),
+        Span::styled(
+            format!(" {}", app.lang().tr("Close")),
+            app.theme.style_key_desc(),
+        ),
     ]);
-    frame.render_widget(ratatui::widgets::Paragraph::new(hint), hint_area);
+    frame.render_widget(Paragraph::new(hint), body_chunks[2]);
 }
 
 /// Create a centered rectangle within an area (percentage-based)
@@ -150,3 +314,258 @@ pub fn centered_rect_fixed(width: u16, height: u16, area: Rect) -> Rect {
     let y = area.y + (area.height.saturating_sub(height)) / 2;
     Rect::new(x, y, width.min(area.width), height.min(area.height))
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::config::Config;
+    use crate::favorites::Favorites;
+    use crate::ignore::IgnoreList;
+    use crate::labels::Labels;
+    use crate::network::types::*;
+    use crate::ui::theme::Theme;
+    use crate::view_state::ViewState;
+
+    /// Long and unicode names on purpose, to catch truncation and width bugs
+    const NETWORKS: &[(&str, u8, u32, SecurityType, bool, bool)] = &[
+        ("HomeWiFi", 82, 5180, SecurityType::WPA2, true, true),
+        ("Neighbor-6E", 41, 6115, SecurityType::WPA3, false, false),
+        (
+            "CoffeeShop_Guest",
+            55,
+            2462,
+            SecurityType::Open,
+            false,
+            false,
+        ),
+        (
+            "Café Ünïcødé ☕ Free WiFi",
+            29,
+            2412,
+            SecurityType::Wpa,
+            false,
+            false,
+        ),
+        (
+            "This Is A Really Long Network Name For Testing Truncation",
+            22,
+            5500,
+            SecurityType::WPA2,
+            true,
+            false,
+        ),
+    ];
+
+    fn app_with(config: Config) -> App {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let theme = Theme::from_config(&config);
+        let mut app = App::new(config, theme, "wlan0".to_string(), ViewState::default(), tx);
+        app.favorites = Favorites::default();
+        app.labels = Labels::default();
+        app.ignore = IgnoreList::default();
+        let networks = NETWORKS
+            .iter()
+            .enumerate()
+            .map(
+                |(i, (ssid, signal, frequency, security, saved, active))| WiFiNetwork {
+                    bssid: format!("AA:BB:CC:DD:EE:{i:02X}"),
+                    frequency: *frequency,
+                    max_bitrate: 866,
+                    security: security.clone(),
+                    is_saved: *saved,
+                    is_active: *active,
+                    ap_path: format!("/org/freedesktop/NetworkManager/AccessPoint/{i}"),
+                    seen_ticks: 100,
+                    ..WiFiNetwork::test(ssid, *signal)
+                },
+            )
+            .collect();
+        app.update_networks(networks);
+        app.connection_status = ConnectionStatus::Connected(Box::new(ConnectionInfo {
+            ssid: "HomeWiFi".into(),
+            bssid: "AA:BB:CC:DD:EE:00".into(),
+            ip4: Some("192.168.1.42/24".into()),
+            gateway: Some("192.168.1.1".into()),
+            frequency: 5180,
+            signal: 82,
+            interface: "wlan0".into(),
+            ..Default::default()
+        }));
+        app
+    }
+
+    fn app() -> App {
+        app_with(Config::default())
+    }
+
+    /// Render a frame and return the buffer as one string per row
+    fn draw(app: &App, width: u16, height: u16) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|frame| render(frame, app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    fn screen(rows: &[String]) -> String {
+        rows.join("\n")
+    }
+
+    fn key(app: &mut App, code: crossterm::event::KeyCode) {
+        app.handle_key(crossterm::event::KeyEvent::from(code));
+    }
+
+    /// Nerd Font glyphs live in the private use areas
+    fn is_private_use(c: char) -> bool {
+        matches!(c as u32, 0xE000..=0xF8FF | 0xF0000..=0xFFFFD | 0x100000..=0x10FFFD)
+    }
+
+    #[test]
+    fn network_list_shows_every_network() {
+        let rows = draw(&app(), 80, 24);
+        let text = screen(&rows);
+        assert!(rows[3].contains("WiFi Networks (5)"));
+        for ssid in [
+            "HomeWiFi",
+            "Neighbor-6E",
+            "CoffeeShop_Guest",
+            "Café Ünïcødé",
+        ] {
+            assert!(text.contains(ssid), "{ssid} missing:\n{text}");
+        }
+        // Strongest first, the active network on top
+        let home = rows.iter().position(|r| r.contains("82%")).unwrap();
+        let coffee = rows.iter().position(|r| r.contains("CoffeeShop")).unwrap();
+        assert!(home < coffee);
+        assert!(rows[23].contains("[Enter] Connect"));
+    }
+
+    #[test]
+    fn long_ssid_is_truncated_inside_its_column() {
+        for (width, height) in [(80, 24), (120, 40)] {
+            let rows = draw(&app(), width, height);
+            let row = rows
+                .iter()
+                .find(|r| r.contains("This Is A Really Long"))
+                .unwrap();
+            assert!(row.contains('…'), "{row}");
+            assert!(!row.contains("Truncation"), "{row}");
+            // The signal column still lines up with the rows above
+            let col = |r: &str| r.chars().position(|c| c == '%').unwrap();
+            let home = rows.iter().find(|r| r.contains("82%")).unwrap();
+            assert_eq!(col(row), col(home));
+        }
+    }
+
+    #[test]
+    fn borders_stay_closed_at_every_size() {
+        for (width, height) in [(50, 12), (80, 24), (120, 40)] {
+            let rows = draw(&app(), width, height);
+            assert_eq!(rows.len(), height as usize);
+            // Body rows between the list's top and bottom border
+            for row in &rows[4..height as usize - 2] {
+                assert!(row.starts_with('│'), "{width}x{height}: {row}");
+                assert!(row.ends_with('│'), "{width}x{height}: {row}");
+            }
+        }
+    }
+
+    #[test]
+    fn detail_panel_only_on_wide_terminals() {
+        let wide = screen(&draw(&app(), 120, 40));
+        assert!(wide.contains("Details"));
+        assert!(wide.contains("192.168.1.1"));
+        assert!(wide.contains("5180 MHz"));
+
+        let narrow = screen(&draw(&app(), 80, 24));
+        assert!(!narrow.contains("Details"));
+    }
+
+    #[test]
+    fn too_small_terminal_shows_minimum() {
+        let text = screen(&draw(&app(), 40, 10));
+        assert!(text.contains("Terminal too small"));
+        assert!(!text.contains("HomeWiFi"));
+    }
+
+    #[test]
+    fn no_nerd_font_glyphs_when_disabled() {
+        let mut config = Config::default();
+        config.appearance.nerd_fonts = false;
+        for (width, height) in [(80, 24), (120, 40)] {
+            let text = screen(&draw(&app_with(config.clone()), width, height));
+            assert!(text.contains("HomeWiFi"));
+            let glyph = text.chars().find(|&c| is_private_use(c));
+            assert_eq!(glyph, None, "{width}x{height}:\n{text}");
+        }
+    }
+
+    #[test]
+    fn password_dialog() {
+        use crossterm::event::KeyCode;
+        let mut app = app();
+        // Neighbor-6E is the third row
+        key(&mut app, KeyCode::Down);
+        key(&mut app, KeyCode::Down);
+        key(&mut app, KeyCode::Enter);
+        assert!(matches!(app.mode, AppMode::PasswordInput { .. }));
+        for c in "secret".chars() {
+            key(&mut app, KeyCode::Char(c));
+        }
+
+        let text = screen(&draw(&app, 80, 24));
+        assert!(text.contains("Connect to \"Neighbor-6E\""));
+        // Masked until revealed
+        assert!(!text.contains("secret"));
+        assert!(text.contains("Cancel"));
+    }
+
+    #[test]
+    fn help_overlay() {
+        let mut app = app();
+        key(&mut app, crossterm::event::KeyCode::Char('?'));
+        assert!(matches!(app.mode, AppMode::Help));
+        let text = screen(&draw(&app, 120, 40));
+        assert!(text.contains("Keybindings"));
+    }
+
+    #[test]
+    fn confirm_forget_active() {
+        let mut app = app();
+        key(&mut app, crossterm::event::KeyCode::Char('f'));
+        assert!(matches!(app.mode, AppMode::Confirm(_)));
+        let text = screen(&draw(&app, 80, 24));
+        assert!(text.contains("HomeWiFi"));
+        assert!(text.contains("Forget"));
+    }
+
+    #[test]
+    fn favorite_gets_its_own_marker() {
+        let mut app = app();
+        app.favorites = ["CoffeeShop_Guest".to_string()].into_iter().collect();
+        let rows = draw(&app, 80, 24);
+        let row = rows.iter().find(|r| r.contains("CoffeeShop")).unwrap();
+        assert!(row.contains('♥'), "{row}");
+        let home = rows.iter().find(|r| r.contains("82%")).unwrap();
+        assert!(!home.contains('♥'), "{home}");
+    }
+
+    #[test]
+    fn statistics_page() {
+        let mut app = app();
+        key(&mut app, crossterm::event::KeyCode::Char(']'));
+        assert!(matches!(app.mode, AppMode::Statistics));
+        let text = screen(&draw(&app, 120, 40));
+        assert!(text.contains("Statistics"));
+        assert!(!text.contains("WiFi Networks"));
+    }
+}
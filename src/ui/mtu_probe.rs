@@ -0,0 +1,132 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::animation::spinner;
+use crate::app::App;
+use crate::network::diagnostics::{MtuProbeLine, ProbeOutcome};
+use crate::ui::theme::Page;
+
+/// Render the path MTU probe: one line per don't-fragment ping, then the
+/// discovered path MTU compared with the interface MTU
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let width = 64_u16.min(area.width.saturating_sub(4));
+    let height = 22_u16.min(area.height.saturating_sub(2));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", lang.tr("Path MTU Probe")),
+            t.style_page_title(Page::Diagnostics),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_page_border(Page::Diagnostics))
+        .style(t.style_default());
+
+    frame.render_widget(block, dialog);
+
+    let inner = Rect {
+        x: dialog.x + 3,
+        y: dialog.y + 1,
+        width: dialog.width.saturating_sub(6),
+        height: dialog.height.saturating_sub(2),
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!("{} ", lang.tr("Towards")), t.style_dim()),
+            Span::styled(app.mtu_probe_host.clone(), t.style_default()),
+            Span::styled(format!(" ({})", lang.tr("don't fragment")), t.style_dim()),
+        ]),
+        Line::from(""),
+    ];
+    for entry in &app.mtu_probe {
+        match entry {
+            MtuProbeLine::Probe { size, outcome } => {
+                let (mark, style, detail) = match outcome {
+                    ProbeOutcome::Passed => {
+                        ("✓", t.style_connected(), lang.tr("passed").to_string())
+                    }
+                    ProbeOutcome::TooBig {
+                        reported: Some(mtu),
+                    } => (
+                        "✗",
+                        t.style_error(),
+                        lang.fill("too big (mtu {mtu})", &[("mtu", mtu)]),
+                    ),
+                    ProbeOutcome::TooBig { reported: None } => {
+                        ("✗", t.style_error(), lang.tr("too big").to_string())
+                    }
+                    ProbeOutcome::NoReply => {
+                        ("✗", t.style_error(), lang.tr("no reply").to_string())
+                    }
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{mark} "), style),
+                    Span::styled(
+                        format!("{size:>5} {}  ", lang.tr("bytes")),
+                        t.style_default(),
+                    ),
+                    Span::styled(detail, t.style_dim()),
+                ]));
+            }
+            MtuProbeLine::Error(msg) => {
+                lines.push(Line::from(Span::styled(
+                    format!("✗ {msg}"),
+                    t.style_error(),
+                )));
+            }
+            MtuProbeLine::Done {
+                path_mtu,
+                iface_mtu,
+            } => {
+                lines.push(Line::from(""));
+                let (summary, style) = match path_mtu {
+                    Some(mtu) if mtu < iface_mtu => (
+                        lang.fill(
+                            "Path MTU {mtu} is below the interface MTU {iface}. Larger \
+                             packets are dropped or fragmented on the way, typical of PPPoE \
+                             or a VPN. Setting the MTU to {mtu} ([m]) avoids stalls.",
+                            &[("mtu", &mtu), ("iface", iface_mtu)],
+                        ),
+                        t.style_warning(),
+                    ),
+                    Some(mtu) => (
+                        lang.fill(
+                            "Path MTU {mtu} — matches the interface MTU",
+                            &[("mtu", &mtu)],
+                        ),
+                        t.style_connected(),
+                    ),
+                    None => (
+                        lang.tr("Nothing got through — the host is unreachable or drops pings")
+                            .to_string(),
+                        t.style_error(),
+                    ),
+                };
+                lines.push(Line::from(Span::styled(summary, style)));
+            }
+        }
+    }
+    if !app.mtu_probe_finished() {
+        let spin = spinner::spinner_frame(app.animation.tick_count);
+        lines.push(Line::from(vec![
+            Span::styled(format!("{spin} "), t.style_accent()),
+            Span::styled(lang.tr("Probing…"), t.style_dim()),
+        ]));
+    }
+
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), inner);
+}
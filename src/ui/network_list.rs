@@ -4,14 +4,15 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
 use unicode_width::UnicodeWidthStr;
 
-use super::theme;
+use super::theme::{self, Page};
 use crate::animation::spinner;
 use crate::animation::transitions::fade_in_opacity;
 use crate::app::{App, AppMode};
+use crate::network::types::{ConnectionStatus, DeviceState, approx_dbm};
 
 /// Truncate a string to `max_chars` grapheme-safe width, appending `…` if truncated.
 /// Never slices into the middle of a multi-byte character.
-fn truncate_ssid(s: &str, max_chars: usize) -> String {
+pub(super) fn truncate_ssid(s: &str, max_chars: usize) -> String {
     if s.width() <= max_chars {
         return format!("{:<width$}", s, width = max_chars);
     }
@@ -57,36 +58,59 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     // Build title
     let visible_count = app.filtered_indices.len();
     let total_count = app.networks.len();
-    let sort_label = app.sort_mode.label();
+    let sort_label = app.lang().tr(app.sort_mode.label());
 
+    let lang = app.lang();
+    let auto_scan = match app.auto_scan {
+        Some(every) => {
+            let every = lang.fill("auto-scan {seconds}s", &[("seconds", &every.as_secs())]);
+            format!("· {every} ")
+        }
+        None => String::new(),
+    };
+    let networks = lang.tr("WiFi Networks");
+    // Ignored and weak networks are counted so they aren't silently lost
+    let mut hidden = String::new();
+    if app.ignored_count > 0 {
+        let ignored = lang.fill("{count} hidden", &[("count", &app.ignored_count)]);
+        hidden.push_str(&format!("· {ignored} "));
+    }
+    if app.weak_count > 0 {
+        let weak = lang.fill("{count} weak", &[("count", &app.weak_count)]);
+        hidden.push_str(&format!("· {weak} "));
+    }
     let title_text = if is_scanning {
         let scan_icon = if nerd { theme::ICON_SCAN } else { "" };
         let spin = spinner::spinner_frame(app.animation.tick_count);
-        format!(" {scan_icon}{spin} Scanning… ")
+        format!(" {scan_icon}{spin} {} ", lang.tr("Scanning…"))
+    } else if !app.marked.is_empty() {
+        let marked = lang.fill("{count} marked", &[("count", &app.marked.len())]);
+        format!(" {networks} ({total_count}) [{sort_label}] · {marked} {hidden}{auto_scan}")
     } else if !app.search_query.is_empty() {
-        format!(" WiFi Networks ({visible_count}/{total_count}) [{sort_label}] ")
+        format!(" {networks} ({visible_count}/{total_count}) [{sort_label}] {hidden}{auto_scan}")
     } else {
-        format!(" WiFi Networks ({total_count}) [{sort_label}] ")
+        format!(" {networks} ({total_count}) [{sort_label}] {hidden}{auto_scan}")
     };
 
     let block = Block::default()
-        .title(Line::from(Span::styled(title_text, t.style_accent_bold())))
+        .title(Line::from(Span::styled(
+            title_text,
+            t.style_page_title(Page::Networks),
+        )))
         .borders(Borders::ALL)
         .border_type(t.border_type)
         .border_style(t.style_border())
         .style(t.style_default());
 
     // Use the filtered visible list
-    let visible = app.visible_networks();
-
-    if visible.is_empty() {
-        let empty_msg = if is_scanning {
+    if app.filtered_indices.is_empty() {
+        let empty_msg = lang.tr(if is_scanning {
             "Scanning for networks…"
         } else if !app.search_query.is_empty() {
             "No matching networks"
         } else {
             "No networks found. Press [s] to scan."
-        };
+        });
         let para = ratatui::widgets::Paragraph::new(empty_msg)
             .block(block)
             .style(t.style_dim())
@@ -100,10 +124,19 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
-    // Build list items from filtered view
-    let items: Vec<ListItem> = visible
-        .iter()
+    // Only the rows that fit are built; dense scans with deduplication off
+    // can list hundreds of BSSIDs
+    let (first, rows) = visible_window(
+        app.selected_index,
+        visible_count,
+        list_area.height.saturating_sub(2) as usize,
+    );
+    let columns = app.wifi_columns();
+    let items: Vec<ListItem> = app
+        .visible_networks()
         .enumerate()
+        .skip(first)
+        .take(rows)
         .map(|(vis_idx, net)| {
             let is_selected = vis_idx == app.selected_index;
             let opacity = fade_in_opacity(net.seen_ticks);
@@ -119,22 +152,43 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
                 Span::styled("  ", t.style_default())
             };
 
-            // Connection status dot
-            let status_dot = if net.is_active {
+            // Connection status dot (spinner while this AP is being joined)
+            let is_connecting = matches!(
+                &app.connection_status,
+                ConnectionStatus::Connecting(ssid) if *ssid == net.ssid
+            );
+            let status_dot = if is_connecting {
+                let spin = spinner::spinner_frame(app.animation.tick_count);
+                Span::styled(format!("{spin} "), t.style_accent())
+            } else if app.marked.contains(&net.ssid) {
+                Span::styled("✓ ", t.style_warning())
+            } else if net.is_active && app.device_state == DeviceState::Deactivating {
+                let bar = spinner::bar_frame(app.animation.tick_count);
+                Span::styled(format!("{bar} "), t.style_warning())
+            } else if net.is_active {
                 Span::styled("● ", t.style_connected())
             } else {
                 Span::styled("  ", t.style_default())
             };
 
-            // SSID with padding (char-boundary-safe truncation)
-            let ssid_width = 28;
-            let ssid_display = truncate_ssid(&net.ssid, ssid_width);
+            // SSID with padding (char-boundary-safe truncation); it gets
+            // the room of hidden columns
+            let ssid_width = 28
+                + if columns.signal { 0 } else { 5 }
+                + if columns.security { 0 } else { 9 }
+                + if columns.saved { 0 } else { 2 }
+                + if columns.band { 0 } else { 3 };
+            let ssid_display = match app.labels.nickname(&net.ssid) {
+                Some(nickname) => truncate_ssid(&format!("{} ({nickname})", net.ssid), ssid_width),
+                None => truncate_ssid(&net.ssid, ssid_width),
+            };
 
+            let is_stale = net.is_stale();
             let ssid_style = if net.is_active {
                 t.style_connected()
             } else if is_selected {
                 t.style_selected()
-            } else if opacity < 1.0 {
+            } else if opacity < 1.0 || is_stale {
                 t.style_dim()
             } else {
                 t.style_default()
@@ -144,14 +198,19 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
             let signal_display = net.display_signal.round() as u8;
             let sig_icon = t.signal_icon(signal_display, nerd);
             let sig_color = t.signal_color(signal_display);
-            let signal_span = Span::styled(
-                sig_icon.to_string(),
-                ratatui::style::Style::default().fg(sig_color),
-            );
+            let signal_span = if t.mono {
+                Span::raw(format!("{} ", t.signal_meter(signal_display)))
+            } else {
+                Span::styled(sig_icon, ratatui::style::Style::default().fg(sig_color))
+            };
 
-            // Signal percentage
+            // Signal percentage, or its dBm estimate
             let pct = Span::styled(
-                format!("{:>3}%", signal_display),
+                if app.signal_dbm {
+                    format!("{:>4}dBm", approx_dbm(signal_display))
+                } else {
+                    format!("{:>3}%", signal_display)
+                },
                 ratatui::style::Style::default().fg(sig_color),
             );
 
@@ -189,6 +248,20 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
                 Span::raw(" ")
             };
 
+            // Favorite (pinned) indicator
+            let favorite = if app.favorites.contains(&net.ssid) {
+                Span::styled(
+                    if nerd {
+                        theme::ICON_FAVORITE
+                    } else {
+                        theme::PLAIN_FAVORITE
+                    },
+                    t.style_warning(),
+                )
+            } else {
+                Span::raw(" ")
+            };
+
             // Band indicator
             let band = {
                 let band_str = match net.band() {
@@ -199,21 +272,52 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
                 Span::styled(format!(" {band_str}"), t.style_dim())
             };
 
-            let line = Line::from(vec![
+            // Alt+1–9 target
+            let number = if !app.config.appearance.row_numbers {
+                Span::raw("")
+            } else if vis_idx < 9 {
+                Span::styled(format!("{} ", vis_idx + 1), t.style_dim())
+            } else {
+                Span::raw("  ")
+            };
+
+            let mut spans = vec![
+                number,
                 selector,
                 status_dot,
                 Span::styled(ssid_display, ssid_style),
                 Span::raw(" "),
                 signal_span,
-                pct,
-                Span::raw(" "),
-                lock_span,
-                security,
-                saved,
-                band,
-            ]);
+            ];
+            if columns.signal {
+                spans.extend([pct, Span::raw(" ")]);
+            }
+            if columns.security {
+                spans.extend([lock_span, security]);
+            }
+            if columns.saved {
+                spans.extend([saved, favorite]);
+            }
+            if columns.band {
+                spans.push(band);
+            }
+            if is_connecting {
+                spans.push(Span::styled(
+                    format!(" {}", lang.tr("connecting…")),
+                    t.style_accent(),
+                ));
+            }
+            // Without color the connected and stale styling needs words
+            if t.mono && net.is_active {
+                spans.push(Span::styled(
+                    format!(" {}", lang.tr("ACTIVE")),
+                    t.style_connected(),
+                ));
+            } else if t.mono && is_stale {
+                spans.push(Span::raw(format!(" ({})", lang.tr("stale"))));
+            }
 
-            ListItem::new(line)
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -223,7 +327,7 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
         .highlight_symbol("");
 
     let mut state = ListState::default();
-    state.select(Some(app.selected_index));
+    state.select(Some(app.selected_index.saturating_sub(first)));
 
     frame.render_stateful_widget(list, list_area, &mut state);
 
@@ -233,6 +337,17 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     }
 }
 
+/// First row and row count of the `len` rows to build for a viewport
+/// `height` rows tall. Like `List` with a fresh `ListState`, the window
+/// starts at the top and follows the selection once it passes the bottom.
+fn visible_window(selected: usize, len: usize, height: usize) -> (usize, usize) {
+    let height = height.max(1);
+    let first = (selected + 1)
+        .saturating_sub(height)
+        .min(len.saturating_sub(1));
+    (first, height.min(len - first))
+}
+
 /// Render the inline search/filter bar at the bottom of the network list
 fn render_search_bar(frame: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
@@ -0,0 +1,120 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::{App, AppMode};
+use crate::palette::Hit;
+
+/// Most matches listed at once in the command palette
+const MAX_SHOWN: usize = 8;
+
+/// Render the command palette or the global search: the typed query above
+/// the best matches, grouped by kind in the global search
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let global = matches!(app.mode, AppMode::GlobalSearch);
+    let hits = app.palette_hits();
+    let body_rows = if global {
+        // Up to three groups, each with a heading
+        hits.len() + 3
+    } else {
+        MAX_SHOWN
+    };
+    let width = 48_u16.min(area.width.saturating_sub(4));
+    let height = (body_rows.max(1) as u16 + 5).min(area.height.saturating_sub(4));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let (title, prompt) = if global {
+        (lang.tr("Search"), " / ")
+    } else {
+        (lang.tr("Go to"), " : ")
+    };
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {title} "),
+            t.style_accent_bold(),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_accent())
+        .style(t.style_default());
+    let inner = block.inner(dialog);
+    frame.render_widget(block, dialog);
+
+    let cursor_char = if app.animation.cursor_visible() {
+        "█"
+    } else {
+        " "
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(prompt, t.style_accent()),
+            Span::styled(app.palette_query.as_str(), t.style_default()),
+            Span::styled(cursor_char, t.style_accent()),
+        ]),
+        Line::default(),
+    ];
+
+    if hits.is_empty() {
+        let msg = lang.tr(if global && app.palette_query.trim().is_empty() {
+            "Type to search networks, interfaces and actions"
+        } else {
+            "No matches"
+        });
+        lines.push(Line::from(Span::styled(format!("  {msg}"), t.style_dim())));
+    }
+
+    // The palette scrolls so the selection stays inside the shown window;
+    // the global search caps each group instead
+    let (skip, take) = if global {
+        (0, hits.len())
+    } else {
+        (
+            (app.palette_selected + 1).saturating_sub(MAX_SHOWN),
+            MAX_SHOWN,
+        )
+    };
+    let mut group = None;
+    for (i, hit) in hits.iter().enumerate().skip(skip).take(take) {
+        if global && group != Some(hit.group()) {
+            group = Some(hit.group());
+            lines.push(Line::from(Span::styled(
+                format!(" {}", lang.tr(hit.group())),
+                t.style_accent_bold(),
+            )));
+        }
+        let selected = i == app.palette_selected;
+        let name = match hit {
+            Hit::Action(action) => lang.tr(action.label()),
+            _ => hit.label(),
+        };
+        let label = format!(" {} {name:<28}", if selected { "▸" } else { " " });
+        let style = if selected {
+            t.style_selected()
+        } else {
+            t.style_default()
+        };
+        let kind = match hit {
+            Hit::Action(action) if action.is_view() => lang.tr("view"),
+            Hit::Action(_) => lang.tr("action"),
+            _ => "",
+        };
+        lines.push(Line::from(vec![
+            Span::styled(label, style),
+            Span::styled(kind, t.style_dim()),
+        ]));
+    }
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
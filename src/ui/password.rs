@@ -8,6 +8,7 @@ use crate::app::App;
 /// Render the password input modal dialog
 pub fn render(frame: &mut Frame, app: &App, area: Rect, ssid: &str) {
     let t = &app.theme;
+    let lang = app.lang();
     let width = 56_u16.min(area.width.saturating_sub(4));
     let height = 8_u16.min(area.height.saturating_sub(4));
 
@@ -23,7 +24,13 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, ssid: &str) {
     let block = Block::default()
         .title(Line::from(vec![
             Span::styled(" 󰌾 ", t.style_accent()),
-            Span::styled(format!("Connect to \"{ssid}\" "), t.style_accent_bold()),
+            Span::styled(
+                format!(
+                    "{} ",
+                    lang.fill("Connect to \"{ssid}\"", &[("ssid", &ssid)])
+                ),
+                t.style_accent_bold(),
+            ),
         ]))
         .borders(Borders::ALL)
         .border_type(t.border_type)
@@ -40,7 +47,7 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, ssid: &str) {
         height: 1,
     };
 
-    let label = Span::styled("Password: ", t.style_dim());
+    let label = Span::styled(format!("{}: ", lang.tr("Password")), t.style_dim());
 
     let password_display = if app.password_visible {
         app.password_input.clone()
@@ -64,11 +71,7 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, ssid: &str) {
     frame.render_widget(Paragraph::new(input_line), inner);
 
     // Show/hide hint
-    let toggle_hint = if app.password_visible {
-        "[Ctrl+H] Hide"
-    } else {
-        "[Ctrl+H] Show"
-    };
+    let toggle_hint = lang.tr(if app.password_visible { "Hide" } else { "Show" });
 
     let hint_area = Rect {
         x: dialog.x + 3,
@@ -79,10 +82,11 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, ssid: &str) {
 
     let hints = Line::from(vec![
         Span::styled("[Enter]", t.style_key_hint()),
-        Span::styled(" Connect  ", t.style_key_desc()),
+        Span::styled(format!(" {}  ", lang.tr("Connect")), t.style_key_desc()),
         Span::styled("[Esc]", t.style_key_hint()),
-        Span::styled(" Cancel  ", t.style_key_desc()),
-        Span::styled(toggle_hint, t.style_key_desc()),
+        Span::styled(format!(" {}  ", lang.tr("Cancel")), t.style_key_desc()),
+        Span::styled("[Ctrl+H]", t.style_key_hint()),
+        Span::styled(format!(" {toggle_hint}"), t.style_key_desc()),
     ]);
 
     frame.render_widget(
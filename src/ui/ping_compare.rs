@@ -0,0 +1,143 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table};
+
+use crate::animation::spinner;
+use crate::app::App;
+use crate::network::diagnostics::{PING_COMPARE_COUNT, PingStats};
+use crate::ui::theme::{Page, Theme};
+
+/// Render the ping comparison: one row per host with live loss and RTT, so
+/// a problem can be pinned on the WiFi, the ISP or the destination
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let width = 76_u16.min(area.width.saturating_sub(4));
+    let height = (app.ping_compare.len() as u16 + 7).min(area.height.saturating_sub(2));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", lang.tr("Compare Ping")),
+            t.style_page_title(Page::Diagnostics),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_page_border(Page::Diagnostics))
+        .style(t.style_default());
+
+    frame.render_widget(block, dialog);
+
+    let inner = Rect {
+        x: dialog.x + 2,
+        y: dialog.y + 1,
+        width: dialog.width.saturating_sub(4),
+        height: dialog.height.saturating_sub(2),
+    };
+
+    let header = Row::new(
+        ["Host", "Sent", "Recv", "Loss", "Avg", "Min", "Max", "Last"]
+            .into_iter()
+            .map(|h| Cell::from(lang.tr(h)).style(t.style_page_title(Page::Diagnostics))),
+    );
+    let rows = app.ping_compare.iter().map(|host| host_row(t, host));
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(16),
+            Constraint::Length(4),
+            Constraint::Length(4),
+            Constraint::Length(5),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(8),
+        ],
+    )
+    .header(header);
+
+    let table_height = inner.height.saturating_sub(2);
+    frame.render_widget(
+        table,
+        Rect {
+            height: table_height,
+            ..inner
+        },
+    );
+
+    let footer = if app.ping_compare_finished() {
+        Line::from(Span::styled(
+            lang.fill(
+                "Done — {count} requests per host",
+                &[("count", &PING_COMPARE_COUNT)],
+            ),
+            t.style_dim(),
+        ))
+    } else {
+        let spin = spinner::spinner_frame(app.animation.tick_count);
+        Line::from(vec![
+            Span::styled(format!("{spin} "), t.style_accent()),
+            Span::styled(
+                lang.fill(
+                    "Pinging every host once a second ({count} requests)…",
+                    &[("count", &PING_COMPARE_COUNT)],
+                ),
+                t.style_dim(),
+            ),
+        ])
+    };
+    frame.render_widget(
+        Paragraph::new(footer),
+        Rect {
+            y: inner.y + table_height + 1,
+            height: 1,
+            ..inner
+        },
+    );
+}
+
+/// One host's row; a failed host shows its error across the stats columns
+fn host_row<'a>(t: &Theme, host: &'a PingStats) -> Row<'a> {
+    if let Some(ref error) = host.error
+        && host.sent == 0
+    {
+        return Row::new(vec![
+            Cell::from(host.host.as_str()),
+            Cell::from(error.as_str()).style(t.style_error()),
+        ]);
+    }
+
+    let loss_style = match host.loss_percent() {
+        Some(loss) if loss >= 100.0 => t.style_error(),
+        Some(loss) if loss > 0.0 => t.style_warning(),
+        Some(_) => t.style_connected(),
+        None => t.style_dim(),
+    };
+    let ms = |rtt: Option<f64>| match rtt {
+        Some(rtt) => Cell::from(format!("{rtt:>5.1} ms")),
+        None => Cell::from("    –").style(t.style_dim()),
+    };
+    Row::new(vec![
+        Cell::from(host.host.as_str()),
+        Cell::from(format!("{:>4}", host.sent)),
+        Cell::from(format!("{:>4}", host.received)),
+        Cell::from(
+            host.loss_percent()
+                .map_or_else(|| "    –".to_string(), |loss| format!("{loss:>4.0}%")),
+        )
+        .style(loss_style),
+        ms(host.avg_rtt()),
+        ms(host.min_rtt),
+        ms(host.max_rtt),
+        ms(host.last_rtt),
+    ])
+}
@@ -0,0 +1,105 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::{App, ProfileField};
+use crate::network::types::ConnectionStatus;
+
+/// Render the advanced connection settings modal; rows with unapplied
+/// edits are marked and show the value they replace
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let ConnectionStatus::Connected(info) = &app.connection_status else {
+        return;
+    };
+    let Some(ref edit) = app.profile_edit else {
+        return;
+    };
+    let pending = edit.changes().len();
+    let t = &app.theme;
+    let lang = app.lang();
+    let width = 60_u16.min(area.width.saturating_sub(4));
+    let height = (ProfileField::ALL.len() as u16 + 6).min(area.height.saturating_sub(2));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let mut title = vec![
+        Span::styled(
+            format!(" {} ", lang.tr("Connection Settings")),
+            t.style_accent_bold(),
+        ),
+        Span::styled(format!("— {} ", info.ssid), t.style_dim()),
+    ];
+    if pending > 0 {
+        title.push(Span::styled(
+            format!("● {} ", lang.tr("modified")),
+            t.style_warning(),
+        ));
+    }
+    let block = Block::default()
+        .title(Line::from(title))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_accent())
+        .style(t.style_default());
+
+    frame.render_widget(block, dialog);
+
+    let mut lines = vec![Line::from(Span::styled(lang.tr("Advanced"), t.style_dim()))];
+    for (i, field) in ProfileField::ALL.iter().enumerate() {
+        let selected = i == app.profile_selected;
+        let (marker, label_style) = if selected {
+            ("▸ ", t.style_selected())
+        } else {
+            ("  ", t.style_default())
+        };
+        let mut row = vec![
+            Span::styled(marker, t.style_accent()),
+            Span::styled(format!("{:<18}", lang.tr(field.label())), label_style),
+        ];
+        if edit.is_field_modified(*field) {
+            row.push(Span::styled(edit.value(*field, lang), t.style_warning()));
+            let was = field.value(&edit.original, lang);
+            row.push(Span::styled(
+                format!("  {}", lang.fill("(was {value})", &[("value", &was)])),
+                t.style_dim(),
+            ));
+        } else {
+            row.push(Span::styled(edit.value(*field, lang), t.style_dim()));
+        }
+        lines.push(Line::from(row));
+    }
+    lines.push(Line::from(""));
+    lines.push(if pending > 0 {
+        Line::from(vec![
+            Span::styled(
+                format!(
+                    "{} — ",
+                    lang.fill("{count} unsaved", &[("count", &pending)])
+                ),
+                t.style_warning(),
+            ),
+            Span::styled("[a]", t.style_key_hint()),
+            Span::styled(format!(" {}  ", lang.tr("Apply")), t.style_key_desc()),
+            Span::styled("[r]", t.style_key_hint()),
+            Span::styled(format!(" {}", lang.tr("Revert")), t.style_key_desc()),
+        ])
+    } else {
+        Line::from(Span::styled(lang.tr("No unsaved changes"), t.style_dim()))
+    });
+
+    let list_area = Rect {
+        x: dialog.x + 3,
+        y: dialog.y + 2,
+        width: dialog.width.saturating_sub(6),
+        height: dialog.height.saturating_sub(3),
+    };
+    frame.render_widget(Paragraph::new(lines), list_area);
+}
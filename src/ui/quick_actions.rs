@@ -0,0 +1,60 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::App;
+
+/// Render the quick actions menu for the selected network
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let Some(net) = app.selected_network() else {
+        return;
+    };
+    let items = app.quick_actions();
+    let width = 52_u16.min(area.width.saturating_sub(4));
+    let height = (items.len() as u16 + 4).min(area.height.saturating_sub(4));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", net.ssid),
+            t.style_accent_bold(),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_accent())
+        .style(t.style_default());
+    let inner = block.inner(dialog);
+    frame.render_widget(block, dialog);
+
+    let mut lines = vec![Line::default()];
+    for (i, item) in items.iter().enumerate() {
+        let selected = i == app.quick_selected;
+        let marker = if selected { "▸" } else { " " };
+        let style = match (selected, item.unavailable.is_some()) {
+            (true, _) => t.style_selected(),
+            (false, true) => t.style_dim(),
+            (false, false) => t.style_default(),
+        };
+        let mut spans = vec![Span::styled(
+            format!(" {marker} {:<22}", lang.tr(item.label)),
+            style,
+        )];
+        if let Some(reason) = item.unavailable {
+            spans.push(Span::styled(lang.tr(reason), t.style_dim()));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
@@ -0,0 +1,99 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use super::details::format_span;
+use super::network_list::truncate_ssid;
+use crate::app::App;
+
+/// Render the recently connected networks, newest first
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let rows = app.recent.as_ref().map_or(1, |r| r.len().max(1));
+    let width = 60_u16.min(area.width.saturating_sub(4));
+    let height = (rows as u16 + 4).min(area.height.saturating_sub(4));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", lang.tr("Recent networks")),
+            t.style_accent_bold(),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_accent())
+        .style(t.style_default());
+    let inner = block.inner(dialog);
+    frame.render_widget(block, dialog);
+
+    let profiles = match &app.recent {
+        None => {
+            let para = Paragraph::new(format!("\n{}", lang.tr("Reading saved profiles…")))
+                .style(t.style_dim())
+                .alignment(Alignment::Center);
+            frame.render_widget(para, inner);
+            return;
+        }
+        Some(profiles) if profiles.is_empty() => {
+            let para = Paragraph::new(format!(
+                "\n{}",
+                lang.tr("No saved network has connected yet")
+            ))
+            .style(t.style_dim())
+            .alignment(Alignment::Center);
+            frame.render_widget(para, inner);
+            return;
+        }
+        Some(profiles) => profiles,
+    };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let mut lines = vec![Line::default()];
+    for (i, profile) in profiles.iter().enumerate() {
+        let selected = i == app.recent_selected;
+        let in_range = app.in_range(&profile.ssid);
+        let marker = if selected { "▸" } else { " " };
+        let style = match (selected, in_range) {
+            (true, _) => t.style_selected(),
+            (false, false) => t.style_dim(),
+            (false, true) => t.style_default(),
+        };
+        let ago = profile
+            .last_connected
+            .map(|at| {
+                let span = format_span(now.saturating_sub(at));
+                lang.fill("{span} ago", &[("span", &span)])
+            })
+            .unwrap_or_default();
+        let mut spans = vec![
+            Span::styled(
+                format!(" {marker} {}", truncate_ssid(&profile.ssid, 26)),
+                style,
+            ),
+            Span::styled(format!("{ago:>10}"), t.style_dim()),
+        ];
+        if !in_range {
+            spans.push(Span::styled(
+                format!("  ({})", lang.tr("not in range")),
+                t.style_dim(),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
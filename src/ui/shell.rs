@@ -0,0 +1,83 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::app::App;
+use crate::network::types::ConnectionStatus;
+
+/// Render the shell escape modal: how to inspect or edit the active profile
+/// outside Nexus
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let ConnectionStatus::Connected(info) = &app.connection_status else {
+        return;
+    };
+    let width = 72_u16.min(area.width.saturating_sub(4));
+    let height = 18_u16.min(area.height.saturating_sub(2));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", lang.tr("Open in Shell")),
+            t.style_accent_bold(),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_accent())
+        .style(t.style_default());
+
+    frame.render_widget(block, dialog);
+
+    let inner = Rect {
+        x: dialog.x + 3,
+        y: dialog.y + 2,
+        width: dialog.width.saturating_sub(6),
+        height: dialog.height.saturating_sub(3),
+    };
+
+    let command = app.nmcli_command().unwrap_or_default();
+    let keyfile = info.profile.keyfile.clone().unwrap_or_else(|| {
+        lang.tr("(unknown — not stored as a keyfile, or not readable)")
+            .to_string()
+    });
+
+    let lines = vec![
+        Line::from(Span::styled(
+            lang.fill(
+                "Settings Nexus can't edit for \"{ssid}\":",
+                &[("ssid", &info.ssid)],
+            ),
+            t.style_dim(),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(lang.tr("Command"), t.style_dim())),
+        Line::from(Span::styled(command, t.style_accent())),
+        Line::from(""),
+        Line::from(Span::styled(lang.tr("Keyfile"), t.style_dim())),
+        Line::from(Span::styled(keyfile, t.style_default())),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(format!("{} ", lang.tr("Share")), t.style_dim()),
+            Span::styled(
+                format!(
+                    "({})",
+                    lang.tr("secrets excluded — give the password separately")
+                ),
+                t.style_warning(),
+            ),
+        ]),
+        Line::from(Span::styled(info.share_command(), t.style_default())),
+    ];
+
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
@@ -0,0 +1,232 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::symbols::Marker;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{
+    Axis, Block, Borders, Cell, Chart, Dataset, GraphType, Paragraph, Row, Table,
+};
+
+use crate::app::App;
+use crate::network::stats::{InterfaceStats, StatsSort, format_bytes, format_rate};
+use crate::ui::theme::{Page, Theme};
+
+/// Render per-interface counters above the focused interface's traffic
+/// graph, in place of the network list
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+
+    let block = Block::default()
+        .title(Line::from(vec![
+            Span::styled(
+                format!(" {} ", lang.tr("Statistics")),
+                t.style_page_title(Page::Statistics),
+            ),
+            Span::styled(format!("({}) ", lang.tr("since launch")), t.style_dim()),
+        ]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_page_border(Page::Statistics))
+        .style(t.style_default());
+
+    if app.interface_stats.is_empty() {
+        let para = Paragraph::new(lang.tr("Reading interface counters…"))
+            .block(block)
+            .style(t.style_dim())
+            .alignment(Alignment::Center);
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let table_height = (app.interface_stats.len() as u16 + 1).min(inner.height / 2);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(table_height),
+            Constraint::Length(1),
+            Constraint::Min(4),
+        ])
+        .split(inner);
+
+    render_table(frame, app, rows[0]);
+    if let Some(stats) = app.interface_stats.get(app.stats_focus) {
+        render_graph(frame, app, stats, rows[2]);
+    }
+}
+
+/// One row per interface: totals and, unless hidden, error/drop counters,
+/// each with its growth since launch
+fn render_table(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let columns = app.interface_columns();
+    let mut titles = vec!["Interface", "RX", "TX"];
+    let mut widths = vec![
+        Constraint::Min(14),
+        Constraint::Length(20),
+        Constraint::Length(20),
+    ];
+    if columns.errors {
+        titles.extend(["RX err", "TX err"]);
+        widths.extend([Constraint::Length(12); 2]);
+    }
+    if columns.drops {
+        titles.extend(["RX drop", "TX drop"]);
+        widths.extend([Constraint::Length(12); 2]);
+    }
+    let sorted = match app.stats_sort {
+        StatsSort::Name => 0,
+        StatsSort::RxRate => 1,
+        StatsSort::TxRate => 2,
+    };
+    let header = Row::new(titles.into_iter().enumerate().map(|(i, h)| {
+        if i == sorted {
+            Cell::from(format!("↓{}", lang.tr(h))).style(t.style_accent_bold())
+        } else {
+            Cell::from(lang.tr(h)).style(t.style_page_title(Page::Statistics))
+        }
+    }));
+
+    let rows = app.interface_stats.iter().enumerate().map(|(i, stats)| {
+        let focused = i == app.stats_focus;
+        let name = if focused {
+            format!("▸ {}", stats.name)
+        } else {
+            format!("  {}", stats.name)
+        };
+        let (Some(now), Some(grown)) = (stats.latest(), stats.since_launch()) else {
+            return Row::new(vec![
+                Cell::from(name),
+                Cell::from(lang.tr("unreadable")).style(t.style_dim()),
+            ]);
+        };
+        let mut cells = vec![
+            Cell::from(name),
+            Cell::from(format!(
+                "{} (+{})",
+                format_bytes(now.rx_bytes),
+                format_bytes(grown.rx_bytes)
+            )),
+            Cell::from(format!(
+                "{} (+{})",
+                format_bytes(now.tx_bytes),
+                format_bytes(grown.tx_bytes)
+            )),
+        ];
+        if columns.errors {
+            cells.push(fault_cell(t, now.rx_errors, grown.rx_errors));
+            cells.push(fault_cell(t, now.tx_errors, grown.tx_errors));
+        }
+        if columns.drops {
+            cells.push(fault_cell(t, now.rx_dropped, grown.rx_dropped));
+            cells.push(fault_cell(t, now.tx_dropped, grown.tx_dropped));
+        }
+        let row = Row::new(cells);
+        if focused {
+            row.style(t.style_selected())
+        } else {
+            row
+        }
+    });
+
+    let table = Table::new(rows, widths).header(header);
+    frame.render_widget(table, area);
+}
+
+/// An error or drop counter; growth since launch is what needs attention
+fn fault_cell(t: &Theme, total: u64, grown: u64) -> Cell<'static> {
+    if grown > 0 {
+        Cell::from(format!("{total} (+{grown})")).style(t.style_warning())
+    } else {
+        Cell::from(total.to_string()).style(t.style_dim())
+    }
+}
+
+/// RX and TX of the focused interface over the selected time range
+fn render_graph(frame: &mut Frame, app: &App, stats: &InterfaceStats, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let range = app.stats_range.samples();
+    let window = |values: &std::collections::VecDeque<f64>| -> Vec<f64> {
+        let skip = values.len().saturating_sub(range);
+        values.iter().skip(skip).copied().collect()
+    };
+    let rx = window(&stats.history.rx);
+    let tx = window(&stats.history.tx);
+    // Keep a floor so an idle link doesn't blow noise up to full height
+    let peak = rx
+        .iter()
+        .chain(&tx)
+        .copied()
+        .fold(0.0, f64::max)
+        .max(1024.0);
+
+    let block = Block::default()
+        .title(Line::from(vec![
+            Span::styled(
+                format!(" {} ", stats.name),
+                t.style_page_title(Page::Statistics),
+            ),
+            Span::styled(
+                format!(
+                    "— {} ",
+                    lang.fill("last {range}", &[("range", &app.stats_range.label())])
+                ),
+                t.style_dim(),
+            ),
+            Span::styled(
+                format!("↓{} ", format_rate(rx.last().copied().unwrap_or_default())),
+                Style::default().fg(t.accent),
+            ),
+            Span::styled(
+                format!("↑{} ", format_rate(tx.last().copied().unwrap_or_default())),
+                Style::default().fg(t.accent2),
+            ),
+        ]))
+        .title_bottom(Line::from(Span::styled(
+            format!(
+                " {} ",
+                lang.fill("peak {rate}", &[("rate", &format_rate(peak))])
+            ),
+            t.style_dim(),
+        )))
+        .borders(Borders::TOP | Borders::BOTTOM)
+        .border_type(t.border_type)
+        .border_style(t.style_border());
+
+    // Right-align the newest sample so the graph scrolls in from the right
+    let points = |values: &[f64]| -> Vec<(f64, f64)> {
+        let offset = range - values.len();
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| ((offset + i) as f64, *v))
+            .collect()
+    };
+    let rx = points(&rx);
+    let tx = points(&tx);
+
+    let datasets = vec![
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(t.accent2))
+            .data(&tx),
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(t.accent))
+            .data(&rx),
+    ];
+
+    let chart = Chart::new(datasets)
+        .block(block)
+        .style(t.style_default())
+        .x_axis(Axis::default().bounds([0.0, (range - 1) as f64]))
+        .y_axis(Axis::default().bounds([0.0, peak]));
+    frame.render_widget(chart, area);
+}
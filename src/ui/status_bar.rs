@@ -1,29 +1,111 @@
+use std::borrow::Cow;
+
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Rect};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::Paragraph;
+use ratatui::widgets::{LineGauge, Paragraph};
 
-use crate::app::{App, AppMode};
+use crate::app::{App, AppMode, ToastLevel};
+use crate::i18n::Language;
 use crate::ui::theme::Theme;
 
 /// Render the bottom status bar with context-sensitive keybinding hints
 pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
+    let lang = app.lang();
+
+    // A pending toast takes precedence over the keybinding hints
+    if let Some(toast) = &app.toast {
+        let style = match toast.level {
+            ToastLevel::Info => t.style_accent(),
+            ToastLevel::Success => t.style_connected(),
+            ToastLevel::Warning => t.style_warning(),
+            ToastLevel::Error => t.style_error(),
+        };
+        // Without color, the level is spelled out
+        let prefix = match toast.level {
+            ToastLevel::Warning if t.mono => format!("{}: ", lang.tr("Warning")),
+            ToastLevel::Error if t.mono => format!("{}: ", lang.tr("Error")),
+            _ => String::new(),
+        };
+        let para = Paragraph::new(Line::from(vec![
+            Span::styled(prefix, style),
+            Span::styled(toast.message.as_str(), style),
+        ]))
+        .alignment(Alignment::Center);
+        frame.render_widget(para, area);
+        return;
+    }
+
+    // While an activation is in flight, replace the hints with a progress strip
+    if matches!(app.mode, AppMode::Connecting)
+        && let Some(ratio) = app.device_state.activation_progress()
+    {
+        render_activation_gauge(frame, app, area, ratio);
+        return;
+    }
+
     let hints = match &app.mode {
         AppMode::Normal | AppMode::Scanning => normal_hints(t),
         AppMode::PasswordInput { .. } => password_hints(t),
         AppMode::Hidden => hidden_hints(t),
         AppMode::Help => help_hints(t),
         AppMode::Search => search_hints(t),
-        AppMode::Connecting | AppMode::Disconnecting => busy_hints(t),
+        AppMode::Input(_) => input_hints(t),
+        AppMode::Confirm(_) => confirm_hints(t),
+        AppMode::Diagnostics => diagnostics_hints(t),
+        AppMode::HttpHeaders => http_headers_hints(t),
+        AppMode::MtuProbe => mtu_probe_hints(t),
+        AppMode::PingCompare => ping_compare_hints(t),
+        AppMode::ShellEscape => shell_hints(t),
+        AppMode::Survey => survey_hints(t),
+        AppMode::Statistics => statistics_hints(t),
+        AppMode::Palette | AppMode::GlobalSearch => palette_hints(t),
+        AppMode::QuickActions => quick_actions_hints(t),
+        AppMode::Recent => recent_hints(t),
+        AppMode::WifiDevices => wifi_devices_hints(t),
+        AppMode::ThemePreview => theme_preview_hints(t),
+        AppMode::IgnoreList => ignore_list_hints(t),
+        AppMode::Events => events_hints(t),
+        AppMode::DetailFocus => detail_focus_hints(t),
+        AppMode::ProfileEdit => profile_hints(t),
+        AppMode::Connecting => connecting_hints(t),
+        AppMode::Disconnecting => busy_hints(t),
         AppMode::Error(_) => error_hints(t),
     };
+    let mut hints = localize(lang, hints);
+
+    // The watchdog badge stays up until the uplink answers again
+    if app.watchdog_alert {
+        hints.insert(
+            0,
+            Span::styled(format!("⚠ {}  ", lang.tr("Uplink down")), t.style_warning()),
+        );
+    }
 
-    let line = Line::from(hints);
+    let line = fit(t, hints, area.width);
     let para = Paragraph::new(line).alignment(Alignment::Center);
     frame.render_widget(para, area);
 }
 
+/// Thin determinate gauge showing which activation stage NM is in
+fn render_activation_gauge(frame: &mut Frame, app: &App, area: Rect, ratio: f64) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let label = format!(
+        " {} · {} ",
+        lang.tr(app.device_state.label()),
+        lang.tr("Esc to cancel")
+    );
+    let gauge = LineGauge::default()
+        .ratio(ratio.clamp(0.0, 1.0))
+        .label(Span::styled(label, t.style_accent_bold()))
+        .filled_style(Style::default().fg(t.accent).bg(t.bg))
+        .unfilled_style(Style::default().fg(t.border).bg(t.bg));
+    frame.render_widget(gauge, area);
+}
+
 fn normal_hints(t: &Theme) -> Vec<Span<'static>> {
     vec![
         key(t, "↑↓/jk"),
@@ -89,14 +171,326 @@ fn search_hints(t: &Theme) -> Vec<Span<'static>> {
     ]
 }
 
+fn input_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "Enter"),
+        desc(t, "Apply "),
+        key(t, "Esc"),
+        desc(t, "Cancel"),
+    ]
+}
+
+fn confirm_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "y"),
+        desc(t, "Confirm "),
+        key(t, "n/Esc"),
+        desc(t, "Cancel"),
+    ]
+}
+
+fn profile_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "↑↓"),
+        desc(t, "Select "),
+        key(t, "Enter"),
+        desc(t, "Edit "),
+        key(t, "a"),
+        desc(t, "Apply "),
+        key(t, "r"),
+        desc(t, "Revert "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn diagnostics_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "r"),
+        desc(t, "Run again "),
+        key(t, "h"),
+        desc(t, "HTTP headers "),
+        key(t, "m"),
+        desc(t, "Path MTU "),
+        key(t, "p"),
+        desc(t, "Ping compare "),
+        key(t, "Tab"),
+        desc(t, "Other tools "),
+        key(t, "c/C"),
+        desc(t, "Clear / all "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn http_headers_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "↑↓/jk"),
+        desc(t, "Scroll "),
+        key(t, "r"),
+        desc(t, "Run again "),
+        key(t, "h"),
+        desc(t, "New URL "),
+        key(t, "Tab"),
+        desc(t, "Other tools "),
+        key(t, "c/C"),
+        desc(t, "Clear / all "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn ping_compare_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "r"),
+        desc(t, "Run again "),
+        key(t, "p"),
+        desc(t, "New hosts "),
+        key(t, "Tab"),
+        desc(t, "Other tools "),
+        key(t, "c/C"),
+        desc(t, "Clear / all "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn mtu_probe_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "r"),
+        desc(t, "Run again "),
+        key(t, "m"),
+        desc(t, "New host "),
+        key(t, "Tab"),
+        desc(t, "Other tools "),
+        key(t, "c/C"),
+        desc(t, "Clear / all "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn survey_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "↑↓/jk"),
+        desc(t, "Navigate "),
+        key(t, "c"),
+        desc(t, "Reset stats "),
+        key(t, "Y/Esc"),
+        desc(t, "Stop survey"),
+    ]
+}
+
+fn statistics_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "←→"),
+        desc(t, "Interface "),
+        key(t, "r"),
+        desc(t, "Time range "),
+        key(t, "S"),
+        desc(t, "Sort "),
+        key(t, "t/Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn palette_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "↑↓"),
+        desc(t, "Select "),
+        key(t, "Enter"),
+        desc(t, "Go "),
+        key(t, "Esc"),
+        desc(t, "Cancel"),
+    ]
+}
+
+fn quick_actions_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "j/k"),
+        desc(t, "Select "),
+        key(t, "Enter"),
+        desc(t, "Run "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn recent_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "j/k"),
+        desc(t, "Select "),
+        key(t, "Enter"),
+        desc(t, "Connect "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn wifi_devices_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "j/k"),
+        desc(t, "Select "),
+        key(t, "Enter"),
+        desc(t, "Use adapter "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn ignore_list_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "j/k"),
+        desc(t, "Select "),
+        key(t, "a"),
+        desc(t, "Add "),
+        key(t, "d"),
+        desc(t, "Delete "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn theme_preview_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "j/k"),
+        desc(t, "Select "),
+        key(t, "y"),
+        desc(t, "Copy value "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn detail_focus_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "↑↓/jk"),
+        desc(t, "Select "),
+        key(t, "y"),
+        desc(t, "Copy value "),
+        key(t, "c"),
+        desc(t, "Copy connect command "),
+        key(t, "n/N"),
+        desc(t, "Nickname / note "),
+        key(t, "Tab/Esc"),
+        desc(t, "Back to list"),
+    ]
+}
+
+fn events_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "c"),
+        desc(t, "Clear "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn shell_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        key(t, "y"),
+        desc(t, "Copy command "),
+        key(t, "p"),
+        desc(t, "Copy keyfile path "),
+        key(t, "s"),
+        desc(t, "Copy share command "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+fn connecting_hints(t: &Theme) -> Vec<Span<'static>> {
+    vec![
+        Span::styled("Connecting…  ", t.style_dim()),
+        key(t, "Esc/d"),
+        desc(t, "Cancel"),
+    ]
+}
+
 fn busy_hints(t: &Theme) -> Vec<Span<'static>> {
     vec![Span::styled("Please wait…", t.style_dim())]
 }
 
 fn error_hints(t: &Theme) -> Vec<Span<'static>> {
-    vec![key(t, "Esc"), desc(t, "Close")]
+    vec![
+        key(t, "↑↓"),
+        desc(t, "Scroll "),
+        key(t, "y"),
+        desc(t, "Copy "),
+        key(t, "Esc"),
+        desc(t, "Close"),
+    ]
+}
+
+/// Translate the hint descriptions, keeping their trailing padding
+fn localize(lang: Language, spans: Vec<Span<'static>>) -> Vec<Span<'static>> {
+    spans
+        .into_iter()
+        .map(|mut span| {
+            if let Cow::Borrowed(text) = span.content {
+                let trimmed = text.trim_end();
+                let translated = lang.tr(trimmed);
+                if translated != trimmed {
+                    span.content = format!("{translated}{}", &text[trimmed.len()..]).into();
+                }
+            }
+            span
+        })
+        .collect()
+}
+
+/// Drop hints from the end until the rest fit in `width`, so a longer
+/// translation or a narrow terminal never cuts a hint in half. A key whose
+/// description was dropped goes with it.
+fn fit(t: &Theme, mut spans: Vec<Span<'static>>, width: u16) -> Line<'static> {
+    let width = usize::from(width);
+    while spans.len() > 1 && spans.iter().map(Span::width).sum::<usize>() > width {
+        spans.pop();
+        if spans.last().is_some_and(|s| s.style == t.style_key_hint()) {
+            spans.pop();
+        }
+    }
+    Line::from(spans)
 }
 
+/// Every hint the status bar can show, for checking the translations
+#[cfg(test)]
+pub fn all_hints(t: &Theme) -> Vec<Span<'static>> {
+    [
+        normal_hints(t),
+        password_hints(t),
+        hidden_hints(t),
+        help_hints(t),
+        search_hints(t),
+        input_hints(t),
+        confirm_hints(t),
+        profile_hints(t),
+        diagnostics_hints(t),
+        http_headers_hints(t),
+        ping_compare_hints(t),
+        mtu_probe_hints(t),
+        survey_hints(t),
+        statistics_hints(t),
+        palette_hints(t),
+        quick_actions_hints(t),
+        recent_hints(t),
+        wifi_devices_hints(t),
+        ignore_list_hints(t),
+        theme_preview_hints(t),
+        detail_focus_hints(t),
+        events_hints(t),
+        shell_hints(t),
+        connecting_hints(t),
+        busy_hints(t),
+        error_hints(t),
+    ]
+    .concat()
+}
+
+/// Labels the status bar translates besides the hints
+#[cfg(test)]
+pub const LABELS: [&str; 4] = ["Warning", "Error", "Uplink down", "Esc to cancel"];
+
 fn key(t: &Theme, k: &'static str) -> Span<'static> {
     Span::styled(format!(" [{k}] "), t.style_key_hint())
 }
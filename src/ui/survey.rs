@@ -0,0 +1,93 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Rect};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState};
+
+use crate::animation::spinner;
+use crate::app::App;
+use crate::ui::theme::Page;
+
+/// Render the site survey table in place of the network list
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let survey = &app.survey;
+    let spin = spinner::spinner_frame(app.animation.tick_count);
+
+    let block = Block::default()
+        .title(Line::from(vec![
+            Span::styled(
+                format!(" {spin} {} ", lang.tr("Site Survey")),
+                t.style_page_title(Page::Survey),
+            ),
+            Span::styled(
+                format!(
+                    "({}) ",
+                    lang.fill(
+                        "{aps} APs, {scans} scans",
+                        &[("aps", &survey.len()), ("scans", &survey.rounds)],
+                    )
+                ),
+                t.style_dim(),
+            ),
+        ]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_border())
+        .style(t.style_default());
+
+    if survey.is_empty() {
+        let para = Paragraph::new(lang.tr("Collecting the first scan…"))
+            .block(block)
+            .style(t.style_dim())
+            .alignment(Alignment::Center);
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let header = Row::new(
+        [
+            "SSID", "BSSID", "Band", "Min", "Avg", "Max", "Samples", "Seen",
+        ]
+        .into_iter()
+        .map(|h| Cell::from(lang.tr(h)).style(t.style_page_title(Page::Survey))),
+    );
+
+    let rows = survey.rows().into_iter().map(|ap| {
+        let avg = ap.avg().round() as u8;
+        let pct =
+            |v: u8| Cell::from(format!("{v:>3}%")).style(Style::default().fg(t.signal_color(v)));
+        Row::new(vec![
+            Cell::from(ap.ssid.clone()),
+            Cell::from(ap.bssid.clone()).style(t.style_dim()),
+            Cell::from(ap.band().to_string()),
+            pct(ap.min),
+            pct(avg),
+            pct(ap.max),
+            Cell::from(format!("{:>4}/{}", ap.samples, survey.rounds)),
+            Cell::from(format!("{}s", ap.last_seen.elapsed().as_secs())).style(t.style_dim()),
+        ])
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(16),
+            Constraint::Length(18),
+            Constraint::Length(8),
+            Constraint::Length(5),
+            Constraint::Length(5),
+            Constraint::Length(5),
+            Constraint::Length(9),
+            Constraint::Length(6),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .row_highlight_style(t.style_selected());
+
+    let mut state = TableState::default();
+    state.select(Some(app.survey_selected));
+    frame.render_stateful_widget(table, area, &mut state);
+}
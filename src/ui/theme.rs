@@ -1,7 +1,8 @@
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::widgets::BorderType;
 
-use crate::config::{Config, ThemeConfig};
+use crate::config::{ColorMode, Config, PageColors, ThemeConfig};
+use crate::network::health::HealthGrade;
 
 // ─── Nerd Font Icons ──────────────────────────────────────────────────────
 // These are glyph constants — not configurable via TOML (they'd break
@@ -16,6 +17,7 @@ pub const ICON_LOCK: &str = "󰌾 ";
 pub const ICON_LOCK_OPEN: &str = "󰴲 ";
 pub const ICON_CONNECTED: &str = " ";
 pub const ICON_SAVED: &str = "★";
+pub const ICON_FAVORITE: &str = "♥";
 pub const ICON_ARROW_RIGHT: &str = " ";
 pub const ICON_HIDDEN: &str = "󰈈 ";
 pub const ICON_SCAN: &str = "󰑐 ";
@@ -28,11 +30,24 @@ pub const PLAIN_LOCK: &str = "[L]";
 pub const PLAIN_LOCK_OPEN: &str = "[O]";
 pub const PLAIN_CONNECTED: &str = "*";
 pub const PLAIN_SAVED: &str = "*";
+pub const PLAIN_FAVORITE: &str = "^";
 pub const PLAIN_ARROW: &str = ">";
 pub const PLAIN_HIDDEN: &str = "[H]";
 
 // ─── Theme (runtime, config-driven) ─────────────────────────────────────
 
+/// A view with its own accent color (`[theme.pages]`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Page {
+    Networks,
+    Details,
+    /// The diagnostics report and the tools opened from it
+    Diagnostics,
+    Survey,
+    Events,
+    Statistics,
+}
+
 /// Runtime theme struct. Built once from Config, then passed around by
 /// reference. All colors come from the user's config.toml.
 #[derive(Debug, Clone)]
@@ -59,8 +74,14 @@ pub struct Theme {
     pub signal_weak: Color,
     pub signal_none: Color,
 
+    // Per-page accents (None = `accent`)
+    pub pages: PageColors,
+
     // Border type
     pub border_type: BorderType,
+
+    /// `color_mode = "mono"`: everything color says has to be said in text
+    pub mono: bool,
 }
 
 impl Theme {
@@ -75,6 +96,12 @@ impl Theme {
             _ => BorderType::Rounded,
         };
 
+        match config.appearance.color_mode {
+            ColorMode::Full => {}
+            ColorMode::HighContrast => return Self::high_contrast(border_type),
+            ColorMode::Mono => return Self::mono(border_type),
+        }
+
         Self {
             bg: t.bg,
             fg: t.fg,
@@ -92,10 +119,77 @@ impl Theme {
             signal_fair: t.signal.fair,
             signal_weak: t.signal.weak,
             signal_none: t.signal.none,
+            pages: t.pages.clone(),
             border_type,
+            mono: false,
         }
     }
 
+    /// Bright ANSI colors on a forced black background, each at least 4.5:1
+    /// against it (WCAG AA) with the usual terminal palettes
+    fn high_contrast(border_type: BorderType) -> Self {
+        Self {
+            bg: Color::Black,
+            fg: Color::White,
+            fg_dim: Color::Gray,
+            accent: Color::LightCyan,
+            accent2: Color::LightMagenta,
+            border: Color::Gray,
+            border_focused: Color::White,
+            connected: Color::LightGreen,
+            warning: Color::LightYellow,
+            error: Color::LightRed,
+            selected_bg: Color::Blue,
+            signal_excellent: Color::LightGreen,
+            signal_good: Color::LightGreen,
+            signal_fair: Color::LightYellow,
+            signal_weak: Color::LightRed,
+            signal_none: Color::LightRed,
+            pages: PageColors::default(),
+            border_type,
+            mono: false,
+        }
+    }
+
+    /// The terminal's own colors throughout
+    fn mono(border_type: BorderType) -> Self {
+        let c = Color::Reset;
+        Self {
+            bg: c,
+            fg: c,
+            fg_dim: c,
+            accent: c,
+            accent2: c,
+            border: c,
+            border_focused: c,
+            connected: c,
+            warning: c,
+            error: c,
+            selected_bg: c,
+            signal_excellent: c,
+            signal_good: c,
+            signal_fair: c,
+            signal_weak: c,
+            signal_none: c,
+            pages: PageColors::default(),
+            border_type,
+            mono: true,
+        }
+    }
+
+    /// Accent color of `page`, falling back to the global accent
+    pub fn page_accent(&self, page: Page) -> Color {
+        let color = match page {
+            Page::Networks => self.pages.networks,
+            Page::Details => self.pages.details,
+            Page::Diagnostics => self.pages.diagnostics,
+            Page::Survey => self.pages.survey,
+            Page::Events => self.pages.events,
+            Page::Statistics => self.pages.statistics,
+        };
+        color.unwrap_or(self.accent)
+    }
+
     // ─── Style Constructors ─────────────────────────────────────────
 
     pub fn style_default(&self) -> Style {
@@ -117,11 +211,29 @@ impl Theme {
             .add_modifier(Modifier::BOLD)
     }
 
-    pub fn style_selected(&self) -> Style {
+    /// Title of `page`, in its own accent
+    pub fn style_page_title(&self, page: Page) -> Style {
         Style::default()
+            .fg(self.page_accent(page))
+            .bg(self.bg)
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Border of a modal page, in its own accent
+    pub fn style_page_border(&self, page: Page) -> Style {
+        Style::default().fg(self.page_accent(page)).bg(self.bg)
+    }
+
+    pub fn style_selected(&self) -> Style {
+        let style = Style::default()
             .fg(self.fg)
             .bg(self.selected_bg)
-            .add_modifier(Modifier::BOLD)
+            .add_modifier(Modifier::BOLD);
+        if self.mono {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        }
     }
 
     pub fn style_connected(&self) -> Style {
@@ -132,7 +244,12 @@ impl Theme {
     }
 
     pub fn style_error(&self) -> Style {
-        Style::default().fg(self.error).bg(self.bg)
+        let style = Style::default().fg(self.error).bg(self.bg);
+        if self.mono {
+            style.add_modifier(Modifier::BOLD)
+        } else {
+            style
+        }
     }
 
     pub fn style_warning(&self) -> Style {
@@ -158,6 +275,15 @@ impl Theme {
         Style::default().fg(self.fg_dim).bg(self.bg)
     }
 
+    /// Connection health: green when good, warning when fair, error when poor
+    pub fn style_health(&self, grade: HealthGrade) -> Style {
+        match grade {
+            HealthGrade::Good => self.style_connected(),
+            HealthGrade::Fair => self.style_warning(),
+            HealthGrade::Poor => self.style_error(),
+        }
+    }
+
     // ─── Signal Helpers ─────────────────────────────────────────────
 
     pub fn signal_color(&self, strength: u8) -> Color {
@@ -185,6 +311,13 @@ impl Theme {
         }
     }
 
+    /// Signal as a five-cell text meter, "[####-]", for when color and
+    /// glyph shape can't carry it
+    pub fn signal_meter(&self, strength: u8) -> String {
+        let filled = (usize::from(strength.min(100)) + 10) / 20;
+        format!("[{}{}]", "#".repeat(filled), "-".repeat(5 - filled))
+    }
+
     pub fn lock_icon(&self, needs_password: bool, nerd_fonts: bool) -> &'static str {
         if nerd_fonts {
             if needs_password {
@@ -0,0 +1,117 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::App;
+use crate::ui::theme::{Page, Theme};
+
+/// Every themed color with the `[theme]` key it comes from, in config order.
+/// Unset page accents show the `accent` they fall back to.
+pub fn swatches(t: &Theme) -> Vec<(&'static str, Color)> {
+    vec![
+        ("bg", t.bg),
+        ("fg", t.fg),
+        ("fg_dim", t.fg_dim),
+        ("accent", t.accent),
+        ("accent2", t.accent2),
+        ("border", t.border),
+        ("border_focused", t.border_focused),
+        ("semantic.connected", t.connected),
+        ("semantic.warning", t.warning),
+        ("semantic.error", t.error),
+        ("semantic.selected_bg", t.selected_bg),
+        ("signal.excellent", t.signal_excellent),
+        ("signal.good", t.signal_good),
+        ("signal.fair", t.signal_fair),
+        ("signal.weak", t.signal_weak),
+        ("signal.none", t.signal_none),
+        ("pages.networks", t.page_accent(Page::Networks)),
+        ("pages.details", t.page_accent(Page::Details)),
+        ("pages.diagnostics", t.page_accent(Page::Diagnostics)),
+        ("pages.survey", t.page_accent(Page::Survey)),
+        ("pages.events", t.page_accent(Page::Events)),
+        ("pages.statistics", t.page_accent(Page::Statistics)),
+    ]
+}
+
+/// A color the way config.toml spells it
+pub fn color_value(color: Color) -> String {
+    match color {
+        Color::Rgb(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+        other => other.to_string().to_lowercase(),
+    }
+}
+
+/// Render a swatch per themed color, then samples of the composite styles
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let swatches = swatches(t);
+    let width = 50_u16.min(area.width.saturating_sub(4));
+    let height = (swatches.len() as u16 + 9).min(area.height.saturating_sub(2));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", lang.tr("Theme")),
+            t.style_accent_bold(),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_accent())
+        .style(t.style_default());
+    let inner = block.inner(dialog);
+    frame.render_widget(block, dialog);
+
+    let mut lines = vec![Line::default()];
+    for (i, (name, color)) in swatches.iter().enumerate() {
+        let selected = i == app.theme_selected;
+        let marker = if selected { "▸" } else { " " };
+        let style = if selected {
+            t.style_selected()
+        } else {
+            t.style_default()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {marker} "), style),
+            Span::styled("████", Style::default().fg(*color)),
+            Span::styled(format!("  {name:<20}"), style),
+            Span::styled(color_value(*color), t.style_dim()),
+        ]));
+    }
+
+    lines.push(Line::default());
+    let samples = [
+        ("Selected row", t.style_selected()),
+        ("Border", t.style_border()),
+        ("Focused border", t.style_border_focused()),
+        ("Dimmed text", t.style_dim()),
+    ];
+    for (label, style) in samples {
+        lines.push(Line::from(vec![
+            Span::raw("   "),
+            Span::styled(format!(" {} ", lang.tr(label)), style),
+        ]));
+    }
+    if t.mono {
+        lines.push(Line::from(Span::styled(
+            format!("   {}", lang.tr("Monochrome: colors are not drawn")),
+            t.style_warning(),
+        )));
+    }
+
+    // Keep the selected swatch in view on short terminals
+    let scroll = (app.theme_selected + 2).saturating_sub(inner.height as usize);
+    let para = Paragraph::new(lines).scroll((scroll as u16, 0));
+    frame.render_widget(para, inner);
+}
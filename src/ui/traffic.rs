@@ -0,0 +1,123 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::symbols::Marker;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Sparkline};
+
+use crate::app::App;
+use crate::config::GraphStyle;
+use crate::network::stats::{HISTORY_LEN, format_rate};
+
+/// Render the rx/tx traffic graph of the active connection
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let history = &app.traffic;
+    let rx_now = history.rx.back().copied().unwrap_or_default();
+    let tx_now = history.tx.back().copied().unwrap_or_default();
+    // Keep a floor so an idle link doesn't blow noise up to full height
+    let peak = history.peak().max(1024.0);
+
+    let block = Block::default()
+        .title(Line::from(vec![
+            Span::styled(format!(" {} ", lang.tr("Traffic")), t.style_accent_bold()),
+            Span::styled(
+                format!("↓{} ", format_rate(rx_now)),
+                Style::default().fg(t.accent),
+            ),
+            Span::styled(
+                format!("↑{} ", format_rate(tx_now)),
+                Style::default().fg(t.accent2),
+            ),
+        ]))
+        .title_bottom(Line::from(Span::styled(
+            format!(
+                " {} ",
+                lang.fill("peak {rate}", &[("rate", &format_rate(peak))])
+            ),
+            t.style_dim(),
+        )))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_border())
+        .style(t.style_default());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    match app.config.appearance.graph_style {
+        GraphStyle::Bars => render_bars(frame, app, inner, peak),
+        GraphStyle::Line => render_line(frame, app, inner, peak),
+    }
+}
+
+/// RX above TX as two block-bar sparklines on a shared scale
+fn render_bars(frame: &mut Frame, app: &App, area: Rect, peak: f64) {
+    let t = &app.theme;
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+        .split(area);
+
+    // Sparkline takes integers; scale to a fixed resolution
+    let scale = |values: &std::collections::VecDeque<f64>| -> Vec<u64> {
+        let skip = values.len().saturating_sub(area.width as usize);
+        values
+            .iter()
+            .skip(skip)
+            .map(|v| (v / peak * 100.0).round() as u64)
+            .collect()
+    };
+    let rx = scale(&app.traffic.rx);
+    let tx = scale(&app.traffic.tx);
+
+    frame.render_widget(
+        Sparkline::default()
+            .data(&rx)
+            .max(100)
+            .style(Style::default().fg(t.accent)),
+        rows[0],
+    );
+    frame.render_widget(
+        Sparkline::default()
+            .data(&tx)
+            .max(100)
+            .style(Style::default().fg(t.accent2)),
+        rows[1],
+    );
+}
+
+/// RX and TX overlaid as braille lines on a shared y-axis
+fn render_line(frame: &mut Frame, app: &App, area: Rect, peak: f64) {
+    let t = &app.theme;
+    // Right-align the newest sample so the graph scrolls in from the right
+    let points = |values: &std::collections::VecDeque<f64>| -> Vec<(f64, f64)> {
+        let offset = HISTORY_LEN - values.len();
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| ((offset + i) as f64, *v))
+            .collect()
+    };
+    let rx = points(&app.traffic.rx);
+    let tx = points(&app.traffic.tx);
+
+    let datasets = vec![
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(t.accent2))
+            .data(&tx),
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(t.accent))
+            .data(&rx),
+    ];
+
+    let chart = Chart::new(datasets)
+        .style(t.style_default())
+        .x_axis(Axis::default().bounds([0.0, (HISTORY_LEN - 1) as f64]))
+        .y_axis(Axis::default().bounds([0.0, peak]));
+    frame.render_widget(chart, area);
+}
@@ -0,0 +1,83 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::App;
+
+/// Render the WiFi adapters to pick from, the one in use marked
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let lang = app.lang();
+    let rows = app.wifi_devices.as_ref().map_or(1, |d| d.len().max(1));
+    let width = 56_u16.min(area.width.saturating_sub(4));
+    let height = (rows as u16 + 4).min(area.height.saturating_sub(4));
+
+    let y_offset = app.animation.dialog_y_offset();
+    let dialog = super::centered_rect_fixed(width, height, area);
+    let dialog = Rect {
+        y: dialog.y.saturating_add(y_offset),
+        ..dialog
+    };
+
+    frame.render_widget(Clear, dialog);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", lang.tr("WiFi adapter")),
+            t.style_accent_bold(),
+        )]))
+        .borders(Borders::ALL)
+        .border_type(t.border_type)
+        .border_style(t.style_accent())
+        .style(t.style_default());
+    let inner = block.inner(dialog);
+    frame.render_widget(block, dialog);
+
+    let devices = match &app.wifi_devices {
+        None => {
+            let para = Paragraph::new(format!("\n{}", lang.tr("Listing WiFi adapters…")))
+                .style(t.style_dim())
+                .alignment(Alignment::Center);
+            frame.render_widget(para, inner);
+            return;
+        }
+        Some(devices) if devices.is_empty() => {
+            let para = Paragraph::new(format!(
+                "\n{}",
+                lang.tr("NetworkManager lists no WiFi adapter")
+            ))
+            .style(t.style_dim())
+            .alignment(Alignment::Center);
+            frame.render_widget(para, inner);
+            return;
+        }
+        Some(devices) => devices,
+    };
+
+    let mut lines = vec![Line::default()];
+    for (i, device) in devices.iter().enumerate() {
+        let selected = i == app.device_selected;
+        let marker = if selected { "▸" } else { " " };
+        let style = if selected {
+            t.style_selected()
+        } else {
+            t.style_default()
+        };
+        let in_use = if device.interface == app.interface_name {
+            format!(" ({})", lang.tr("in use"))
+        } else {
+            String::new()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {marker} {:<16}", format!("{}{in_use}", device.interface)),
+                style,
+            ),
+            Span::styled(format!("{:<12}", device.driver), t.style_dim()),
+            Span::styled(lang.tr(device.state.label()), t.style_dim()),
+        ]));
+    }
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
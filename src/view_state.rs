@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::config::Config;
+
+/// View toggles remembered across launches, persisted as TOML in
+/// ~/.local/state/nexus/view.toml. A toggle never flipped falls back to
+/// its `[appearance]` setting. The default is not backed by a file, so
+/// toggles only last for the session.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ViewState {
+    /// Whether the detail panel beside the network list is shown (`i`)
+    details: Option<bool>,
+    /// Where toggles are written; None keeps them in memory
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl ViewState {
+    /// Load the saved toggles; a missing file means none, and a corrupt one
+    /// is logged and ignored (it is replaced on the next toggle)
+    pub fn load() -> Self {
+        let path = Config::log_dir().join("view.toml");
+        let state = match std::fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text).unwrap_or_else(|e| {
+                warn!("Ignoring unreadable {}: {}", path.display(), e);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        };
+        Self {
+            path: Some(path),
+            ..state
+        }
+    }
+
+    pub fn details(&self) -> Option<bool> {
+        self.details
+    }
+
+    /// Remember the detail panel's visibility and write the file
+    pub fn set_details(&mut self, visible: bool) -> Result<()> {
+        self.details = Some(visible);
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let text = toml::to_string(self).wrap_err("Failed to encode the view state")?;
+        // Same as the favorites: write beside the file and rename over it
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, text)
+            .and_then(|()| std::fs::rename(&tmp, path))
+            .wrap_err_with(|| format!("Failed to write {}", path.display()))
+    }
+}
@@ -0,0 +1,353 @@
+use std::collections::HashSet;
+use std::io::Write as _;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use eyre::Result;
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tracing::debug;
+use zbus::Connection;
+use zbus::zvariant::OwnedObjectPath;
+
+use crate::config::{CliArgs, Config};
+use crate::event::{Event, NetworkCommand};
+use crate::export::rfc3339;
+use crate::network::NetworkBackend;
+use crate::network::manager::NmBackend;
+use crate::network::mock::MockBackend;
+use crate::network::types::{ConnectionInfo, Connectivity, WiFiNetwork};
+
+// ─── Output ─────────────────────────────────────────────────────────────
+// One JSON object per line on stdout, e.g.
+//
+//   {"time":"2024-05-01T13:37:00Z","event":"connected","ssid":"HomeWiFi",...}
+//   {"time":"2024-05-01T13:41:12Z","event":"connectivity","state":"Portal"}
+//   {"time":"2024-05-01T13:42:05Z","event":"ap_appeared","ssid":"Cafe",...}
+
+/// Something a script watching the network may want to react to
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum WatchEvent {
+    Connected {
+        ssid: String,
+        bssid: String,
+        interface: String,
+        ip4: Option<String>,
+        signal: u8,
+    },
+    Disconnected {
+        ssid: String,
+    },
+    Connectivity {
+        state: String,
+    },
+    ApAppeared {
+        ssid: String,
+        signal: u8,
+        security: String,
+    },
+    ApDisappeared {
+        ssid: String,
+    },
+    DeviceAdded {
+        interface: String,
+        path: String,
+    },
+    Resumed,
+    BusLost,
+    BusRestored,
+}
+
+/// A line of output: the event stamped with when it was seen
+#[derive(Serialize)]
+struct Record<'a> {
+    time: String,
+    #[serde(flatten)]
+    event: &'a WatchEvent,
+}
+
+/// `nexus watch`: follow NM's signals without the TUI and print an event
+/// per line until Ctrl+C (or until stdout is closed). The terminal is never
+/// put in raw mode, so stopping is just returning; the listener tasks end
+/// with the runtime.
+pub async fn run(cli: &CliArgs, config: &Config) -> Result<()> {
+    let (event_tx, event_rx) = mpsc::unbounded_channel();
+    let (device_tx, device_rx) = mpsc::unbounded_channel();
+
+    if cli.use_mock_backend() {
+        let backend = Arc::new(MockBackend::new(event_tx.clone()));
+        crate::network::mock::start_demo_listener(event_tx);
+        return watch(backend, event_rx, device_rx, config.scan_interval()).await;
+    }
+
+    let backend = Arc::new(NmBackend::new(config.interface()).await?);
+    let listener = crate::network::signals::start_signal_listener(
+        backend.connection(),
+        backend.device_path(),
+        backend.interface_name(),
+        event_tx.clone(),
+    )
+    .await;
+    crate::network::signals::watch_bus(Arc::clone(&backend), listener, event_tx);
+    watch_devices_added(backend.connection(), device_tx).await;
+    watch(backend, event_rx, device_rx, config.scan_interval()).await
+}
+
+/// What the last refresh saw, to report only what changed
+#[derive(Default)]
+struct State {
+    connection: Option<ConnectionInfo>,
+    connectivity: Option<Connectivity>,
+    ssids: Option<HashSet<String>>,
+}
+
+async fn watch<B: NetworkBackend + 'static>(
+    backend: Arc<B>,
+    mut events: mpsc::UnboundedReceiver<Event>,
+    mut devices: mpsc::UnboundedReceiver<WatchEvent>,
+    scan_interval: Duration,
+) -> Result<()> {
+    let mut state = State::default();
+    // A network that is up when the watch starts is reported right away, so
+    // a script doesn't wait for the next reconnect to act on it
+    if !emit_all(&refresh_connection(backend.as_ref(), &mut state).await) {
+        return Ok(());
+    }
+
+    // NM scans in the background on its own; the watch only reads the access
+    // points it already knows about. The read runs in a task so Ctrl+C isn't
+    // held up behind a slow D-Bus round trip.
+    let mut scans = tokio::time::interval(scan_interval.max(Duration::from_secs(1)));
+    let (scan_tx, mut scan_rx) = mpsc::unbounded_channel();
+    let mut reading = false;
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    loop {
+        let out = tokio::select! {
+            _ = &mut ctrl_c => return Ok(()),
+            _ = scans.tick() => {
+                if !reading {
+                    reading = true;
+                    let backend = Arc::clone(&backend);
+                    let tx = scan_tx.clone();
+                    tokio::spawn(async move {
+                        let _ = tx.send(backend.access_points().await);
+                    });
+                }
+                Vec::new()
+            }
+            Some(networks) = scan_rx.recv() => {
+                reading = false;
+                compare_networks(networks, &mut state)
+            }
+            Some(event) = devices.recv() => vec![event],
+            event = events.recv() => match event {
+                None => return Ok(()),
+                // Device state changes reach the connection before the
+                // debounced property refresh does
+                Some(
+                    Event::Command(NetworkCommand::RefreshConnection)
+                    | Event::DeviceStateChanged(_),
+                ) => refresh_connection(backend.as_ref(), &mut state).await,
+                Some(Event::Resumed) => vec![WatchEvent::Resumed],
+                Some(Event::BusLost) => vec![WatchEvent::BusLost],
+                Some(Event::BusRestored) => vec![WatchEvent::BusRestored],
+                Some(_) => Vec::new(),
+            },
+        };
+        if !emit_all(&out) {
+            return Ok(());
+        }
+    }
+}
+
+/// Compare the active connection and NM's connectivity with the last look
+async fn refresh_connection<B: NetworkBackend>(backend: &B, state: &mut State) -> Vec<WatchEvent> {
+    let mut out = Vec::new();
+    let connection = match backend.current_connection().await {
+        Ok(connection) => connection,
+        Err(e) => {
+            debug!("Watch: connection refresh failed: {:#}", e);
+            return out;
+        }
+    };
+
+    let old = state.connection.as_ref().map(|c| c.ssid.as_str());
+    let new = connection.as_ref().map(|c| c.ssid.as_str());
+    if old != new {
+        if let Some(ssid) = old {
+            out.push(WatchEvent::Disconnected {
+                ssid: ssid.to_string(),
+            });
+        }
+        if let Some(info) = &connection {
+            out.push(WatchEvent::Connected {
+                ssid: info.ssid.clone(),
+                bssid: info.bssid.clone(),
+                interface: info.interface.clone(),
+                ip4: info.ip4.clone(),
+                signal: info.signal,
+            });
+        }
+    }
+    state.connection = connection;
+
+    if let Ok(connectivity) = backend.connectivity().await
+        && state.connectivity.replace(connectivity) != Some(connectivity)
+    {
+        out.push(WatchEvent::Connectivity {
+            state: connectivity.to_string(),
+        });
+    }
+    out
+}
+
+/// Report SSIDs that came into or went out of range since the last read of
+/// NM's access points. The first read only sets the baseline; `nexus scan`
+/// is for listing what's there.
+fn compare_networks(networks: Result<Vec<WiFiNetwork>>, state: &mut State) -> Vec<WatchEvent> {
+    let networks = match networks {
+        Ok(networks) => networks,
+        Err(e) => {
+            debug!("Watch: reading access points failed: {:#}", e);
+            return Vec::new();
+        }
+    };
+    // One entry per BSSID: keep the strongest of each SSID
+    let mut visible: Vec<&WiFiNetwork> = networks.iter().filter(|n| !n.ssid.is_empty()).collect();
+    visible.sort_by_key(|n| std::cmp::Reverse(n.signal_strength));
+    let mut ssids = HashSet::new();
+    visible.retain(|n| ssids.insert(n.ssid.clone()));
+
+    let Some(previous) = state.ssids.replace(ssids.clone()) else {
+        return Vec::new();
+    };
+    let mut out: Vec<WatchEvent> = visible
+        .iter()
+        .filter(|n| !previous.contains(&n.ssid))
+        .map(|n| WatchEvent::ApAppeared {
+            ssid: n.ssid.clone(),
+            signal: n.signal_strength,
+            security: n.security.to_string(),
+        })
+        .collect();
+    let mut gone: Vec<&String> = previous.difference(&ssids).collect();
+    gone.sort();
+    out.extend(
+        gone.into_iter()
+            .map(|ssid| WatchEvent::ApDisappeared { ssid: ssid.clone() }),
+    );
+    out
+}
+
+/// Report network devices NM adds (a USB adapter plugged in, say)
+async fn watch_devices_added(conn: Connection, tx: mpsc::UnboundedSender<WatchEvent>) {
+    use futures::StreamExt;
+
+    let signals = async {
+        let proxy = zbus::Proxy::new(
+            &conn,
+            "org.freedesktop.NetworkManager",
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.NetworkManager",
+        )
+        .await?;
+        proxy.receive_signal("DeviceAdded").await
+    };
+    let mut stream = match signals.await {
+        Ok(stream) => stream,
+        Err(e) => {
+            debug!("No DeviceAdded signals ({})", e);
+            return;
+        }
+    };
+    tokio::spawn(async move {
+        while let Some(msg) = stream.next().await {
+            let Ok(path) = msg.body().deserialize::<OwnedObjectPath>() else {
+                continue;
+            };
+            let interface = device_interface(&conn, &path).await.unwrap_or_default();
+            let event = WatchEvent::DeviceAdded {
+                interface,
+                path: path.to_string(),
+            };
+            if tx.send(event).is_err() {
+                return;
+            }
+        }
+    });
+}
+
+async fn device_interface(conn: &Connection, path: &OwnedObjectPath) -> zbus::Result<String> {
+    zbus::Proxy::new(
+        conn,
+        "org.freedesktop.NetworkManager",
+        path.as_str(),
+        "org.freedesktop.NetworkManager.Device",
+    )
+    .await?
+    .get_property("Interface")
+    .await
+}
+
+/// Print events as JSON lines. Returns false once stdout is gone (the
+/// reading end of a pipe closed), which ends the watch.
+fn emit_all(events: &[WatchEvent]) -> bool {
+    let mut stdout = std::io::stdout().lock();
+    for event in events {
+        let record = Record {
+            time: rfc3339(SystemTime::now()),
+            event,
+        };
+        let Ok(line) = serde_json::to_string(&record) else {
+            continue;
+        };
+        if writeln!(stdout, "{line}")
+            .and_then(|()| stdout.flush())
+            .is_err()
+        {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ap(ssid: &str, signal: u8) -> WiFiNetwork {
+        WiFiNetwork::test(ssid, signal)
+    }
+
+    fn names(events: &[WatchEvent]) -> Vec<String> {
+        events
+            .iter()
+            .map(|e| match e {
+                WatchEvent::ApAppeared { ssid, signal, .. } => format!("+{ssid} {signal}"),
+                WatchEvent::ApDisappeared { ssid } => format!("-{ssid}"),
+                other => format!("{other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn first_read_is_only_the_baseline() {
+        let mut state = State::default();
+        assert!(compare_networks(Ok(vec![ap("Home", 70)]), &mut state).is_empty());
+        assert!(compare_networks(Err(eyre::eyre!("no bus")), &mut state).is_empty());
+        assert!(compare_networks(Ok(vec![ap("Home", 60)]), &mut state).is_empty());
+    }
+
+    #[test]
+    fn reports_each_ssid_once_across_bssids() {
+        let mut state = State::default();
+        compare_networks(Ok(vec![ap("Home", 70), ap("Old", 30)]), &mut state);
+
+        let aps = vec![ap("Home", 70), ap("Cafe", 20), ap("", 50), ap("Cafe", 55)];
+        let events = compare_networks(Ok(aps), &mut state);
+        assert_eq!(names(&events), ["+Cafe 55", "-Old"]);
+    }
+}